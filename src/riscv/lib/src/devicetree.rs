@@ -102,6 +102,9 @@ pub fn generate_custom(
                 fdt.property_u32("reg", 0x0)?;
                 fdt.property_string("status", "okay")?;
                 fdt.property_string("compatible", "riscv")?;
+
+                // Size of the blocks zeroed by `cbo.zero`
+                fdt.property_u32("riscv,cboz-block-size", memory::CACHE_BLOCK_SIZE as u32)?;
             });
         });
     });
@@ -13,13 +13,18 @@ pub(crate) mod value;
 
 use arithmetic::Arithmetic;
 use comparable::Comparable;
+use rustc_apfloat::ieee::Double;
+use rustc_apfloat::ieee::Single;
 
 pub use self::value::StoreLoadInt;
+
 use crate::instruction_context::value::PhiValue;
 use crate::interpreter::atomics::ReservationSetOption;
 use crate::interpreter::atomics::reset_reservation_set;
+use crate::interpreter::float::f_classify;
 use crate::machine_state::MachineCoreState;
 use crate::machine_state::ProgramCounterUpdate;
+use crate::machine_state::csregisters::CSRegister;
 use crate::machine_state::instruction::Args;
 use crate::machine_state::memory::Address;
 use crate::machine_state::memory::BadMemoryAccess;
@@ -32,6 +37,7 @@ use crate::machine_state::registers::XRegister;
 use crate::machine_state::registers::XValue;
 use crate::machine_state::registers::XValue32;
 use crate::parser::XRegisterParsed;
+use crate::parser::instruction::InstrRoundingMode;
 use crate::parser::instruction::InstrWidth;
 use crate::parser::split_x0;
 use crate::state_backend::ManagerReadWrite;
@@ -57,7 +63,7 @@ pub(crate) trait ICB {
     /// A 64-bit floating-point value stored in [`FRegisters`].
     ///
     /// [`FRegisters`]: crate::machine_state::registers::FRegisters
-    type FValue;
+    type FValue: Copy;
 
     /// Perform a read to a [`NonZeroXRegister`], with the given value.
     /// This is a specialized version of `xregister_read` that is only used for
@@ -75,12 +81,93 @@ pub(crate) trait ICB {
     /// Construct an [`ICB::XValue32`] from an `imm: i32`.
     fn xvalue32_of_imm(&mut self, imm: i32) -> Self::XValue32;
 
-    #[expect(unused, reason = "Will Be Used Soon™")]
+    /// Perform a read of an [`FRegister`].
     fn fregister_read(&mut self, reg: FRegister) -> Self::FValue;
 
-    #[expect(unused, reason = "Will Be Used Soon™")]
+    /// Perform a write to an [`FRegister`], with the given value.
     fn fregister_write(&mut self, reg: FRegister, value: Self::FValue);
 
+    /// Reinterpret the bits of an [`ICB::FValue`] as an [`ICB::XValue`].
+    fn fvalue_to_xvalue(&mut self, value: Self::FValue) -> Self::XValue;
+
+    /// Reinterpret the bits of an [`ICB::XValue`] as an [`ICB::FValue`].
+    fn xvalue_to_fvalue(&mut self, value: Self::XValue) -> Self::FValue;
+
+    /// Perform the rounding floating-point operation `op` over values of the given `format`.
+    ///
+    /// Operands that are not used by `op` are ignored. The result is rounded according to `rm`,
+    /// and any exceptions raised are accrued in `fflags`.
+    ///
+    /// Raises [`Exception::IllegalInstruction`] on an invalid rounding mode.
+    fn f_arith(
+        &mut self,
+        format: FloatFormat,
+        op: FArith,
+        rs1: Self::FValue,
+        rs2: Self::FValue,
+        rs3: Self::FValue,
+        rm: InstrRoundingMode,
+    ) -> Self::IResult<Self::FValue>;
+
+    /// Perform the exact (non-rounding) floating-point operation `op` over two values of the
+    /// given `format`.
+    fn f_binary(
+        &mut self,
+        format: FloatFormat,
+        op: FBinary,
+        lhs: Self::FValue,
+        rhs: Self::FValue,
+    ) -> Self::FValue;
+
+    /// Compare two floating-point values of the given `format`, returning `1` if the comparison
+    /// holds and `0` otherwise.
+    fn f_compare(
+        &mut self,
+        format: FloatFormat,
+        op: FCompare,
+        lhs: Self::FValue,
+        rhs: Self::FValue,
+    ) -> Self::XValue;
+
+    /// Classify a floating-point value of the given `format`, returning the 10-bit mask
+    /// produced by `FCLASS.*`.
+    fn f_classify(&mut self, format: FloatFormat, value: Self::FValue) -> Self::XValue;
+
+    /// Convert an integer of the given `int` format to a floating-point value of `format`.
+    ///
+    /// Raises [`Exception::IllegalInstruction`] on an invalid rounding mode.
+    fn f_from_int(
+        &mut self,
+        format: FloatFormat,
+        int: FIntFormat,
+        value: Self::XValue,
+        rm: InstrRoundingMode,
+    ) -> Self::IResult<Self::FValue>;
+
+    /// Convert a floating-point value of `format` to an integer of the given `int` format.
+    ///
+    /// The result is sign-extended to the full width of an [`ICB::XValue`].
+    ///
+    /// Raises [`Exception::IllegalInstruction`] on an invalid rounding mode.
+    fn f_to_int(
+        &mut self,
+        format: FloatFormat,
+        int: FIntFormat,
+        value: Self::FValue,
+        rm: InstrRoundingMode,
+    ) -> Self::IResult<Self::XValue>;
+
+    /// Convert a floating-point value between the `from` and `to` formats.
+    ///
+    /// Raises [`Exception::IllegalInstruction`] on an invalid rounding mode.
+    fn f_convert(
+        &mut self,
+        from: FloatFormat,
+        to: FloatFormat,
+        value: Self::FValue,
+        rm: InstrRoundingMode,
+    ) -> Self::IResult<Self::FValue>;
+
     /// Perform a read of the program counter.
     fn pc_read(&mut self) -> Self::XValue;
 
@@ -100,7 +187,6 @@ pub(crate) trait ICB {
     fn extend_signed(&mut self, value: Self::XValue32) -> Self::XValue;
 
     /// Zero-extend an [`XValue32`] to an [`XValue`].
-    #[expect(dead_code, reason = "Will Be Used Soon™")]
     fn extend_unsigned(&mut self, value: Self::XValue32) -> Self::XValue;
 
     /// Multiply two [`XValue`] values and return the high 64 bits of the result, with
@@ -160,10 +246,11 @@ pub(crate) trait ICB {
     fn err_illegal_instruction<In>(&mut self) -> Self::IResult<In>;
 
     /// Raise an [`Exception::StoreAMOAccessFault`] error if `address` is not
-    /// aligned to the given [`LoadStoreWidth`].
-    fn atomic_access_fault_guard<V: StoreLoadInt>(
+    /// a multiple of `alignment` bytes.
+    fn atomic_access_fault_guard_aligned(
         &mut self,
         address: Self::XValue,
+        alignment: u64,
         reservation_set_option: ReservationSetOption,
     ) -> Self::IResult<()>;
 
@@ -205,6 +292,29 @@ pub(crate) trait ICB {
     /// Read the reservation set start address.
     fn reservation_set_read(&mut self) -> Self::XValue;
 
+    /// Read the value of a [`CSRegister`].
+    fn csregister_read(&mut self, csr: CSRegister) -> Self::XValue;
+
+    /// Write a value to a [`CSRegister`].
+    ///
+    /// The caller is responsible for checking that `csr` is writable.
+    fn csregister_write(&mut self, csr: CSRegister, value: Self::XValue);
+
+    /// Replace the value of a [`CSRegister`], returning the previous value.
+    ///
+    /// The caller is responsible for checking that `csr` is writable.
+    fn csregister_replace(&mut self, csr: CSRegister, value: Self::XValue) -> Self::XValue;
+
+    /// Set the given bits of a [`CSRegister`], returning the previous value.
+    ///
+    /// The caller is responsible for checking that `csr` is writable.
+    fn csregister_set_bits(&mut self, csr: CSRegister, bits: Self::XValue) -> Self::XValue;
+
+    /// Clear the given bits of a [`CSRegister`], returning the previous value.
+    ///
+    /// The caller is responsible for checking that `csr` is writable.
+    fn csregister_clear_bits(&mut self, csr: CSRegister, bits: Self::XValue) -> Self::XValue;
+
     // ----------------
     // Provided Methods
     // ----------------
@@ -227,6 +337,16 @@ pub(crate) trait ICB {
             self.xregister_write_nz(reg, value)
         }
     }
+
+    /// Raise an [`Exception::StoreAMOAccessFault`] error if `address` is not
+    /// aligned to the given [`LoadStoreWidth`].
+    fn atomic_access_fault_guard<V: StoreLoadInt>(
+        &mut self,
+        address: Self::XValue,
+        reservation_set_option: ReservationSetOption,
+    ) -> Self::IResult<()> {
+        self.atomic_access_fault_guard_aligned(address, V::WIDTH as u64, reservation_set_option)
+    }
 }
 
 impl<MC: MemoryConfig, M: ManagerReadWrite> ICB for MachineCoreState<MC, M> {
@@ -273,6 +393,120 @@ impl<MC: MemoryConfig, M: ManagerReadWrite> ICB for MachineCoreState<MC, M> {
         self.hart.fregisters.write(reg, value)
     }
 
+    #[inline(always)]
+    fn fvalue_to_xvalue(&mut self, value: Self::FValue) -> Self::XValue {
+        value.into()
+    }
+
+    #[inline(always)]
+    fn xvalue_to_fvalue(&mut self, value: Self::XValue) -> Self::FValue {
+        value.into()
+    }
+
+    #[inline(always)]
+    fn f_arith(
+        &mut self,
+        format: FloatFormat,
+        op: FArith,
+        rs1: Self::FValue,
+        rs2: Self::FValue,
+        rs3: Self::FValue,
+        rm: InstrRoundingMode,
+    ) -> Self::IResult<Self::FValue> {
+        match format {
+            FloatFormat::Single => self.hart.f_arith::<Single>(op, rs1, rs2, rs3, rm),
+            FloatFormat::Double => self.hart.f_arith::<Double>(op, rs1, rs2, rs3, rm),
+        }
+    }
+
+    #[inline(always)]
+    fn f_binary(
+        &mut self,
+        format: FloatFormat,
+        op: FBinary,
+        lhs: Self::FValue,
+        rhs: Self::FValue,
+    ) -> Self::FValue {
+        match format {
+            FloatFormat::Single => self.hart.f_binary::<Single>(op, lhs, rhs),
+            FloatFormat::Double => self.hart.f_binary::<Double>(op, lhs, rhs),
+        }
+    }
+
+    #[inline(always)]
+    fn f_compare(
+        &mut self,
+        format: FloatFormat,
+        op: FCompare,
+        lhs: Self::FValue,
+        rhs: Self::FValue,
+    ) -> Self::XValue {
+        match format {
+            FloatFormat::Single => self.hart.f_compare::<Single>(op, lhs, rhs),
+            FloatFormat::Double => self.hart.f_compare::<Double>(op, lhs, rhs),
+        }
+    }
+
+    #[inline(always)]
+    fn f_classify(&mut self, format: FloatFormat, value: Self::FValue) -> Self::XValue {
+        match format {
+            FloatFormat::Single => f_classify::<Single>(value),
+            FloatFormat::Double => f_classify::<Double>(value),
+        }
+    }
+
+    #[inline(always)]
+    fn f_from_int(
+        &mut self,
+        format: FloatFormat,
+        int: FIntFormat,
+        value: Self::XValue,
+        rm: InstrRoundingMode,
+    ) -> Self::IResult<Self::FValue> {
+        match format {
+            FloatFormat::Single => self.hart.f_from_int::<Single>(int, value, rm),
+            FloatFormat::Double => self.hart.f_from_int::<Double>(int, value, rm),
+        }
+    }
+
+    #[inline(always)]
+    fn f_to_int(
+        &mut self,
+        format: FloatFormat,
+        int: FIntFormat,
+        value: Self::FValue,
+        rm: InstrRoundingMode,
+    ) -> Self::IResult<Self::XValue> {
+        match format {
+            FloatFormat::Single => self.hart.f_to_int::<Single>(int, value, rm),
+            FloatFormat::Double => self.hart.f_to_int::<Double>(int, value, rm),
+        }
+    }
+
+    #[inline(always)]
+    fn f_convert(
+        &mut self,
+        from: FloatFormat,
+        to: FloatFormat,
+        value: Self::FValue,
+        rm: InstrRoundingMode,
+    ) -> Self::IResult<Self::FValue> {
+        match (from, to) {
+            (FloatFormat::Single, FloatFormat::Single) => {
+                self.hart.f_convert::<Single, Single>(value, rm)
+            }
+            (FloatFormat::Single, FloatFormat::Double) => {
+                self.hart.f_convert::<Single, Double>(value, rm)
+            }
+            (FloatFormat::Double, FloatFormat::Single) => {
+                self.hart.f_convert::<Double, Single>(value, rm)
+            }
+            (FloatFormat::Double, FloatFormat::Double) => {
+                self.hart.f_convert::<Double, Double>(value, rm)
+            }
+        }
+    }
+
     #[inline(always)]
     fn pc_read(&mut self) -> Self::XValue {
         self.hart.pc.read()
@@ -359,13 +593,14 @@ impl<MC: MemoryConfig, M: ManagerReadWrite> ICB for MachineCoreState<MC, M> {
     }
 
     #[inline(always)]
-    fn atomic_access_fault_guard<V: StoreLoadInt>(
+    fn atomic_access_fault_guard_aligned(
         &mut self,
         address: Address,
+        alignment: u64,
         reservation_set_option: ReservationSetOption,
     ) -> Self::IResult<()> {
-        let width = self.xvalue_of_imm(V::WIDTH as i64);
-        let remainder = address.modulus_unsigned(width, self);
+        let alignment = self.xvalue_of_imm(alignment as i64);
+        let remainder = address.modulus_unsigned(alignment, self);
         let zero = self.xvalue_of_imm(0);
 
         if remainder.compare(zero, Predicate::NotEqual, self) {
@@ -441,6 +676,31 @@ impl<MC: MemoryConfig, M: ManagerReadWrite> ICB for MachineCoreState<MC, M> {
     fn reservation_set_read(&mut self) -> Self::XValue {
         self.hart.reservation_set.start_addr.read()
     }
+
+    #[inline(always)]
+    fn csregister_read(&mut self, csr: CSRegister) -> Self::XValue {
+        self.hart.csregisters.read(csr)
+    }
+
+    #[inline(always)]
+    fn csregister_write(&mut self, csr: CSRegister, value: Self::XValue) {
+        self.hart.csregisters.write(csr, value)
+    }
+
+    #[inline(always)]
+    fn csregister_replace(&mut self, csr: CSRegister, value: Self::XValue) -> Self::XValue {
+        self.hart.csregisters.replace(csr, value)
+    }
+
+    #[inline(always)]
+    fn csregister_set_bits(&mut self, csr: CSRegister, bits: Self::XValue) -> Self::XValue {
+        self.hart.csregisters.set_bits(csr, bits).repr()
+    }
+
+    #[inline(always)]
+    fn csregister_clear_bits(&mut self, csr: CSRegister, bits: Self::XValue) -> Self::XValue {
+        self.hart.csregisters.clear_bits(csr, bits).repr()
+    }
 }
 
 /// Operators for producing a boolean from two values.
@@ -463,6 +723,12 @@ pub enum Shift {
     RightUnsigned,
     /// Arithmetic right shift. Sign-bits (ones) are shifted into the most significant bits.
     RightSigned,
+    /// Left rotation. Bits shifted out of the most significant bits are shifted into the
+    /// least significant bits.
+    RotateLeft,
+    /// Right rotation. Bits shifted out of the least significant bits are shifted into the
+    /// most significant bits.
+    RotateRight,
 }
 
 /// The type of X64 mul_high operation to perform.
@@ -472,6 +738,101 @@ pub enum MulHighType {
     SignedUnsigned,
 }
 
+/// Floating-point formats that the [`ICB`] may operate over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatFormat {
+    /// IEEE 754 single-precision, NaN-boxed in an [`FValue`].
+    Single,
+    /// IEEE 754 double-precision.
+    Double,
+}
+
+/// Floating-point operations that round their result.
+///
+/// **NB** This type may be passed over C-FFI. See [state_access] for more
+/// information.
+///
+/// [state_access]: crate::jit::state_access
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FArith {
+    /// `rs1 + rs2`
+    Add = 0,
+    /// `rs1 - rs2`
+    Sub = 1,
+    /// `rs1 x rs2`
+    Mul = 2,
+    /// `rs1 / rs2`
+    Div = 3,
+    /// `sqrt(rs1)`
+    Sqrt = 4,
+    /// `(rs1 x rs2) + rs3`
+    MulAdd = 5,
+    /// `(rs1 x rs2) - rs3`
+    MulSub = 6,
+    /// `-(rs1 x rs2) + rs3`
+    NegMulSub = 7,
+    /// `-(rs1 x rs2) - rs3`
+    NegMulAdd = 8,
+}
+
+/// Floating-point operations over two values, which never round their result.
+///
+/// **NB** This type may be passed over C-FFI. See [state_access] for more
+/// information.
+///
+/// [state_access]: crate::jit::state_access
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FBinary {
+    /// The smaller of `lhs` and `rhs`, where `-0.0 < +0.0`.
+    Min = 0,
+    /// The larger of `lhs` and `rhs`, where `-0.0 < +0.0`.
+    Max = 1,
+    /// `lhs` with the sign bit of `rhs`.
+    SignInject = 2,
+    /// `lhs` with the negated sign bit of `rhs`.
+    SignInjectNegate = 3,
+    /// `lhs` with the sign bit set to the XOR of the sign bits of `lhs` and `rhs`.
+    SignInjectXor = 4,
+}
+
+/// Comparisons between floating-point values.
+///
+/// **NB** This type may be passed over C-FFI. See [state_access] for more
+/// information.
+///
+/// [state_access]: crate::jit::state_access
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FCompare {
+    /// Quiet equality comparison.
+    Equal = 0,
+    /// Signalling less-than comparison.
+    LessThan = 1,
+    /// Signalling less-than-or-equal comparison.
+    LessThanOrEqual = 2,
+}
+
+/// Integer formats that floating-point values may be converted to or from.
+///
+/// **NB** This type may be passed over C-FFI. See [state_access] for more
+/// information.
+///
+/// [state_access]: crate::jit::state_access
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FIntFormat {
+    /// Signed 32-bit integer (`W`).
+    Word = 0,
+    /// Unsigned 32-bit integer (`WU`).
+    WordUnsigned = 1,
+    /// Signed 64-bit integer (`L`).
+    Long = 2,
+    /// Unsigned 64-bit integer (`LU`).
+    LongUnsigned = 3,
+}
+
 /// Supported value widths for loading from/storing to main memory for XRegisters.
 ///
 /// **NB** This type may be passed over C-FFI. See [state_access] for more
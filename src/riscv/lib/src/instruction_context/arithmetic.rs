@@ -60,6 +60,27 @@ pub trait Arithmetic<I: ICB + ?Sized>: Copy {
 
     /// Return the signed minimum of two **XValues**.
     fn min_signed(self, other: Self, icb: &mut I) -> Self;
+
+    /// Return the unsigned minimum of two **XValues**.
+    fn min_unsigned(self, other: Self, icb: &mut I) -> Self;
+
+    /// Return the signed maximum of two **XValues**.
+    fn max_signed(self, other: Self, icb: &mut I) -> Self;
+
+    /// Return the unsigned maximum of two **XValues**.
+    fn max_unsigned(self, other: Self, icb: &mut I) -> Self;
+
+    /// Count the number of leading zero bits of the **XValue**.
+    fn leading_zeros(self, icb: &mut I) -> Self;
+
+    /// Count the number of trailing zero bits of the **XValue**.
+    fn trailing_zeros(self, icb: &mut I) -> Self;
+
+    /// Count the number of bits set in the **XValue**.
+    fn count_ones(self, icb: &mut I) -> Self;
+
+    /// Reverse the byte order of the **XValue**.
+    fn swap_bytes(self, icb: &mut I) -> Self;
 }
 
 impl<I: ICB> Arithmetic<I> for XValue {
@@ -104,6 +125,8 @@ impl<I: ICB> Arithmetic<I> for XValue {
             Shift::Left => self << amount,
             Shift::RightUnsigned => self >> amount,
             Shift::RightSigned => (self as i64 >> amount) as Self,
+            Shift::RotateLeft => self.rotate_left(amount as u32),
+            Shift::RotateRight => self.rotate_right(amount as u32),
         }
     }
 
@@ -118,6 +141,34 @@ impl<I: ICB> Arithmetic<I> for XValue {
     fn min_signed(self, other: Self, _: &mut I) -> Self {
         (self as i64).min(other as i64) as Self
     }
+
+    fn min_unsigned(self, other: Self, _: &mut I) -> Self {
+        self.min(other)
+    }
+
+    fn max_signed(self, other: Self, _: &mut I) -> Self {
+        (self as i64).max(other as i64) as Self
+    }
+
+    fn max_unsigned(self, other: Self, _: &mut I) -> Self {
+        self.max(other)
+    }
+
+    fn leading_zeros(self, _: &mut I) -> Self {
+        self.leading_zeros() as Self
+    }
+
+    fn trailing_zeros(self, _: &mut I) -> Self {
+        self.trailing_zeros() as Self
+    }
+
+    fn count_ones(self, _: &mut I) -> Self {
+        self.count_ones() as Self
+    }
+
+    fn swap_bytes(self, _: &mut I) -> Self {
+        self.swap_bytes()
+    }
 }
 
 impl<I: ICB> Arithmetic<I> for XValue32 {
@@ -162,6 +213,8 @@ impl<I: ICB> Arithmetic<I> for XValue32 {
             Shift::Left => self << amount,
             Shift::RightUnsigned => self >> amount,
             Shift::RightSigned => (self as i32 >> amount) as Self,
+            Shift::RotateLeft => self.rotate_left(amount),
+            Shift::RotateRight => self.rotate_right(amount),
         }
     }
 
@@ -176,4 +229,32 @@ impl<I: ICB> Arithmetic<I> for XValue32 {
     fn min_signed(self, other: Self, _: &mut I) -> Self {
         (self as i32).min(other as i32) as Self
     }
+
+    fn min_unsigned(self, other: Self, _: &mut I) -> Self {
+        self.min(other)
+    }
+
+    fn max_signed(self, other: Self, _: &mut I) -> Self {
+        (self as i32).max(other as i32) as Self
+    }
+
+    fn max_unsigned(self, other: Self, _: &mut I) -> Self {
+        self.max(other)
+    }
+
+    fn leading_zeros(self, _: &mut I) -> Self {
+        self.leading_zeros() as Self
+    }
+
+    fn trailing_zeros(self, _: &mut I) -> Self {
+        self.trailing_zeros() as Self
+    }
+
+    fn count_ones(self, _: &mut I) -> Self {
+        self.count_ones() as Self
+    }
+
+    fn swap_bytes(self, _: &mut I) -> Self {
+        self.swap_bytes()
+    }
 }
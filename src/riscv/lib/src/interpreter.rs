@@ -4,18 +4,21 @@
 // SPDX-License-Identifier: MIT
 
 pub mod atomics;
+pub mod bitmanip;
 pub mod branching;
 mod common_memory;
+pub mod crypto;
 pub mod float;
 pub mod integer;
 pub mod load_store;
-pub mod rv32a;
 pub mod rv32c;
 pub mod rv32i;
-pub mod rv64a;
 pub mod rv64c;
 pub mod rv64d;
 pub mod rv64dc;
 pub mod rv64f;
+pub mod rv64zfh;
+pub mod rv64zicboz;
 pub mod rv64zicsr;
 pub mod rv64zifencei;
+pub mod rv64zve64x;
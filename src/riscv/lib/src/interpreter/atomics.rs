@@ -4,19 +4,15 @@
 
 //! Core logic for atomic instructions
 
-use std::mem;
-
 use crate::instruction_context::ICB;
+use crate::instruction_context::LoadStoreWidth;
 use crate::instruction_context::Predicate;
 use crate::instruction_context::StoreLoadInt;
 use crate::instruction_context::arithmetic::Arithmetic;
 use crate::instruction_context::comparable::Comparable;
-use crate::machine_state::MachineCoreState;
-use crate::machine_state::memory;
 use crate::machine_state::registers::XRegister;
 use crate::machine_state::reservation_set::RES_SET_BITMASK;
 use crate::machine_state::reservation_set::UNSET_VALUE;
-use crate::state_backend as backend;
 use crate::traps::Exception;
 
 pub const SC_SUCCESS: u64 = 0;
@@ -31,77 +27,12 @@ pub enum ReservationSetOption {
     Reset,
 }
 
-impl<MC, M> MachineCoreState<MC, M>
-where
-    MC: memory::MemoryConfig,
-    M: backend::ManagerReadWrite,
-{
-    /// Generic implementation of any atomic memory operation, implementing
-    /// read-modify-write operations for multi-processor synchronisation
-    /// (Section 8.4)
-    fn run_amo<T: backend::Elem>(
-        &mut self,
-        rs1: XRegister,
-        rs2: XRegister,
-        rd: XRegister,
-        f: fn(T, T) -> T,
-        to: fn(u64) -> T,
-        from: fn(T) -> u64,
-    ) -> Result<(), Exception> {
-        let address_rs1 = self.hart.xregisters.read(rs1);
-
-        // "The A extension requires that the address held in rs1 be naturally
-        // aligned to the size of the operand (i.e., eight-byte aligned for
-        // 64-bit words and four-byte aligned for 32-bit words). If the address
-        // is not naturally aligned, an address-misaligned exception or
-        // an access-fault exception will be generated."
-        if address_rs1 % mem::size_of::<T>() as u64 != 0 {
-            return Err(Exception::StoreAMOAccessFault(address_rs1));
-        }
-
-        // Load the value from address in rs1
-        let value_rs1: T = self.read_from_address(address_rs1)?;
-
-        // Apply the binary operation to the loaded value and the value in rs2
-        let value_rs2 = to(self.hart.xregisters.read(rs2));
-        let value = f(value_rs1, value_rs2);
-
-        // Write the value read fom the address in rs1 in rd
-        self.hart.xregisters.write(rd, from(value_rs1));
-
-        // Store the resulting value to the address in rs1
-        self.write_to_address(address_rs1, value)
-    }
-
-    /// Generic implementation of an atomic memory operation which works on
-    /// 32-bit values
-    pub(super) fn run_amo_w(
-        &mut self,
-        rs1: XRegister,
-        rs2: XRegister,
-        rd: XRegister,
-        f: fn(i32, i32) -> i32,
-    ) -> Result<(), Exception> {
-        self.run_amo(rs1, rs2, rd, f, |x| x as i32, |x| x as u64)
-    }
-
-    /// Generic implementation of an atomic memory operation which works on
-    /// 64-bit values
-    pub(super) fn run_amo_d(
-        &mut self,
-        rs1: XRegister,
-        rs2: XRegister,
-        rd: XRegister,
-        f: fn(u64, u64) -> u64,
-    ) -> Result<(), Exception> {
-        self.run_amo(rs1, rs2, rd, f, |x| x, |x| x)
-    }
-}
-
-/// Generic implementation of any atomic memory operation which works on 64-bit values,
-/// implementing read-modify-write operations for multi-processor synchronisation
-/// (Section 8.4)
-fn run_x64_atomic<I: ICB>(
+/// Generic implementation of any atomic memory operation, implementing read-modify-write
+/// operations for multi-processor synchronisation (Section 8.4)
+///
+/// For word-sized operations both operands are sign-extended from 32 bits before `f`
+/// is applied, and only the lower 32 bits of the result are stored.
+fn run_atomic<I: ICB, V: StoreLoadInt>(
     icb: &mut I,
     rs1: XRegister,
     rs2: XRegister,
@@ -110,31 +41,172 @@ fn run_x64_atomic<I: ICB>(
 ) -> I::IResult<()> {
     let address_rs1 = icb.xregister_read(rs1);
 
-    // Handle the case where the address is not aligned.
-    let result = icb.atomic_access_fault_guard::<u64>(address_rs1, ReservationSetOption::NoReset);
+    // "The A extension requires that the address held in rs1 be naturally
+    // aligned to the size of the operand (i.e., eight-byte aligned for
+    // 64-bit words and four-byte aligned for 32-bit words). If the address
+    // is not naturally aligned, an address-misaligned exception or
+    // an access-fault exception will be generated."
+    let result = icb.atomic_access_fault_guard::<V>(address_rs1, ReservationSetOption::NoReset);
 
     // Continue with the operation if the address is aligned.
-    let val_rs1_result = I::and_then(result, |_| icb.main_memory_load::<u64>(address_rs1));
+    let val_rs1_result = I::and_then(result, |_| icb.main_memory_load::<V>(address_rs1));
 
     // Continue with the operation if the load was successful.
     I::and_then(val_rs1_result, |val_rs1| {
         // Apply the binary operation to the loaded value and the value in rs2
         let val_rs2 = icb.xregister_read(rs2);
+        let val_rs2 = match V::WIDTH {
+            LoadStoreWidth::Word => {
+                let val_rs2 = icb.narrow(val_rs2);
+                icb.extend_signed(val_rs2)
+            }
+            _ => val_rs2,
+        };
         let res = f(val_rs1, val_rs2, icb);
 
         // Write the value read fom the address in rs1 in rd
         icb.xregister_write(rd, val_rs1);
 
         // Store the resulting value to the address in rs1
-        icb.main_memory_store::<u64>(address_rs1, res)
+        icb.main_memory_store::<V>(address_rs1, res)
     })
 }
 
+/// Loads in `rd` the sign-extended word from the address in `rs1` and stores
+/// the result of adding it to `val(rs2)` back to the address in `rs1`.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x32_atomic_add<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, i32>(icb, rs1, rs2, rd, |x, y, icb| x.add(y, icb))
+}
+
+/// Loads in `rd` the sign-extended word from the address in `rs1` and stores
+/// the result of XORing it with `val(rs2)` back to the address in `rs1`.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x32_atomic_xor<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, i32>(icb, rs1, rs2, rd, |x, y, icb| x.xor(y, icb))
+}
+
+/// Loads in `rd` the sign-extended word from the address in `rs1` and stores
+/// the result of ANDing it with `val(rs2)` back to the address in `rs1`.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x32_atomic_and<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, i32>(icb, rs1, rs2, rd, |x, y, icb| x.and(y, icb))
+}
+
+/// Loads in `rd` the sign-extended word from the address in `rs1` and stores
+/// the result of ORing it with `val(rs2)` back to the address in `rs1`.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x32_atomic_or<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, i32>(icb, rs1, rs2, rd, |x, y, icb| x.or(y, icb))
+}
+
+/// Loads in `rd` the sign-extended word from the address in `rs1` and stores
+/// the minimum between it and `val(rs2)` back to the address in `rs1`.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x32_atomic_min_signed<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, i32>(icb, rs1, rs2, rd, |x, y, icb| x.min_signed(y, icb))
+}
+
+/// Loads in `rd` the sign-extended word from the address in `rs1` and stores
+/// the maximum between it and `val(rs2)` back to the address in `rs1`.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x32_atomic_max_signed<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, i32>(icb, rs1, rs2, rd, |x, y, icb| x.max_signed(y, icb))
+}
+
+/// Loads in `rd` the sign-extended word from the address in `rs1` and stores
+/// the minimum between it and `val(rs2)` back to the address in `rs1`, treating
+/// both as unsigned values.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x32_atomic_min_unsigned<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, i32>(icb, rs1, rs2, rd, |x, y, icb| x.min_unsigned(y, icb))
+}
+
+/// Loads in `rd` the sign-extended word from the address in `rs1` and stores
+/// the maximum between it and `val(rs2)` back to the address in `rs1`, treating
+/// both as unsigned values.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x32_atomic_max_unsigned<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, i32>(icb, rs1, rs2, rd, |x, y, icb| x.max_unsigned(y, icb))
+}
+
 /// Loads in `rd` the value from the address in `rs1` and stores the result of
 /// adding it to `val(rs2)` back to the address in `rs1`.
 ///
-/// The `aq` and `rl` bits specify additional memory constraints
-/// in multi-hart environments so they are currently ignored.
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
 pub fn run_x64_atomic_add<I: ICB>(
     icb: &mut I,
     rs1: XRegister,
@@ -143,7 +215,55 @@ pub fn run_x64_atomic_add<I: ICB>(
     _aq: bool,
     _rl: bool,
 ) -> I::IResult<()> {
-    run_x64_atomic(icb, rs1, rs2, rd, |x, y, icb| x.add(y, icb))
+    run_atomic::<I, u64>(icb, rs1, rs2, rd, |x, y, icb| x.add(y, icb))
+}
+
+/// Loads in `rd` the value from the address in `rs1` and stores the result of
+/// XORing it with `val(rs2)` back to the address in `rs1`.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x64_atomic_xor<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, u64>(icb, rs1, rs2, rd, |x, y, icb| x.xor(y, icb))
+}
+
+/// Loads in `rd` the value from the address in `rs1` and stores the result of
+/// ANDing it with `val(rs2)` back to the address in `rs1`.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x64_atomic_and<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, u64>(icb, rs1, rs2, rd, |x, y, icb| x.and(y, icb))
+}
+
+/// Loads in `rd` the value from the address in `rs1` and stores the result of
+/// ORing it with `val(rs2)` back to the address in `rs1`.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x64_atomic_or<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, u64>(icb, rs1, rs2, rd, |x, y, icb| x.or(y, icb))
 }
 
 /// Loads in `rd` the value from the address in `rs1` and stores the minimum
@@ -159,7 +279,57 @@ pub fn run_x64_atomic_min_signed<I: ICB>(
     _aq: bool,
     _rl: bool,
 ) -> I::IResult<()> {
-    run_x64_atomic(icb, rs1, rs2, rd, |x, y, icb| x.min_signed(y, icb))
+    run_atomic::<I, u64>(icb, rs1, rs2, rd, |x, y, icb| x.min_signed(y, icb))
+}
+
+/// Loads in `rd` the value from the address in `rs1` and stores the maximum
+/// between it and `val(rs2)` back to the address in `rs1`.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x64_atomic_max_signed<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, u64>(icb, rs1, rs2, rd, |x, y, icb| x.max_signed(y, icb))
+}
+
+/// Loads in `rd` the value from the address in `rs1` and stores the minimum
+/// between it and `val(rs2)` back to the address in `rs1`, treating both as
+/// unsigned values.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x64_atomic_min_unsigned<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, u64>(icb, rs1, rs2, rd, |x, y, icb| x.min_unsigned(y, icb))
+}
+
+/// Loads in `rd` the value from the address in `rs1` and stores the maximum
+/// between it and `val(rs2)` back to the address in `rs1`, treating both as
+/// unsigned values.
+///
+/// The `aq` and `rl` bits specify additional memory constraints in
+/// multi-hart environments so they are currently ignored.
+pub fn run_x64_atomic_max_unsigned<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _aq: bool,
+    _rl: bool,
+) -> I::IResult<()> {
+    run_atomic::<I, u64>(icb, rs1, rs2, rd, |x, y, icb| x.max_unsigned(y, icb))
 }
 
 /// Loads a word or a double from the address in `rs1`, places the
@@ -391,6 +561,157 @@ pub fn run_x64_atomic_store<I: ICB>(
     run_atomic_store::<I, i64>(icb, rs1, rs2, rd)
 }
 
+/// Loads the value at the address in `rs1` and compares it with `val(rd)`, after
+/// `expected` has been applied to the latter. If they are equal, `val(rs2)` is
+/// stored back to the address in `rs1`, otherwise the loaded value is written back
+/// unchanged. The loaded value is then placed in `rd`.
+///
+/// Write permission is required regardless of the outcome of the comparison, which
+/// is why a store is performed in both cases.
+fn run_atomic_compare_and_swap<I: ICB, V: StoreLoadInt>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    expected: fn(I::XValue, &mut I) -> I::XValue,
+) -> I::IResult<()> {
+    let address_rs1 = icb.xregister_read(rs1);
+
+    // AMOCAS.W/D have the same alignment requirements as the other AMOs.
+    let result = icb.atomic_access_fault_guard::<V>(address_rs1, ReservationSetOption::NoReset);
+
+    // Continue with the operation if the address is aligned.
+    let val_rs1_result = I::and_then(result, |_| icb.main_memory_load::<V>(address_rs1));
+
+    // Continue with the operation if the load was successful.
+    I::and_then(val_rs1_result, |val_rs1| {
+        let val_rd = icb.xregister_read(rd);
+        let val_rd = expected(val_rd, icb);
+        let cond = val_rs1.compare(val_rd, Predicate::Equal, icb);
+
+        let result = icb.branch_merge::<Result<(), Exception>, _, _>(
+            cond,
+            |icb| {
+                let val_rs2 = icb.xregister_read(rs2);
+                icb.main_memory_store::<V>(address_rs1, val_rs2)
+            },
+            |icb| icb.main_memory_store::<V>(address_rs1, val_rs1),
+        );
+
+        // Only write the original memory value to rd once the store has succeeded.
+        I::map(result, |_| icb.xregister_write(rd, val_rs1))
+    })
+}
+
+/// Compares the 32-bit value at the memory address in `rs1` with the low 32 bits
+/// of `rd` and, if they are equal, stores the low 32 bits of `rs2` to that address.
+/// The original 32-bit memory value is sign-extended and loaded into `rd`.
+///
+/// The `aq` and `rl` bits specify additional memory ordering constraints in
+/// multi-hart environments so they are currently ignored.
+/// The address in `rs1` must be 4-byte aligned.
+pub fn run_x32_atomic_compare_and_swap<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _rl: bool,
+    _aq: bool,
+) -> I::IResult<()> {
+    run_atomic_compare_and_swap::<I, i32>(icb, rs1, rs2, rd, |val, icb| {
+        let val = icb.narrow(val);
+        icb.extend_signed(val)
+    })
+}
+
+/// Compares the 64-bit value at the memory address in `rs1` with the value in `rd`
+/// and, if they are equal, stores the value in `rs2` to that address.
+/// The original 64-bit memory value is loaded into `rd`.
+///
+/// The `aq` and `rl` bits specify additional memory ordering constraints in
+/// multi-hart environments so they are currently ignored.
+/// The address in `rs1` must be 8-byte aligned.
+pub fn run_x64_atomic_compare_and_swap<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _rl: bool,
+    _aq: bool,
+) -> I::IResult<()> {
+    run_atomic_compare_and_swap::<I, i64>(icb, rs1, rs2, rd, |val, _| val)
+}
+
+/// Size in bytes of the quad-word operated on by `AMOCAS.Q`.
+const QUAD_WORD_WIDTH: u64 = 16;
+
+/// Compares the 128-bit value at the memory address in `rs1` with the register pair
+/// starting at `rd` and, if they are equal, stores the register pair starting at `rs2`
+/// to that address. The original 128-bit memory value is loaded into the `rd` pair.
+///
+/// The low half of a value lives in the even register of a pair and the high half
+/// in the following odd register. See [`XRegister::pair_high`].
+///
+/// The `aq` and `rl` bits specify additional memory ordering constraints in
+/// multi-hart environments so they are currently ignored.
+/// The address in `rs1` must be 16-byte aligned.
+pub fn run_x128_atomic_compare_and_swap<I: ICB>(
+    icb: &mut I,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: XRegister,
+    _rl: bool,
+    _aq: bool,
+) -> I::IResult<()> {
+    let address_lo = icb.xregister_read(rs1);
+    let offset = icb.xvalue_of_imm(QUAD_WORD_WIDTH as i64 / 2);
+    let address_hi = address_lo.add(offset, icb);
+
+    let result = icb.atomic_access_fault_guard_aligned(
+        address_lo,
+        QUAD_WORD_WIDTH,
+        ReservationSetOption::NoReset,
+    );
+
+    // Continue with the operation if the address is aligned. Both halves lie in
+    // the same naturally aligned quad-word, so they are either both accessible or not.
+    let val_lo_result = I::and_then(result, |_| icb.main_memory_load::<u64>(address_lo));
+    let val_result = I::and_then(val_lo_result, |val_lo| {
+        let val_hi_result = icb.main_memory_load::<u64>(address_hi);
+        I::map(val_hi_result, |val_hi| (val_lo, val_hi))
+    });
+
+    I::and_then(val_result, |(val_lo, val_hi)| {
+        let rd_hi = rd.pair_high();
+
+        let expected_lo = icb.xregister_read(rd);
+        let expected_hi = icb.xregister_read(rd_hi);
+        let lo_equal = val_lo.compare(expected_lo, Predicate::Equal, icb);
+        let hi_equal = val_hi.compare(expected_hi, Predicate::Equal, icb);
+        let cond = icb.bool_and(lo_equal, hi_equal);
+
+        let result = icb.branch_merge::<Result<(), Exception>, _, _>(
+            cond,
+            |icb| {
+                let new_lo = icb.xregister_read(rs2);
+                let new_hi = icb.xregister_read(rs2.pair_high());
+                let result = icb.main_memory_store::<u64>(address_lo, new_lo);
+                I::and_then(result, |_| icb.main_memory_store::<u64>(address_hi, new_hi))
+            },
+            |icb| {
+                let result = icb.main_memory_store::<u64>(address_lo, val_lo);
+                I::and_then(result, |_| icb.main_memory_store::<u64>(address_hi, val_hi))
+            },
+        );
+
+        // Only write the original memory value to the rd pair once the store has succeeded.
+        I::map(result, |_| {
+            icb.xregister_write(rd, val_lo);
+            icb.xregister_write(rd_hi, val_hi);
+        })
+    })
+}
+
 // Reservation Set Helper Functionss
 
 /// Reset the reservation set to an unset state.
@@ -432,6 +753,10 @@ fn test_and_unset_reservation_set<V: StoreLoadInt, I: ICB>(
 
 #[cfg(test)]
 pub(crate) mod test {
+    use std::ops::BitAnd;
+    use std::ops::BitOr;
+    use std::ops::BitXor;
+
     use proptest::prelude::*;
 
     use super::*;
@@ -443,7 +768,11 @@ pub(crate) mod test {
     use crate::machine_state::registers::a1;
     use crate::machine_state::registers::a2;
     use crate::machine_state::registers::a3;
+    use crate::machine_state::registers::a4;
+    use crate::machine_state::registers::a5;
     use crate::machine_state::registers::a7;
+    use crate::machine_state::registers::x0;
+    use crate::machine_state::registers::x1;
     use crate::state::NewState;
 
     macro_rules! test_atomic_loadstore {
@@ -548,6 +877,118 @@ pub(crate) mod test {
         u64
     );
 
+    test_atomic!(
+        test_run_x64_atomic_xor,
+        super::run_x64_atomic_xor,
+        u64::bitxor,
+        8,
+        u64
+    );
+
+    test_atomic!(
+        test_run_x64_atomic_and,
+        super::run_x64_atomic_and,
+        u64::bitand,
+        8,
+        u64
+    );
+
+    test_atomic!(
+        test_run_x64_atomic_or,
+        super::run_x64_atomic_or,
+        u64::bitor,
+        8,
+        u64
+    );
+
+    test_atomic!(
+        test_run_x64_atomic_max_signed,
+        super::run_x64_atomic_max_signed,
+        |r1_val, r2_val| i64::max(r1_val as i64, r2_val as i64) as u64,
+        8,
+        u64
+    );
+
+    test_atomic!(
+        test_run_x64_atomic_min_unsigned,
+        super::run_x64_atomic_min_unsigned,
+        u64::min,
+        8,
+        u64
+    );
+
+    test_atomic!(
+        test_run_x64_atomic_max_unsigned,
+        super::run_x64_atomic_max_unsigned,
+        u64::max,
+        8,
+        u64
+    );
+
+    test_atomic!(
+        test_run_x32_atomic_add,
+        super::run_x32_atomic_add,
+        i32::wrapping_add,
+        4,
+        i32
+    );
+
+    test_atomic!(
+        test_run_x32_atomic_xor,
+        super::run_x32_atomic_xor,
+        i32::bitxor,
+        4,
+        i32
+    );
+
+    test_atomic!(
+        test_run_x32_atomic_and,
+        super::run_x32_atomic_and,
+        i32::bitand,
+        4,
+        i32
+    );
+
+    test_atomic!(
+        test_run_x32_atomic_or,
+        super::run_x32_atomic_or,
+        i32::bitor,
+        4,
+        i32
+    );
+
+    test_atomic!(
+        test_run_x32_atomic_min_signed,
+        super::run_x32_atomic_min_signed,
+        i32::min,
+        4,
+        i32
+    );
+
+    test_atomic!(
+        test_run_x32_atomic_max_signed,
+        super::run_x32_atomic_max_signed,
+        i32::max,
+        4,
+        i32
+    );
+
+    test_atomic!(
+        test_run_x32_atomic_min_unsigned,
+        super::run_x32_atomic_min_unsigned,
+        |r1_val, r2_val| u32::min(r1_val as u32, r2_val as u32) as i32,
+        4,
+        i32
+    );
+
+    test_atomic!(
+        test_run_x32_atomic_max_unsigned,
+        super::run_x32_atomic_max_unsigned,
+        |r1_val, r2_val| u32::max(r1_val as u32, r2_val as u32) as i32,
+        4,
+        i32
+    );
+
     test_atomic!(
         test_run_x64_atomic_swap,
         super::run_x64_atomic_swap,
@@ -596,6 +1037,142 @@ pub(crate) mod test {
         u32
     );
 
+    backend_test!(test_atomic_compare_and_swap, F, {
+        let state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+        let state_cell = std::cell::RefCell::new(state);
+
+        proptest!(|(
+            address in (0..511_u64).prop_map(|x| x * 8),
+            mem_val in any::<u64>(),
+            other_val in any::<u64>(),
+            new_val in any::<u64>(),
+            upper_bits in any::<u32>(),
+        )| {
+            let mut state = state_cell.borrow_mut();
+            state.reset();
+            state.main_memory.set_all_readable_writeable();
+            state.hart.xregisters.write(a0, address);
+            state.hart.xregisters.write(a1, new_val);
+
+            // AMOCAS.D succeeds when rd holds the value in memory
+            state.write_to_address(address, mem_val)?;
+            state.hart.xregisters.write(a2, mem_val);
+            run_x64_atomic_compare_and_swap(&mut *state, a0, a1, a2, false, false)?;
+            prop_assert_eq!(state.read_from_address::<u64>(address)?, new_val);
+            prop_assert_eq!(state.hart.xregisters.read(a2), mem_val);
+
+            // AMOCAS.D leaves memory untouched when rd holds a different value
+            state.write_to_address(address, mem_val)?;
+            state.hart.xregisters.write(a2, other_val);
+            run_x64_atomic_compare_and_swap(&mut *state, a0, a1, a2, false, false)?;
+            let expected = if other_val == mem_val { new_val } else { mem_val };
+            prop_assert_eq!(state.read_from_address::<u64>(address)?, expected);
+            prop_assert_eq!(state.hart.xregisters.read(a2), mem_val);
+
+            // AMOCAS.W only compares the low 32 bits of rd and sign-extends the loaded word
+            let mem_word = mem_val as u32;
+            let expected_word = (mem_word as i32) as u64;
+            state.write_to_address(address, mem_word)?;
+            state.hart.xregisters.write(a2, ((upper_bits as u64) << 32) | mem_word as u64);
+            run_x32_atomic_compare_and_swap(&mut *state, a0, a1, a2, false, false)?;
+            prop_assert_eq!(state.read_from_address::<u32>(address)?, new_val as u32);
+            prop_assert_eq!(state.hart.xregisters.read(a2), expected_word);
+
+            // AMOCAS.W leaves memory untouched when the low 32 bits of rd differ
+            state.write_to_address(address, mem_word)?;
+            state.hart.xregisters.write(a2, other_val);
+            run_x32_atomic_compare_and_swap(&mut *state, a0, a1, a2, false, false)?;
+            let expected = if other_val as u32 == mem_word { new_val as u32 } else { mem_word };
+            prop_assert_eq!(state.read_from_address::<u32>(address)?, expected);
+            prop_assert_eq!(state.hart.xregisters.read(a2), expected_word);
+        });
+    });
+
+    backend_test!(test_x128_atomic_compare_and_swap, F, {
+        let state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+        let state_cell = std::cell::RefCell::new(state);
+
+        proptest!(|(
+            address in (0..255_u64).prop_map(|x| x * 16),
+            mem_lo in any::<u64>(),
+            mem_hi in any::<u64>(),
+            new_lo in any::<u64>(),
+            new_hi in any::<u64>(),
+        )| {
+            let mut state = state_cell.borrow_mut();
+            state.reset();
+            state.main_memory.set_all_readable_writeable();
+            state.hart.xregisters.write(a1, address);
+
+            // Both halves match: the pair starting at a4 is stored to memory
+            state.write_to_address(address, mem_lo)?;
+            state.write_to_address(address + 8, mem_hi)?;
+            state.hart.xregisters.write(a2, mem_lo);
+            state.hart.xregisters.write(a3, mem_hi);
+            state.hart.xregisters.write(a4, new_lo);
+            state.hart.xregisters.write(a5, new_hi);
+            run_x128_atomic_compare_and_swap(&mut *state, a1, a4, a2, false, false)?;
+            prop_assert_eq!(state.read_from_address::<u64>(address)?, new_lo);
+            prop_assert_eq!(state.read_from_address::<u64>(address + 8)?, new_hi);
+            prop_assert_eq!(state.hart.xregisters.read(a2), mem_lo);
+            prop_assert_eq!(state.hart.xregisters.read(a3), mem_hi);
+
+            // Only the low half matches: memory is left untouched
+            state.write_to_address(address, mem_lo)?;
+            state.write_to_address(address + 8, mem_hi)?;
+            state.hart.xregisters.write(a2, mem_lo);
+            state.hart.xregisters.write(a3, !mem_hi);
+            run_x128_atomic_compare_and_swap(&mut *state, a1, a4, a2, false, false)?;
+            prop_assert_eq!(state.read_from_address::<u64>(address)?, mem_lo);
+            prop_assert_eq!(state.read_from_address::<u64>(address + 8)?, mem_hi);
+            prop_assert_eq!(state.hart.xregisters.read(a2), mem_lo);
+            prop_assert_eq!(state.hart.xregisters.read(a3), mem_hi);
+
+            // The x0 pair reads as zero for both halves and discards the loaded value
+            state.write_to_address(address, 0u64)?;
+            state.write_to_address(address + 8, 0u64)?;
+            run_x128_atomic_compare_and_swap(&mut *state, a1, a4, x0, false, false)?;
+            prop_assert_eq!(state.read_from_address::<u64>(address)?, new_lo);
+            prop_assert_eq!(state.read_from_address::<u64>(address + 8)?, new_hi);
+            prop_assert_eq!(state.hart.xregisters.read(x1), 0);
+        });
+    });
+
+    backend_test!(test_atomic_compare_and_swap_misaligned, F, {
+        let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+        state.main_memory.set_all_readable_writeable();
+        state.hart.xregisters.write(a0, 0x1234);
+
+        for (address, instr, width) in [
+            (
+                2,
+                run_x32_atomic_compare_and_swap as fn(&mut _, _, _, _, _, _) -> _,
+                4,
+            ),
+            (4, run_x64_atomic_compare_and_swap, 8),
+            (8, run_x128_atomic_compare_and_swap, 16),
+        ] {
+            for offset in [0, 8, 16, 24] {
+                state.write_to_address(offset, 0u64).unwrap();
+            }
+            state.hart.xregisters.write(a1, address);
+            state.hart.xregisters.write(a2, 0);
+            assert_eq!(
+                instr(&mut state, a1, a0, a2, false, false),
+                Err(Exception::StoreAMOAccessFault(address))
+            );
+
+            // Neither memory nor rd is modified
+            assert_eq!(state.read_from_address::<u64>(0), Ok(0));
+            assert_eq!(state.read_from_address::<u64>(8), Ok(0));
+            assert_eq!(state.hart.xregisters.read(a2), 0);
+
+            // The naturally aligned address succeeds
+            state.hart.xregisters.write(a1, width);
+            assert_eq!(instr(&mut state, a1, a0, a2, false, false), Ok(()));
+        }
+    });
+
     backend_test!(test_alignment, F, {
         let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
         state.main_memory.set_all_readable_writeable();
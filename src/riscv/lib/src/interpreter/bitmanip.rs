@@ -0,0 +1,816 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Implementation of the Zba, Zbb, Zbs & Zbkb bit-manipulation extensions for RISC-V over the ICB.
+
+use crate::instruction_context::ICB;
+use crate::instruction_context::Shift;
+use crate::instruction_context::arithmetic::Arithmetic;
+use crate::machine_state::registers::NonZeroXRegister;
+use crate::machine_state::registers::XRegister;
+use crate::parser::SHIFT_BITMASK;
+
+/// Perform `(val(rs1) << shift) + val(rs2)` and store the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `SH1ADD`
+/// - `SH2ADD`
+/// - `SH3ADD`
+pub fn run_shift_add(
+    icb: &mut impl ICB,
+    shift: i64,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: NonZeroXRegister,
+) {
+    let lhs = icb.xregister_read(rs1);
+    let shift = icb.xvalue_of_imm(shift);
+    let lhs = lhs.shift(Shift::Left, shift, icb);
+
+    let rhs = icb.xregister_read(rs2);
+    let res = lhs.add(rhs, icb);
+
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Zero-extend the lowest 32 bits of `val(rs1)`, shift it left by `shift` and add
+/// `val(rs2)`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `ADD.UW`
+/// - `SH1ADD.UW`
+/// - `SH2ADD.UW`
+/// - `SH3ADD.UW`
+pub fn run_shift_add_unsigned_word(
+    icb: &mut impl ICB,
+    shift: i64,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: NonZeroXRegister,
+) {
+    let lhs = icb.xregister_read(rs1);
+    let lhs = icb.narrow(lhs);
+    let lhs = icb.extend_unsigned(lhs);
+    let shift = icb.xvalue_of_imm(shift);
+    let lhs = lhs.shift(Shift::Left, shift, icb);
+
+    let rhs = icb.xregister_read(rs2);
+    let res = lhs.add(rhs, icb);
+
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Zero-extend the lowest 32 bits of `val(rs1)` and shift it left by `imm`,
+/// storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `SLLI.UW`
+pub fn run_shift_left_immediate_unsigned_word(
+    icb: &mut impl ICB,
+    imm: i64,
+    rs1: XRegister,
+    rd: NonZeroXRegister,
+) {
+    let lhs = icb.xregister_read(rs1);
+    let lhs = icb.narrow(lhs);
+    let lhs = icb.extend_unsigned(lhs);
+    let shift = icb.xvalue_of_imm(imm & SHIFT_BITMASK);
+    let res = lhs.shift(Shift::Left, shift, icb);
+
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Saves in `rd` the bitwise AND between `val(rs1)` and the inverse of `val(rs2)`.
+///
+/// Relevant RISC-V opcodes:
+/// - `ANDN`
+pub fn run_and_not(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let lhs = icb.xregister_read(rs1);
+    let rhs = icb.xregister_read(rs2);
+    let ones = icb.xvalue_of_imm(-1);
+    let rhs = rhs.xor(ones, icb);
+
+    let res = lhs.and(rhs, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Saves in `rd` the bitwise OR between `val(rs1)` and the inverse of `val(rs2)`.
+///
+/// Relevant RISC-V opcodes:
+/// - `ORN`
+pub fn run_or_not(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let lhs = icb.xregister_read(rs1);
+    let rhs = icb.xregister_read(rs2);
+    let ones = icb.xvalue_of_imm(-1);
+    let rhs = rhs.xor(ones, icb);
+
+    let res = lhs.or(rhs, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Saves in `rd` the inverse of the bitwise XOR between `val(rs1)` and `val(rs2)`.
+///
+/// Relevant RISC-V opcodes:
+/// - `XNOR`
+pub fn run_xor_not(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let lhs = icb.xregister_read(rs1);
+    let rhs = icb.xregister_read(rs2);
+    let res = lhs.xor(rhs, icb);
+
+    let ones = icb.xvalue_of_imm(-1);
+    let res = res.xor(ones, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Count the number of leading zero bits in `val(rs1)`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `CLZ`
+pub fn run_count_leading_zeros(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    let val = icb.xregister_read(rs1);
+    let res = val.leading_zeros(icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Count the number of leading zero bits in the lowest 32 bits of `val(rs1)`,
+/// storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `CLZW`
+pub fn run_x32_count_leading_zeros(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    let val = icb.xregister_read(rs1);
+    let val = icb.narrow(val);
+    let res = val.leading_zeros(icb);
+    let res = icb.extend_unsigned(res);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Count the number of trailing zero bits in `val(rs1)`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `CTZ`
+pub fn run_count_trailing_zeros(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    let val = icb.xregister_read(rs1);
+    let res = val.trailing_zeros(icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Count the number of trailing zero bits in the lowest 32 bits of `val(rs1)`,
+/// storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `CTZW`
+pub fn run_x32_count_trailing_zeros(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    let val = icb.xregister_read(rs1);
+    let val = icb.narrow(val);
+    let res = val.trailing_zeros(icb);
+    let res = icb.extend_unsigned(res);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Count the number of set bits in `val(rs1)`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `CPOP`
+pub fn run_count_set_bits(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    let val = icb.xregister_read(rs1);
+    let res = val.count_ones(icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Count the number of set bits in the lowest 32 bits of `val(rs1)`,
+/// storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `CPOPW`
+pub fn run_x32_count_set_bits(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    let val = icb.xregister_read(rs1);
+    let val = icb.narrow(val);
+    let res = val.count_ones(icb);
+    let res = icb.extend_unsigned(res);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Store the larger of `val(rs1)` and `val(rs2)` in `rd`, treating both as _signed integers_.
+///
+/// Relevant RISC-V opcodes:
+/// - `MAX`
+pub fn run_max_signed(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let lhs = icb.xregister_read(rs1);
+    let rhs = icb.xregister_read(rs2);
+    let res = lhs.max_signed(rhs, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Store the larger of `val(rs1)` and `val(rs2)` in `rd`, treating both as _unsigned integers_.
+///
+/// Relevant RISC-V opcodes:
+/// - `MAXU`
+pub fn run_max_unsigned(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let lhs = icb.xregister_read(rs1);
+    let rhs = icb.xregister_read(rs2);
+    let res = lhs.max_unsigned(rhs, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Store the smaller of `val(rs1)` and `val(rs2)` in `rd`, treating both as _signed integers_.
+///
+/// Relevant RISC-V opcodes:
+/// - `MIN`
+pub fn run_min_signed(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let lhs = icb.xregister_read(rs1);
+    let rhs = icb.xregister_read(rs2);
+    let res = lhs.min_signed(rhs, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Store the smaller of `val(rs1)` and `val(rs2)` in `rd`, treating both as _unsigned integers_.
+///
+/// Relevant RISC-V opcodes:
+/// - `MINU`
+pub fn run_min_unsigned(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let lhs = icb.xregister_read(rs1);
+    let rhs = icb.xregister_read(rs2);
+    let res = lhs.min_unsigned(rhs, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Sign-extend the lowest `64 - unused_bits` bits of `val(rs1)`, storing the result in `rd`.
+#[inline(always)]
+fn sign_extend(icb: &mut impl ICB, unused_bits: i64, rs1: XRegister, rd: NonZeroXRegister) {
+    let val = icb.xregister_read(rs1);
+    let unused_bits = icb.xvalue_of_imm(unused_bits);
+    let val = val.shift(Shift::Left, unused_bits, icb);
+    let res = val.shift(Shift::RightSigned, unused_bits, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Sign-extend the lowest byte of `val(rs1)`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `SEXT.B`
+pub fn run_sign_extend_byte(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    sign_extend(icb, 56, rs1, rd)
+}
+
+/// Sign-extend the lowest 16 bits of `val(rs1)`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `SEXT.H`
+pub fn run_sign_extend_half(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    sign_extend(icb, 48, rs1, rd)
+}
+
+/// Zero-extend the lowest 16 bits of `val(rs1)`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `ZEXT.H`
+pub fn run_zero_extend_half(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    let val = icb.xregister_read(rs1);
+    let mask = icb.xvalue_of_imm(u16::MAX as i64);
+    let res = val.and(mask, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Set each byte of `rd` to `0xFF` if the corresponding byte of `val(rs1)` is non-zero,
+/// or to `0x00` otherwise.
+///
+/// Relevant RISC-V opcodes:
+/// - `ORC.B`
+pub fn run_or_combine_bytes(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    const LOW_BITS: i64 = 0x7F7F_7F7F_7F7F_7F7F;
+    const HIGH_BIT: i64 = !LOW_BITS;
+
+    let val = icb.xregister_read(rs1);
+
+    // Adding `0x7F` to the lower 7 bits of a byte sets its high bit if any of those bits are
+    // set, without carrying into the next byte. Combined with the original high bit, this
+    // leaves the high bit of each byte set exactly when the byte is non-zero.
+    let low_bits = icb.xvalue_of_imm(LOW_BITS);
+    let spread = val.and(low_bits, icb);
+    let spread = spread.add(low_bits, icb);
+    let spread = spread.or(val, icb);
+
+    let high_bit = icb.xvalue_of_imm(HIGH_BIT);
+    let non_zero = spread.and(high_bit, icb);
+
+    // Widen each high bit to fill the whole byte.
+    let seven = icb.xvalue_of_imm(7);
+    let non_zero = non_zero.shift(Shift::RightUnsigned, seven, icb);
+    let byte = icb.xvalue_of_imm(0xFF);
+    let res = non_zero.mul(byte, icb);
+
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Reverse the order of the bytes in `val(rs1)`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `REV8`
+pub fn run_byte_reverse(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    let val = icb.xregister_read(rs1);
+    let res = val.swap_bytes(icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Construct the mask with only the bit at index `val(rs2)[5:0]` set.
+#[inline(always)]
+fn single_bit_mask<I: ICB>(icb: &mut I, rs2: XRegister) -> I::XValue {
+    let index = icb.xregister_read(rs2);
+    let bitmask = icb.xvalue_of_imm(SHIFT_BITMASK);
+    let index = index.and(bitmask, icb);
+
+    let one = icb.xvalue_of_imm(1);
+    one.shift(Shift::Left, index, icb)
+}
+
+/// Clear the bit of `val(rs1)` at index `val(rs2)[5:0]`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `BCLR`
+pub fn run_bit_clear(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let mask = single_bit_mask(icb, rs2);
+    let ones = icb.xvalue_of_imm(-1);
+    let mask = mask.xor(ones, icb);
+
+    let val = icb.xregister_read(rs1);
+    let res = val.and(mask, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Clear the bit of `val(rs1)` at index `imm[5:0]`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `BCLRI`
+pub fn run_bit_clear_immediate(icb: &mut impl ICB, imm: i64, rs1: XRegister, rd: NonZeroXRegister) {
+    let mask = icb.xvalue_of_imm(!(1 << (imm & SHIFT_BITMASK)));
+
+    let val = icb.xregister_read(rs1);
+    let res = val.and(mask, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Store in `rd` the bit of `val(rs1)` at index `val(rs2)[5:0]`.
+///
+/// Relevant RISC-V opcodes:
+/// - `BEXT`
+pub fn run_bit_extract(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let index = icb.xregister_read(rs2);
+    let bitmask = icb.xvalue_of_imm(SHIFT_BITMASK);
+    let index = index.and(bitmask, icb);
+
+    let val = icb.xregister_read(rs1);
+    let val = val.shift(Shift::RightUnsigned, index, icb);
+    let one = icb.xvalue_of_imm(1);
+    let res = val.and(one, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Store in `rd` the bit of `val(rs1)` at index `imm[5:0]`.
+///
+/// Relevant RISC-V opcodes:
+/// - `BEXTI`
+pub fn run_bit_extract_immediate(
+    icb: &mut impl ICB,
+    imm: i64,
+    rs1: XRegister,
+    rd: NonZeroXRegister,
+) {
+    let index = icb.xvalue_of_imm(imm & SHIFT_BITMASK);
+
+    let val = icb.xregister_read(rs1);
+    let val = val.shift(Shift::RightUnsigned, index, icb);
+    let one = icb.xvalue_of_imm(1);
+    let res = val.and(one, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Invert the bit of `val(rs1)` at index `val(rs2)[5:0]`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `BINV`
+pub fn run_bit_invert(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let mask = single_bit_mask(icb, rs2);
+
+    let val = icb.xregister_read(rs1);
+    let res = val.xor(mask, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Invert the bit of `val(rs1)` at index `imm[5:0]`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `BINVI`
+pub fn run_bit_invert_immediate(
+    icb: &mut impl ICB,
+    imm: i64,
+    rs1: XRegister,
+    rd: NonZeroXRegister,
+) {
+    let mask = icb.xvalue_of_imm(1 << (imm & SHIFT_BITMASK));
+
+    let val = icb.xregister_read(rs1);
+    let res = val.xor(mask, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Set the bit of `val(rs1)` at index `val(rs2)[5:0]`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `BSET`
+pub fn run_bit_set(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let mask = single_bit_mask(icb, rs2);
+
+    let val = icb.xregister_read(rs1);
+    let res = val.or(mask, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Set the bit of `val(rs1)` at index `imm[5:0]`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `BSETI`
+pub fn run_bit_set_immediate(icb: &mut impl ICB, imm: i64, rs1: XRegister, rd: NonZeroXRegister) {
+    let mask = icb.xvalue_of_imm(1 << (imm & SHIFT_BITMASK));
+
+    let val = icb.xregister_read(rs1);
+    let res = val.or(mask, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Store the lowest 32 bits of `val(rs1)` in the lower half of `rd` and the lowest
+/// 32 bits of `val(rs2)` in its upper half.
+///
+/// Relevant RISC-V opcodes:
+/// - `PACK`
+pub fn run_pack(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let lower = icb.xregister_read(rs1);
+    let lower = icb.narrow(lower);
+    let lower = icb.extend_unsigned(lower);
+
+    let upper = icb.xregister_read(rs2);
+    let thirty_two = icb.xvalue_of_imm(32);
+    let upper = upper.shift(Shift::Left, thirty_two, icb);
+
+    let res = lower.or(upper, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Store the lowest byte of `val(rs1)` in bits `[7:0]` of `rd` and the lowest byte
+/// of `val(rs2)` in bits `[15:8]`, zeroing the remaining bits.
+///
+/// Relevant RISC-V opcodes:
+/// - `PACKH`
+pub fn run_pack_byte(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let mask = icb.xvalue_of_imm(u8::MAX as i64);
+
+    let lower = icb.xregister_read(rs1);
+    let lower = lower.and(mask, icb);
+
+    let upper = icb.xregister_read(rs2);
+    let upper = upper.and(mask, icb);
+    let eight = icb.xvalue_of_imm(8);
+    let upper = upper.shift(Shift::Left, eight, icb);
+
+    let res = lower.or(upper, icb);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Store the lowest 16 bits of `val(rs1)` in bits `[15:0]` and the lowest 16 bits
+/// of `val(rs2)` in bits `[31:16]` of a word, saving the sign-extended result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `PACKW`
+pub fn run_x32_pack(icb: &mut impl ICB, rs1: XRegister, rs2: XRegister, rd: NonZeroXRegister) {
+    let lower = icb.xregister_read(rs1);
+    let lower = icb.narrow(lower);
+    let mask = icb.xvalue32_of_imm(u16::MAX as i32);
+    let lower = lower.and(mask, icb);
+
+    let upper = icb.xregister_read(rs2);
+    let upper = icb.narrow(upper);
+    let sixteen = icb.xvalue32_of_imm(16);
+    let upper = upper.shift(Shift::Left, sixteen, icb);
+
+    let res = lower.or(upper, icb);
+    let res = icb.extend_signed(res);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Reverse the order of the bits within each byte of `val(rs1)`, storing the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `BREV8`
+pub fn run_reverse_bits_in_bytes(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    // Swap adjacent bits, then adjacent bit pairs, then the nibbles of each byte.
+    const STEPS: [(i64, i64); 3] = [
+        (1, 0x5555_5555_5555_5555),
+        (2, 0x3333_3333_3333_3333),
+        (4, 0x0F0F_0F0F_0F0F_0F0F),
+    ];
+
+    let mut val = icb.xregister_read(rs1);
+
+    for (amount, mask) in STEPS {
+        let amount = icb.xvalue_of_imm(amount);
+        let mask = icb.xvalue_of_imm(mask);
+
+        let lower = val.shift(Shift::RightUnsigned, amount, icb);
+        let lower = lower.and(mask, icb);
+        let upper = val.and(mask, icb);
+        let upper = upper.shift(Shift::Left, amount, icb);
+
+        val = lower.or(upper, icb);
+    }
+
+    icb.xregister_write_nz(rd, val)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::arbitrary::any;
+    use proptest::prop_assert_eq;
+    use proptest::proptest;
+
+    use super::*;
+    use crate::backend_test;
+    use crate::interpreter::integer::run_shift;
+    use crate::interpreter::integer::run_shift_immediate;
+    use crate::interpreter::integer::run_x32_shift;
+    use crate::interpreter::integer::run_x32_shift_immediate;
+    use crate::machine_state::MachineCoreState;
+    use crate::machine_state::memory::M4K;
+    use crate::machine_state::registers::a0;
+    use crate::machine_state::registers::a1;
+    use crate::machine_state::registers::nz;
+    use crate::state::NewState;
+
+    backend_test!(test_address_generation, F, {
+        proptest!(|(lhs in any::<u64>(), rhs in any::<u64>(), shift in 0_i64..4)| {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+            state.hart.xregisters.write(a0, lhs);
+            state.hart.xregisters.write(a1, rhs);
+
+            run_shift_add(&mut state, shift, a0, a1, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                (lhs << shift).wrapping_add(rhs)
+            );
+
+            run_shift_add_unsigned_word(&mut state, shift, a0, a1, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                ((lhs as u32 as u64) << shift).wrapping_add(rhs)
+            );
+
+            run_shift_left_immediate_unsigned_word(&mut state, shift * 10, a0, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                (lhs as u32 as u64) << (shift * 10)
+            );
+        });
+    });
+
+    backend_test!(test_logical_with_negate, F, {
+        proptest!(|(lhs in any::<u64>(), rhs in any::<u64>())| {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+            state.hart.xregisters.write(a0, lhs);
+            state.hart.xregisters.write(a1, rhs);
+
+            run_and_not(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), lhs & !rhs);
+
+            run_or_not(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), lhs | !rhs);
+
+            run_xor_not(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), !(lhs ^ rhs));
+        });
+    });
+
+    backend_test!(test_count_bits, F, {
+        proptest!(|(val in any::<u64>(), shift in 0_u32..64)| {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+
+            // Shifting ensures that the leading & trailing zeros are well distributed
+            for val in [0, val, val >> shift, val << shift] {
+                state.hart.xregisters.write(a0, val);
+
+                run_count_leading_zeros(&mut state, a0, nz::a1);
+                prop_assert_eq!(state.hart.xregisters.read_nz(nz::a1), val.leading_zeros() as u64);
+
+                run_x32_count_leading_zeros(&mut state, a0, nz::a1);
+                prop_assert_eq!(
+                    state.hart.xregisters.read_nz(nz::a1),
+                    (val as u32).leading_zeros() as u64
+                );
+
+                run_count_trailing_zeros(&mut state, a0, nz::a1);
+                prop_assert_eq!(state.hart.xregisters.read_nz(nz::a1), val.trailing_zeros() as u64);
+
+                run_x32_count_trailing_zeros(&mut state, a0, nz::a1);
+                prop_assert_eq!(
+                    state.hart.xregisters.read_nz(nz::a1),
+                    (val as u32).trailing_zeros() as u64
+                );
+
+                run_count_set_bits(&mut state, a0, nz::a1);
+                prop_assert_eq!(state.hart.xregisters.read_nz(nz::a1), val.count_ones() as u64);
+
+                run_x32_count_set_bits(&mut state, a0, nz::a1);
+                prop_assert_eq!(
+                    state.hart.xregisters.read_nz(nz::a1),
+                    (val as u32).count_ones() as u64
+                );
+            }
+        });
+    });
+
+    backend_test!(test_min_max, F, {
+        proptest!(|(lhs in any::<u64>(), rhs in any::<u64>())| {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+            state.hart.xregisters.write(a0, lhs);
+            state.hart.xregisters.write(a1, rhs);
+
+            run_max_signed(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                (lhs as i64).max(rhs as i64) as u64
+            );
+
+            run_max_unsigned(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), lhs.max(rhs));
+
+            run_min_signed(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                (lhs as i64).min(rhs as i64) as u64
+            );
+
+            run_min_unsigned(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), lhs.min(rhs));
+        });
+    });
+
+    backend_test!(test_extend, F, {
+        proptest!(|(val in any::<u64>())| {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+            state.hart.xregisters.write(a0, val);
+
+            run_sign_extend_byte(&mut state, a0, nz::a1);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a1), val as i8 as u64);
+
+            run_sign_extend_half(&mut state, a0, nz::a1);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a1), val as i16 as u64);
+
+            run_zero_extend_half(&mut state, a0, nz::a1);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a1), val as u16 as u64);
+        });
+    });
+
+    backend_test!(test_rotate, F, {
+        proptest!(|(val in any::<u64>(), amount in any::<u64>())| {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+            state.hart.xregisters.write_nz(nz::a0, val);
+            state.hart.xregisters.write_nz(nz::a1, amount);
+
+            run_shift(&mut state, Shift::RotateLeft, nz::a0, nz::a1, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                val.rotate_left((amount & 0x3F) as u32)
+            );
+
+            run_shift(&mut state, Shift::RotateRight, nz::a0, nz::a1, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                val.rotate_right((amount & 0x3F) as u32)
+            );
+
+            run_shift_immediate(&mut state, Shift::RotateRight, (amount & 0x3F) as i64, nz::a0, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                val.rotate_right((amount & 0x3F) as u32)
+            );
+
+            run_x32_shift(&mut state, Shift::RotateLeft, a0, a1, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                (val as u32).rotate_left((amount & 0x1F) as u32) as i32 as u64
+            );
+
+            run_x32_shift(&mut state, Shift::RotateRight, a0, a1, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                (val as u32).rotate_right((amount & 0x1F) as u32) as i32 as u64
+            );
+
+            run_x32_shift_immediate(&mut state, Shift::RotateRight, nz::a0, (amount & 0x1F) as i64, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                (val as u32).rotate_right((amount & 0x1F) as u32) as i32 as u64
+            );
+        });
+    });
+
+    backend_test!(test_bytes, F, {
+        let val_orc_rev = [
+            (0_u64, 0_u64, 0_u64),
+            (0x0102_0304_0506_0708, u64::MAX, 0x0807_0605_0403_0201),
+            (
+                0x8000_0100_0000_7F00,
+                0xFF00_FF00_0000_FF00,
+                0x007F_0000_0001_0080,
+            ),
+            (
+                0x0000_0000_0000_0080,
+                0x0000_0000_0000_00FF,
+                0x8000_0000_0000_0000,
+            ),
+        ];
+
+        for (val, orc, rev) in val_orc_rev {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+            state.hart.xregisters.write(a0, val);
+
+            run_or_combine_bytes(&mut state, a0, nz::a1);
+            assert_eq!(state.hart.xregisters.read_nz(nz::a1), orc);
+
+            run_byte_reverse(&mut state, a0, nz::a1);
+            assert_eq!(state.hart.xregisters.read_nz(nz::a1), rev);
+        }
+
+        proptest!(|(val in any::<u64>())| {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+            state.hart.xregisters.write(a0, val);
+
+            let expected = u64::from_le_bytes(
+                val.to_le_bytes().map(|byte| if byte == 0 { 0 } else { 0xFF }),
+            );
+            run_or_combine_bytes(&mut state, a0, nz::a1);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a1), expected);
+        });
+    });
+
+    backend_test!(test_single_bit, F, {
+        proptest!(|(val in any::<u64>(), index in any::<u64>())| {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+            state.hart.xregisters.write(a0, val);
+            state.hart.xregisters.write(a1, index);
+            let bit = 1_u64 << (index & 0x3F);
+            let imm = (index & 0x3F) as i64;
+
+            run_bit_clear(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), val & !bit);
+            run_bit_clear_immediate(&mut state, imm, a0, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), val & !bit);
+
+            run_bit_extract(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), (val & bit != 0) as u64);
+            run_bit_extract_immediate(&mut state, imm, a0, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), (val & bit != 0) as u64);
+
+            run_bit_invert(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), val ^ bit);
+            run_bit_invert_immediate(&mut state, imm, a0, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), val ^ bit);
+
+            run_bit_set(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), val | bit);
+            run_bit_set_immediate(&mut state, imm, a0, nz::a2);
+            prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), val | bit);
+        });
+    });
+
+    backend_test!(test_pack, F, {
+        proptest!(|(lhs in any::<u64>(), rhs in any::<u64>())| {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+            state.hart.xregisters.write(a0, lhs);
+            state.hart.xregisters.write(a1, rhs);
+
+            run_pack(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                (lhs as u32 as u64) | (rhs << 32)
+            );
+
+            run_pack_byte(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                (lhs as u8 as u64) | ((rhs as u8 as u64) << 8)
+            );
+
+            run_x32_pack(&mut state, a0, a1, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                ((lhs as u16 as u32) | ((rhs as u16 as u32) << 16)) as i32 as u64
+            );
+
+            run_reverse_bits_in_bytes(&mut state, a0, nz::a2);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a2),
+                u64::from_le_bytes(lhs.to_le_bytes().map(u8::reverse_bits))
+            );
+        });
+    });
+}
@@ -0,0 +1,217 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Implementation of the Zknh scalar cryptography extension for RISC-V over the ICB.
+//!
+//! Only the RV64 forms are supported: the `SHA512*R`, `SHA512*H` & `SHA512*L`
+//! instructions are RV32-only.
+
+use crate::instruction_context::ICB;
+use crate::instruction_context::Shift;
+use crate::instruction_context::arithmetic::Arithmetic;
+use crate::machine_state::registers::NonZeroXRegister;
+use crate::machine_state::registers::XRegister;
+
+/// XOR together the results of applying each of `steps` to the lowest 32 bits of
+/// `val(rs1)`, saving the sign-extended result in `rd`.
+#[inline(always)]
+fn sha256_transform(
+    icb: &mut impl ICB,
+    steps: [(Shift, i32); 3],
+    rs1: XRegister,
+    rd: NonZeroXRegister,
+) {
+    let val = icb.xregister_read(rs1);
+    let val = icb.narrow(val);
+
+    let mut res = icb.xvalue32_of_imm(0);
+    for (shift, amount) in steps {
+        let amount = icb.xvalue32_of_imm(amount);
+        let step = val.shift(shift, amount, icb);
+        res = res.xor(step, icb);
+    }
+
+    let res = icb.extend_signed(res);
+    icb.xregister_write_nz(rd, res)
+}
+
+/// XOR together the results of applying each of `steps` to `val(rs1)`, saving the
+/// result in `rd`.
+#[inline(always)]
+fn sha512_transform(
+    icb: &mut impl ICB,
+    steps: [(Shift, i64); 3],
+    rs1: XRegister,
+    rd: NonZeroXRegister,
+) {
+    let val = icb.xregister_read(rs1);
+
+    let mut res = icb.xvalue_of_imm(0);
+    for (shift, amount) in steps {
+        let amount = icb.xvalue_of_imm(amount);
+        let step = val.shift(shift, amount, icb);
+        res = res.xor(step, icb);
+    }
+
+    icb.xregister_write_nz(rd, res)
+}
+
+/// Apply the SHA-256 `Σ0` function to the lowest 32 bits of `val(rs1)`,
+/// saving the sign-extended result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `SHA256SUM0`
+pub fn run_sha256_sum0(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    use Shift::RotateRight as Ror;
+    sha256_transform(icb, [(Ror, 2), (Ror, 13), (Ror, 22)], rs1, rd)
+}
+
+/// Apply the SHA-256 `Σ1` function to the lowest 32 bits of `val(rs1)`,
+/// saving the sign-extended result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `SHA256SUM1`
+pub fn run_sha256_sum1(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    use Shift::RotateRight as Ror;
+    sha256_transform(icb, [(Ror, 6), (Ror, 11), (Ror, 25)], rs1, rd)
+}
+
+/// Apply the SHA-256 `σ0` function to the lowest 32 bits of `val(rs1)`,
+/// saving the sign-extended result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `SHA256SIG0`
+pub fn run_sha256_sig0(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    use Shift::RightUnsigned as Srl;
+    use Shift::RotateRight as Ror;
+    sha256_transform(icb, [(Ror, 7), (Ror, 18), (Srl, 3)], rs1, rd)
+}
+
+/// Apply the SHA-256 `σ1` function to the lowest 32 bits of `val(rs1)`,
+/// saving the sign-extended result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `SHA256SIG1`
+pub fn run_sha256_sig1(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    use Shift::RightUnsigned as Srl;
+    use Shift::RotateRight as Ror;
+    sha256_transform(icb, [(Ror, 17), (Ror, 19), (Srl, 10)], rs1, rd)
+}
+
+/// Apply the SHA-512 `Σ0` function to `val(rs1)`, saving the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `SHA512SUM0`
+pub fn run_sha512_sum0(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    use Shift::RotateRight as Ror;
+    sha512_transform(icb, [(Ror, 28), (Ror, 34), (Ror, 39)], rs1, rd)
+}
+
+/// Apply the SHA-512 `Σ1` function to `val(rs1)`, saving the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `SHA512SUM1`
+pub fn run_sha512_sum1(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    use Shift::RotateRight as Ror;
+    sha512_transform(icb, [(Ror, 14), (Ror, 18), (Ror, 41)], rs1, rd)
+}
+
+/// Apply the SHA-512 `σ0` function to `val(rs1)`, saving the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `SHA512SIG0`
+pub fn run_sha512_sig0(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    use Shift::RightUnsigned as Srl;
+    use Shift::RotateRight as Ror;
+    sha512_transform(icb, [(Ror, 1), (Ror, 8), (Srl, 7)], rs1, rd)
+}
+
+/// Apply the SHA-512 `σ1` function to `val(rs1)`, saving the result in `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `SHA512SIG1`
+pub fn run_sha512_sig1(icb: &mut impl ICB, rs1: XRegister, rd: NonZeroXRegister) {
+    use Shift::RightUnsigned as Srl;
+    use Shift::RotateRight as Ror;
+    sha512_transform(icb, [(Ror, 19), (Ror, 61), (Srl, 6)], rs1, rd)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::arbitrary::any;
+    use proptest::prop_assert_eq;
+    use proptest::proptest;
+
+    use super::*;
+    use crate::backend_test;
+    use crate::machine_state::MachineCoreState;
+    use crate::machine_state::memory::M4K;
+    use crate::machine_state::registers::a0;
+    use crate::machine_state::registers::nz;
+    use crate::state::NewState;
+
+    backend_test!(test_sha256, F, {
+        proptest!(|(val in any::<u64>())| {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+            state.hart.xregisters.write(a0, val);
+            let x = val as u32;
+            let sext = |res: u32| res as i32 as u64;
+
+            run_sha256_sum0(&mut state, a0, nz::a1);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a1),
+                sext(x.rotate_right(2) ^ x.rotate_right(13) ^ x.rotate_right(22))
+            );
+
+            run_sha256_sum1(&mut state, a0, nz::a1);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a1),
+                sext(x.rotate_right(6) ^ x.rotate_right(11) ^ x.rotate_right(25))
+            );
+
+            run_sha256_sig0(&mut state, a0, nz::a1);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a1),
+                sext(x.rotate_right(7) ^ x.rotate_right(18) ^ (x >> 3))
+            );
+
+            run_sha256_sig1(&mut state, a0, nz::a1);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a1),
+                sext(x.rotate_right(17) ^ x.rotate_right(19) ^ (x >> 10))
+            );
+        });
+    });
+
+    backend_test!(test_sha512, F, {
+        proptest!(|(x in any::<u64>())| {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+            state.hart.xregisters.write(a0, x);
+
+            run_sha512_sum0(&mut state, a0, nz::a1);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a1),
+                x.rotate_right(28) ^ x.rotate_right(34) ^ x.rotate_right(39)
+            );
+
+            run_sha512_sum1(&mut state, a0, nz::a1);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a1),
+                x.rotate_right(14) ^ x.rotate_right(18) ^ x.rotate_right(41)
+            );
+
+            run_sha512_sig0(&mut state, a0, nz::a1);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a1),
+                x.rotate_right(1) ^ x.rotate_right(8) ^ (x >> 7)
+            );
+
+            run_sha512_sig1(&mut state, a0, nz::a1);
+            prop_assert_eq!(
+                state.hart.xregisters.read_nz(nz::a1),
+                x.rotate_right(19) ^ x.rotate_right(61) ^ (x >> 6)
+            );
+        });
+    });
+}
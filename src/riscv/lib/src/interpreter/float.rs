@@ -14,6 +14,13 @@ use rustc_apfloat::Round;
 use rustc_apfloat::Status;
 use rustc_apfloat::StatusAnd;
 
+use crate::instruction_context::FArith;
+use crate::instruction_context::FBinary;
+use crate::instruction_context::FCompare;
+use crate::instruction_context::FIntFormat;
+use crate::instruction_context::FloatFormat;
+use crate::instruction_context::ICB;
+use crate::instruction_context::arithmetic::Arithmetic;
 use crate::machine_state::csregisters::CSRRepr;
 use crate::machine_state::csregisters::CSRegister;
 use crate::machine_state::csregisters::CSRegisters;
@@ -21,6 +28,7 @@ use crate::machine_state::hart_state::HartState;
 use crate::machine_state::registers::FRegister;
 use crate::machine_state::registers::FValue;
 use crate::machine_state::registers::XRegister;
+use crate::machine_state::registers::XValue;
 use crate::parser::instruction::InstrRoundingMode;
 use crate::state_backend as backend;
 use crate::traps::Exception;
@@ -30,6 +38,9 @@ pub trait FloatExt: Float + Into<FValue> + Copy + Neg + From<FValue> {
     /// significand bits clear expect the MSB (the quiet bit).
     fn canonical_nan() -> Self;
 
+    /// Correctly rounded square root, as computed by `FSQRT.*`.
+    fn sqrt_r(self, rm: Round) -> StatusAnd<Self>;
+
     /// Canonicalise floating-point values to the canonical nan.
     fn canonicalise(self) -> Self {
         if self.is_nan() {
@@ -54,24 +65,9 @@ where
     ///
     /// Does not set the floating-point exception flags.
     pub(super) fn run_fclass<F: FloatExt>(&mut self, rs1: FRegister, rd: XRegister) {
-        let rval: F = self.fregisters.read(rs1).into();
-
-        let is_neg = rval.is_negative();
-
-        let res: u64 = match rval {
-            _ if rval.is_neg_infinity() => 1,
-            _ if is_neg && rval.is_normal() => 1 << 1,
-            _ if is_neg && rval.is_denormal() => 1 << 2,
-            _ if rval.is_neg_zero() => 1 << 3,
-            _ if rval.is_pos_zero() => 1 << 4,
-            _ if rval.is_denormal() => 1 << 5,
-            _ if rval.is_normal() => 1 << 6,
-            _ if rval.is_pos_infinity() => 1 << 7,
-            _ if rval.is_signaling() => 1 << 8,
-            _ => 1 << 9,
-        };
+        let rval = self.fregisters.read(rs1);
 
-        self.xregisters.write(rd, res);
+        self.xregisters.write(rd, f_classify::<F>(rval));
     }
 
     /// `FEQ.*` instruction.
@@ -83,14 +79,10 @@ where
     ///
     /// If either input is `NaN`, the result is `0`.
     pub(super) fn run_feq<F: FloatExt>(&mut self, rs1: FRegister, rs2: FRegister, rd: XRegister) {
-        let rval1: F = self.fregisters.read(rs1).into();
-        let rval2: F = self.fregisters.read(rs2).into();
+        let rval1 = self.fregisters.read(rs1);
+        let rval2 = self.fregisters.read(rs2);
 
-        if rval1.is_signaling() || rval2.is_signaling() {
-            self.csregisters.set_exception_flag(Fflag::NV);
-        }
-
-        let res = if rval1 == rval2 { 1 } else { 0 };
+        let res = self.f_compare::<F>(FCompare::Equal, rval1, rval2);
 
         self.xregisters.write(rd, res);
     }
@@ -102,14 +94,10 @@ where
     /// If either input is `NaN`, the result is `0`, and the invalid operation exception
     /// flag is set.
     pub(super) fn run_flt<F: FloatExt>(&mut self, rs1: FRegister, rs2: FRegister, rd: XRegister) {
-        let rval1: F = self.fregisters.read(rs1).into();
-        let rval2: F = self.fregisters.read(rs2).into();
+        let rval1 = self.fregisters.read(rs1);
+        let rval2 = self.fregisters.read(rs2);
 
-        if rval1.is_nan() || rval2.is_nan() {
-            self.csregisters.set_exception_flag(Fflag::NV);
-        }
-
-        let res = if rval1 < rval2 { 1 } else { 0 };
+        let res = self.f_compare::<F>(FCompare::LessThan, rval1, rval2);
 
         self.xregisters.write(rd, res);
     }
@@ -121,14 +109,10 @@ where
     /// If either input is `NaN`, the result is `0`, and the invalid operation exception
     /// flag is set.
     pub(super) fn run_fle<F: FloatExt>(&mut self, rs1: FRegister, rs2: FRegister, rd: XRegister) {
-        let rval1: F = self.fregisters.read(rs1).into();
-        let rval2: F = self.fregisters.read(rs2).into();
+        let rval1 = self.fregisters.read(rs1);
+        let rval2 = self.fregisters.read(rs2);
 
-        if rval1.is_nan() || rval2.is_nan() {
-            self.csregisters.set_exception_flag(Fflag::NV);
-        }
-
-        let res = if rval1 <= rval2 { 1 } else { 0 };
+        let res = self.f_compare::<F>(FCompare::LessThanOrEqual, rval1, rval2);
 
         self.xregisters.write(rd, res);
     }
@@ -475,16 +459,7 @@ where
         let rval1: F = self.fregisters.read(rs1).into();
         let rval2: F = self.fregisters.read(rs2).into();
 
-        let sign_bit_1 = rval1.is_negative();
-        let sign_bit_2 = rval2.is_negative();
-
-        let sign_bit = pick_sign(sign_bit_1, sign_bit_2);
-
-        let res = if sign_bit == sign_bit_1 {
-            rval1
-        } else {
-            -rval1
-        };
+        let res = sign_injection(rval1, rval2, pick_sign);
 
         self.fregisters.write(rd, res.into());
     }
@@ -499,6 +474,202 @@ where
         let rval1: F = self.fregisters.read(rs1).into();
         let rval2: F = self.fregisters.read(rs2).into();
 
+        let res = self.min_max_values(rval1, rval2, cmp);
+
+        self.fregisters.write(rd, res.into());
+    }
+}
+
+impl<M> HartState<M>
+where
+    M: backend::ManagerReadWrite,
+{
+    /// Perform the rounding operation `op` over floating-point values.
+    ///
+    /// See [`ICB::f_arith`].
+    ///
+    /// [`ICB::f_arith`]: crate::instruction_context::ICB::f_arith
+    pub(crate) fn f_arith<F: FloatExt>(
+        &mut self,
+        op: FArith,
+        rval1: FValue,
+        rval2: FValue,
+        rval3: FValue,
+        rm: InstrRoundingMode,
+    ) -> Result<FValue, Exception> {
+        let rval1: F = rval1.into();
+        let rval2: F = rval2.into();
+        let rval3: F = rval3.into();
+
+        let rm = self.f_rounding_mode(rm)?;
+
+        let StatusAnd { status, value } = match op {
+            FArith::Add => rval1.add_r(rval2, rm).map(F::canonicalise),
+            FArith::Sub => rval1.sub_r(rval2, rm).map(F::canonicalise),
+            FArith::Mul => rval1.mul_r(rval2, rm).map(F::canonicalise),
+            FArith::Div => rval1.div_r(rval2, rm).map(F::canonicalise),
+            FArith::Sqrt => rval1.sqrt_r(rm),
+            FArith::MulAdd => rval1.mul_add_r(rval2, rval3, rm).map(F::canonicalise),
+            FArith::MulSub => rval1.mul_add_r(rval2, -rval3, rm).map(F::canonicalise),
+            FArith::NegMulSub => (-rval1).mul_add_r(rval2, rval3, rm).map(F::canonicalise),
+            FArith::NegMulAdd => (-rval1).mul_add_r(rval2, -rval3, rm).map(F::canonicalise),
+        };
+
+        if status != Status::OK {
+            self.csregisters.set_exception_flag_status(status);
+        }
+
+        Ok(value.into())
+    }
+
+    /// Perform the exact operation `op` over two floating-point values.
+    ///
+    /// See [`ICB::f_binary`].
+    ///
+    /// [`ICB::f_binary`]: crate::instruction_context::ICB::f_binary
+    pub(crate) fn f_binary<F: FloatExt>(
+        &mut self,
+        op: FBinary,
+        rval1: FValue,
+        rval2: FValue,
+    ) -> FValue {
+        let rval1: F = rval1.into();
+        let rval2: F = rval2.into();
+
+        let res = match op {
+            FBinary::Min => self.min_max_values(rval1, rval2, F::minimum),
+            FBinary::Max => self.min_max_values(rval1, rval2, F::maximum),
+            FBinary::SignInject => sign_injection(rval1, rval2, |_x, y| y),
+            FBinary::SignInjectNegate => sign_injection(rval1, rval2, |_x, y| !y),
+            FBinary::SignInjectXor => sign_injection(rval1, rval2, |x, y| x ^ y),
+        };
+
+        res.into()
+    }
+
+    /// Compare two floating-point values, returning `1` if the comparison holds.
+    ///
+    /// See [`ICB::f_compare`].
+    ///
+    /// [`ICB::f_compare`]: crate::instruction_context::ICB::f_compare
+    pub(crate) fn f_compare<F: FloatExt>(
+        &mut self,
+        op: FCompare,
+        rval1: FValue,
+        rval2: FValue,
+    ) -> XValue {
+        let rval1: F = rval1.into();
+        let rval2: F = rval2.into();
+
+        let invalid = match op {
+            FCompare::Equal => rval1.is_signaling() || rval2.is_signaling(),
+            FCompare::LessThan | FCompare::LessThanOrEqual => rval1.is_nan() || rval2.is_nan(),
+        };
+
+        if invalid {
+            self.csregisters.set_exception_flag(Fflag::NV);
+        }
+
+        let res = match op {
+            FCompare::Equal => rval1 == rval2,
+            FCompare::LessThan => rval1 < rval2,
+            FCompare::LessThanOrEqual => rval1 <= rval2,
+        };
+
+        res as XValue
+    }
+
+    /// Convert an integer to a floating-point value.
+    ///
+    /// See [`ICB::f_from_int`].
+    ///
+    /// [`ICB::f_from_int`]: crate::instruction_context::ICB::f_from_int
+    pub(crate) fn f_from_int<F: FloatExt>(
+        &mut self,
+        int: FIntFormat,
+        rval: XValue,
+        rm: InstrRoundingMode,
+    ) -> Result<FValue, Exception> {
+        let rm = self.f_rounding_mode(rm)?;
+
+        let StatusAnd { status, value } = match int {
+            FIntFormat::Word => F::from_i128_r(rval as i32 as i128, rm),
+            FIntFormat::WordUnsigned => F::from_u128_r(rval as u32 as u128, rm),
+            FIntFormat::Long => F::from_i128_r(rval as i64 as i128, rm),
+            FIntFormat::LongUnsigned => F::from_u128_r(rval as u128, rm),
+        };
+
+        if status != Status::OK {
+            self.csregisters.set_exception_flag_status(status);
+        }
+
+        Ok(value.into())
+    }
+
+    /// Convert a floating-point value to an integer.
+    ///
+    /// See [`ICB::f_to_int`].
+    ///
+    /// [`ICB::f_to_int`]: crate::instruction_context::ICB::f_to_int
+    pub(crate) fn f_to_int<F: FloatExt>(
+        &mut self,
+        int: FIntFormat,
+        rval: FValue,
+        rm: InstrRoundingMode,
+    ) -> Result<XValue, Exception> {
+        let rval: F = rval.into();
+
+        let rm = self.f_rounding_mode(rm)?;
+
+        // spec requires returning the same as for +ve infinity for nans,
+        // which differs from impl in rustc_apfloat
+        let rval = if rval.is_nan() { F::INFINITY } else { rval };
+
+        let StatusAnd { status, value } = match int {
+            FIntFormat::Word => rval
+                .to_i128_r(32, rm, &mut false)
+                .map(|i| i as i32 as XValue),
+            FIntFormat::WordUnsigned => rval
+                .to_u128_r(32, rm, &mut false)
+                .map(|u| u as i32 as XValue),
+            FIntFormat::Long => rval.to_i128_r(64, rm, &mut false).map(|i| i as XValue),
+            FIntFormat::LongUnsigned => rval.to_u128_r(64, rm, &mut false).map(|u| u as XValue),
+        };
+
+        if status != Status::OK {
+            self.csregisters.set_exception_flag_status(status);
+        }
+
+        Ok(value)
+    }
+
+    /// Convert a floating-point value between formats.
+    ///
+    /// See [`ICB::f_convert`].
+    ///
+    /// [`ICB::f_convert`]: crate::instruction_context::ICB::f_convert
+    pub(crate) fn f_convert<F: FloatExt + FloatConvert<T>, T: FloatExt>(
+        &mut self,
+        rval: FValue,
+        rm: InstrRoundingMode,
+    ) -> Result<FValue, Exception> {
+        let rval: F = rval.into();
+
+        let rm = self.f_rounding_mode(rm)?;
+
+        // ignored - all information comes from status.
+        let mut loses_info = false;
+
+        let StatusAnd { status, value } = rval.convert_r(rm, &mut loses_info).map(T::canonicalise);
+
+        if status != Status::OK {
+            self.csregisters.set_exception_flag_status(status);
+        }
+
+        Ok(value.into())
+    }
+
+    fn min_max_values<F: FloatExt>(&mut self, rval1: F, rval2: F, cmp: fn(F, F) -> F) -> F {
         let rval1_nan = rval1.is_nan();
         let rval2_nan = rval2.is_nan();
 
@@ -513,10 +684,358 @@ where
             self.csregisters.set_exception_flag(Fflag::NV);
         }
 
-        self.fregisters.write(rd, res.into());
+        res
+    }
+}
+
+/// Classify a floating-point value, returning the 10-bit mask produced by `FCLASS.*`.
+///
+/// Exactly one bit of the result is set.
+pub(crate) fn f_classify<F: FloatExt>(rval: FValue) -> XValue {
+    let rval: F = rval.into();
+
+    let is_neg = rval.is_negative();
+
+    match rval {
+        _ if rval.is_neg_infinity() => 1,
+        _ if is_neg && rval.is_normal() => 1 << 1,
+        _ if is_neg && rval.is_denormal() => 1 << 2,
+        _ if rval.is_neg_zero() => 1 << 3,
+        _ if rval.is_pos_zero() => 1 << 4,
+        _ if rval.is_denormal() => 1 << 5,
+        _ if rval.is_normal() => 1 << 6,
+        _ if rval.is_pos_infinity() => 1 << 7,
+        _ if rval.is_signaling() => 1 << 8,
+        _ => 1 << 9,
+    }
+}
+
+/// Replace the sign bit of `rval1` with the one chosen by `pick_sign`.
+fn sign_injection<F: FloatExt>(rval1: F, rval2: F, pick_sign: fn(bool, bool) -> bool) -> F {
+    let sign_bit_1 = rval1.is_negative();
+    let sign_bit_2 = rval2.is_negative();
+
+    let sign_bit = pick_sign(sign_bit_1, sign_bit_2);
+
+    if sign_bit == sign_bit_1 {
+        rval1
+    } else {
+        -rval1
     }
 }
 
+/// Perform the rounding operation `op` over `rs1`, writing the result to `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `FSQRT.S`
+/// - `FSQRT.D`
+pub fn run_f_arith_1<I: ICB>(
+    icb: &mut I,
+    format: FloatFormat,
+    op: FArith,
+    rs1: FRegister,
+    rm: InstrRoundingMode,
+    rd: FRegister,
+) -> I::IResult<()> {
+    let rval1 = icb.fregister_read(rs1);
+
+    let res = icb.f_arith(format, op, rval1, rval1, rval1, rm);
+
+    I::map(res, |value| icb.fregister_write(rd, value))
+}
+
+/// Perform the rounding operation `op` over `rs1` & `rs2`, writing the result to `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `FADD.*`
+/// - `FSUB.*`
+/// - `FMUL.*`
+/// - `FDIV.*`
+pub fn run_f_arith_2<I: ICB>(
+    icb: &mut I,
+    format: FloatFormat,
+    op: FArith,
+    rs1: FRegister,
+    rs2: FRegister,
+    rm: InstrRoundingMode,
+    rd: FRegister,
+) -> I::IResult<()> {
+    let rval1 = icb.fregister_read(rs1);
+    let rval2 = icb.fregister_read(rs2);
+
+    let res = icb.f_arith(format, op, rval1, rval2, rval2, rm);
+
+    I::map(res, |value| icb.fregister_write(rd, value))
+}
+
+/// Perform the fused rounding operation `op` over `rs1`, `rs2` & `rs3`, writing the result
+/// to `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `FMADD.*`
+/// - `FMSUB.*`
+/// - `FNMSUB.*`
+/// - `FNMADD.*`
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Fused operations have three operands"
+)]
+pub fn run_f_arith_3<I: ICB>(
+    icb: &mut I,
+    format: FloatFormat,
+    op: FArith,
+    rs1: FRegister,
+    rs2: FRegister,
+    rs3: FRegister,
+    rm: InstrRoundingMode,
+    rd: FRegister,
+) -> I::IResult<()> {
+    let rval1 = icb.fregister_read(rs1);
+    let rval2 = icb.fregister_read(rs2);
+    let rval3 = icb.fregister_read(rs3);
+
+    let res = icb.f_arith(format, op, rval1, rval2, rval3, rm);
+
+    I::map(res, |value| icb.fregister_write(rd, value))
+}
+
+/// Perform the exact operation `op` over `rs1` & `rs2`, writing the result to `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `FMIN.*`
+/// - `FMAX.*`
+/// - `FSGNJ.*`
+/// - `FSGNJN.*`
+/// - `FSGNJX.*`
+pub fn run_f_binary<I: ICB>(
+    icb: &mut I,
+    format: FloatFormat,
+    op: FBinary,
+    rs1: FRegister,
+    rs2: FRegister,
+    rd: FRegister,
+) {
+    let rval1 = icb.fregister_read(rs1);
+    let rval2 = icb.fregister_read(rs2);
+
+    let res = icb.f_binary(format, op, rval1, rval2);
+
+    icb.fregister_write(rd, res)
+}
+
+/// Compare `rs1` & `rs2`, writing `1` to `rd` if the comparison holds, and `0` otherwise.
+///
+/// Relevant RISC-V opcodes:
+/// - `FEQ.*`
+/// - `FLT.*`
+/// - `FLE.*`
+pub fn run_f_compare<I: ICB>(
+    icb: &mut I,
+    format: FloatFormat,
+    op: FCompare,
+    rs1: FRegister,
+    rs2: FRegister,
+    rd: XRegister,
+) {
+    let rval1 = icb.fregister_read(rs1);
+    let rval2 = icb.fregister_read(rs2);
+
+    let res = icb.f_compare(format, op, rval1, rval2);
+
+    icb.xregister_write(rd, res)
+}
+
+/// Write the class of the floating-point value in `rs1` to `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `FCLASS.S`
+/// - `FCLASS.D`
+pub fn run_f_classify<I: ICB>(icb: &mut I, format: FloatFormat, rs1: FRegister, rd: XRegister) {
+    let rval = icb.fregister_read(rs1);
+
+    let res = icb.f_classify(format, rval);
+
+    icb.xregister_write(rd, res)
+}
+
+/// Convert the integer in `rs1` to a floating-point value, writing the result to `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `FCVT.{S,D}.W`
+/// - `FCVT.{S,D}.WU`
+/// - `FCVT.{S,D}.L`
+/// - `FCVT.{S,D}.LU`
+pub fn run_f_from_int<I: ICB>(
+    icb: &mut I,
+    format: FloatFormat,
+    int: FIntFormat,
+    rs1: XRegister,
+    rm: InstrRoundingMode,
+    rd: FRegister,
+) -> I::IResult<()> {
+    let rval = icb.xregister_read(rs1);
+
+    let res = icb.f_from_int(format, int, rval, rm);
+
+    I::map(res, |value| icb.fregister_write(rd, value))
+}
+
+/// Convert the floating-point value in `rs1` to an integer, writing the result to `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `FCVT.W.{S,D}`
+/// - `FCVT.WU.{S,D}`
+/// - `FCVT.L.{S,D}`
+/// - `FCVT.LU.{S,D}`
+pub fn run_f_to_int<I: ICB>(
+    icb: &mut I,
+    format: FloatFormat,
+    int: FIntFormat,
+    rs1: FRegister,
+    rm: InstrRoundingMode,
+    rd: XRegister,
+) -> I::IResult<()> {
+    let rval = icb.fregister_read(rs1);
+
+    let res = icb.f_to_int(format, int, rval, rm);
+
+    I::map(res, |value| icb.xregister_write(rd, value))
+}
+
+/// Convert the floating-point value in `rs1` between formats, writing the result to `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `FCVT.S.D`
+/// - `FCVT.D.S`
+pub fn run_f_convert<I: ICB>(
+    icb: &mut I,
+    from: FloatFormat,
+    to: FloatFormat,
+    rs1: FRegister,
+    rm: InstrRoundingMode,
+    rd: FRegister,
+) -> I::IResult<()> {
+    let rval = icb.fregister_read(rs1);
+
+    let res = icb.f_convert(from, to, rval, rm);
+
+    I::map(res, |value| icb.fregister_write(rd, value))
+}
+
+/// Move the bits of the floating-point value in `rs1` to `rd`.
+///
+/// Single-precision values are sign-extended from their lower 32 bits.
+///
+/// Relevant RISC-V opcodes:
+/// - `FMV.X.W`
+/// - `FMV.X.D`
+pub fn run_fmv_x_f<I: ICB>(icb: &mut I, format: FloatFormat, rs1: FRegister, rd: XRegister) {
+    let rval = icb.fregister_read(rs1);
+    let rval = icb.fvalue_to_xvalue(rval);
+
+    let res = match format {
+        FloatFormat::Single => {
+            let rval = icb.narrow(rval);
+            icb.extend_signed(rval)
+        }
+        FloatFormat::Double => rval,
+    };
+
+    icb.xregister_write(rd, res)
+}
+
+/// Move the bits of the integer in `rs1` to the floating-point register `rd`.
+///
+/// Single-precision values are taken from the lower 32 bits, and NaN-boxed.
+///
+/// Relevant RISC-V opcodes:
+/// - `FMV.W.X`
+/// - `FMV.D.X`
+pub fn run_fmv_f_x<I: ICB>(icb: &mut I, format: FloatFormat, rs1: XRegister, rd: FRegister) {
+    let rval = icb.xregister_read(rs1);
+
+    let res = match format {
+        FloatFormat::Single => {
+            let rval = icb.narrow(rval);
+            let rval = icb.extend_unsigned(rval);
+            nan_box(icb, rval)
+        }
+        FloatFormat::Double => rval,
+    };
+
+    let res = icb.xvalue_to_fvalue(res);
+    icb.fregister_write(rd, res)
+}
+
+/// Load a floating-point value from the address starting at `val(rs1) + imm`, writing it
+/// to `rd`.
+///
+/// Relevant RISC-V opcodes:
+/// - `FLW`
+/// - `FLD`
+/// - `C.FLD`
+/// - `C.FLDSP`
+pub fn run_f_load<I: ICB>(
+    icb: &mut I,
+    format: FloatFormat,
+    imm: i64,
+    rs1: XRegister,
+    rd: FRegister,
+) -> I::IResult<()> {
+    let base_address = icb.xregister_read(rs1);
+    let offset = icb.xvalue_of_imm(imm);
+
+    let address = base_address.add(offset, icb);
+
+    let value = match format {
+        FloatFormat::Single => {
+            let value = icb.main_memory_load::<u32>(address);
+            I::map(value, |value| nan_box(icb, value))
+        }
+        FloatFormat::Double => icb.main_memory_load::<u64>(address),
+    };
+
+    I::map(value, |value| {
+        let value = icb.xvalue_to_fvalue(value);
+        icb.fregister_write(rd, value)
+    })
+}
+
+/// Store the floating-point value in `rs2` to the address starting at `val(rs1) + imm`.
+///
+/// Relevant RISC-V opcodes:
+/// - `FSW`
+/// - `FSD`
+/// - `C.FSD`
+/// - `C.FSDSP`
+pub fn run_f_store<I: ICB>(
+    icb: &mut I,
+    format: FloatFormat,
+    imm: i64,
+    rs1: XRegister,
+    rs2: FRegister,
+) -> I::IResult<()> {
+    let base_address = icb.xregister_read(rs1);
+    let offset = icb.xvalue_of_imm(imm);
+
+    let address = base_address.add(offset, icb);
+
+    let value = icb.fregister_read(rs2);
+    let value = icb.fvalue_to_xvalue(value);
+
+    match format {
+        FloatFormat::Single => icb.main_memory_store::<u32>(address, value),
+        FloatFormat::Double => icb.main_memory_store::<u64>(address, value),
+    }
+}
+
+/// NaN-box a single-precision value held in the lower 32 bits of `value`, by setting all
+/// upper 32 bits to `1`.
+fn nan_box<I: ICB>(icb: &mut I, value: I::XValue) -> I::XValue {
+    let upper = icb.xvalue_of_imm(0xffff_ffff_0000_0000_u64 as i64);
+    value.or(upper, icb)
+}
+
 /// There are 5 supported rounding modes
 #[expect(clippy::upper_case_acronyms, reason = "Matches the RISC-V spec")]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
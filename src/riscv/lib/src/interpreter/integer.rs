@@ -749,6 +749,51 @@ pub fn run_x32_rem_unsigned(
     icb.xregister_write_nz(rd, result);
 }
 
+/// Store `0` in `rd` if `val(rs2) == 0`, otherwise store `val(rs1)`.
+///
+/// Relevant RISC-V opcodes:
+/// - `CZERO.EQZ`
+pub fn run_conditional_zero_equal_zero(
+    icb: &mut impl ICB,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: NonZeroXRegister,
+) {
+    run_conditional_zero(icb, Predicate::Equal, rs1, rs2, rd)
+}
+
+/// Store `0` in `rd` if `val(rs2) != 0`, otherwise store `val(rs1)`.
+///
+/// Relevant RISC-V opcodes:
+/// - `CZERO.NEZ`
+pub fn run_conditional_zero_not_equal_zero(
+    icb: &mut impl ICB,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: NonZeroXRegister,
+) {
+    run_conditional_zero(icb, Predicate::NotEqual, rs1, rs2, rd)
+}
+
+/// Store `0` in `rd` if `val(rs2)` compared against `0` with `predicate` holds,
+/// otherwise store `val(rs1)`.
+#[inline(always)]
+fn run_conditional_zero(
+    icb: &mut impl ICB,
+    predicate: Predicate,
+    rs1: XRegister,
+    rs2: XRegister,
+    rd: NonZeroXRegister,
+) {
+    let rval1 = icb.xregister_read(rs1);
+    let rval2 = icb.xregister_read(rs2);
+    let zero = icb.xvalue_of_imm(0);
+    let cond = rval2.compare(zero, predicate, icb);
+
+    let result = icb.branch_merge::<XValue, _, _>(cond, |icb| icb.xvalue_of_imm(0), |_icb| rval1);
+    icb.xregister_write_nz(rd, result);
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::arbitrary::any;
@@ -1440,4 +1485,23 @@ mod tests {
         );
         test_x64_mul_high!(state, 0u64, u64::MAX, MulHighType::Unsigned, 0u64);
     });
+
+    backend_test!(test_conditional_zero, F, {
+        proptest!(|(rs1_val in any::<u64>(), rs2_val in any::<u64>())| {
+            let mut state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+
+            for rs2_val in [0, rs2_val] {
+                state.hart.xregisters.write(a0, rs1_val);
+                state.hart.xregisters.write(a1, rs2_val);
+
+                run_conditional_zero_equal_zero(&mut state, a0, a1, nz::a2);
+                let expected = if rs2_val == 0 { 0 } else { rs1_val };
+                prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), expected);
+
+                run_conditional_zero_not_equal_zero(&mut state, a0, a1, nz::a2);
+                let expected = if rs2_val != 0 { 0 } else { rs1_val };
+                prop_assert_eq!(state.hart.xregisters.read_nz(nz::a2), expected);
+            }
+        });
+    });
 }
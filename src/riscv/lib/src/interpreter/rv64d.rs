@@ -7,6 +7,7 @@
 //! Chapter 12 - "D" Standard Extension for Double-Precision Floating-Point
 
 use rustc_apfloat::Float;
+use rustc_apfloat::Round;
 use rustc_apfloat::Status;
 use rustc_apfloat::StatusAnd;
 use rustc_apfloat::ieee::Double;
@@ -42,6 +43,11 @@ impl FloatExt for Double {
     fn canonical_nan() -> Self {
         Self::from_bits(CANONICAL_NAN_BITS as u128)
     }
+
+    fn sqrt_r(self, rm: Round) -> StatusAnd<Self> {
+        let (res, _iterations) = ieee_apsqrt::sqrt_accurate(self.to_bits() as u64, rm);
+        res.map(|bits| Self::from_bits(bits as u128))
+    }
 }
 
 impl<M> HartState<M>
@@ -154,11 +160,11 @@ where
         rm: InstrRoundingMode,
         rd: FRegister,
     ) -> Result<(), Exception> {
-        let rval: u64 = self.fregisters.read(rs1).into();
+        let rval: Double = self.fregisters.read(rs1).into();
 
         let rm = self.f_rounding_mode(rm)?;
 
-        let (StatusAnd { status, value }, _iterations) = ieee_apsqrt::sqrt_accurate(rval, rm);
+        let StatusAnd { status, value } = rval.sqrt_r(rm);
 
         if status != Status::OK {
             self.csregisters.set_exception_flag_status(status);
@@ -7,6 +7,7 @@
 //! Chapter 11 - "F" Standard Extension for Single-Precision Floating-Point
 
 use rustc_apfloat::Float;
+use rustc_apfloat::Round;
 use rustc_apfloat::Status;
 use rustc_apfloat::StatusAnd;
 use rustc_apfloat::ieee::Single;
@@ -31,15 +32,7 @@ impl From<Single> for FValue {
 
 impl From<FValue> for Single {
     fn from(f: FValue) -> Self {
-        let val: u64 = f.into();
-
-        // Check value correctly NaN boxed:
-        // all upper bits must be set to 1
-        if val >> 32 != 0xffffffff {
-            Single::canonical_nan()
-        } else {
-            Single::from_bits(val as u32 as u128)
-        }
+        Single::from_bits(fvalue_to_f32_bits(f) as u128)
     }
 }
 
@@ -49,6 +42,11 @@ impl FloatExt for Single {
     fn canonical_nan() -> Self {
         Self::from_bits(CANONICAL_NAN_BITS as u128)
     }
+
+    fn sqrt_r(self, rm: Round) -> StatusAnd<Self> {
+        let (res, _iterations) = ieee_apsqrt::sqrt_accurate(self.to_bits() as u32, rm);
+        res.map(|bits| Self::from_bits(bits as u128))
+    }
 }
 
 impl<M> HartState<M>
@@ -161,18 +159,16 @@ where
         rm: InstrRoundingMode,
         rd: FRegister,
     ) -> Result<(), Exception> {
-        let rval = self.fregisters.read(rs1);
+        let rval: Single = self.fregisters.read(rs1).into();
         let rm = self.f_rounding_mode(rm)?;
 
-        let rval = fvalue_to_f32_bits(rval);
-
-        let (StatusAnd { status, value }, _iterations) = ieee_apsqrt::sqrt_accurate(rval, rm);
+        let StatusAnd { status, value } = rval.sqrt_r(rm);
 
         if status != Status::OK {
             self.csregisters.set_exception_flag_status(status);
         }
 
-        self.fregisters.write(rd, f32_to_fvalue(value));
+        self.fregisters.write(rd, value.into());
 
         Ok(())
     }
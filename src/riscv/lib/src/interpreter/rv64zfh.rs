@@ -0,0 +1,741 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Implementation of Zfh & Zfhmin extensions for RISC-V
+//!
+//! "Zfh" and "Zfhmin" Standard Extensions for Half-Precision Floating-Point
+
+use rustc_apfloat::Float;
+use rustc_apfloat::FloatConvert;
+use rustc_apfloat::Round;
+use rustc_apfloat::Status;
+use rustc_apfloat::StatusAnd;
+use rustc_apfloat::ieee::Double;
+use rustc_apfloat::ieee::Half;
+use rustc_apfloat::ieee::Single;
+
+use super::float::FloatExt;
+use crate::machine_state::MachineCoreState;
+use crate::machine_state::hart_state::HartState;
+use crate::machine_state::memory;
+use crate::machine_state::registers::FRegister;
+use crate::machine_state::registers::FValue;
+use crate::machine_state::registers::XRegister;
+use crate::parser::instruction::InstrRoundingMode;
+use crate::state_backend as backend;
+use crate::traps::Exception;
+
+impl From<Half> for FValue {
+    fn from(f: Half) -> Self {
+        let val = f.to_bits();
+        f16_to_fvalue(val as u16)
+    }
+}
+
+impl From<FValue> for Half {
+    fn from(f: FValue) -> Self {
+        Half::from_bits(fvalue_to_f16_bits(f) as u128)
+    }
+}
+
+const CANONICAL_NAN_BITS: u16 = 0x7e00;
+
+impl FloatExt for Half {
+    fn canonical_nan() -> Self {
+        Self::from_bits(CANONICAL_NAN_BITS as u128)
+    }
+
+    /// The square root is computed in double precision, and then rounded to half
+    /// precision. A double has more than twice the precision of a half plus two
+    /// bits, so the double rounding never changes the result.
+    fn sqrt_r(self, rm: Round) -> StatusAnd<Self> {
+        // ignored - all information comes from status.
+        let mut loses_info = false;
+
+        let StatusAnd {
+            status: widen_status,
+            value: wide,
+        }: StatusAnd<Double> = self.convert_r(rm, &mut loses_info);
+
+        let (
+            StatusAnd {
+                status: sqrt_status,
+                value: root,
+            },
+            _iterations,
+        ) = ieee_apsqrt::sqrt_accurate(wide.to_bits() as u64, rm);
+
+        let StatusAnd {
+            status: narrow_status,
+            value,
+        }: StatusAnd<Half> = Double::from_bits(root as u128)
+            .convert_r(rm, &mut loses_info)
+            .map(Half::canonicalise);
+
+        StatusAnd {
+            status: widen_status | sqrt_status | narrow_status,
+            value,
+        }
+    }
+}
+
+impl<M> HartState<M>
+where
+    M: backend::ManagerReadWrite,
+{
+    /// `FCLASS.H` F-type instruction.
+    ///
+    /// See [Self::run_fclass].
+    pub fn run_fclass_h(&mut self, rs1: FRegister, rd: XRegister) -> Result<(), Exception> {
+        self.run_fclass::<Half>(rs1, rd);
+        Ok(())
+    }
+
+    /// `FEQ.H` R-type instruction.
+    ///
+    /// See [Self::run_feq].
+    pub fn run_feq_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rd: XRegister,
+    ) -> Result<(), Exception> {
+        self.run_feq::<Half>(rs1, rs2, rd);
+        Ok(())
+    }
+
+    /// `FLE.H` R-type instruction.
+    ///
+    /// See [Self::run_fle].
+    pub fn run_fle_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rd: XRegister,
+    ) -> Result<(), Exception> {
+        self.run_fle::<Half>(rs1, rs2, rd);
+        Ok(())
+    }
+
+    /// `FLT.H` R-type instruction.
+    ///
+    /// See [Self::run_flt].
+    pub fn run_flt_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rd: XRegister,
+    ) -> Result<(), Exception> {
+        self.run_flt::<Half>(rs1, rs2, rd);
+        Ok(())
+    }
+
+    /// `FADD.H` R-type instruction.
+    ///
+    /// See [Self::run_fadd].
+    pub fn run_fadd_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fadd::<Half>(rs1, rs2, rm, rd)
+    }
+
+    /// `FSUB.H` R-type instruction.
+    ///
+    /// See [Self::run_fsub].
+    pub fn run_fsub_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fsub::<Half>(rs1, rs2, rm, rd)
+    }
+
+    /// `FMUL.H` R-type instruction.
+    ///
+    /// See [Self::run_fmul].
+    pub fn run_fmul_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fmul::<Half>(rs1, rs2, rm, rd)
+    }
+
+    /// `FDIV.H` R-type instruction.
+    ///
+    /// See [Self::run_fdiv].
+    pub fn run_fdiv_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fdiv::<Half>(rs1, rs2, rm, rd)
+    }
+
+    /// `FSQRT.H` R-type instruction.
+    ///
+    /// See [`FloatExt::sqrt_r`].
+    pub fn run_fsqrt_h(
+        &mut self,
+        rs1: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        let rval: Half = self.fregisters.read(rs1).into();
+        let rm = self.f_rounding_mode(rm)?;
+
+        let StatusAnd { status, value } = rval.sqrt_r(rm);
+
+        if status != Status::OK {
+            self.csregisters.set_exception_flag_status(status);
+        }
+
+        self.fregisters.write(rd, value.into());
+
+        Ok(())
+    }
+
+    /// `FMIN.H` R-type instruction.
+    ///
+    /// See [Self::run_fmin].
+    pub fn run_fmin_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fmin::<Half>(rs1, rs2, rd);
+        Ok(())
+    }
+
+    /// `FMAX.H` R-type instruction.
+    ///
+    /// See [Self::run_fmax].
+    pub fn run_fmax_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fmax::<Half>(rs1, rs2, rd);
+        Ok(())
+    }
+
+    /// `FMADD.H` instruction.
+    ///
+    /// See [Self::run_fmadd].
+    pub fn run_fmadd_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rs3: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fmadd::<Half>(rs1, rs2, rs3, rm, rd)
+    }
+
+    /// `FMSUB.H` instruction.
+    ///
+    /// See [Self::run_fmsub].
+    pub fn run_fmsub_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rs3: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fmsub::<Half>(rs1, rs2, rs3, rm, rd)
+    }
+
+    /// `FNMSUB.H` instruction.
+    ///
+    /// See [Self::run_fnmsub].
+    pub fn run_fnmsub_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rs3: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fnmsub::<Half>(rs1, rs2, rs3, rm, rd)
+    }
+
+    /// `FNMADD.H` instruction.
+    ///
+    /// See [Self::run_fnmadd].
+    pub fn run_fnmadd_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rs3: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fnmadd::<Half>(rs1, rs2, rs3, rm, rd)
+    }
+
+    /// `FCVT.H.W` R-type instruction.
+    ///
+    /// See [Self::run_fcvt_int_fmt].
+    pub fn run_fcvt_h_w(
+        &mut self,
+        rs1: XRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fcvt_int_fmt(rs1, rm, rd, |u| u as i32 as i128, Half::from_i128_r)
+    }
+
+    /// `FCVT.H.WU` R-type instruction.
+    ///
+    /// See [Self::run_fcvt_int_fmt].
+    pub fn run_fcvt_h_wu(
+        &mut self,
+        rs1: XRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fcvt_int_fmt(rs1, rm, rd, |u| u as u32 as u128, Half::from_u128_r)
+    }
+
+    /// `FCVT.H.L` R-type instruction.
+    ///
+    /// See [Self::run_fcvt_int_fmt].
+    pub fn run_fcvt_h_l(
+        &mut self,
+        rs1: XRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fcvt_int_fmt(rs1, rm, rd, |u| u as i64 as i128, Half::from_i128_r)
+    }
+
+    /// `FCVT.H.LU` R-type instruction.
+    ///
+    /// See [Self::run_fcvt_int_fmt].
+    pub fn run_fcvt_h_lu(
+        &mut self,
+        rs1: XRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fcvt_int_fmt(rs1, rm, rd, |u| u as u128, Half::from_u128_r)
+    }
+
+    /// `FCVT.W.H` R-type instruction.
+    pub fn run_fcvt_w_h(
+        &mut self,
+        rs1: FRegister,
+        rm: InstrRoundingMode,
+        rd: XRegister,
+    ) -> Result<(), Exception> {
+        self.run_fcvt_fmt_int(
+            rs1,
+            rm,
+            rd,
+            |u| u as i32 as u64,
+            |f, rm| Half::to_i128_r(f, 32, rm, &mut false),
+        )
+    }
+
+    /// `FCVT.WU.H` R-type instruction.
+    pub fn run_fcvt_wu_h(
+        &mut self,
+        rs1: FRegister,
+        rm: InstrRoundingMode,
+        rd: XRegister,
+    ) -> Result<(), Exception> {
+        self.run_fcvt_fmt_int(
+            rs1,
+            rm,
+            rd,
+            |u| u as i32 as u64,
+            |f, rm| Half::to_u128_r(f, 32, rm, &mut false),
+        )
+    }
+
+    /// `FCVT.L.H` R-type instruction.
+    pub fn run_fcvt_l_h(
+        &mut self,
+        rs1: FRegister,
+        rm: InstrRoundingMode,
+        rd: XRegister,
+    ) -> Result<(), Exception> {
+        self.run_fcvt_fmt_int(
+            rs1,
+            rm,
+            rd,
+            |u| u as u64,
+            |f, rm| Half::to_i128_r(f, 64, rm, &mut false),
+        )
+    }
+
+    /// `FCVT.LU.H` R-type instruction.
+    pub fn run_fcvt_lu_h(
+        &mut self,
+        rs1: FRegister,
+        rm: InstrRoundingMode,
+        rd: XRegister,
+    ) -> Result<(), Exception> {
+        self.run_fcvt_fmt_int(
+            rs1,
+            rm,
+            rd,
+            |u| u as u64,
+            |f, rm| Half::to_u128_r(f, 64, rm, &mut false),
+        )
+    }
+
+    /// `FCVT.S.H` R-type instruction.
+    ///
+    /// See [Self::run_fcvt_fmt_fmt].
+    pub fn run_fcvt_s_h(
+        &mut self,
+        rs1: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fcvt_fmt_fmt::<Half, Single>(rs1, rm, rd)
+    }
+
+    /// `FCVT.H.S` R-type instruction.
+    ///
+    /// See [Self::run_fcvt_fmt_fmt].
+    pub fn run_fcvt_h_s(
+        &mut self,
+        rs1: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fcvt_fmt_fmt::<Single, Half>(rs1, rm, rd)
+    }
+
+    /// `FCVT.D.H` R-type instruction.
+    ///
+    /// See [Self::run_fcvt_fmt_fmt].
+    pub fn run_fcvt_d_h(
+        &mut self,
+        rs1: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fcvt_fmt_fmt::<Half, Double>(rs1, rm, rd)
+    }
+
+    /// `FCVT.H.D` R-type instruction.
+    ///
+    /// See [Self::run_fcvt_fmt_fmt].
+    pub fn run_fcvt_h_d(
+        &mut self,
+        rs1: FRegister,
+        rm: InstrRoundingMode,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fcvt_fmt_fmt::<Double, Half>(rs1, rm, rd)
+    }
+
+    /// `FSGNJ.H` R-type instruction.
+    ///
+    /// See [Self::run_fsgnj].
+    pub fn run_fsgnj_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fsgnj::<Half>(rs1, rs2, rd);
+        Ok(())
+    }
+
+    /// `FSGNJN.H` R-type instruction.
+    ///
+    /// See [Self::run_fsgnjn].
+    pub fn run_fsgnjn_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fsgnjn::<Half>(rs1, rs2, rd);
+        Ok(())
+    }
+
+    /// `FSGNJX.H` R-type instruction.
+    ///
+    /// See [Self::run_fsgnjx].
+    pub fn run_fsgnjx_h(
+        &mut self,
+        rs1: FRegister,
+        rs2: FRegister,
+        rd: FRegister,
+    ) -> Result<(), Exception> {
+        self.run_fsgnjx::<Half>(rs1, rs2, rd);
+        Ok(())
+    }
+
+    /// `FMV.X.H` F-type instruction
+    ///
+    /// Moves the half-precision value in floating-point register `rs1`
+    /// represented in IEEE 754-2008 encoding to the lower 16 bits of
+    /// integer register `rd`.
+    ///
+    /// The bits are not modified in the transfer,
+    /// and in particular, the payloads of non-canonical NaNs are preserved.
+    ///
+    /// The higher 48 bits of the destination register are filled with copies
+    /// of the floating-point number’s sign bit.
+    pub fn run_fmv_x_h(&mut self, rs1: FRegister, rd: XRegister) -> Result<(), Exception> {
+        let rval: u64 = self.fregisters.read(rs1).into();
+        let rval = rval as i16 as u64;
+
+        self.xregisters.write(rd, rval);
+        Ok(())
+    }
+
+    /// `FMV.H.X` F-type instruction
+    ///
+    /// Moves the half-precision value encoded in IEEE 754-2008 standard
+    /// encoding from the lower 16 bits of integer register `rs1` to the
+    /// floating-point register `rd`.
+    ///
+    /// The bits are not modified in the transfer,
+    /// and in particular, the payloads of non-canonical NaNs are preserved.
+    pub fn run_fmv_h_x(&mut self, rs1: XRegister, rd: FRegister) -> Result<(), Exception> {
+        let rval = self.xregisters.read(rs1) as u16;
+        let rval = f16_to_fvalue(rval);
+
+        self.fregisters.write(rd, rval);
+        Ok(())
+    }
+}
+
+impl<MC, M> MachineCoreState<MC, M>
+where
+    MC: memory::MemoryConfig,
+    M: backend::ManagerReadWrite,
+{
+    /// `FLH` I-type instruction.
+    ///
+    /// Loads a half-precision floating point value from memory into `rd`.
+    /// It uses the same address format as integer-base ISA.
+    pub fn run_flh(&mut self, imm: i64, rs1: XRegister, rd: FRegister) -> Result<(), Exception> {
+        let val: u16 = self.read_from_bus(imm, rs1)?;
+        let val = f16_to_fvalue(val);
+
+        self.hart.fregisters.write(rd, val);
+        Ok(())
+    }
+
+    /// `FSH` S-type instruction.
+    ///
+    /// Stores a half-precision floating point value into memory from `rs2`.
+    /// It uses the same address format as integer-base ISA.
+    pub fn run_fsh(&mut self, imm: i64, rs1: XRegister, rs2: FRegister) -> Result<(), Exception> {
+        let val: u64 = self.hart.fregisters.read(rs2).into();
+        self.write_to_bus(imm, rs1, val as u16)
+    }
+}
+
+/// The upper 48 bits are set to `1` for any `f16` write.
+#[inline(always)]
+fn f16_to_fvalue(val: u16) -> FValue {
+    (val as u64 | 0xffffffffffff0000).into()
+}
+
+fn fvalue_to_f16_bits(f: FValue) -> u16 {
+    let val: u64 = f.into();
+
+    // Check value correctly NaN boxed:
+    // all upper bits must be set to 1
+    if val >> 16 != 0xffffffffffff {
+        CANONICAL_NAN_BITS
+    } else {
+        val as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary_int::u5;
+    use proptest::prelude::*;
+    use rustc_apfloat::Float;
+    use rustc_apfloat::ieee::Half;
+
+    use super::f16_to_fvalue;
+    use crate::backend_test;
+    use crate::interpreter::float::RoundingMode;
+    use crate::machine_state::MachineCoreState;
+    use crate::machine_state::csregisters::CSRRepr;
+    use crate::machine_state::csregisters::CSRegister;
+    use crate::machine_state::hart_state::HartState;
+    use crate::machine_state::memory::M4K;
+    use crate::machine_state::registers::fa1;
+    use crate::machine_state::registers::fa2;
+    use crate::machine_state::registers::fa3;
+    use crate::machine_state::registers::fa4;
+    use crate::machine_state::registers::parse_fregister;
+    use crate::machine_state::registers::parse_xregister;
+    use crate::machine_state::registers::t0;
+    use crate::parser::instruction::InstrRoundingMode;
+    use crate::state::NewState;
+    use crate::traps::Exception;
+
+    const RNE: InstrRoundingMode = InstrRoundingMode::Static(RoundingMode::RNE);
+
+    backend_test!(test_fmv_h, F, {
+        proptest!(|(
+            h in any::<u16>(),
+            rs1 in (1_u8..31).prop_map(u5::new).prop_map(parse_xregister),
+            rs1_f in (1_u8..31).prop_map(u5::new).prop_map(parse_fregister),
+            rs2 in (1_u8..31).prop_map(u5::new).prop_map(parse_xregister),
+        )| {
+            let mut state = HartState::new(&mut F::manager());
+
+            state.xregisters.write(rs1, h as u64);
+
+            assert!(state.run_fmv_h_x(rs1, rs1_f).is_ok());
+
+            let read: u64 = state.fregisters.read(rs1_f).into();
+            assert_eq!(read, h as u64 | 0xffffffffffff0000, "Expected bits to be NaN-boxed in fregister");
+
+            assert!(state.run_fmv_x_h(rs1_f, rs2).is_ok());
+
+            let read = state.xregisters.read(rs2);
+            assert_eq!(read, h as i16 as u64, "Expected sign bit to be extended");
+        });
+    });
+
+    backend_test!(test_fcvt_h_roundtrip, F, {
+        proptest!(|(h in any::<u16>())| {
+            let mut state = HartState::new(&mut F::manager());
+            let is_nan = Half::from_bits(h as u128).is_nan();
+
+            state.fregisters.write(fa1, f16_to_fvalue(h));
+
+            // Widening is exact, and narrowing back recovers the original value
+            state.run_fcvt_s_h(fa1, RNE, fa2).unwrap();
+            state.run_fcvt_h_s(fa2, RNE, fa3).unwrap();
+            state.run_fcvt_d_h(fa1, RNE, fa2).unwrap();
+            state.run_fcvt_h_d(fa2, RNE, fa4).unwrap();
+
+            let expected = if is_nan { 0x7e00 } else { h };
+            prop_assert_eq!(state.fregisters.read(fa3), f16_to_fvalue(expected));
+            prop_assert_eq!(state.fregisters.read(fa4), f16_to_fvalue(expected));
+
+            if !Half::from_bits(h as u128).is_signaling() {
+                prop_assert_eq!(state.csregisters.read::<CSRRepr>(CSRegister::fflags), 0);
+            }
+        });
+    });
+
+    backend_test!(test_fcvt_h_flags, F, {
+        let mut state = HartState::new(&mut F::manager());
+
+        // Values which are not correctly NaN-boxed are read as the canonical NaN
+        state.fregisters.write(fa1, 0x3c00.into());
+        state.run_fcvt_s_h(fa1, RNE, fa2).unwrap();
+        assert_eq!(u64::from(state.fregisters.read(fa2)), 0xffffffff_7fc00000);
+        assert_eq!(state.csregisters.read::<CSRRepr>(CSRegister::fflags), 0);
+
+        // 1.0 is exact
+        state.fregisters.write(fa1, (1.0_f64.to_bits()).into());
+        state.run_fcvt_h_d(fa1, RNE, fa2).unwrap();
+        assert_eq!(state.fregisters.read(fa2), f16_to_fvalue(0x3c00));
+        assert_eq!(state.csregisters.read::<CSRRepr>(CSRegister::fflags), 0);
+
+        // 65520.0 is beyond the largest finite half, overflowing to infinity
+        state.fregisters.write(fa1, (65520.0_f64.to_bits()).into());
+        state.run_fcvt_h_d(fa1, RNE, fa2).unwrap();
+        assert_eq!(state.fregisters.read(fa2), f16_to_fvalue(0x7c00));
+        assert_eq!(
+            state.csregisters.read::<CSRRepr>(CSRegister::fflags),
+            0b00101
+        );
+    });
+
+    backend_test!(test_fsqrt_h, F, {
+        let mut state = HartState::new(&mut F::manager());
+
+        // sqrt(4.0) == 2.0 exactly
+        state.fregisters.write(fa1, f16_to_fvalue(0x4400));
+        state.run_fsqrt_h(fa1, RNE, fa2).unwrap();
+        assert_eq!(state.fregisters.read(fa2), f16_to_fvalue(0x4000));
+        assert_eq!(state.csregisters.read::<CSRRepr>(CSRegister::fflags), 0);
+
+        // sqrt(2.0) is inexact
+        state.fregisters.write(fa1, f16_to_fvalue(0x4000));
+        state.run_fsqrt_h(fa1, RNE, fa2).unwrap();
+        assert_eq!(state.fregisters.read(fa2), f16_to_fvalue(0x3da8));
+        assert_eq!(
+            state.csregisters.read::<CSRRepr>(CSRegister::fflags),
+            0b00001
+        );
+
+        // sqrt(-1.0) is invalid
+        state.csregisters.write(CSRegister::fflags, 0);
+        state.fregisters.write(fa1, f16_to_fvalue(0xbc00));
+        state.run_fsqrt_h(fa1, RNE, fa2).unwrap();
+        assert_eq!(state.fregisters.read(fa2), f16_to_fvalue(0x7e00));
+        assert_eq!(
+            state.csregisters.read::<CSRRepr>(CSRegister::fflags),
+            0b10000
+        );
+    });
+
+    backend_test!(test_load_store, F, {
+        let state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+        let state_cell = std::cell::RefCell::new(state);
+
+        proptest!(|(
+            val in any::<u16>(),
+        )|
+        {
+            let mut state = state_cell.borrow_mut();
+            state.reset();
+            state.main_memory.set_all_readable_writeable();
+
+            let mut perform_test = |offset: u64| -> Result<(), Exception> {
+                // Save test values v_i in registers ai
+                state.hart.fregisters.write(fa1, f16_to_fvalue(val));
+
+                // t0 will hold the "global" offset of all loads / stores we are going to make
+                state.hart.xregisters.write(t0, offset);
+
+                // Perform the stores
+                state.run_fsh(2, t0, fa1)?;
+
+                state.run_flh(2, t0, fa4)?;
+
+                assert_eq!(state.hart.fregisters.read(fa4), f16_to_fvalue(val));
+                Ok(())
+            };
+
+            let invalid_offset = 0u64.wrapping_sub(1024);
+            let aligned_offset = 512;
+            let misaligned_offset = 513;
+
+            // Out of bounds loads / stores
+            prop_assert!(perform_test(invalid_offset).is_err_and(|e|
+                matches!(e, Exception::StoreAMOAccessFault(_))
+            ));
+            // Aligned loads / stores
+            prop_assert!(perform_test(aligned_offset).is_ok());
+            // Unaligned loads / stores
+            prop_assert!(perform_test(misaligned_offset).is_ok());
+        });
+    });
+}
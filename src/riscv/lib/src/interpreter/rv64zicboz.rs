@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Implementation of the Zicboz extension for RISC-V
+//!
+//! Chapter 19 - Unprivileged spec
+
+use crate::machine_state::MachineCoreState;
+use crate::machine_state::memory::CACHE_BLOCK_SIZE;
+use crate::machine_state::memory::Memory;
+use crate::machine_state::memory::MemoryConfig;
+use crate::machine_state::registers::XRegister;
+use crate::state_backend as backend;
+use crate::traps::Exception;
+
+impl<MC, M> MachineCoreState<MC, M>
+where
+    MC: MemoryConfig,
+    M: backend::ManagerReadWrite,
+{
+    /// `CBO.ZERO` instruction.
+    ///
+    /// Zero the [`CACHE_BLOCK_SIZE`] bytes of the cache block containing the address
+    /// `val(rs1)`. The whole block is written at once, so it is either zeroed entirely or,
+    /// if its page is not writable, left untouched with a store access fault raised.
+    pub fn run_cbo_zero(&mut self, rs1: XRegister) -> Result<(), Exception> {
+        let address = self.hart.xregisters.read(rs1);
+        let block_address = address & !(CACHE_BLOCK_SIZE - 1);
+
+        self.main_memory
+            .write_all(block_address, &[0u64; CACHE_BLOCK_SIZE as usize / 8])
+            .map_err(|_| Exception::StoreAMOAccessFault(address))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::backend_test;
+    use crate::machine_state::memory::M4K;
+    use crate::machine_state::memory::PAGE_SIZE;
+    use crate::machine_state::memory::Permissions;
+    use crate::machine_state::registers::a0;
+    use crate::state::NewState;
+
+    backend_test!(test_cbo_zero, F, {
+        let state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+        let state_cell = std::cell::RefCell::new(state);
+
+        proptest!(|(
+            offset in 0..PAGE_SIZE.get(),
+        )|
+        {
+            let mut state = state_cell.borrow_mut();
+            state.reset();
+            state.main_memory.set_all_readable_writeable();
+            state.main_memory.write_all(0, &[u64::MAX; 512]).unwrap();
+
+            // Any address within the block zeroes the whole block, and nothing else
+            state.hart.xregisters.write(a0, offset);
+            state.run_cbo_zero(a0).unwrap();
+
+            let block_start = offset - offset % CACHE_BLOCK_SIZE;
+            for address in (0..PAGE_SIZE.get()).step_by(8) {
+                let expected = if (block_start..block_start + CACHE_BLOCK_SIZE).contains(&address) {
+                    0
+                } else {
+                    u64::MAX
+                };
+                prop_assert_eq!(state.main_memory.read::<u64>(address).unwrap(), expected);
+            }
+
+            // Blocks in pages without write permissions are left untouched
+            state.main_memory.write_all(0, &[u64::MAX; 512]).unwrap();
+            state
+                .main_memory
+                .protect_pages(0, PAGE_SIZE.get() as usize, Permissions::NONE)
+                .unwrap();
+            prop_assert_eq!(
+                state.run_cbo_zero(a0),
+                Err(Exception::StoreAMOAccessFault(offset))
+            );
+
+            state.main_memory.set_all_readable_writeable();
+            prop_assert_eq!(state.main_memory.read::<u64>(block_start).unwrap(), u64::MAX);
+        });
+    });
+}
@@ -2,146 +2,152 @@
 //!
 //! Chapter 9 - Unprivileged spec
 
-use crate::machine_state::csregisters;
-use crate::machine_state::hart_state::HartState;
-use crate::machine_state::registers;
-use crate::state_backend as backend;
-
-impl<M> HartState<M>
-where
-    M: backend::ManagerReadWrite,
-{
-    /// Execute a `CSRRW` instruction.
-    #[inline(always)]
-    pub fn run_csrrw(
-        &mut self,
-        csr: csregisters::CSRegister,
-        rs1: registers::XRegister,
-        rd: registers::XRegister,
-    ) -> csregisters::Result<()> {
-        let value = self.xregisters.read(rs1);
-        self.csr_replace(csr, value, rd)
+use crate::instruction_context::ICB;
+use crate::machine_state::csregisters::CSRegister;
+use crate::machine_state::registers::XRegister;
+
+/// Mask selecting the 5-bit unsigned immediate of the `CSR*I` instructions.
+const CSR_IMM_MASK: i64 = 0b11111;
+
+/// Raise an illegal instruction exception if `csr` is not writable.
+///
+/// See [`crate::machine_state::csregisters::check_write`].
+#[inline(always)]
+fn check_write<I: ICB>(icb: &mut I, csr: CSRegister) -> I::IResult<()> {
+    if csr.is_read_only() {
+        return icb.err_illegal_instruction();
     }
 
-    /// Execute a `CSRRWI` instruction.
-    #[inline(always)]
-    pub fn run_csrrwi(
-        &mut self,
-        csr: csregisters::CSRegister,
-        imm: registers::XValue,
-        rd: registers::XRegister,
-    ) -> csregisters::Result<()> {
-        self.csr_replace(csr, imm & 0b11111, rd)
-    }
+    icb.ok(())
+}
 
-    /// Replace the value in `csr` with `value` and write the previous value to `rd`.
-    /// When `rd = x0`, no read side effects are triggered.
-    #[inline(always)]
-    fn csr_replace(
-        &mut self,
-        csr: csregisters::CSRegister,
-        value: registers::XValue,
-        rd: registers::XRegister,
-    ) -> csregisters::Result<()> {
-        csregisters::check_write(csr)?;
+/// Execute a `CSRRW` instruction.
+#[inline(always)]
+pub fn run_csrrw<I: ICB>(
+    icb: &mut I,
+    csr: CSRegister,
+    rs1: XRegister,
+    rd: XRegister,
+) -> I::IResult<()> {
+    let value = icb.xregister_read(rs1);
+    csr_replace(icb, csr, value, rd)
+}
 
+/// Execute a `CSRRWI` instruction.
+#[inline(always)]
+pub fn run_csrrwi<I: ICB>(icb: &mut I, csr: CSRegister, imm: i64, rd: XRegister) -> I::IResult<()> {
+    let value = icb.xvalue_of_imm(imm & CSR_IMM_MASK);
+    csr_replace(icb, csr, value, rd)
+}
+
+/// Replace the value in `csr` with `value` and write the previous value to `rd`.
+/// When `rd = x0`, no read side effects are triggered.
+#[inline(always)]
+fn csr_replace<I: ICB>(
+    icb: &mut I,
+    csr: CSRegister,
+    value: I::XValue,
+    rd: XRegister,
+) -> I::IResult<()> {
+    let result = check_write(icb, csr);
+
+    I::map(result, |_| {
         // When `rd = x0`, we don't want to trigger any CSR read effects.
         if rd.is_zero() {
-            self.csregisters.write(csr, value);
+            icb.csregister_write(csr, value);
         } else {
-            let old: registers::XValue = self.csregisters.replace(csr, value);
-            self.xregisters.write(rd, old);
+            let old = icb.csregister_replace(csr, value);
+            icb.xregister_write(rd, old);
         }
-        Ok(())
-    }
-
-    /// Execute the `CSRRS` instruction.
-    #[inline(always)]
-    pub fn run_csrrs(
-        &mut self,
-        csr: csregisters::CSRegister,
-        rs1: registers::XRegister,
-        rd: registers::XRegister,
-    ) -> csregisters::Result<()> {
-        // When `rs1 = x0`, we don't want to trigger any CSR write effects.
-        let old = if rs1.is_zero() {
-            self.csregisters.read(csr)
-        } else {
-            csregisters::check_write(csr)?;
-
-            let value = self.xregisters.read(rs1);
-            self.csregisters.set_bits(csr, value)
-        };
-
-        self.xregisters.write(rd, old.repr());
-        Ok(())
-    }
-
-    /// Execute the `CSRRSI` instruction.
-    #[inline(always)]
-    pub fn run_csrrsi(
-        &mut self,
-        csr: csregisters::CSRegister,
-        imm: registers::XValue,
-        rd: registers::XRegister,
-    ) -> csregisters::Result<()> {
-        let imm = imm & 0b11111;
-
-        // When `imm = 0`, we don't want to trigger any CSR write effects.
-        let old = if imm == 0 {
-            self.csregisters.read(csr)
-        } else {
-            csregisters::check_write(csr)?;
-
-            self.csregisters.set_bits(csr, imm)
-        };
-
-        self.xregisters.write(rd, old.repr());
-        Ok(())
-    }
-
-    /// Execute the `CSRRC` instruction.
-    #[inline(always)]
-    pub fn run_csrrc(
-        &mut self,
-        csr: csregisters::CSRegister,
-        rs1: registers::XRegister,
-        rd: registers::XRegister,
-    ) -> csregisters::Result<()> {
-        // When `rs1 = x0`, we don't want to trigger any CSR write effects.
-        let old = if rs1.is_zero() {
-            self.csregisters.read(csr)
-        } else {
-            csregisters::check_write(csr)?;
-
-            let value = self.xregisters.read(rs1);
-            self.csregisters.clear_bits(csr, value)
-        };
+    })
+}
 
-        self.xregisters.write(rd, old.repr());
-        Ok(())
-    }
+/// Execute the `CSRRS` instruction.
+#[inline(always)]
+pub fn run_csrrs<I: ICB>(
+    icb: &mut I,
+    csr: CSRegister,
+    rs1: XRegister,
+    rd: XRegister,
+) -> I::IResult<()> {
+    // When `rs1 = x0`, we don't want to trigger any CSR write effects.
+    let old = if rs1.is_zero() {
+        let old = icb.csregister_read(csr);
+        icb.ok(old)
+    } else {
+        let result = check_write(icb, csr);
+
+        I::map(result, |_| {
+            let value = icb.xregister_read(rs1);
+            icb.csregister_set_bits(csr, value)
+        })
+    };
+
+    I::map(old, |old| icb.xregister_write(rd, old))
+}
 
-    /// Execute the `CSRRCI` instruction.
-    #[inline(always)]
-    pub fn run_csrrci(
-        &mut self,
-        csr: csregisters::CSRegister,
-        imm: registers::XValue,
-        rd: registers::XRegister,
-    ) -> csregisters::Result<()> {
-        let imm = imm & 0b11111;
-
-        // When `imm = 0`, we don't want to trigger any CSR write effects.
-        let old = if imm == 0 {
-            self.csregisters.read(csr)
-        } else {
-            csregisters::check_write(csr)?;
+/// Execute the `CSRRSI` instruction.
+#[inline(always)]
+pub fn run_csrrsi<I: ICB>(icb: &mut I, csr: CSRegister, imm: i64, rd: XRegister) -> I::IResult<()> {
+    let imm = imm & CSR_IMM_MASK;
+
+    // When `imm = 0`, we don't want to trigger any CSR write effects.
+    let old = if imm == 0 {
+        let old = icb.csregister_read(csr);
+        icb.ok(old)
+    } else {
+        let result = check_write(icb, csr);
+
+        I::map(result, |_| {
+            let imm = icb.xvalue_of_imm(imm);
+            icb.csregister_set_bits(csr, imm)
+        })
+    };
+
+    I::map(old, |old| icb.xregister_write(rd, old))
+}
 
-            self.csregisters.clear_bits(csr, imm)
-        };
+/// Execute the `CSRRC` instruction.
+#[inline(always)]
+pub fn run_csrrc<I: ICB>(
+    icb: &mut I,
+    csr: CSRegister,
+    rs1: XRegister,
+    rd: XRegister,
+) -> I::IResult<()> {
+    // When `rs1 = x0`, we don't want to trigger any CSR write effects.
+    let old = if rs1.is_zero() {
+        let old = icb.csregister_read(csr);
+        icb.ok(old)
+    } else {
+        let result = check_write(icb, csr);
+
+        I::map(result, |_| {
+            let value = icb.xregister_read(rs1);
+            icb.csregister_clear_bits(csr, value)
+        })
+    };
+
+    I::map(old, |old| icb.xregister_write(rd, old))
+}
 
-        self.xregisters.write(rd, old.repr());
-        Ok(())
-    }
+/// Execute the `CSRRCI` instruction.
+#[inline(always)]
+pub fn run_csrrci<I: ICB>(icb: &mut I, csr: CSRegister, imm: i64, rd: XRegister) -> I::IResult<()> {
+    let imm = imm & CSR_IMM_MASK;
+
+    // When `imm = 0`, we don't want to trigger any CSR write effects.
+    let old = if imm == 0 {
+        let old = icb.csregister_read(csr);
+        icb.ok(old)
+    } else {
+        let result = check_write(icb, csr);
+
+        I::map(result, |_| {
+            let imm = icb.xvalue_of_imm(imm);
+            icb.csregister_clear_bits(csr, imm)
+        })
+    };
+
+    I::map(old, |old| icb.xregister_write(rd, old))
 }
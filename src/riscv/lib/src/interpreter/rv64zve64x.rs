@@ -0,0 +1,1117 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Implementation of the Zve64x vector extension for RISC-V
+//!
+//! Only a minimal subset of the integer vector instructions is supported: vector
+//! configuration, unit-stride loads & stores, and basic integer arithmetic. All of them
+//! operate on unmasked elements, leaving tail elements undisturbed.
+//!
+//! Chapter 31 - Unprivileged spec
+
+use crate::machine_state::MachineCoreState;
+use crate::machine_state::csregisters::CSRegister;
+use crate::machine_state::hart_state::HartState;
+use crate::machine_state::memory;
+use crate::machine_state::registers::XRegister;
+use crate::machine_state::vector::ElementWidth;
+use crate::machine_state::vector::VRegister;
+use crate::machine_state::vector::VTYPE_VILL;
+use crate::machine_state::vector::VType;
+use crate::machine_state::vector::is_group_aligned;
+use crate::state_backend as backend;
+use crate::traps::Exception;
+
+/// Application vector length requested by a `vset{i}vl{i}` instruction
+enum Avl {
+    /// Use the given number of elements
+    Value(u64),
+
+    /// Use the maximum number of elements allowed by the new configuration
+    Max,
+
+    /// Keep the current vector length
+    Current,
+}
+
+/// Second source operand of a vector arithmetic instruction
+enum Operand {
+    /// Use the corresponding element of a vector register group
+    Vector(VRegister),
+
+    /// Use the same scalar value for every element
+    Scalar(u64),
+}
+
+impl<M> HartState<M>
+where
+    M: backend::ManagerReadWrite,
+{
+    /// Read the current vector configuration.
+    ///
+    /// Raises an [`Exception::IllegalInstruction`] if `vtype.vill` is set.
+    fn vector_config(&self) -> Result<VType, Exception> {
+        let vtype: u64 = self.csregisters.read(CSRegister::vtype);
+        VType::decode(vtype).ok_or(Exception::IllegalInstruction)
+    }
+
+    /// Return the range of body elements an instruction operates on, and reset `vstart`
+    /// as required after the execution of any vector instruction.
+    fn vector_body(&mut self) -> std::ops::Range<usize> {
+        let vstart: u64 = self.csregisters.read(CSRegister::vstart);
+        let vl: u64 = self.csregisters.read(CSRegister::vl);
+        self.csregisters.write(CSRegister::vstart, 0u64);
+        vstart as usize..vl as usize
+    }
+
+    /// Set `vtype` and `vl` to the configuration requested by a `vset{i}vl{i}`
+    /// instruction, returning the new vector length.
+    ///
+    /// Unsupported configurations set `vtype.vill` and clear `vl`.
+    fn set_vector_config(&mut self, avl: Avl, vtype: u64) -> u64 {
+        let vl = match VType::decode(vtype) {
+            Some(vtype) => {
+                let vlmax = vtype.vlmax() as u64;
+                let vl = match avl {
+                    Avl::Value(avl) => avl.min(vlmax),
+                    Avl::Max => vlmax,
+                    Avl::Current => {
+                        let vl: u64 = self.csregisters.read(CSRegister::vl);
+                        vl.min(vlmax)
+                    }
+                };
+                self.csregisters.write(CSRegister::vtype, vtype.raw);
+                vl
+            }
+            None => {
+                self.csregisters.write(CSRegister::vtype, VTYPE_VILL);
+                0
+            }
+        };
+
+        self.csregisters.write(CSRegister::vl, vl);
+        self.csregisters.write(CSRegister::vstart, 0u64);
+        vl
+    }
+
+    /// `VSETVLI` instruction.
+    ///
+    /// Configure the vector unit with the `vtypei` immediate, requesting `val(rs1)`
+    /// elements, and save the new vector length in `rd`. If `rs1` is `x0`, the maximum
+    /// vector length is requested instead, unless `rd` is also `x0`, in which case
+    /// the current vector length is kept.
+    pub fn run_vsetvli(
+        &mut self,
+        vtypei: i64,
+        rs1: XRegister,
+        rd: XRegister,
+    ) -> Result<(), Exception> {
+        let avl = if !rs1.is_zero() {
+            Avl::Value(self.xregisters.read(rs1))
+        } else if !rd.is_zero() {
+            Avl::Max
+        } else {
+            Avl::Current
+        };
+
+        let vl = self.set_vector_config(avl, vtypei as u64);
+        self.xregisters.write(rd, vl);
+        Ok(())
+    }
+
+    /// `VSETIVLI` instruction.
+    ///
+    /// Configure the vector unit with the `vtypei` immediate, requesting `avl` elements,
+    /// and save the new vector length in `rd`.
+    pub fn run_vsetivli(&mut self, vtypei: i64, avl: u64, rd: XRegister) -> Result<(), Exception> {
+        let vl = self.set_vector_config(Avl::Value(avl), vtypei as u64);
+        self.xregisters.write(rd, vl);
+        Ok(())
+    }
+
+    /// `VSETVL` instruction.
+    ///
+    /// Configure the vector unit with `val(rs2)`, requesting `val(rs1)` elements, and save
+    /// the new vector length in `rd`. `x0` operands are treated as for `VSETVLI`.
+    pub fn run_vsetvl(
+        &mut self,
+        rs1: XRegister,
+        rs2: XRegister,
+        rd: XRegister,
+    ) -> Result<(), Exception> {
+        let avl = if !rs1.is_zero() {
+            Avl::Value(self.xregisters.read(rs1))
+        } else if !rd.is_zero() {
+            Avl::Max
+        } else {
+            Avl::Current
+        };
+
+        let vtype = self.xregisters.read(rs2);
+        let vl = self.set_vector_config(avl, vtype);
+        self.xregisters.write(rd, vl);
+        Ok(())
+    }
+
+    /// Apply `op` to each body element of `vs2` together with the matching element of
+    /// `operand`, saving the results in `vd`.
+    ///
+    /// Scalar operands are truncated to the selected element width. Register groups must
+    /// be aligned to the group multiplier, otherwise an [`Exception::IllegalInstruction`]
+    /// is raised.
+    #[inline(always)]
+    fn run_vector_arith(
+        &mut self,
+        operand: Operand,
+        vs2: VRegister,
+        vd: VRegister,
+        op: impl Fn(ElementWidth, u64, u64) -> u64,
+    ) -> Result<(), Exception> {
+        let vtype = self.vector_config()?;
+        let aligned = |reg| is_group_aligned(reg, vtype.lmul_log2);
+        let operand_aligned = match operand {
+            Operand::Vector(vs1) => aligned(vs1),
+            Operand::Scalar(_) => true,
+        };
+        if !aligned(vd) || !aligned(vs2) || !operand_aligned {
+            return Err(Exception::IllegalInstruction);
+        }
+
+        let sew = vtype.sew;
+        for i in self.vector_body() {
+            let lhs = self.vregisters.read(vs2, sew, i);
+            let rhs = match operand {
+                Operand::Vector(vs1) => self.vregisters.read(vs1, sew, i),
+                Operand::Scalar(val) => val & sew.mask(),
+            };
+            self.vregisters.write(vd, sew, i, op(sew, lhs, rhs));
+        }
+
+        Ok(())
+    }
+
+    /// Add the elements of `vs2` and the elements of `vs1`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VADD.VV`
+    pub fn run_vadd_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, add)
+    }
+
+    /// Add the elements of `vs2` and `val(rs1)`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VADD.VX`
+    pub fn run_vadd_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, add)
+    }
+
+    /// Add the elements of `vs2` and `imm`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VADD.VI`
+    pub fn run_vadd_vi(
+        &mut self,
+        imm: i64,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(imm as u64), vs2, vd, add)
+    }
+
+    /// Subtract the elements of `vs1` from the elements of `vs2`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VSUB.VV`
+    pub fn run_vsub_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, sub)
+    }
+
+    /// Subtract `val(rs1)` from the elements of `vs2`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VSUB.VX`
+    pub fn run_vsub_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, sub)
+    }
+
+    /// Subtract the elements of `vs2` from `val(rs1)`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VRSUB.VX`
+    pub fn run_vrsub_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, rsub)
+    }
+
+    /// Subtract the elements of `vs2` from `imm`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VRSUB.VI`
+    pub fn run_vrsub_vi(
+        &mut self,
+        imm: i64,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(imm as u64), vs2, vd, rsub)
+    }
+
+    /// Bitwise AND the elements of `vs2` with the elements of `vs1`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VAND.VV`
+    pub fn run_vand_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, and)
+    }
+
+    /// Bitwise AND the elements of `vs2` with `val(rs1)`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VAND.VX`
+    pub fn run_vand_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, and)
+    }
+
+    /// Bitwise AND the elements of `vs2` with `imm`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VAND.VI`
+    pub fn run_vand_vi(
+        &mut self,
+        imm: i64,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(imm as u64), vs2, vd, and)
+    }
+
+    /// Bitwise OR the elements of `vs2` with the elements of `vs1`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VOR.VV`
+    pub fn run_vor_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, or)
+    }
+
+    /// Bitwise OR the elements of `vs2` with `val(rs1)`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VOR.VX`
+    pub fn run_vor_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, or)
+    }
+
+    /// Bitwise OR the elements of `vs2` with `imm`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VOR.VI`
+    pub fn run_vor_vi(&mut self, imm: i64, vs2: VRegister, vd: VRegister) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(imm as u64), vs2, vd, or)
+    }
+
+    /// Bitwise XOR the elements of `vs2` with the elements of `vs1`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VXOR.VV`
+    pub fn run_vxor_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, xor)
+    }
+
+    /// Bitwise XOR the elements of `vs2` with `val(rs1)`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VXOR.VX`
+    pub fn run_vxor_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, xor)
+    }
+
+    /// Bitwise XOR the elements of `vs2` with `imm`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VXOR.VI`
+    pub fn run_vxor_vi(
+        &mut self,
+        imm: i64,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(imm as u64), vs2, vd, xor)
+    }
+
+    /// Shift the elements of `vs2` left by the elements of `vs1`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VSLL.VV`
+    pub fn run_vsll_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, sll)
+    }
+
+    /// Shift the elements of `vs2` left by `val(rs1)`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VSLL.VX`
+    pub fn run_vsll_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, sll)
+    }
+
+    /// Shift the elements of `vs2` left by the unsigned `imm`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VSLL.VI`
+    pub fn run_vsll_vi(
+        &mut self,
+        imm: i64,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(imm as u64), vs2, vd, sll)
+    }
+
+    /// Logically shift the elements of `vs2` right by the elements of `vs1`, saving the results
+    /// in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VSRL.VV`
+    pub fn run_vsrl_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, srl)
+    }
+
+    /// Logically shift the elements of `vs2` right by `val(rs1)`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VSRL.VX`
+    pub fn run_vsrl_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, srl)
+    }
+
+    /// Logically shift the elements of `vs2` right by the unsigned `imm`, saving the results in
+    /// `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VSRL.VI`
+    pub fn run_vsrl_vi(
+        &mut self,
+        imm: i64,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(imm as u64), vs2, vd, srl)
+    }
+
+    /// Arithmetically shift the elements of `vs2` right by the elements of `vs1`, saving the
+    /// results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VSRA.VV`
+    pub fn run_vsra_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, sra)
+    }
+
+    /// Arithmetically shift the elements of `vs2` right by `val(rs1)`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VSRA.VX`
+    pub fn run_vsra_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, sra)
+    }
+
+    /// Arithmetically shift the elements of `vs2` right by the unsigned `imm`, saving the
+    /// results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VSRA.VI`
+    pub fn run_vsra_vi(
+        &mut self,
+        imm: i64,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(imm as u64), vs2, vd, sra)
+    }
+
+    /// Take the unsigned minimum of the elements of `vs2` and the elements of `vs1`, saving the
+    /// results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMINU.VV`
+    pub fn run_vminu_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, minu)
+    }
+
+    /// Take the unsigned minimum of the elements of `vs2` and `val(rs1)`, saving the results in
+    /// `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMINU.VX`
+    pub fn run_vminu_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, minu)
+    }
+
+    /// Take the signed minimum of the elements of `vs2` and the elements of `vs1`, saving the
+    /// results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMIN.VV`
+    pub fn run_vmin_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, min)
+    }
+
+    /// Take the signed minimum of the elements of `vs2` and `val(rs1)`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMIN.VX`
+    pub fn run_vmin_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, min)
+    }
+
+    /// Take the unsigned maximum of the elements of `vs2` and the elements of `vs1`, saving the
+    /// results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMAXU.VV`
+    pub fn run_vmaxu_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, maxu)
+    }
+
+    /// Take the unsigned maximum of the elements of `vs2` and `val(rs1)`, saving the results in
+    /// `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMAXU.VX`
+    pub fn run_vmaxu_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, maxu)
+    }
+
+    /// Take the signed maximum of the elements of `vs2` and the elements of `vs1`, saving the
+    /// results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMAX.VV`
+    pub fn run_vmax_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, max)
+    }
+
+    /// Take the signed maximum of the elements of `vs2` and `val(rs1)`, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMAX.VX`
+    pub fn run_vmax_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, max)
+    }
+
+    /// Multiply the elements of `vs2` by the elements of `vs1`, keeping the low bits of the
+    /// product, saving the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMUL.VV`
+    pub fn run_vmul_vv(
+        &mut self,
+        vs1: VRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Vector(vs1), vs2, vd, mul)
+    }
+
+    /// Multiply the elements of `vs2` by `val(rs1)`, keeping the low bits of the product, saving
+    /// the results in `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMUL.VX`
+    pub fn run_vmul_vx(
+        &mut self,
+        rs1: XRegister,
+        vs2: VRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(self.xregisters.read(rs1)), vs2, vd, mul)
+    }
+
+    /// Copy the elements of `vs1` to `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMV.V.V`
+    pub fn run_vmv_v_v(&mut self, vs1: VRegister, vd: VRegister) -> Result<(), Exception> {
+        // There is no `vs2` operand: the elements of `vd` are passed in its place and discarded
+        self.run_vector_arith(Operand::Vector(vs1), vd, vd, mv)
+    }
+
+    /// Splat `val(rs1)` to the elements of `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMV.V.X`
+    pub fn run_vmv_v_x(&mut self, rs1: XRegister, vd: VRegister) -> Result<(), Exception> {
+        let val = self.xregisters.read(rs1);
+        self.run_vector_arith(Operand::Scalar(val), vd, vd, mv)
+    }
+
+    /// Splat `imm` to the elements of `vd`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMV.V.I`
+    pub fn run_vmv_v_i(&mut self, imm: i64, vd: VRegister) -> Result<(), Exception> {
+        self.run_vector_arith(Operand::Scalar(imm as u64), vd, vd, mv)
+    }
+
+    /// Save the sign-extended first element of `vs2` in `rd`, regardless of `vl`.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMV.X.S`
+    pub fn run_vmv_x_s(&mut self, vs2: VRegister, rd: XRegister) -> Result<(), Exception> {
+        let sew = self.vector_config()?.sew;
+        let val = sew.sign_extend(self.vregisters.read(vs2, sew, 0));
+        self.csregisters.write(CSRegister::vstart, 0u64);
+        self.xregisters.write(rd, val);
+        Ok(())
+    }
+
+    /// Save `val(rs1)` in the first element of `vd`, if it is a body element.
+    ///
+    /// Relevant RISC-V opcodes:
+    /// - `VMV.S.X`
+    pub fn run_vmv_s_x(&mut self, rs1: XRegister, vd: VRegister) -> Result<(), Exception> {
+        let sew = self.vector_config()?.sew;
+        if self.vector_body().contains(&0) {
+            let val = self.xregisters.read(rs1);
+            self.vregisters.write(vd, sew, 0, val);
+        }
+        Ok(())
+    }
+}
+
+impl<MC, M> MachineCoreState<MC, M>
+where
+    MC: memory::MemoryConfig,
+    M: backend::ManagerReadWrite,
+{
+    /// Check that a unit-stride access of elements of width `eew` is legal for `vreg`,
+    /// returning the element width and the range of elements to access.
+    fn vector_memory_access(
+        &mut self,
+        eew: ElementWidth,
+        vreg: VRegister,
+    ) -> Result<std::ops::Range<usize>, Exception> {
+        let vtype = self.hart.vector_config()?;
+        match vtype.emul_log2(eew) {
+            Some(emul_log2) if is_group_aligned(vreg, emul_log2) => Ok(self.hart.vector_body()),
+            _ => Err(Exception::IllegalInstruction),
+        }
+    }
+
+    /// Load the body elements of width `eew` of `vd` from consecutive addresses
+    /// starting at `val(rs1)`.
+    ///
+    /// If an element fails to load, `vstart` is set to its index so that the
+    /// instruction can be resumed.
+    fn run_vector_load(
+        &mut self,
+        eew: ElementWidth,
+        rs1: XRegister,
+        vd: VRegister,
+    ) -> Result<(), Exception> {
+        let body = self.vector_memory_access(eew, vd)?;
+        let base = self.hart.xregisters.read(rs1);
+
+        for i in body {
+            let address = base.wrapping_add((i << eew.log2_bytes()) as u64);
+            let val = match eew {
+                ElementWidth::E8 => self.read_from_address::<u8>(address).map(u64::from),
+                ElementWidth::E16 => self.read_from_address::<u16>(address).map(u64::from),
+                ElementWidth::E32 => self.read_from_address::<u32>(address).map(u64::from),
+                ElementWidth::E64 => self.read_from_address::<u64>(address),
+            };
+
+            match val {
+                Ok(val) => self.hart.vregisters.write(vd, eew, i, val),
+                Err(exception) => {
+                    self.hart.csregisters.write(CSRegister::vstart, i as u64);
+                    return Err(exception);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Store the body elements of width `eew` of `vs3` to consecutive addresses
+    /// starting at `val(rs1)`.
+    ///
+    /// If an element fails to store, `vstart` is set to its index so that the
+    /// instruction can be resumed.
+    fn run_vector_store(
+        &mut self,
+        eew: ElementWidth,
+        rs1: XRegister,
+        vs3: VRegister,
+    ) -> Result<(), Exception> {
+        let body = self.vector_memory_access(eew, vs3)?;
+        let base = self.hart.xregisters.read(rs1);
+
+        for i in body {
+            let address = base.wrapping_add((i << eew.log2_bytes()) as u64);
+            let val = self.hart.vregisters.read(vs3, eew, i);
+            let res = match eew {
+                ElementWidth::E8 => self.write_to_address(address, val as u8),
+                ElementWidth::E16 => self.write_to_address(address, val as u16),
+                ElementWidth::E32 => self.write_to_address(address, val as u32),
+                ElementWidth::E64 => self.write_to_address(address, val),
+            };
+
+            if let Err(exception) = res {
+                self.hart.csregisters.write(CSRegister::vstart, i as u64);
+                return Err(exception);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `VLE8.V` instruction.
+    ///
+    /// Load 8-bit elements into `vd` from consecutive addresses starting at `val(rs1)`.
+    pub fn run_vle8(&mut self, rs1: XRegister, vd: VRegister) -> Result<(), Exception> {
+        self.run_vector_load(ElementWidth::E8, rs1, vd)
+    }
+
+    /// `VLE16.V` instruction.
+    ///
+    /// Load 16-bit elements into `vd` from consecutive addresses starting at `val(rs1)`.
+    pub fn run_vle16(&mut self, rs1: XRegister, vd: VRegister) -> Result<(), Exception> {
+        self.run_vector_load(ElementWidth::E16, rs1, vd)
+    }
+
+    /// `VLE32.V` instruction.
+    ///
+    /// Load 32-bit elements into `vd` from consecutive addresses starting at `val(rs1)`.
+    pub fn run_vle32(&mut self, rs1: XRegister, vd: VRegister) -> Result<(), Exception> {
+        self.run_vector_load(ElementWidth::E32, rs1, vd)
+    }
+
+    /// `VLE64.V` instruction.
+    ///
+    /// Load 64-bit elements into `vd` from consecutive addresses starting at `val(rs1)`.
+    pub fn run_vle64(&mut self, rs1: XRegister, vd: VRegister) -> Result<(), Exception> {
+        self.run_vector_load(ElementWidth::E64, rs1, vd)
+    }
+
+    /// `VSE8.V` instruction.
+    ///
+    /// Store 8-bit elements of `vs3` to consecutive addresses starting at `val(rs1)`.
+    pub fn run_vse8(&mut self, rs1: XRegister, vs3: VRegister) -> Result<(), Exception> {
+        self.run_vector_store(ElementWidth::E8, rs1, vs3)
+    }
+
+    /// `VSE16.V` instruction.
+    ///
+    /// Store 16-bit elements of `vs3` to consecutive addresses starting at `val(rs1)`.
+    pub fn run_vse16(&mut self, rs1: XRegister, vs3: VRegister) -> Result<(), Exception> {
+        self.run_vector_store(ElementWidth::E16, rs1, vs3)
+    }
+
+    /// `VSE32.V` instruction.
+    ///
+    /// Store 32-bit elements of `vs3` to consecutive addresses starting at `val(rs1)`.
+    pub fn run_vse32(&mut self, rs1: XRegister, vs3: VRegister) -> Result<(), Exception> {
+        self.run_vector_store(ElementWidth::E32, rs1, vs3)
+    }
+
+    /// `VSE64.V` instruction.
+    ///
+    /// Store 64-bit elements of `vs3` to consecutive addresses starting at `val(rs1)`.
+    pub fn run_vse64(&mut self, rs1: XRegister, vs3: VRegister) -> Result<(), Exception> {
+        self.run_vector_store(ElementWidth::E64, rs1, vs3)
+    }
+}
+
+fn add(_sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    lhs.wrapping_add(rhs)
+}
+
+fn sub(_sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    lhs.wrapping_sub(rhs)
+}
+
+fn rsub(_sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    rhs.wrapping_sub(lhs)
+}
+
+fn and(_sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    lhs & rhs
+}
+
+fn or(_sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    lhs | rhs
+}
+
+fn xor(_sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    lhs ^ rhs
+}
+
+/// Only the lowest `log2(SEW)` bits of the shift amount are used.
+fn shift_amount(sew: ElementWidth, rhs: u64) -> u64 {
+    rhs & (sew.bits() as u64 - 1)
+}
+
+fn sll(sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    lhs << shift_amount(sew, rhs)
+}
+
+fn srl(sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    lhs >> shift_amount(sew, rhs)
+}
+
+fn sra(sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    (sew.sign_extend(lhs) as i64 >> shift_amount(sew, rhs)) as u64
+}
+
+fn minu(_sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    lhs.min(rhs)
+}
+
+fn min(sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    (sew.sign_extend(lhs) as i64).min(sew.sign_extend(rhs) as i64) as u64
+}
+
+fn maxu(_sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    lhs.max(rhs)
+}
+
+fn max(sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    (sew.sign_extend(lhs) as i64).max(sew.sign_extend(rhs) as i64) as u64
+}
+
+fn mul(_sew: ElementWidth, lhs: u64, rhs: u64) -> u64 {
+    lhs.wrapping_mul(rhs)
+}
+
+fn mv(_sew: ElementWidth, _lhs: u64, rhs: u64) -> u64 {
+    rhs
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::backend_test;
+    use crate::machine_state::MachineCoreState;
+    use crate::machine_state::csregisters::CSRRepr;
+    use crate::machine_state::csregisters::CSRegister;
+    use crate::machine_state::hart_state::HartState;
+    use crate::machine_state::memory::M4K;
+    use crate::machine_state::registers::a0;
+    use crate::machine_state::registers::a1;
+    use crate::machine_state::registers::t0;
+    use crate::machine_state::registers::x0;
+    use crate::machine_state::vector::ElementWidth;
+    use crate::machine_state::vector::VRegister::*;
+    use crate::state::NewState;
+    use crate::traps::Exception;
+
+    // vtype immediates, with tail & mask agnostic policies
+    const E8_M1: i64 = 0b11_000_000;
+    const E8_M8: i64 = 0b11_000_011;
+    const E16_M2: i64 = 0b11_001_001;
+    const E32_M1: i64 = 0b11_010_000;
+    const E64_M1: i64 = 0b11_011_000;
+    const E64_MF2: i64 = 0b11_011_111;
+
+    backend_test!(test_vsetvl, F, {
+        let mut state = HartState::new(&mut F::manager());
+        state.reset(0);
+
+        // The vector unit starts out in an illegal configuration
+        assert_eq!(
+            state.run_vadd_vv(v1, v2, v3),
+            Err(Exception::IllegalInstruction)
+        );
+
+        // The requested vector length is capped by VLMAX = VLEN / SEW * LMUL
+        state.xregisters.write(a0, 100);
+        state.run_vsetvli(E16_M2, a0, a1).unwrap();
+        assert_eq!(state.xregisters.read(a1), 16);
+        assert_eq!(state.csregisters.read::<CSRRepr>(CSRegister::vl), 16);
+        assert_eq!(
+            state.csregisters.read::<CSRRepr>(CSRegister::vtype),
+            E16_M2 as u64
+        );
+
+        state.xregisters.write(a0, 3);
+        state.run_vsetvli(E16_M2, a0, a1).unwrap();
+        assert_eq!(state.xregisters.read(a1), 3);
+
+        // rs1 = x0 keeps vl, capped by the new VLMAX, if rd = x0 too
+        state.run_vsetvli(E64_M1, x0, x0).unwrap();
+        assert_eq!(state.csregisters.read::<CSRRepr>(CSRegister::vl), 2);
+
+        // rs1 = x0 requests VLMAX otherwise
+        state.run_vsetivli(E8_M1, 5, a1).unwrap();
+        assert_eq!(state.xregisters.read(a1), 5);
+        state.run_vsetvli(E8_M1, x0, a1).unwrap();
+        assert_eq!(state.xregisters.read(a1), 16);
+
+        // Unsupported configurations set vill & clear vl
+        state.xregisters.write(t0, E64_MF2 as u64);
+        state.run_vsetvl(a0, t0, a1).unwrap();
+        assert_eq!(state.xregisters.read(a1), 0);
+        assert_eq!(
+            state.csregisters.read::<CSRRepr>(CSRegister::vtype),
+            1 << 63
+        );
+        assert_eq!(
+            state.run_vmv_x_s(v1, a1),
+            Err(Exception::IllegalInstruction)
+        );
+
+        // Register groups must be aligned to LMUL
+        state.run_vsetivli(E16_M2, 16, a1).unwrap();
+        assert_eq!(
+            state.run_vadd_vv(v2, v4, v3),
+            Err(Exception::IllegalInstruction)
+        );
+    });
+
+    backend_test!(test_vector_arith, F, {
+        type Run<M> = fn(&mut HartState<M>) -> Result<(), Exception>;
+        type Expected = fn(u32, u32) -> u32;
+
+        proptest!(|(
+            lhs in any::<[u32; 4]>(),
+            rhs in any::<[u32; 4]>(),
+            vl in 0_usize..=4,
+        )| {
+            let mut state = HartState::new(&mut F::manager());
+            state.reset(0);
+            state.run_vsetivli(E32_M1, vl as u64, a1).unwrap();
+
+            // The scalar operand is set to the first element of `rhs`, with garbage in the
+            // upper bits that must be ignored
+            state.xregisters.write(a0, 0xdead_beef_0000_0000 | rhs[0] as u64);
+
+            let ops: [(Run<_>, Expected, bool); 10] = [
+                (|s| s.run_vadd_vv(v1, v2, v3), u32::wrapping_add, false),
+                (|s| s.run_vsub_vx(a0, v2, v3), u32::wrapping_sub, true),
+                (|s| s.run_vrsub_vx(a0, v2, v3), |l, r| r.wrapping_sub(l), true),
+                (|s| s.run_vxor_vv(v1, v2, v3), |l, r| l ^ r, false),
+                (|s| s.run_vsra_vv(v1, v2, v3), |l, r| ((l as i32) >> (r & 31)) as u32, false),
+                (|s| s.run_vsrl_vx(a0, v2, v3), |l, r| l >> (r & 31), true),
+                (|s| s.run_vmin_vv(v1, v2, v3), |l, r| (l as i32).min(r as i32) as u32, false),
+                (|s| s.run_vmaxu_vx(a0, v2, v3), u32::max, true),
+                (|s| s.run_vmul_vv(v1, v2, v3), u32::wrapping_mul, false),
+                (|s| s.run_vmv_v_x(a0, v3), |_, r| r, true),
+            ];
+
+            for (run, expected, scalar) in ops {
+                state.vregisters.reset();
+                for i in 0..4 {
+                    state.vregisters.write(v2, ElementWidth::E32, i, lhs[i] as u64);
+                    state.vregisters.write(v1, ElementWidth::E32, i, rhs[i] as u64);
+                }
+
+                run(&mut state).unwrap();
+
+                for i in 0..4 {
+                    let rhs = if scalar { rhs[0] } else { rhs[i] };
+                    // Tail elements are left undisturbed
+                    let expected = if i < vl { expected(lhs[i], rhs) } else { 0 };
+                    prop_assert_eq!(state.vregisters.read(v3, ElementWidth::E32, i), expected as u64);
+                }
+            }
+        });
+    });
+
+    backend_test!(test_vector_immediates, F, {
+        let mut state = HartState::new(&mut F::manager());
+        state.reset(0);
+        state.run_vsetivli(E8_M1, 16, a1).unwrap();
+
+        state.run_vmv_v_i(-3, v1).unwrap();
+        assert_eq!(state.vregisters.read(v1, ElementWidth::E8, 15), 0xfd);
+
+        state.run_vadd_vi(5, v1, v2).unwrap();
+        assert_eq!(state.vregisters.read(v2, ElementWidth::E8, 0), 2);
+
+        state.run_vsll_vi(7, v2, v3).unwrap();
+        assert_eq!(state.vregisters.read(v3, ElementWidth::E8, 7), 0);
+
+        // vmv.x.s sign-extends the first element, while vmv.s.x only writes the first element
+        state.run_vmv_x_s(v1, a0).unwrap();
+        assert_eq!(state.xregisters.read(a0), -3_i64 as u64);
+
+        state.xregisters.write(a0, 0x1234);
+        state.run_vmv_s_x(a0, v2).unwrap();
+        assert_eq!(state.vregisters.read(v2, ElementWidth::E8, 0), 0x34);
+        assert_eq!(state.vregisters.read(v2, ElementWidth::E8, 1), 2);
+    });
+
+    backend_test!(test_vector_load_store, F, {
+        let state = MachineCoreState::<M4K, _>::new(&mut F::manager());
+        let state_cell = std::cell::RefCell::new(state);
+
+        proptest!(|(
+            vals in any::<[u64; 2]>(),
+        )|
+        {
+            let mut state = state_cell.borrow_mut();
+            state.reset();
+            state.main_memory.set_all_readable_writeable();
+
+            state.hart.run_vsetivli(E64_M1, 2, a1).unwrap();
+            state.hart.vregisters.write(v1, ElementWidth::E64, 0, vals[0]);
+            state.hart.vregisters.write(v1, ElementWidth::E64, 1, vals[1]);
+
+            // Store 64-bit elements, then load them back as bytes
+            state.hart.xregisters.write(t0, 512);
+            state.run_vse64(t0, v1).unwrap();
+
+            state.hart.run_vsetivli(E8_M1, 16, a1).unwrap();
+            state.run_vle8(t0, v2).unwrap();
+
+            let bytes = [vals[0].to_le_bytes(), vals[1].to_le_bytes()].concat();
+            for (i, byte) in bytes.into_iter().enumerate() {
+                prop_assert_eq!(state.hart.vregisters.read(v2, ElementWidth::E8, i), byte as u64);
+            }
+
+            // Elements beyond the end of memory fault, recording the faulting index in vstart
+            state.hart.xregisters.write(t0, 4096 - 8);
+            let res = state.run_vle8(t0, v3);
+            prop_assert!(matches!(res, Err(Exception::LoadAccessFault(_))));
+            prop_assert_eq!(state.hart.csregisters.read::<CSRRepr>(CSRegister::vstart), 8);
+
+            // Resuming the instruction only loads the remaining elements
+            state.hart.xregisters.write(t0, 0);
+            state.run_vle8(t0, v3).unwrap();
+            prop_assert_eq!(state.hart.vregisters.read(v3, ElementWidth::E8, 7), 0);
+            prop_assert_eq!(state.hart.csregisters.read::<CSRRepr>(CSRegister::vstart), 0);
+
+            // The effective group multiplier EMUL = EEW / SEW * LMUL must be supported
+            state.hart.run_vsetivli(E8_M8, 1, a1).unwrap();
+            prop_assert_eq!(state.run_vle64(t0, v8), Err(Exception::IllegalInstruction));
+        });
+    });
+}
@@ -6,30 +6,42 @@
 //! instructions to native code.
 
 pub(crate) mod builder;
+pub mod code_cache;
+pub mod dump;
+pub mod eviction;
 pub(crate) mod state_access;
 
 use std::collections::HashMap;
 use std::ffi::c_void;
+use std::num::NonZeroUsize;
+use std::path::Path;
 
 use cranelift::codegen::CodegenError;
+use cranelift::codegen::control::ControlPlane;
 use cranelift::codegen::ir::types::I64;
+use cranelift::codegen::isa::OwnedTargetIsa;
 use cranelift::codegen::settings::SetError;
 use cranelift::frontend::FunctionBuilderContext;
 use cranelift::prelude::*;
-use cranelift_jit::JITBuilder;
-use cranelift_jit::JITModule;
+use cranelift_module::FuncId;
 use cranelift_module::Linkage;
 use cranelift_module::Module;
 use cranelift_module::ModuleError;
+use cranelift_module::ModuleReloc;
+use cranelift_module::ModuleResult;
 use state_access::JitStateAccess;
 use thiserror::Error;
 
 use self::builder::Builder;
+use self::code_cache::CachedCode;
+use self::code_cache::CodeCache;
+use self::dump::CodeDump;
+use self::eviction::Evicted;
+use self::eviction::Generation;
 use self::state_access::JsaCalls;
-use self::state_access::JsaImports;
-use self::state_access::register_jsa_symbols;
 use crate::machine_state::MachineCoreState;
 use crate::machine_state::block_cache::metrics::block_metrics;
+use crate::machine_state::block_cache::superblock::TraceBlock;
 use crate::machine_state::instruction::Instruction;
 use crate::machine_state::memory::MemoryConfig;
 use crate::state_backend::ManagerBase;
@@ -78,6 +90,22 @@ pub enum JitError {
     /// Unable to register external [`JitStateAccess`] functionality.
     #[error("Unable to register external JSA functions: {0}")]
     JsaRegistration(#[from] ModuleError),
+    /// Unable to open the persistent code cache.
+    #[error("Unable to open the JIT code cache: {0}")]
+    CodeCache(#[from] std::io::Error),
+    /// Unable to open the directory compiled code is dumped to.
+    #[error("Unable to open the JIT dump directory: {0}")]
+    Dump(std::io::Error),
+}
+
+/// How a block of instructions is exited, once lowered.
+enum BlockExit {
+    /// The block ran to completion, or ended with an unconditional jump. The exit has yet to
+    /// be lowered.
+    Normal,
+
+    /// The block ended with an unconditional exception, which has been lowered as the exit.
+    Exception,
 }
 
 /// The JIT is responsible for compiling blocks of instructions to machine code,
@@ -92,15 +120,34 @@ pub struct JIT<MC: MemoryConfig, M: ManagerBase> {
     /// context per thread, though this isn't in the simple demo here.
     ctx: codegen::Context,
 
-    /// The module, with the jit backend, which manages the JIT'd
-    /// functions.
-    module: JITModule,
+    /// ISA targeted by the compiled code.
+    isa: OwnedTargetIsa,
+
+    /// Generation that new functions are compiled into. See [`eviction`].
+    current: Generation<MC, M>,
 
-    /// Imported [JitStateAccess] functions.
-    jsa_imports: JsaImports<MC, M>,
+    /// Generation preceding the current one, only kept when a code budget is set.
+    previous: Option<Generation<MC, M>>,
 
-    /// Cache of compilation results.
+    /// Maximum size of the code held by the JIT, in bytes. Unbounded when `None`.
+    code_budget: Option<NonZeroUsize>,
+
+    /// Functions evicted since last taken, see [`JIT::take_evicted`].
+    evicted: Vec<Evicted>,
+
+    /// Cache of compilation results. Failures are kept until their generation is evicted, like
+    /// compiled functions.
     cache: HashMap<Hash, Option<JitFn<MC, M>>>,
+
+    /// Persistent cache of compiled code, shared across runs.
+    code_cache: Option<CodeCache>,
+
+    /// Directory compiled code is dumped to, for debugging.
+    code_dump: Option<CodeDump>,
+
+    /// Whether codegen failures are injected, see [`JIT::inject_codegen_failures`].
+    #[cfg(test)]
+    inject_codegen_failures: bool,
 }
 
 impl<MC: MemoryConfig, M: JitStateAccess> JIT<MC, M> {
@@ -121,21 +168,72 @@ impl<MC: MemoryConfig, M: JitStateAccess> JIT<MC, M> {
         let isa_builder = cranelift_native::builder().map_err(JitError::UnsupportedPlatform)?;
         let isa = isa_builder.finish(settings::Flags::new(flag_builder))?;
 
-        let mut builder = JITBuilder::with_isa(isa, cranelift_module::default_libcall_names());
-        register_jsa_symbols::<MC, M>(&mut builder);
-
-        let mut module = JITModule::new(builder);
-        let jsa_imports = JsaImports::declare_in_module(&mut module)?;
+        let current = Generation::new(isa.clone())?;
 
         Ok(Self {
             builder_context: FunctionBuilderContext::new(),
             ctx: codegen::Context::new(),
-            module,
-            jsa_imports,
+            isa,
+            current,
+            previous: None,
+            code_budget: None,
+            evicted: Vec::new(),
             cache: Default::default(),
+            code_cache: None,
+            code_dump: None,
+            #[cfg(test)]
+            inject_codegen_failures: false,
         })
     }
 
+    /// Create a new instance of the JIT, which persists compiled code under the given
+    /// directory.
+    ///
+    /// Blocks previously compiled under the same directory, by the same executable for the same
+    /// host ISA, Cranelift settings, memory configuration and manager, are loaded rather than
+    /// lowered and compiled again. See [`code_cache`].
+    pub fn with_code_cache(dir: impl AsRef<Path>) -> Result<Self, JitError> {
+        // Lowered code embeds the bounds of memory, and offsets into the machine state.
+        let lowering = format!(
+            "{}\n{}\n{}",
+            std::any::type_name::<MC>(),
+            MC::TOTAL_BYTES,
+            std::any::type_name::<M>()
+        );
+
+        let mut jit = Self::new()?;
+        jit.code_cache = Some(CodeCache::open(dir, jit.isa.as_ref(), &lowering)?);
+
+        Ok(jit)
+    }
+
+    /// The persistent cache of compiled code, if any. See [`JIT::with_code_cache`].
+    pub fn code_cache(&self) -> Option<&CodeCache> {
+        self.code_cache.as_ref()
+    }
+
+    /// Dump the instructions, Cranelift IR and host machine code of every function compiled
+    /// from now on under the given directory. See [`dump`].
+    pub fn enable_dump(&mut self, dir: impl AsRef<Path>) -> Result<(), JitError> {
+        self.code_dump = Some(CodeDump::open(dir).map_err(JitError::Dump)?);
+
+        Ok(())
+    }
+
+    /// Bound the memory used by compiled code to roughly `budget` bytes, evicting functions to
+    /// stay within it. Memory is unbounded when `None`. See [`eviction`].
+    pub fn set_code_budget(&mut self, budget: Option<NonZeroUsize>) {
+        self.code_budget = budget;
+    }
+
+    /// Take the functions evicted since this was last called.
+    ///
+    /// Their code is only freed by [`Evicted::free`], which must happen once nothing refers to
+    /// the functions anymore.
+    pub fn take_evicted(&mut self) -> Vec<Evicted> {
+        std::mem::take(&mut self.evicted)
+    }
+
     /// Compile a sequence of instructions to a callable native function.
     ///
     /// Not all instructions are currently supported. For blocks containing
@@ -149,30 +247,104 @@ impl<MC: MemoryConfig, M: JitStateAccess> JIT<MC, M> {
             return *compilation_result;
         }
 
+        if let Some(fun) = self.load_cached(&hash) {
+            return Some(fun);
+        }
+
         let mut builder = self.start();
 
-        // Check if the opcode of the instruction is supported in JIT and stop compilation in JIT if not.
-        for i in instr {
-            let Some(lower) = i.opcode.to_lowering() else {
+        match Self::lower(&mut builder, instr) {
+            None => {
+                builder.fail();
+                self.clear();
+                self.record_failure(&hash);
+                return None;
+            }
+            Some(BlockExit::Exception) => builder.end_unconditional_exception(),
+            Some(BlockExit::Normal) => builder.end(),
+        }
+
+        self.produce_function(&hash, instr)
+    }
+
+    /// Compile the blocks along the path of a superblock to a single callable native function.
+    ///
+    /// Each block is followed by the next only when it exits at the address of the next block.
+    /// Otherwise, the function returns - see [`superblock`] for more information.
+    ///
+    /// As with [`JIT::compile`], `None` is returned when the blocks contain unsupported
+    /// instructions.
+    ///
+    /// [`superblock`]: crate::machine_state::block_cache::superblock
+    pub fn compile_trace(&mut self, trace: &[TraceBlock]) -> Option<JitFn<MC, M>> {
+        let Ok(hash) = Hash::blake2b_hash(trace) else {
+            return None;
+        };
+
+        if let Some(compilation_result) = self.cache.get(&hash) {
+            return *compilation_result;
+        }
+
+        if let Some(fun) = self.load_cached(&hash) {
+            return Some(fun);
+        }
+
+        let mut builder = self.start();
+        let mut exit = BlockExit::Normal;
+
+        for (i, block) in trace.iter().enumerate() {
+            if i > 0 {
+                if let BlockExit::Normal = exit {
+                    builder.end_member();
+                }
+
+                builder.start_next_member(block.address);
+            }
+
+            if i + 1 < trace.len() {
+                builder.start_member();
+            }
+
+            let Some(block_exit) = Self::lower(&mut builder, &block.instr) else {
                 builder.fail();
                 self.clear();
-                self.cache.insert(hash, None);
+                self.record_failure(&hash);
                 return None;
             };
 
+            exit = block_exit;
+        }
+
+        match exit {
+            BlockExit::Exception => builder.end_unconditional_exception(),
+            BlockExit::Normal => builder.end(),
+        }
+
+        let instr = trace
+            .iter()
+            .flat_map(|block| block.instr.iter().copied())
+            .collect::<Vec<_>>();
+        self.produce_function(&hash, &instr)
+    }
+
+    /// Lower a block of instructions, returning how the block is exited once all of them have
+    /// been lowered.
+    ///
+    /// Returns `None` if any of the instructions are not supported.
+    fn lower(builder: &mut Builder<'_, MC, M>, instr: &[Instruction]) -> Option<BlockExit> {
+        // Check if the opcode of the instruction is supported in JIT and stop compilation in JIT if not.
+        for i in instr {
+            let lower = i.opcode.to_lowering()?;
+
             let pc_update = unsafe {
                 // # SAFETY: lower is called with args from the same instruction that it
                 // was derived
-                (lower)(i.args(), &mut builder)
+                (lower)(i.args(), builder)
             };
 
             // `pc_update == None` indicates an exception was raised.
             let Some(pc_update) = pc_update else {
-                builder.end_unconditional_exception();
-
-                let jcall = self.produce_function(&hash);
-
-                return Some(jcall);
+                return Some(BlockExit::Exception);
             };
 
             if !builder.complete_step(pc_update) {
@@ -181,10 +353,7 @@ impl<MC: MemoryConfig, M: JitStateAccess> JIT<MC, M> {
             }
         }
 
-        builder.end();
-        let jcall = self.produce_function(&hash);
-
-        Some(jcall)
+        Some(BlockExit::Normal)
     }
 
     /// Setup the builder, ensuring the entry block of the function is correct.
@@ -198,7 +367,19 @@ impl<MC: MemoryConfig, M: JitStateAccess> JIT<MC, M> {
     ///
     /// | `steps: usize`                | `int`                           |
     fn start(&mut self) -> Builder<'_, MC, M> {
-        let ptr = self.module.target_config().pointer_type();
+        self.push_signature();
+
+        let ptr = self.current.module.target_config().pointer_type();
+        let builder = FunctionBuilder::new(&mut self.ctx.func, &mut self.builder_context);
+        let jsa_call =
+            JsaCalls::func_calls(&mut self.current.module, &self.current.jsa_imports, ptr);
+
+        Builder::<'_, MC, M>::new(builder, jsa_call)
+    }
+
+    /// Set the signature of the function under construction, see [`JIT::start`].
+    fn push_signature(&mut self) {
+        let ptr = self.current.module.target_config().pointer_type();
 
         // first param ignored
         self.ctx.func.signature.params.push(AbiParam::new(ptr));
@@ -211,51 +392,245 @@ impl<MC: MemoryConfig, M: JitStateAccess> JIT<MC, M> {
 
         // return steps
         self.ctx.func.signature.returns.push(AbiParam::new(I64));
+    }
 
-        let builder = FunctionBuilder::new(&mut self.ctx.func, &mut self.builder_context);
-        let jsa_call = JsaCalls::func_calls(&mut self.module, &self.jsa_imports, ptr);
+    /// Load the function compiled for `hash` from the code cache, if persisted. This happens
+    /// before anything is lowered, so that loading skips lowering and codegen altogether.
+    fn load_cached(&mut self, hash: &Hash) -> Option<JitFn<MC, M>> {
+        let code = self.code_cache.as_mut()?.get(hash)?;
+        let name = hex::encode(hash);
 
-        Builder::<'_, MC, M>::new(builder, jsa_call)
+        let fun = match self.define_cached(&name, hash, &code) {
+            Ok(fun) => fun,
+            Err(error) => {
+                crate::log::warning! {
+                    hash = name,
+                    error = format!("{error:?}"),
+                    "Unable to load JIT-compiled code from the code cache, compiling it instead",
+                }
+                return None;
+            }
+        };
+
+        self.record_function(hash, fun);
+
+        Some(fun)
+    }
+
+    /// Declare and define a function from code loaded from the code cache, then finalise it.
+    fn define_cached(
+        &mut self,
+        name: &str,
+        hash: &Hash,
+        code: &CachedCode,
+    ) -> ModuleResult<JitFn<MC, M>> {
+        self.push_signature();
+        let id =
+            self.current
+                .module
+                .declare_function(name, Linkage::Export, &self.ctx.func.signature);
+        self.clear();
+        let id = id?;
+
+        self.current.module.define_function_bytes(
+            id,
+            code.alignment(),
+            code.code(),
+            &code.relocs(id),
+        )?;
+
+        self.finish(id, hash, code.code().len())
     }
 
     /// Finalise and cache the function under construction.
-    fn produce_function(&mut self, hash: &Hash) -> JitFn<MC, M> {
+    ///
+    /// Codegen failures are logged and cached as `None`, so that the block is run in
+    /// interpreted mode instead.
+    fn produce_function(&mut self, hash: &Hash, instr: &[Instruction]) -> Option<JitFn<MC, M>> {
         let name = hex::encode(hash);
 
-        let fun = self.finalise(&name);
+        let fun = match self.finalise(&name, hash, instr) {
+            Ok(fun) => fun,
+            Err(error) => {
+                crate::log::error! {
+                    hash = name,
+                    instructions = format!("{instr:?}"),
+                    error = format!("{error:?}"),
+                    "JIT compilation failed, falling back to interpreted execution",
+                }
+
+                self.record_failure(hash);
+                return None;
+            }
+        };
+
+        self.record_function(hash, fun);
 
+        Some(fun)
+    }
+
+    /// Cache a function that has been compiled - or loaded from the code cache - for `hash`.
+    fn record_function(&mut self, hash: &Hash, fun: JitFn<MC, M>) {
         self.cache.insert(*hash, Some(fun));
         block_metrics!(hash = hash, record_jitted);
 
-        fun
+        self.enforce_code_budget();
     }
 
-    /// Finalise the function currently under construction.
-    fn finalise(&mut self, name: &str) -> JitFn<MC, M> {
-        let id = self
-            .module
-            .declare_function(name.as_ref(), Linkage::Export, &self.ctx.func.signature)
-            .map_err(|e| e.to_string())
-            .unwrap();
+    /// Cache a failed compilation for `hash`, so that it is not attempted again until the current
+    /// generation is evicted.
+    fn record_failure(&mut self, hash: &Hash) {
+        self.cache.insert(*hash, None);
+        self.current.record_failure(*hash);
+    }
 
-        // define the function to jit
-        self.module.define_function(id, &mut self.ctx).unwrap();
+    /// Start a new generation once the current one has exceeded its share of the code budget,
+    /// evicting the previous generation.
+    fn enforce_code_budget(&mut self) {
+        let Some(budget) = self.code_budget else {
+            return;
+        };
+
+        if self.current.code_size() < budget.get() / 2 {
+            return;
+        }
+
+        let next = match Generation::new(self.isa.clone()) {
+            Ok(next) => next,
+            Err(error) => {
+                crate::log::error! {
+                    error = format!("{error:?}"),
+                    "Unable to start a new JIT generation, exceeding the code budget",
+                }
+                return;
+            }
+        };
+
+        let current = std::mem::replace(&mut self.current, next);
+
+        if let Some(previous) = self.previous.replace(current) {
+            for hash in previous.failures() {
+                self.cache.remove(hash);
+            }
+
+            let evicted = previous.evict();
+
+            for (hash, _) in evicted.functions() {
+                self.cache.remove(hash);
+            }
+
+            self.evicted.push(evicted);
+        }
+    }
 
-        // finalise the function
-        self.module.finalize_definitions().unwrap();
-        let code = self.module.get_finalized_function(id);
+    /// Finalise the function currently under construction.
+    fn finalise(
+        &mut self,
+        name: &str,
+        hash: &Hash,
+        instr: &[Instruction],
+    ) -> ModuleResult<JitFn<MC, M>> {
+        // the IR is optimised in place during codegen, so must be captured beforehand
+        let ir = self.code_dump.as_ref().map(|_| {
+            self.ctx.set_disasm(true);
+            self.ctx.func.display().to_string()
+        });
+
+        let id = self.define(name, hash);
+        let code_size = self
+            .ctx
+            .compiled_code()
+            .map_or(0, |code| code.code_buffer().len());
+
+        if let (Some(code_dump), Some(ir)) = (&self.code_dump, ir) {
+            if let Err(error) = code_dump.write(hash, instr, &ir, &self.ctx) {
+                crate::log::warning! {
+                    hash = name,
+                    error = format!("{error:?}"),
+                    "Unable to dump JIT-compiled code",
+                }
+            }
+        }
 
+        // the context must be cleared regardless of whether codegen succeeded
         self.clear();
+        let id = id?;
+
+        self.finish(id, hash, code_size)
+    }
+
+    /// Finalise the function defined as `id`, making it callable.
+    fn finish(&mut self, id: FuncId, hash: &Hash, code_size: usize) -> ModuleResult<JitFn<MC, M>> {
+        self.current.module.finalize_definitions()?;
+        let code = self.current.module.get_finalized_function(id);
+        self.current.record(*hash, code as usize, code_size);
 
         // SAFETY: the signature of a JitFn matches exactly the abi we specified in the
         //         entry block. Compilation has succeeded & therefore this produced code
         //         is safe to call.
-        unsafe { std::mem::transmute(code) }
+        Ok(unsafe { std::mem::transmute::<*const u8, JitFn<MC, M>>(code) })
+    }
+
+    /// Declare and define the function currently under construction.
+    fn define(&mut self, name: &str, hash: &Hash) -> ModuleResult<FuncId> {
+        #[cfg(test)]
+        if self.inject_codegen_failures {
+            return Err(ModuleError::Compilation(CodegenError::ImplLimitExceeded));
+        }
+
+        let id = self.current.module.declare_function(
+            name.as_ref(),
+            Linkage::Export,
+            &self.ctx.func.signature,
+        )?;
+
+        // define the function to jit
+        match &self.code_cache {
+            None => self.current.module.define_function(id, &mut self.ctx)?,
+            Some(code_cache) => {
+                self.ctx
+                    .compile(self.isa.as_ref(), &mut ControlPlane::default())
+                    .map_err(|e| ModuleError::Compilation(e.inner))?;
+
+                let code = self
+                    .ctx
+                    .compiled_code()
+                    .expect("Code is available after successful compilation");
+                let alignment = code.buffer.alignment as u64;
+                let relocs = code
+                    .buffer
+                    .relocs()
+                    .iter()
+                    .map(|reloc| ModuleReloc::from_mach_reloc(reloc, &self.ctx.func, id))
+                    .collect::<Vec<_>>();
+
+                self.current.module.define_function_bytes(
+                    id,
+                    alignment,
+                    code.code_buffer(),
+                    &relocs,
+                )?;
+
+                // the code is persisted prior to relocation, which is done again when loading
+                if let Some(cached) = CachedCode::new(&self.ctx.func, code) {
+                    code_cache.insert(hash, &cached);
+                }
+            }
+        }
+
+        Ok(id)
+    }
+
+    /// Make every subsequent compilation fail during codegen, to exercise the fallback to
+    /// interpreted execution.
+    #[cfg(test)]
+    pub(crate) fn inject_codegen_failures(&mut self) {
+        self.inject_codegen_failures = true;
     }
 
     /// Clear the current context to allow a new function to be compiled
     fn clear(&mut self) {
-        self.module.clear_context(&mut self.ctx)
+        self.current.module.clear_context(&mut self.ctx)
     }
 }
 
@@ -270,6 +645,9 @@ impl<MC: MemoryConfig, M: JitStateAccess> Default for JIT<MC, M> {
 
 #[cfg(test)]
 mod tests {
+    use std::ops::BitAnd;
+    use std::ops::BitOr;
+    use std::ops::BitXor;
     use std::ptr::null;
 
     use Instruction as I;
@@ -282,6 +660,7 @@ mod tests {
     use crate::machine_state::block_cache::block::Block;
     use crate::machine_state::block_cache::block::Interpreted;
     use crate::machine_state::block_cache::block::InterpretedBlockBuilder;
+    use crate::machine_state::memory::M1M;
     use crate::machine_state::memory::M4K;
     use crate::machine_state::memory::Memory;
     use crate::machine_state::memory::MemoryConfig;
@@ -1738,6 +2117,93 @@ mod tests {
         }
     });
 
+    backend_test!(test_compile_trace, F, {
+        // The first block only falls through to the second when a1 == a2 after incrementing.
+        let first = TraceBlock {
+            address: 0x100,
+            instr: vec![
+                I::new_addi(nz::a1, nz::a1, 1, Uncompressed),
+                I::new_branch_not_equal(nz::a1, nz::a2, 0x100, Uncompressed),
+            ],
+        };
+
+        // The second block jumps back to the start of the first.
+        let second = TraceBlock {
+            address: 0x108,
+            instr: vec![
+                I::new_addi(nz::a3, nz::a3, 1, Uncompressed),
+                I::new_j(-0xC, Uncompressed),
+            ],
+        };
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        let fun = jit
+            .compile_trace(&[first.clone(), second.clone()])
+            .expect("Compilation of trace should succeed.");
+
+        // (initial a2, expected steps, expected pc)
+        let scenarios = [
+            // continues onto the second block
+            (1, 4, 0x100),
+            // leaves through the side exit after the first block
+            (5, 2, 0x204),
+        ];
+
+        let mut manager = F::manager();
+        let mut interpreted_bb = InterpretedBlockBuilder;
+
+        for (a2, expected_steps, expected_pc) in scenarios {
+            let mut interpreted = MachineCoreState::<M4K, _>::new(&mut manager);
+            let mut jitted = MachineCoreState::<M4K, _>::new(&mut manager);
+
+            for core in [&mut interpreted, &mut jitted] {
+                core.hart.xregisters.write_nz(nz::a2, a2);
+                core.hart.pc.write(first.address);
+            }
+
+            // Run the blocks one at a time, as the dispatch loop would.
+            let mut interpreted_steps = 0;
+            for block in [&first, &second] {
+                if interpreted.hart.pc.read() != block.address {
+                    break;
+                }
+
+                let mut interpreted_block = Interpreted::<M4K, _>::new(&mut manager);
+                interpreted_block.start_block();
+                for instr in block.instr.iter() {
+                    interpreted_block.push_instr(*instr);
+                }
+
+                let res = unsafe {
+                    // SAFETY: interpreted blocks are always callable
+                    interpreted_block.run_block(
+                        &mut interpreted,
+                        block.address,
+                        &mut interpreted_bb,
+                    )
+                };
+                assert_eq!(res.error, None);
+                interpreted_steps += res.steps;
+            }
+
+            let mut jitted_res = Ok(());
+            let jitted_steps = unsafe {
+                // # Safety - the jit is not dropped until after we
+                //            exit the block.
+                (fun)(null(), &mut jitted, first.address, &mut jitted_res, null())
+            };
+
+            assert!(jitted_res.is_ok());
+            assert_eq!(jitted_steps, expected_steps);
+            assert_eq!(interpreted_steps, expected_steps);
+            assert_eq!(jitted.hart.pc.read(), expected_pc);
+            assert_eq_struct(
+                &interpreted.struct_ref::<FnManagerIdent>(),
+                &jitted.struct_ref::<FnManagerIdent>(),
+            );
+        }
+    });
+
     backend_test!(test_add_immediate_to_pc, F, {
         use crate::machine_state::registers::NonZeroXRegister::*;
 
@@ -2218,6 +2684,115 @@ mod tests {
         }
     });
 
+    backend_test!(test_memory_permissions, F, {
+        use crate::machine_state::memory::Permissions;
+        use crate::machine_state::registers::NonZeroXRegister as NZ;
+        use crate::machine_state::registers::XRegister::*;
+
+        const ADDRESS: u64 = M4K::TOTAL_BYTES as u64 / 2;
+        const XREG_VALUE: u64 = 0xFFEEDDCCBBAA9988;
+
+        // the load will fail as the page is not readable
+        let denied_load = ScenarioBuilder::default()
+            .set_setup_hook(setup_hook!(core, F, {
+                core.main_memory.write(ADDRESS, XREG_VALUE).unwrap();
+                core.main_memory
+                    .protect_pages(0, M4K::TOTAL_BYTES, Permissions::WRITE)
+                    .unwrap();
+            }))
+            .set_instructions(&[
+                I::new_li(NZ::x1, ADDRESS as i64, InstrWidth::Compressed),
+                I::new_x64_load_signed(x2, x1, 0, InstrWidth::Uncompressed),
+                I::new_nop(InstrWidth::Compressed),
+            ])
+            .set_expected_steps(2)
+            .set_assert_hook(assert_hook!(core, F, {
+                let value: u64 = core.hart.xregisters.read(x2);
+                assert_eq!(value, 0, "Found {value:x}, but expected load to fail");
+            }))
+            .build();
+
+        // the store will fail as the page is not writable
+        let denied_store = ScenarioBuilder::default()
+            .set_setup_hook(setup_hook!(core, F, {
+                core.main_memory
+                    .protect_pages(0, M4K::TOTAL_BYTES, Permissions::NONE)
+                    .unwrap();
+            }))
+            .set_instructions(&[
+                I::new_li(NZ::x1, ADDRESS as i64, InstrWidth::Compressed),
+                I::new_li(NZ::x2, XREG_VALUE as i64, InstrWidth::Compressed),
+                I::new_x64_store(x1, x2, 0, InstrWidth::Uncompressed),
+                I::new_nop(InstrWidth::Compressed),
+            ])
+            .set_expected_steps(3)
+            .build();
+
+        let scenarios: &[Scenario<F>] = &[denied_load, denied_store];
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        let mut interpreted_bb = InterpretedBlockBuilder;
+
+        for scenario in scenarios {
+            scenario.run(&mut jit, &mut interpreted_bb);
+        }
+    });
+
+    backend_test!(test_code_cache, F, {
+        use crate::machine_state::registers::NonZeroXRegister as NZ;
+        use crate::machine_state::registers::XRegister::*;
+
+        const ADDRESS: u64 = M4K::TOTAL_BYTES as u64 / 2;
+        const XREG_VALUE: u64 = 0xFFEEDDCCBBAA9988;
+
+        let scenario = ScenarioBuilder::default()
+            .set_instructions(&[
+                I::new_li(NZ::x1, ADDRESS as i64, InstrWidth::Compressed),
+                I::new_li(NZ::x2, XREG_VALUE as i64, InstrWidth::Compressed),
+                I::new_x64_store(x1, x2, 0, InstrWidth::Uncompressed),
+                I::new_x64_load_signed(x3, x1, 0, InstrWidth::Uncompressed),
+            ])
+            .set_assert_hook(assert_hook!(core, F, {
+                let value: u64 = core.hart.xregisters.read(x3);
+                assert_eq!(value, XREG_VALUE);
+            }))
+            .build();
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut interpreted_bb = InterpretedBlockBuilder;
+
+        let hits_and_misses = |jit: &JIT<M4K, F::Manager>| {
+            let code_cache = jit.code_cache().unwrap();
+            (code_cache.hits(), code_cache.misses())
+        };
+
+        // cold start: the block is compiled, and persisted
+        let mut jit = JIT::<M4K, F::Manager>::with_code_cache(dir.path()).unwrap();
+        scenario.run(&mut jit, &mut interpreted_bb);
+        assert_eq!(hits_and_misses(&jit), (0, 1));
+
+        // warm start: the persisted code is loaded & relocated. Compiling the block would fail,
+        // hence running it shows that the loaded code is executed.
+        let mut jit = JIT::<M4K, F::Manager>::with_code_cache(dir.path()).unwrap();
+        jit.inject_codegen_failures();
+        scenario.run(&mut jit, &mut interpreted_bb);
+        assert_eq!(hits_and_misses(&jit), (1, 0));
+
+        // a fresh directory holds no persisted code
+        let other_dir = tempfile::tempdir().unwrap();
+        let mut jit = JIT::<M4K, F::Manager>::with_code_cache(other_dir.path()).unwrap();
+        scenario.run(&mut jit, &mut interpreted_bb);
+        assert_eq!(hits_and_misses(&jit), (0, 1));
+
+        // code compiled for another memory configuration is not loaded, as it embeds different
+        // memory bounds
+        let mut jit = JIT::<M1M, F::Manager>::with_code_cache(dir.path()).unwrap();
+        assert!(jit.compile(&scenario.instructions).is_some());
+
+        let code_cache = jit.code_cache().unwrap();
+        assert_eq!((code_cache.hits(), code_cache.misses()), (0, 1));
+    });
+
     backend_test!(test_xor, F, {
         use crate::machine_state::registers::NonZeroXRegister::*;
 
@@ -2416,6 +2991,18 @@ mod tests {
             invalid_x64_atomic_unsigned(I::new_x64_atomic_add, 10, 30, u64::wrapping_add),
             valid_x64_atomic_signed(I::new_x64_atomic_min_signed, -10, 30, i64::min),
             invalid_x64_atomic_signed(I::new_x64_atomic_min_signed, 10, -30, i64::min),
+            valid_x64_atomic_signed(I::new_x64_atomic_max_signed, -10, 30, i64::max),
+            invalid_x64_atomic_signed(I::new_x64_atomic_max_signed, 10, -30, i64::max),
+            valid_x64_atomic_unsigned(I::new_x64_atomic_xor, 0b1100, 0b1010, u64::bitxor),
+            invalid_x64_atomic_unsigned(I::new_x64_atomic_xor, 0b1100, 0b1010, u64::bitxor),
+            valid_x64_atomic_unsigned(I::new_x64_atomic_and, 0b1100, 0b1010, u64::bitand),
+            invalid_x64_atomic_unsigned(I::new_x64_atomic_and, 0b1100, 0b1010, u64::bitand),
+            valid_x64_atomic_unsigned(I::new_x64_atomic_or, 0b1100, 0b1010, u64::bitor),
+            invalid_x64_atomic_unsigned(I::new_x64_atomic_or, 0b1100, 0b1010, u64::bitor),
+            valid_x64_atomic_unsigned(I::new_x64_atomic_min_unsigned, u64::MAX, 30, u64::min),
+            invalid_x64_atomic_unsigned(I::new_x64_atomic_min_unsigned, 10, 30, u64::min),
+            valid_x64_atomic_unsigned(I::new_x64_atomic_max_unsigned, u64::MAX, 30, u64::max),
+            invalid_x64_atomic_unsigned(I::new_x64_atomic_max_unsigned, 10, 30, u64::max),
         ];
 
         let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
@@ -2426,34 +3013,143 @@ mod tests {
         }
     });
 
-    backend_test!(test_mul_high, F, {
-        use crate::machine_state::registers::NonZeroXRegister::*;
+    backend_test!(test_x32_atomic, F, {
+        use crate::machine_state::registers::NonZeroXRegister as NZ;
+        use crate::machine_state::registers::XRegister::*;
 
-        let test_mul_high = |constructor: fn(
-            NonZeroXRegister,
-            NonZeroXRegister,
-            NonZeroXRegister,
-            InstrWidth,
-        ) -> I,
-                             lhs_reg: NonZeroXRegister,
-                             lhs_val: u64,
-                             rhs_reg: NonZeroXRegister,
-                             rhs_val: u64,
-                             expected: u64|
+        type ConstructAtomicFn = fn(
+            rd: XRegister,
+            rs1: XRegister,
+            rs2: XRegister,
+            aq: bool,
+            rl: bool,
+            width: InstrWidth,
+        ) -> I;
+
+        const MEMORY_SIZE: u64 = M4K::TOTAL_BYTES as u64;
+
+        const ADDRESS_BASE_ATOMICS: u64 = MEMORY_SIZE / 2;
+
+        // The upper 32 bits of `val2` are ignored by word-sized AMOs, so they are
+        // filled with garbage to check that only the lower word is used.
+        let valid_x32_atomic = |constructor: ConstructAtomicFn,
+                                val1: i32,
+                                val2: i32,
+                                fun: fn(i32, i32) -> i32|
          -> Scenario<F> {
             ScenarioBuilder::default()
                 .set_setup_hook(setup_hook!(core, F, {
-                    core.hart.xregisters.write_nz(lhs_reg, lhs_val);
-                    core.hart.xregisters.write_nz(rhs_reg, rhs_val);
+                    core.main_memory.write(ADDRESS_BASE_ATOMICS, val1).unwrap();
                 }))
-                .set_instructions(&[constructor(x2, lhs_reg, rhs_reg, Compressed)])
+                .set_instructions(&[
+                    I::new_li(NZ::x1, ADDRESS_BASE_ATOMICS as i64, InstrWidth::Compressed),
+                    I::new_li(
+                        NZ::x2,
+                        (0x5A5A_5A5A << 32) | (val2 as u32 as i64),
+                        InstrWidth::Compressed,
+                    ),
+                    constructor(x3, x1, x2, false, false, InstrWidth::Uncompressed),
+                    I::new_nop(InstrWidth::Compressed),
+                ])
+                .set_expected_steps(4)
                 .set_assert_hook(assert_hook!(core, F, {
-                    assert_eq!(core.hart.xregisters.read_nz(x2), expected);
+                    let value: u64 = core.hart.xregisters.read(x3);
+                    assert_eq!(value as i64, val1 as i64);
+
+                    let res: i32 = core.main_memory.read(ADDRESS_BASE_ATOMICS).unwrap();
+                    let expected = fun(val1, val2);
+                    assert_eq!(res, expected, "Found {res:x}, expected {expected:x}");
                 }))
                 .build()
         };
 
-        let scenarios: &[Scenario<F>] = &[
+        let invalid_x32_atomic =
+            |constructor: ConstructAtomicFn, val1: i32, val2: i32| -> Scenario<F> {
+                ScenarioBuilder::default()
+                    .set_setup_hook(setup_hook!(core, F, {
+                        core.main_memory
+                            .write(ADDRESS_BASE_ATOMICS + 2, val1)
+                            .unwrap();
+                    }))
+                    .set_instructions(&[
+                        I::new_li(
+                            NZ::x1,
+                            (ADDRESS_BASE_ATOMICS + 2) as i64,
+                            InstrWidth::Compressed,
+                        ),
+                        I::new_li(NZ::x2, val2 as i64, InstrWidth::Compressed),
+                        constructor(x3, x1, x2, false, false, InstrWidth::Uncompressed),
+                        I::new_nop(InstrWidth::Compressed),
+                    ])
+                    .set_expected_steps(3)
+                    .set_assert_hook(assert_hook!(core, F, {
+                        let value: u64 = core.hart.xregisters.read(x3);
+                        assert_eq!(value, 0);
+
+                        let res: i32 = core.main_memory.read(ADDRESS_BASE_ATOMICS + 2).unwrap();
+                        assert_eq!(res, val1, "Found {res:x}, expected {val1:x}");
+                    }))
+                    .build()
+            };
+
+        let min_unsigned = |x: i32, y: i32| u32::min(x as u32, y as u32) as i32;
+        let max_unsigned = |x: i32, y: i32| u32::max(x as u32, y as u32) as i32;
+
+        let scenarios: &[Scenario<F>] = &[
+            valid_x32_atomic(I::new_x32_atomic_add, i32::MAX, 1, i32::wrapping_add),
+            invalid_x32_atomic(I::new_x32_atomic_add, 10, 30),
+            valid_x32_atomic(I::new_x32_atomic_xor, 0b1100, 0b1010, i32::bitxor),
+            invalid_x32_atomic(I::new_x32_atomic_xor, 0b1100, 0b1010),
+            valid_x32_atomic(I::new_x32_atomic_and, 0b1100, 0b1010, i32::bitand),
+            invalid_x32_atomic(I::new_x32_atomic_and, 0b1100, 0b1010),
+            valid_x32_atomic(I::new_x32_atomic_or, 0b1100, 0b1010, i32::bitor),
+            invalid_x32_atomic(I::new_x32_atomic_or, 0b1100, 0b1010),
+            valid_x32_atomic(I::new_x32_atomic_min_signed, 10, -30, i32::min),
+            invalid_x32_atomic(I::new_x32_atomic_min_signed, 10, -30),
+            valid_x32_atomic(I::new_x32_atomic_max_signed, -10, 30, i32::max),
+            invalid_x32_atomic(I::new_x32_atomic_max_signed, -10, 30),
+            valid_x32_atomic(I::new_x32_atomic_min_unsigned, -10, 30, min_unsigned),
+            invalid_x32_atomic(I::new_x32_atomic_min_unsigned, -10, 30),
+            valid_x32_atomic(I::new_x32_atomic_max_unsigned, -10, 30, max_unsigned),
+            invalid_x32_atomic(I::new_x32_atomic_max_unsigned, -10, 30),
+        ];
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        let mut interpreted_bb = InterpretedBlockBuilder;
+
+        for scenario in scenarios {
+            scenario.run(&mut jit, &mut interpreted_bb);
+        }
+    });
+
+    backend_test!(test_mul_high, F, {
+        use crate::machine_state::registers::NonZeroXRegister::*;
+
+        let test_mul_high = |constructor: fn(
+            NonZeroXRegister,
+            NonZeroXRegister,
+            NonZeroXRegister,
+            InstrWidth,
+        ) -> I,
+                             lhs_reg: NonZeroXRegister,
+                             lhs_val: u64,
+                             rhs_reg: NonZeroXRegister,
+                             rhs_val: u64,
+                             expected: u64|
+         -> Scenario<F> {
+            ScenarioBuilder::default()
+                .set_setup_hook(setup_hook!(core, F, {
+                    core.hart.xregisters.write_nz(lhs_reg, lhs_val);
+                    core.hart.xregisters.write_nz(rhs_reg, rhs_val);
+                }))
+                .set_instructions(&[constructor(x2, lhs_reg, rhs_reg, Compressed)])
+                .set_assert_hook(assert_hook!(core, F, {
+                    assert_eq!(core.hart.xregisters.read_nz(x2), expected);
+                }))
+                .build()
+        };
+
+        let scenarios: &[Scenario<F>] = &[
             // MULH (Signed × Signed)
             test_mul_high(
                 I::new_x64_mul_high_signed,
@@ -3025,4 +3721,791 @@ mod tests {
             scenario.run(&mut jit, &mut interpreted_bb);
         }
     });
+
+    backend_test!(test_bitmanip, F, {
+        use crate::machine_state::registers::*;
+
+        let instructions = [
+            // Zba
+            I::new_shift1_add(nz::a2, a0, a1, Uncompressed),
+            I::new_shift2_add(nz::a2, a0, a1, Uncompressed),
+            I::new_shift3_add(nz::a2, a0, a1, Uncompressed),
+            I::new_add_unsigned_word(nz::a2, a0, a1, Uncompressed),
+            I::new_shift1_add_unsigned_word(nz::a2, a0, a1, Uncompressed),
+            I::new_shift2_add_unsigned_word(nz::a2, a0, a1, Uncompressed),
+            I::new_shift3_add_unsigned_word(nz::a2, a0, a1, Uncompressed),
+            I::new_shift_left_immediate_unsigned_word(nz::a2, a0, 13, Uncompressed),
+            // Zbb
+            I::new_and_not(nz::a2, a0, a1, Uncompressed),
+            I::new_or_not(nz::a2, a0, a1, Uncompressed),
+            I::new_xor_not(nz::a2, a0, a1, Uncompressed),
+            I::new_count_leading_zeros(nz::a2, a0, Uncompressed),
+            I::new_count_trailing_zeros(nz::a2, a0, Uncompressed),
+            I::new_count_set_bits(nz::a2, a0, Uncompressed),
+            I::new_x32_count_leading_zeros(nz::a2, a0, Uncompressed),
+            I::new_x32_count_trailing_zeros(nz::a2, a0, Uncompressed),
+            I::new_x32_count_set_bits(nz::a2, a0, Uncompressed),
+            I::new_max_signed(nz::a2, a0, a1, Uncompressed),
+            I::new_max_unsigned(nz::a2, a0, a1, Uncompressed),
+            I::new_min_signed(nz::a2, a0, a1, Uncompressed),
+            I::new_min_unsigned(nz::a2, a0, a1, Uncompressed),
+            I::new_sign_extend_byte(nz::a2, a0, Uncompressed),
+            I::new_sign_extend_half(nz::a2, a0, Uncompressed),
+            I::new_zero_extend_half(nz::a2, a0, Uncompressed),
+            I::new_rotate_left(nz::a2, nz::a0, nz::a1, Uncompressed),
+            I::new_rotate_right(nz::a2, nz::a0, nz::a1, Uncompressed),
+            I::new_rotate_right_immediate(nz::a2, nz::a0, 45, Uncompressed),
+            I::new_x32_rotate_left(nz::a2, a0, a1, Uncompressed),
+            I::new_x32_rotate_right(nz::a2, a0, a1, Uncompressed),
+            I::new_x32_rotate_right_immediate(nz::a2, nz::a0, 27, Uncompressed),
+            I::new_or_combine_bytes(nz::a2, a0, Uncompressed),
+            I::new_byte_reverse(nz::a2, a0, Uncompressed),
+            // Zbs
+            I::new_bit_clear(nz::a2, a0, a1, Uncompressed),
+            I::new_bit_clear_immediate(nz::a2, a0, 63, Uncompressed),
+            I::new_bit_extract(nz::a2, a0, a1, Uncompressed),
+            I::new_bit_extract_immediate(nz::a2, a0, 7, Uncompressed),
+            I::new_bit_invert(nz::a2, a0, a1, Uncompressed),
+            I::new_bit_invert_immediate(nz::a2, a0, 31, Uncompressed),
+            I::new_bit_set(nz::a2, a0, a1, Uncompressed),
+            I::new_bit_set_immediate(nz::a2, a0, 0, Uncompressed),
+        ];
+
+        let values = [
+            (0, 0),
+            (0x8000_0000_0000_0001, 0xFFFF_FFFF_FFFF_FF80),
+            (0x0123_4567_89AB_CDEF, 0x0000_0000_0000_0043),
+            (0x0000_00FF_8000_7F00, 0x7FFF_FFFF_FFFF_FFFF),
+        ];
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        let mut interpreted_bb = InterpretedBlockBuilder;
+
+        for (lhs, rhs) in values {
+            for instr in instructions {
+                // The JIT & interpreter states are compared after each run.
+                ScenarioBuilder::<F>::default()
+                    .set_setup_hook(setup_hook!(core, F, {
+                        core.hart.xregisters.write(a0, lhs);
+                        core.hart.xregisters.write(a1, rhs);
+                    }))
+                    .set_instructions(&[instr])
+                    .build()
+                    .run(&mut jit, &mut interpreted_bb);
+            }
+        }
+    });
+
+    backend_test!(test_conditional_zero, F, {
+        use crate::machine_state::registers::*;
+
+        let test_conditional_zero = |rs1_val: u64, rs2_val: u64| {
+            ScenarioBuilder::default()
+                .set_setup_hook(setup_hook!(core, F, {
+                    core.hart.xregisters.write(a0, rs1_val);
+                    core.hart.xregisters.write(a1, rs2_val);
+                }))
+                .set_instructions(&[
+                    I::new_conditional_zero_equal_zero(nz::a2, a0, a1, Uncompressed),
+                    I::new_conditional_zero_not_equal_zero(nz::a3, a0, a1, Compressed),
+                ])
+                .set_assert_hook(assert_hook!(core, F, {
+                    let (eqz, nez) = if rs2_val == 0 {
+                        (0, rs1_val)
+                    } else {
+                        (rs1_val, 0)
+                    };
+                    assert_eq!(core.hart.xregisters.read_nz(nz::a2), eqz);
+                    assert_eq!(core.hart.xregisters.read_nz(nz::a3), nez);
+                }))
+                .build()
+        };
+
+        let scenarios: &[Scenario<F>] = &[
+            test_conditional_zero(0, 0),
+            test_conditional_zero(42, 0),
+            test_conditional_zero(42, 1),
+            test_conditional_zero(u64::MAX, 1 << 63),
+        ];
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        let mut interpreted_bb = InterpretedBlockBuilder;
+
+        for scenario in scenarios {
+            scenario.run(&mut jit, &mut interpreted_bb);
+        }
+    });
+
+    backend_test!(test_atomic_compare_and_swap, F, {
+        use crate::machine_state::registers::NonZeroXRegister as NZ;
+        use crate::machine_state::registers::*;
+
+        const MEMORY_SIZE: u64 = M4K::TOTAL_BYTES as u64;
+        const ADDRESS_BASE_ATOMICS: u64 = MEMORY_SIZE / 2;
+
+        // Memory holds `mem` at `addr` and `addr + 8`, the pair starting at a2 holds the
+        // expected value and the pair starting at a4 holds the value to store.
+        let test_compare_and_swap =
+            |constructor: fn(XRegister, XRegister, XRegister, bool, bool, InstrWidth) -> I,
+             addr: u64,
+             mem: [u64; 2],
+             expected: [u64; 2],
+             expected_rd: [u64; 2],
+             expected_mem: [u64; 2]|
+             -> Scenario<F> {
+                ScenarioBuilder::default()
+                    .set_setup_hook(setup_hook!(core, F, {
+                        core.main_memory.set_all_readable_writeable();
+                        core.main_memory.write(addr, mem[0]).unwrap();
+                        core.main_memory.write(addr + 8, mem[1]).unwrap();
+                        core.hart.xregisters.write(a2, expected[0]);
+                        core.hart.xregisters.write(a3, expected[1]);
+                        core.hart.xregisters.write(a4, 0x1111);
+                        core.hart.xregisters.write(a5, 0x2222);
+                    }))
+                    .set_instructions(&[
+                        I::new_li(NZ::x1, addr as i64, InstrWidth::Uncompressed),
+                        constructor(a2, x1, a4, false, false, InstrWidth::Uncompressed),
+                    ])
+                    .set_assert_hook(assert_hook!(core, F, {
+                        assert_eq!(core.hart.xregisters.read(a2), expected_rd[0]);
+                        assert_eq!(core.hart.xregisters.read(a3), expected_rd[1]);
+
+                        let lo: u64 = core.main_memory.read(addr).unwrap();
+                        let hi: u64 = core.main_memory.read(addr + 8).unwrap();
+                        assert_eq!([lo, hi], expected_mem);
+                    }))
+                    .build()
+            };
+
+        let scenarios: &[Scenario<F>] = &[
+            // Successful 32-bit compare-and-swap, ignoring the upper bits of rd
+            test_compare_and_swap(
+                I::new_x32_atomic_compare_and_swap,
+                ADDRESS_BASE_ATOMICS,
+                [0xFFFF_FFFF_8000_0000, 7],
+                [0x1234_5678_8000_0000, 3],
+                [0xFFFF_FFFF_8000_0000, 3],
+                [0xFFFF_FFFF_0000_1111, 7],
+            ),
+            // Failed 32-bit compare-and-swap
+            test_compare_and_swap(
+                I::new_x32_atomic_compare_and_swap,
+                ADDRESS_BASE_ATOMICS + 4,
+                [0x100, 0],
+                [0x101, 3],
+                [0x100, 3],
+                [0x100, 0],
+            ),
+            // Successful 64-bit compare-and-swap
+            test_compare_and_swap(
+                I::new_x64_atomic_compare_and_swap,
+                ADDRESS_BASE_ATOMICS + 8,
+                [0x100, 0x200],
+                [0x100, 3],
+                [0x100, 3],
+                [0x1111, 0x200],
+            ),
+            // Failed 64-bit compare-and-swap
+            test_compare_and_swap(
+                I::new_x64_atomic_compare_and_swap,
+                ADDRESS_BASE_ATOMICS + 8,
+                [0x100, 0x200],
+                [0x1_0000_0100, 3],
+                [0x100, 3],
+                [0x100, 0x200],
+            ),
+            // Successful 128-bit compare-and-swap
+            test_compare_and_swap(
+                I::new_x128_atomic_compare_and_swap,
+                ADDRESS_BASE_ATOMICS + 16,
+                [0x100, 0x200],
+                [0x100, 0x200],
+                [0x100, 0x200],
+                [0x1111, 0x2222],
+            ),
+            // Failed 128-bit compare-and-swap, only the high half differs
+            test_compare_and_swap(
+                I::new_x128_atomic_compare_and_swap,
+                ADDRESS_BASE_ATOMICS + 16,
+                [0x100, 0x200],
+                [0x100, 0x201],
+                [0x100, 0x200],
+                [0x100, 0x200],
+            ),
+            // 128-bit compare-and-swap on an address that is only 8-byte aligned
+            ScenarioBuilder::default()
+                .set_setup_hook(setup_hook!(core, F, {
+                    core.main_memory.set_all_readable_writeable();
+                    core.hart.xregisters.write(a2, 0);
+                    core.hart.xregisters.write(a4, 0x1111);
+                }))
+                .set_instructions(&[
+                    I::new_li(
+                        NZ::x1,
+                        (ADDRESS_BASE_ATOMICS + 8) as i64,
+                        InstrWidth::Uncompressed,
+                    ),
+                    I::new_x128_atomic_compare_and_swap(
+                        a2,
+                        x1,
+                        a4,
+                        false,
+                        false,
+                        InstrWidth::Uncompressed,
+                    ),
+                    I::new_nop(InstrWidth::Compressed),
+                ])
+                .set_expected_steps(2)
+                .set_assert_hook(assert_hook!(core, F, {
+                    let mem: u64 = core.main_memory.read(ADDRESS_BASE_ATOMICS + 8).unwrap();
+                    assert_eq!(mem, 0);
+                }))
+                .build(),
+        ];
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        let mut interpreted_bb = InterpretedBlockBuilder;
+
+        for scenario in scenarios {
+            scenario.run(&mut jit, &mut interpreted_bb);
+        }
+    });
+
+    backend_test!(test_scalar_crypto, F, {
+        use crate::machine_state::registers::*;
+
+        let test_scalar_crypto = |rs1_val: u64, rs2_val: u64| {
+            ScenarioBuilder::default()
+                .set_setup_hook(setup_hook!(core, F, {
+                    core.hart.xregisters.write(a0, rs1_val);
+                    core.hart.xregisters.write(a1, rs2_val);
+                }))
+                .set_instructions(&[
+                    I::new_pack(nz::a2, a0, a1, Uncompressed),
+                    I::new_pack_byte(nz::a3, a0, a1, Uncompressed),
+                    I::new_x32_pack(nz::a4, a0, a1, Uncompressed),
+                    I::new_reverse_bits_in_bytes(nz::a5, a0, Uncompressed),
+                    I::new_sha256_sig0(nz::a6, a0, Uncompressed),
+                    I::new_sha512_sum1(nz::a7, a0, Uncompressed),
+                ])
+                .set_assert_hook(assert_hook!(core, F, {
+                    let x = rs1_val as u32;
+                    let sha256_sig0 = x.rotate_right(7) ^ x.rotate_right(18) ^ (x >> 3);
+                    let sha512_sum1 = rs1_val.rotate_right(14)
+                        ^ rs1_val.rotate_right(18)
+                        ^ rs1_val.rotate_right(41);
+                    let brev8 = u64::from_le_bytes(rs1_val.to_le_bytes().map(u8::reverse_bits));
+
+                    assert_eq!(
+                        core.hart.xregisters.read_nz(nz::a2),
+                        (rs1_val & 0xFFFF_FFFF) | (rs2_val << 32)
+                    );
+                    assert_eq!(
+                        core.hart.xregisters.read_nz(nz::a3),
+                        (rs1_val & 0xFF) | ((rs2_val & 0xFF) << 8)
+                    );
+                    assert_eq!(
+                        core.hart.xregisters.read_nz(nz::a4),
+                        ((rs1_val & 0xFFFF) | ((rs2_val & 0xFFFF) << 16)) as i32 as u64
+                    );
+                    assert_eq!(core.hart.xregisters.read_nz(nz::a5), brev8);
+                    assert_eq!(
+                        core.hart.xregisters.read_nz(nz::a6),
+                        sha256_sig0 as i32 as u64
+                    );
+                    assert_eq!(core.hart.xregisters.read_nz(nz::a7), sha512_sum1);
+                }))
+                .build()
+        };
+
+        let scenarios: &[Scenario<F>] = &[
+            test_scalar_crypto(0, 0),
+            test_scalar_crypto(0x0123_4567_89AB_CDEF, 0xFEDC_BA98_7654_3210),
+            test_scalar_crypto(u64::MAX, 1 << 63),
+            test_scalar_crypto(0x8000_8000, 0x8000),
+        ];
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        let mut interpreted_bb = InterpretedBlockBuilder;
+
+        for scenario in scenarios {
+            scenario.run(&mut jit, &mut interpreted_bb);
+        }
+    });
+
+    backend_test!(test_float, F, {
+        use crate::interpreter::float::RoundingMode;
+        use crate::machine_state::csregisters::CSRegister;
+        use crate::machine_state::registers::*;
+        use crate::parser::instruction::FCmpArgs;
+        use crate::parser::instruction::FR1ArgWithRounding;
+        use crate::parser::instruction::FR2ArgsWithRounding;
+        use crate::parser::instruction::FR3ArgsWithRounding;
+        use crate::parser::instruction::FRArgs;
+        use crate::parser::instruction::FRegToXRegArgs;
+        use crate::parser::instruction::FRegToXRegArgsWithRounding;
+        use crate::parser::instruction::InstrCacheable as IC;
+        use crate::parser::instruction::InstrRoundingMode;
+        use crate::parser::instruction::XRegToFRegArgs;
+        use crate::parser::instruction::XRegToFRegArgsWithRounding;
+
+        let r1 = |rs1, rm, rd| FR1ArgWithRounding { rs1, rm, rd };
+        let r2 = |rs1, rs2, rm, rd| FR2ArgsWithRounding { rs1, rs2, rm, rd };
+        let r3 = |rs1, rs2, rs3, rm, rd| FR3ArgsWithRounding {
+            rs1,
+            rs2,
+            rs3,
+            rm,
+            rd,
+        };
+        let fr = |rs1, rs2, rd| FRArgs { rs1, rs2, rd };
+        let cmp = |rs1, rs2, rd| FCmpArgs { rs1, rs2, rd };
+        let f_to_x = |rs1, rd| FRegToXRegArgs { rs1, rd };
+        let f_to_x_rm = |rs1, rm, rd| FRegToXRegArgsWithRounding { rs1, rm, rd };
+        let x_to_f = |rs1, rd| XRegToFRegArgs { rs1, rd };
+        let x_to_f_rm = |rs1, rm, rd| XRegToFRegArgsWithRounding { rs1, rm, rd };
+
+        // Every F & D operation over the values in f1, f2, f3 & a0, compared against the
+        // interpreter - including the results, fflags and any exceptions raised.
+        let float_ops = |rs1: u64, rs2: u64, rs3: u64, int: u64, rm, frm: u64| {
+            let instructions = [
+                IC::Faddd(r2(f1, f2, rm, f4)),
+                IC::Fsubs(r2(f1, f2, rm, f5)),
+                IC::Fmuld(r2(f1, f2, rm, f6)),
+                IC::Fdivs(r2(f1, f2, rm, f7)),
+                IC::Fdivd(r2(f1, f2, rm, f8)),
+                IC::Fsqrts(r1(f1, rm, f9)),
+                IC::Fsqrtd(r1(f2, rm, f10)),
+                IC::Fmaddd(r3(f1, f2, f3, rm, f11)),
+                IC::Fmsubs(r3(f1, f2, f3, rm, f12)),
+                IC::Fnmsubd(r3(f1, f2, f3, rm, f13)),
+                IC::Fnmadds(r3(f1, f2, f3, rm, f14)),
+                IC::Fmins(fr(f1, f2, f15)),
+                IC::Fmaxd(fr(f1, f2, f16)),
+                IC::Fsgnjs(fr(f1, f2, f17)),
+                IC::Fsgnjnd(fr(f1, f2, f18)),
+                IC::Fsgnjxs(fr(f1, f2, f19)),
+                IC::Feqd(cmp(f1, f2, a1)),
+                IC::Flts(cmp(f1, f2, a2)),
+                IC::Fled(cmp(f1, f2, a3)),
+                IC::FclassS(f_to_x(f1, a4)),
+                IC::FclassD(f_to_x(f2, a5)),
+                IC::Fcvtws(f_to_x_rm(f1, rm, a6)),
+                IC::Fcvtwud(f_to_x_rm(f2, rm, a7)),
+                IC::Fcvtld(f_to_x_rm(f1, rm, s2)),
+                IC::Fcvtlus(f_to_x_rm(f2, rm, s3)),
+                IC::Fcvtsw(x_to_f_rm(a0, rm, f20)),
+                IC::Fcvtdwu(x_to_f_rm(a0, rm, f21)),
+                IC::Fcvtsl(x_to_f_rm(a0, rm, f22)),
+                IC::Fcvtdlu(x_to_f_rm(a0, rm, f23)),
+                IC::Fcvtsd(r1(f1, rm, f24)),
+                IC::Fcvtds(r1(f2, rm, f25)),
+                IC::FmvXW(f_to_x(f3, s4)),
+                IC::FmvXD(f_to_x(f3, s5)),
+                IC::FmvWX(x_to_f(a0, f26)),
+                IC::FmvDX(x_to_f(a0, f27)),
+            ];
+
+            ScenarioBuilder::default()
+                .set_setup_hook(setup_hook!(core, F, {
+                    core.hart.fregisters.write(f1, rs1.into());
+                    core.hart.fregisters.write(f2, rs2.into());
+                    core.hart.fregisters.write(f3, rs3.into());
+                    core.hart.xregisters.write(a0, int);
+                    core.hart.csregisters.write(CSRegister::frm, frm);
+                }))
+                .set_instructions(&instructions.map(|instr| I::from(&instr)))
+                .build()
+        };
+
+        let boxed = |value: f32| value.to_bits() as u64 | 0xFFFF_FFFF_0000_0000;
+        let bits = |value: f64| value.to_bits();
+
+        const RNE: InstrRoundingMode = InstrRoundingMode::Static(RoundingMode::RNE);
+        const RTZ: InstrRoundingMode = InstrRoundingMode::Static(RoundingMode::RTZ);
+        const RDN: InstrRoundingMode = InstrRoundingMode::Static(RoundingMode::RDN);
+        const RUP: InstrRoundingMode = InstrRoundingMode::Static(RoundingMode::RUP);
+        const RMM: InstrRoundingMode = InstrRoundingMode::Static(RoundingMode::RMM);
+        const DYN: InstrRoundingMode = InstrRoundingMode::Dynamic;
+
+        const F64_SNAN: u64 = 0x7FF4_0000_0000_0000;
+        const F64_QNAN: u64 = 0x7FF8_0000_0000_0001;
+        const F32_SNAN_BOXED: u64 = 0xFFFF_FFFF_7FA0_0000;
+        const F32_UNBOXED: u64 = 0x0000_0000_3FC0_0000;
+
+        let basic_arith = ScenarioBuilder::default()
+            .set_setup_hook(setup_hook!(core, F, {
+                core.hart.fregisters.write(f1, 1.5f64.to_bits().into());
+                core.hart.fregisters.write(f2, 2.25f64.to_bits().into());
+            }))
+            .set_instructions(&[
+                I::from(&IC::Faddd(r2(f1, f2, RNE, f3))),
+                I::from(&IC::Fdivd(r2(f1, f2, RNE, f4))),
+            ])
+            .set_assert_hook(assert_hook!(core, F, {
+                let sum: u64 = core.hart.fregisters.read(f3).into();
+                assert_eq!(f64::from_bits(sum), 3.75);
+
+                // 1.5 / 2.25 is inexact
+                let fflags: u64 = core.hart.csregisters.read(CSRegister::fflags);
+                assert_eq!(fflags, 0b00001);
+            }))
+            .build();
+
+        let scenarios: &[Scenario<F>] = &[
+            basic_arith,
+            // Exact & inexact results, over every static rounding mode
+            float_ops(boxed(1.5), bits(2.25), bits(-0.5), 7, RNE, 0),
+            float_ops(boxed(1.0), bits(3.0), bits(1e308), 7, RNE, 0),
+            float_ops(boxed(-1.0), bits(-3.0), bits(0.1), u64::MAX, RTZ, 0),
+            float_ops(boxed(2.5), bits(-2.5), bits(0.1), 1 << 63, RDN, 0),
+            float_ops(boxed(-2.5), bits(2.5), bits(-0.1), 1 << 31, RUP, 0),
+            float_ops(boxed(0.5), bits(1.5), bits(-0.1), 0xFFFF_FFFF, RMM, 0),
+            // Dynamic rounding modes, read from frm
+            float_ops(boxed(1.0), bits(3.0), bits(1e-308), 3, DYN, 0b001),
+            float_ops(boxed(-1.0), bits(7.0), bits(1e-308), 3, DYN, 0b100),
+            // Overflow, underflow, division by zero & invalid operations
+            float_ops(boxed(f32::MAX), bits(f64::MAX), bits(f64::MAX), 0, RNE, 0),
+            float_ops(boxed(f32::MIN_POSITIVE), bits(1e300), bits(0.0), 0, RNE, 0),
+            float_ops(boxed(-1.0), bits(0.0), bits(-0.0), 0, RNE, 0),
+            float_ops(boxed(f32::INFINITY), bits(f64::NEG_INFINITY), 0, 0, RNE, 0),
+            // NaNs: signalling, quiet, and single-precision values that are not NaN-boxed
+            float_ops(F32_SNAN_BOXED, F64_SNAN, F64_QNAN, 0, RNE, 0),
+            float_ops(F32_UNBOXED, F64_QNAN, F64_SNAN, 0, RNE, 0),
+            float_ops(boxed(f32::NAN), bits(1.0), F32_UNBOXED, 0, RNE, 0),
+            // Invalid dynamic rounding modes raise an illegal instruction exception
+            ScenarioBuilder::default()
+                .set_setup_hook(setup_hook!(core, F, {
+                    core.hart.csregisters.write(CSRegister::frm, 0b101);
+                }))
+                .set_instructions(&[
+                    I::from(&IC::Fmins(fr(f1, f2, f3))),
+                    I::from(&IC::Fmuls(r2(f1, f2, DYN, f4))),
+                ])
+                // the exception is handled as the second step
+                .set_expected_steps(2)
+                .build(),
+            ScenarioBuilder::default()
+                .set_setup_hook(setup_hook!(core, F, {
+                    core.hart.csregisters.write(CSRegister::frm, 0b110);
+                }))
+                .set_instructions(&[I::from(&IC::Fcvtdl(x_to_f_rm(a0, DYN, f1)))])
+                .build(),
+        ];
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        let mut interpreted_bb = InterpretedBlockBuilder;
+
+        for scenario in scenarios {
+            scenario.run(&mut jit, &mut interpreted_bb);
+        }
+    });
+
+    backend_test!(test_float_load_store, F, {
+        use crate::machine_state::registers::*;
+        use crate::parser::instruction::CIBDTypeArgs;
+        use crate::parser::instruction::CSSDTypeArgs;
+        use crate::parser::instruction::FLoadArgs;
+        use crate::parser::instruction::FStoreArgs;
+        use crate::parser::instruction::InstrCacheable as IC;
+
+        const MEMORY_SIZE: u64 = M4K::TOTAL_BYTES as u64;
+        const ADDRESS_BASE: u64 = MEMORY_SIZE / 2;
+
+        const F64_VALUE: u64 = 0x4009_21FB_5444_2D18;
+        const F32_VALUE: u32 = 0x4049_0FDB;
+
+        let load = |rs1, rd, imm| FLoadArgs { rs1, rd, imm };
+        let store = |rs1, rs2, imm| FStoreArgs { rs1, rs2, imm };
+
+        let valid = ScenarioBuilder::default()
+            .set_setup_hook(setup_hook!(core, F, {
+                core.main_memory.write(ADDRESS_BASE, F64_VALUE).unwrap();
+                core.main_memory.write(ADDRESS_BASE + 8, F32_VALUE).unwrap();
+                core.hart.xregisters.write(sp, ADDRESS_BASE);
+                // Not NaN-boxed, so stored as the canonical NaN by `fsw`.
+                core.hart.fregisters.write(f5, 0x1234_5678_u64.into());
+            }))
+            .set_instructions(&[
+                I::new_li(nz::a0, ADDRESS_BASE as i64, Compressed),
+                I::from(&IC::Fld(load(a0, f1, 0))),
+                I::from(&IC::Flw(load(a0, f2, 8))),
+                I::from(&IC::Fsd(store(a0, f2, 16))),
+                I::from(&IC::Fsw(store(a0, f1, 24))),
+                I::from(&IC::Fsw(store(a0, f5, 28))),
+                I::from(&IC::CFld(load(a0, f3, 3))),
+                I::from(&IC::CFsd(store(a0, f3, 32))),
+                I::from(&IC::CFldsp(CIBDTypeArgs {
+                    rd_rs1: f4,
+                    imm: 16,
+                })),
+                I::from(&IC::CFsdsp(CSSDTypeArgs { rs2: f4, imm: 40 })),
+            ])
+            .set_assert_hook(assert_hook!(core, F, {
+                let f1_val: u64 = core.hart.fregisters.read(f1).into();
+                let f2_val: u64 = core.hart.fregisters.read(f2).into();
+                assert_eq!(f1_val, F64_VALUE);
+                assert_eq!(f2_val, F32_VALUE as u64 | 0xFFFF_FFFF_0000_0000);
+
+                let stored: u64 = core.main_memory.read(ADDRESS_BASE + 40).unwrap();
+                assert_eq!(stored, f2_val);
+
+                let stored: u32 = core.main_memory.read(ADDRESS_BASE + 28).unwrap();
+                assert_eq!(stored, 0x7FC0_0000);
+            }))
+            .build();
+
+        let invalid_load = ScenarioBuilder::default()
+            .set_instructions(&[
+                I::new_li(nz::a0, MEMORY_SIZE as i64 - 4, Compressed),
+                I::from(&IC::Fld(load(a0, f1, 0))),
+                I::new_nop(Compressed),
+            ])
+            // the load will fail due to being out of bounds
+            .set_expected_steps(2)
+            .build();
+
+        let invalid_store = ScenarioBuilder::default()
+            .set_instructions(&[
+                I::new_li(nz::a0, MEMORY_SIZE as i64 - 2, Compressed),
+                I::from(&IC::Fsw(store(a0, f1, 0))),
+                I::new_nop(Compressed),
+            ])
+            // the store will fail due to being out of bounds
+            .set_expected_steps(2)
+            .build();
+
+        let scenarios: &[Scenario<F>] = &[valid, invalid_load, invalid_store];
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        let mut interpreted_bb = InterpretedBlockBuilder;
+
+        for scenario in scenarios {
+            scenario.run(&mut jit, &mut interpreted_bb);
+        }
+    });
+
+    backend_test!(test_csr, F, {
+        use crate::machine_state::csregisters::CSRegister;
+        use crate::machine_state::registers::*;
+        use crate::parser::instruction::CsrArgs;
+        use crate::parser::instruction::CsriArgs;
+        use crate::parser::instruction::InstrCacheable as IC;
+
+        let r = |csr, rs1, rd| CsrArgs { rd, rs1, csr };
+        let i = |csr, imm, rd| CsriArgs { rd, imm, csr };
+
+        let valid = ScenarioBuilder::default()
+            .set_setup_hook(setup_hook!(core, F, {
+                core.hart.csregisters.write(CSRegister::frm, 0b011_u64);
+                core.hart.csregisters.write(CSRegister::fflags, 0b10101_u64);
+                core.hart.csregisters.write(CSRegister::vstart, 0xBEEF_u64);
+            }))
+            .set_instructions(&[
+                I::new_li(nz::a0, 0b001, Compressed),
+                I::new_li(nz::a1, 0b00110, Compressed),
+                // frrm a2
+                I::from(&IC::Csrrs(r(CSRegister::frm, x0, a2))),
+                // fsrm a3, a0
+                I::from(&IC::Csrrw(r(CSRegister::frm, a0, a3))),
+                // fsflags a1 - no read
+                I::from(&IC::Csrrw(r(CSRegister::fflags, a1, x0))),
+                I::from(&IC::Csrrs(r(CSRegister::fflags, a0, a4))),
+                I::from(&IC::Csrrc(r(CSRegister::fflags, a1, a5))),
+                I::from(&IC::Csrrc(r(CSRegister::fcsr, x0, a6))),
+                // only the lower 5 bits of the immediate are used
+                I::from(&IC::Csrrwi(i(CSRegister::vstart, 0b1110_0101, a7))),
+                I::from(&IC::Csrrsi(i(CSRegister::vstart, 0b11010, t0))),
+                I::from(&IC::Csrrci(i(CSRegister::vstart, 0b00011, t1))),
+                I::from(&IC::Csrrsi(i(CSRegister::vstart, 0, t2))),
+                I::from(&IC::Csrrci(i(CSRegister::cycle, 0, t3))),
+                I::from(&IC::Csrrs(r(CSRegister::instret, x0, t4))),
+            ])
+            .set_assert_hook(assert_hook!(core, F, {
+                let frm_before: u64 = core.hart.xregisters.read(a2);
+                assert_eq!(frm_before, 0b011);
+                let frm_swapped: u64 = core.hart.xregisters.read(a3);
+                assert_eq!(frm_swapped, 0b011);
+                let frm: u64 = core.hart.csregisters.read(CSRegister::frm);
+                assert_eq!(frm, 0b001);
+
+                let fflags: u64 = core.hart.csregisters.read(CSRegister::fflags);
+                assert_eq!(fflags, 0b00001);
+            }))
+            .build();
+
+        let write_read_only = |csr_instr: IC| -> Scenario<F> {
+            ScenarioBuilder::default()
+                .set_instructions(&[
+                    I::new_li(nz::a0, 1, Compressed),
+                    I::from(&csr_instr),
+                    I::new_nop(Compressed),
+                ])
+                // the exception is handled as the second step
+                .set_expected_steps(2)
+                .build()
+        };
+
+        let scenarios: &[Scenario<F>] = &[
+            valid,
+            write_read_only(IC::Csrrw(r(CSRegister::cycle, a0, x0))),
+            write_read_only(IC::Csrrs(r(CSRegister::instret, a0, a1))),
+            write_read_only(IC::Csrrc(r(CSRegister::vlenb, a0, a1))),
+            write_read_only(IC::Csrrwi(i(CSRegister::cycle, 1, a1))),
+            write_read_only(IC::Csrrsi(i(CSRegister::time, 1, a1))),
+            write_read_only(IC::Csrrci(i(CSRegister::vlenb, 1, a1))),
+        ];
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        let mut interpreted_bb = InterpretedBlockBuilder;
+
+        for scenario in scenarios {
+            scenario.run(&mut jit, &mut interpreted_bb);
+        }
+    });
+
+    backend_test!(test_xregister_cache, F, {
+        use crate::machine_state::registers::*;
+
+        const MEMORY_SIZE: u64 = M4K::TOTAL_BYTES as u64;
+
+        // Registers written before exiting on a branch are written back to the state.
+        let branch = |initial: u64, expected_steps: usize| {
+            ScenarioBuilder::default()
+                .set_setup_hook(setup_hook!(core, F, {
+                    core.hart.xregisters.write_nz(nz::a1, initial);
+                }))
+                .set_instructions(&[
+                    I::new_li(nz::a2, 4, Compressed),
+                    I::new_addi(nz::a1, nz::a1, 1, Uncompressed),
+                    I::new_branch_equal(nz::a1, nz::a2, 0x100, Uncompressed),
+                    I::new_add(nz::a3, nz::a1, nz::a2, Compressed),
+                    I::new_addi(nz::a1, nz::a1, 1, Uncompressed),
+                ])
+                .set_expected_steps(expected_steps)
+                .set_assert_hook(assert_hook!(core, F, {
+                    let incremented: u64 = core.hart.xregisters.read(a1);
+                    let constant: u64 = core.hart.xregisters.read(a2);
+                    assert_ne!(incremented, initial);
+                    assert_eq!(constant, 4);
+                }))
+                .build()
+        };
+
+        // Registers written before an exception are written back to the state.
+        let exception = ScenarioBuilder::default()
+            .set_instructions(&[
+                I::new_li(nz::a1, MEMORY_SIZE as i64 - 8, Compressed),
+                I::new_addi(nz::a1, nz::a1, 4, Uncompressed),
+                I::new_mv(nz::a2, nz::a1, Compressed),
+                I::new_x64_load_signed(a3, a1, 0, Uncompressed),
+                I::new_nop(Compressed),
+            ])
+            // the load will fail due to being out of bounds
+            .set_expected_steps(4)
+            .set_assert_hook(assert_hook!(core, F, {
+                let copied: u64 = core.hart.xregisters.read(a2);
+                assert_eq!(copied, MEMORY_SIZE - 4);
+            }))
+            .build();
+
+        let scenarios: &[Scenario<F>] = &[
+            // branch taken
+            branch(3, 3),
+            // branch not taken
+            branch(5, 5),
+            exception,
+        ];
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        let mut interpreted_bb = InterpretedBlockBuilder;
+
+        for scenario in scenarios {
+            scenario.run(&mut jit, &mut interpreted_bb);
+        }
+    });
+
+    backend_test!(test_code_budget_eviction, F, {
+        let block = |value: i64| {
+            [
+                I::new_li(nz::a1, value, Compressed),
+                I::new_addi(nz::a1, nz::a1, 1, Uncompressed),
+            ]
+        };
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        // Every function exceeds the budget, so each compilation starts a new generation.
+        jit.set_code_budget(NonZeroUsize::new(1));
+
+        for value in 0..4 {
+            jit.compile(&block(value))
+                .expect("Compilation of block should succeed.");
+        }
+
+        // Only the two most recent generations are kept.
+        let evicted = jit.take_evicted();
+        let evicted_hashes = evicted
+            .iter()
+            .flat_map(|evicted| evicted.functions().iter().map(|(hash, _)| *hash))
+            .collect::<Vec<_>>();
+        let expected_hashes = [0, 1]
+            .map(|value| Hash::blake2b_hash(block(value).as_slice()).unwrap())
+            .to_vec();
+        assert_eq!(evicted_hashes, expected_hashes);
+        assert!(jit.take_evicted().is_empty());
+
+        for evicted in evicted {
+            // Safety: none of the evicted functions are called again.
+            unsafe { evicted.free() }
+        }
+
+        // Evicted blocks are compiled again when requested.
+        let fun = jit
+            .compile(&block(0))
+            .expect("Compilation of evicted block should succeed.");
+
+        let mut manager = F::manager();
+        let mut core = MachineCoreState::<M4K, _>::new(&mut manager);
+        core.hart.pc.write(0x100);
+
+        let mut res = Ok(());
+        let steps = unsafe {
+            // # Safety - the jit is not dropped until after we
+            //            exit the block.
+            (fun)(null(), &mut core, 0x100, &mut res, null())
+        };
+
+        assert!(res.is_ok());
+        assert_eq!(steps, 2);
+        assert_eq!(core.hart.xregisters.read_nz(nz::a1), 1);
+    });
+
+    backend_test!(test_code_budget_failures_age_out, F, {
+        let block = |value: i64| [I::new_li(nz::a1, value, Compressed)];
+
+        let mut jit = JIT::<M4K, F::Manager>::new().unwrap();
+        jit.set_code_budget(NonZeroUsize::new(1));
+
+        jit.inject_codegen_failures = true;
+        assert!(jit.compile(&block(0)).is_none());
+
+        // Failures are cached, so the block is not compiled again.
+        jit.inject_codegen_failures = false;
+        assert!(jit.compile(&block(0)).is_none());
+
+        // Every function exceeds the budget, so each compilation starts a new generation. The
+        // generation holding the failure is evicted by the second.
+        for value in 1..3 {
+            jit.compile(&block(value))
+                .expect("Compilation of block should succeed.");
+        }
+
+        for evicted in jit.take_evicted() {
+            // Safety: none of the evicted functions are called again.
+            unsafe { evicted.free() }
+        }
+
+        assert!(jit.compile(&block(0)).is_some());
+    });
 }
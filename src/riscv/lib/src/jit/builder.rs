@@ -14,6 +14,7 @@ pub(super) mod errno;
 use cranelift::codegen::ir;
 use cranelift::codegen::ir::BlockArg;
 use cranelift::codegen::ir::InstBuilder;
+use cranelift::codegen::ir::MemFlags;
 use cranelift::codegen::ir::Value;
 use cranelift::codegen::ir::condcodes::IntCC;
 use cranelift::codegen::ir::types::I32;
@@ -22,11 +23,21 @@ use cranelift::frontend::FunctionBuilder;
 use cranelift::prelude::types::I128;
 use errno::AtomicAccessGuard;
 
+use self::block_state::CachedXRegisters;
 use self::block_state::DynamicValues;
+use self::block_state::XRegisterVariables;
 use self::errno::Errno;
 use super::state_access::JitStateAccess;
 use super::state_access::JsaCalls;
+use super::state_access::MemoryAccess;
+use super::state_access::ir_extend_loaded;
+use crate::instruction_context::FArith;
+use crate::instruction_context::FBinary;
+use crate::instruction_context::FCompare;
+use crate::instruction_context::FIntFormat;
+use crate::instruction_context::FloatFormat;
 use crate::instruction_context::ICB;
+use crate::instruction_context::LoadStoreWidth;
 use crate::instruction_context::MulHighType;
 use crate::instruction_context::Predicate;
 use crate::instruction_context::StoreLoadInt;
@@ -36,12 +47,23 @@ use crate::instruction_context::value::PhiValue;
 use crate::interpreter::atomics::ReservationSetOption;
 use crate::jit::builder::block_state::PCUpdate;
 use crate::machine_state::ProgramCounterUpdate;
+use crate::machine_state::csregisters::CSRegister;
 use crate::machine_state::memory::MemoryConfig;
 use crate::machine_state::registers::FRegister;
 use crate::machine_state::registers::NonZeroXRegister;
+use crate::parser::instruction::InstrRoundingMode;
 use crate::parser::instruction::InstrWidth;
 use crate::state_backend::ManagerBase;
 
+/// Flags for accessing guest memory directly.
+///
+/// Accesses are only performed once checked to be within bounds, but may be unaligned.
+fn guest_memory_flags() -> MemFlags {
+    MemFlags::new()
+        .with_notrap()
+        .with_endianness(ir::Endianness::Little)
+}
+
 /// A newtype for wrapping [`Value`], representing a 64-bit value in the JIT context.
 #[derive(Copy, Clone, Debug)]
 pub struct X64(pub Value);
@@ -70,6 +92,9 @@ pub(crate) struct Builder<'a, MC: MemoryConfig, M: ManagerBase> {
     /// Values that are dynamically updated throughout lowering.
     dynamic: DynamicValues,
 
+    /// Variables caching the values of the registers, see [`CachedXRegisters`].
+    xreg_vars: XRegisterVariables,
+
     /// The final Cranelift-IR block that is last executed on exit from a JIT-compiled
     /// block cache block.
     ///
@@ -79,6 +104,12 @@ pub(crate) struct Builder<'a, MC: MemoryConfig, M: ManagerBase> {
     /// point, and also once all instructions have been executed--if no branching took place.
     end_block: Option<ir::Block>,
 
+    /// When lowering a member of a superblock that is followed by another, the Cranelift-IR
+    /// block that exits from the member jump to - in place of the end block.
+    ///
+    /// It takes the same parameters as the end block. See [`Builder::start_next_member`].
+    member_exit: Option<ir::Block>,
+
     /// Value representing a pointer to `result: Result<(), EnvironException>`
     result_ptr_val: Value,
 }
@@ -100,13 +131,17 @@ impl<'a, MC: MemoryConfig, JSA: JitStateAccess> Builder<'a, MC, JSA> {
         let result_ptr_val = builder.block_params(entry_block)[3];
         // last param ignored
 
+        let xreg_vars = XRegisterVariables::new(&mut builder);
+
         Self {
             builder,
             jsa_call,
             core_ptr_val,
             result_ptr_val,
             dynamic: DynamicValues::new(pc_val),
+            xreg_vars,
             end_block: None,
+            member_exit: None,
         }
     }
 
@@ -166,6 +201,59 @@ impl<'a, MC: MemoryConfig, JSA: JitStateAccess> Builder<'a, MC, JSA> {
         self.builder.finalize();
     }
 
+    /// Start lowering a member of a superblock, that is followed by another member.
+    ///
+    /// Exits from the member - other than on unhandled exceptions - no longer exit the function,
+    /// but jump to the point where execution may continue with the next member.
+    pub(super) fn start_member(&mut self) {
+        let member_exit = self.builder.create_block();
+        self.builder.append_block_param(member_exit, I64);
+        self.builder.append_block_param(member_exit, I64);
+
+        self.member_exit = Some(member_exit);
+    }
+
+    /// Exit the current member of a superblock, after all of its instructions have been lowered.
+    ///
+    /// This is not required if the member ended with an unconditional exception.
+    pub(super) fn end_member(&mut self) {
+        self.jump_to_end();
+    }
+
+    /// Continue lowering with the next member of a superblock, which starts at `address`.
+    ///
+    /// Execution only continues with the next member when the current member exits at its
+    /// address. Otherwise, the function is exited, as if the current member was the last.
+    pub(super) fn start_next_member(&mut self, address: u64) {
+        let member_exit = self
+            .member_exit
+            .take()
+            .expect("The current member must have been started with `start_member`");
+
+        self.builder.switch_to_block(member_exit);
+        let pc_val = self.builder.block_params(member_exit)[0];
+        let steps_val = self.builder.block_params(member_exit)[1];
+
+        let next_member = self.builder.create_block();
+        let side_exit = self.builder.create_block();
+
+        let is_next = self
+            .builder
+            .ins()
+            .icmp_imm(IntCC::Equal, pc_val, address as i64);
+        self.builder
+            .ins()
+            .brif(is_next, next_member, &[], side_exit, &[]);
+
+        self.builder.switch_to_block(side_exit);
+        self.exit(X64(pc_val), steps_val);
+
+        // Registers were written back on exiting the member, and are loaded afresh by the next.
+        self.builder.switch_to_block(next_member);
+        let pc_val = self.builder.ins().iconst(I64, address as i64);
+        self.dynamic = DynamicValues::new_member(X64(pc_val), steps_val);
+    }
+
     /// Complete a step, updating the program counter in the process.
     ///
     /// Returns `false` if an unconditional exit from the block occurs, in which case compilation
@@ -174,14 +262,82 @@ impl<'a, MC: MemoryConfig, JSA: JitStateAccess> Builder<'a, MC, JSA> {
         self.dynamic.complete_step(pc_update)
     }
 
+    /// Write the values of the given registers, which must be cached, back to the state.
+    fn write_back_xregisters(&mut self, regs: impl Iterator<Item = NonZeroXRegister>) {
+        for reg in regs {
+            let value = self.builder.use_var(self.xreg_vars.get(reg));
+
+            JSA::ir_xreg_write(
+                &mut self.jsa_call,
+                &mut self.builder,
+                self.core_ptr_val,
+                reg,
+                X64(value),
+            );
+        }
+    }
+
+    /// Write all dirty registers back to the state.
+    ///
+    /// This must happen before exiting the function, or calling anything that may observe the
+    /// registers in the state.
+    fn write_back_dirty_xregisters(&mut self) {
+        let xregs = self.dynamic.xregs_mut();
+        let dirty = xregs.dirty();
+        xregs.clean();
+
+        self.write_back_xregisters(dirty);
+    }
+
+    /// Leave one branch of a [`ICB::branch_merge`], started from `outer`.
+    ///
+    /// Registers first written within the branch are written back to the state, as their
+    /// variables are not defined on the other path. Returns the registers cached at the end of
+    /// the branch.
+    fn leave_branch(&mut self, outer: DynamicValues) -> CachedXRegisters {
+        let xregs = self.dynamic.xregs();
+        self.write_back_xregisters(xregs.dirty_since(outer.xregs()));
+
+        xregs
+    }
+
     /// Jump from the current block to the end block, exiting the function.
+    ///
+    /// When lowering a member of a superblock, this instead exits the member - see
+    /// [`Builder::start_member`].
     fn jump_to_end(&mut self) {
+        self.write_back_dirty_xregisters();
+
         // compute steps taken so far
-        let steps_val = self.builder.ins().iconst(I64, self.dynamic.steps() as i64);
+        let steps_val = self.dynamic.read_steps(&mut self.builder);
 
         // get the new value of the pc to write back to the state
         let pc_val = self.dynamic.read_pc(&mut self.builder);
 
+        match self.member_exit {
+            Some(member_exit) => {
+                self.builder.ins().jump(member_exit, &[
+                    BlockArg::Value(pc_val.0),
+                    BlockArg::Value(steps_val),
+                ]);
+            }
+            None => self.exit(pc_val, steps_val),
+        }
+    }
+
+    /// Jump from the current block to the end block, exiting the function, even when lowering
+    /// a member of a superblock.
+    fn jump_to_function_end(&mut self) {
+        self.write_back_dirty_xregisters();
+
+        let steps_val = self.dynamic.read_steps(&mut self.builder);
+        let pc_val = self.dynamic.read_pc(&mut self.builder);
+
+        self.exit(pc_val, steps_val);
+    }
+
+    /// Jump from the current block to the end block, with the given `pc` and `steps`.
+    fn exit(&mut self, pc_val: X64, steps_val: Value) {
         let end_block = self
             .end_block
             .unwrap_or_else(|| self.builder.create_block());
@@ -195,6 +351,8 @@ impl<'a, MC: MemoryConfig, JSA: JitStateAccess> Builder<'a, MC, JSA> {
 
     /// Handle an exception that has occurred.
     fn handle_exception(&mut self, exception_ptr: Value) {
+        self.write_back_dirty_xregisters();
+
         let current_pc = self.pc_read();
 
         let outcome = self.jsa_call.handle_exception(
@@ -212,7 +370,7 @@ impl<'a, MC: MemoryConfig, JSA: JitStateAccess> Builder<'a, MC, JSA> {
 
         // if !handled -> exit directly; environ needs to be consulted.
         //                pc has been committed
-        self.jump_to_end();
+        self.jump_to_function_end();
     }
 
     /// Branch and exit if the given condition holds.
@@ -303,22 +461,30 @@ impl<MC: MemoryConfig, JSA: JitStateAccess> ICB for Builder<'_, MC, JSA> {
     }
 
     fn xregister_read_nz(&mut self, reg: NonZeroXRegister) -> Self::XValue {
-        JSA::ir_xreg_read(
+        let var = self.xreg_vars.get(reg);
+
+        if self.dynamic.xregs().is_cached(reg) {
+            return X64(self.builder.use_var(var));
+        }
+
+        // Load the register on first use - it remains cached for the rest of the block.
+        let value = JSA::ir_xreg_read(
             &mut self.jsa_call,
             &mut self.builder,
             self.core_ptr_val,
             reg,
-        )
+        );
+
+        self.builder.def_var(var, value.0);
+        self.dynamic.xregs_mut().loaded(reg);
+
+        value
     }
 
     fn xregister_write_nz(&mut self, reg: NonZeroXRegister, value: Self::XValue) {
-        JSA::ir_xreg_write(
-            &mut self.jsa_call,
-            &mut self.builder,
-            self.core_ptr_val,
-            reg,
-            value,
-        )
+        // The write to the state is deferred, until exiting the block.
+        self.builder.def_var(self.xreg_vars.get(reg), value.0);
+        self.dynamic.xregs_mut().written(reg);
     }
 
     fn fregister_write(&mut self, reg: FRegister, value: Self::FValue) {
@@ -340,6 +506,113 @@ impl<MC: MemoryConfig, JSA: JitStateAccess> ICB for Builder<'_, MC, JSA> {
         )
     }
 
+    fn fvalue_to_xvalue(&mut self, value: Self::FValue) -> Self::XValue {
+        X64(value.0)
+    }
+
+    fn xvalue_to_fvalue(&mut self, value: Self::XValue) -> Self::FValue {
+        F64(value.0)
+    }
+
+    fn f_arith(
+        &mut self,
+        format: FloatFormat,
+        op: FArith,
+        rs1: Self::FValue,
+        rs2: Self::FValue,
+        rs3: Self::FValue,
+        rm: InstrRoundingMode,
+    ) -> Self::IResult<Self::FValue> {
+        let errno = self.jsa_call.f_arith(
+            &mut self.builder,
+            self.core_ptr_val,
+            format,
+            op,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        );
+
+        let res = errno.handle(self);
+
+        Some(res)
+    }
+
+    fn f_binary(
+        &mut self,
+        format: FloatFormat,
+        op: FBinary,
+        lhs: Self::FValue,
+        rhs: Self::FValue,
+    ) -> Self::FValue {
+        self.jsa_call
+            .f_binary(&mut self.builder, self.core_ptr_val, format, op, lhs, rhs)
+    }
+
+    fn f_compare(
+        &mut self,
+        format: FloatFormat,
+        op: FCompare,
+        lhs: Self::FValue,
+        rhs: Self::FValue,
+    ) -> Self::XValue {
+        self.jsa_call
+            .f_compare(&mut self.builder, self.core_ptr_val, format, op, lhs, rhs)
+    }
+
+    fn f_classify(&mut self, format: FloatFormat, value: Self::FValue) -> Self::XValue {
+        self.jsa_call.f_classify(&mut self.builder, format, value)
+    }
+
+    fn f_from_int(
+        &mut self,
+        format: FloatFormat,
+        int: FIntFormat,
+        value: Self::XValue,
+        rm: InstrRoundingMode,
+    ) -> Self::IResult<Self::FValue> {
+        let errno =
+            self.jsa_call
+                .f_from_int(&mut self.builder, self.core_ptr_val, format, int, value, rm);
+
+        let res = errno.handle(self);
+
+        Some(res)
+    }
+
+    fn f_to_int(
+        &mut self,
+        format: FloatFormat,
+        int: FIntFormat,
+        value: Self::FValue,
+        rm: InstrRoundingMode,
+    ) -> Self::IResult<Self::XValue> {
+        let errno =
+            self.jsa_call
+                .f_to_int(&mut self.builder, self.core_ptr_val, format, int, value, rm);
+
+        let res = errno.handle(self);
+
+        Some(res)
+    }
+
+    fn f_convert(
+        &mut self,
+        from: FloatFormat,
+        to: FloatFormat,
+        value: Self::FValue,
+        rm: InstrRoundingMode,
+    ) -> Self::IResult<Self::FValue> {
+        let errno =
+            self.jsa_call
+                .f_convert(&mut self.builder, self.core_ptr_val, from, to, value, rm);
+
+        let res = errno.handle(self);
+
+        Some(res)
+    }
+
     fn xvalue_of_imm(&mut self, imm: i64) -> Self::XValue {
         X64(self.builder.ins().iconst(I64, imm))
     }
@@ -405,6 +678,7 @@ impl<MC: MemoryConfig, JSA: JitStateAccess> ICB for Builder<'_, MC, JSA> {
         self.builder.switch_to_block(true_block);
 
         let res_val = Phi::to_ir_vals(true_branch(self));
+        let true_xregs = self.leave_branch(snapshot);
         self.builder.ins().jump(
             post_block,
             res_val
@@ -419,6 +693,7 @@ impl<MC: MemoryConfig, JSA: JitStateAccess> ICB for Builder<'_, MC, JSA> {
         self.builder.switch_to_block(false_block);
 
         let res_val = Phi::to_ir_vals(false_branch(self));
+        let false_xregs = self.leave_branch(snapshot);
         self.builder.ins().jump(
             post_block,
             res_val
@@ -431,18 +706,21 @@ impl<MC: MemoryConfig, JSA: JitStateAccess> ICB for Builder<'_, MC, JSA> {
 
         // The post-block is the common exit point for both branches.
         self.builder.switch_to_block(post_block);
+        *self.dynamic.xregs_mut() =
+            CachedXRegisters::merge(snapshot.xregs(), true_xregs, false_xregs);
         let params = self.builder.block_params(post_block);
 
         Phi::from_ir_vals(params.to_vec().as_slice(), self)
     }
 
-    fn atomic_access_fault_guard<V: StoreLoadInt>(
+    fn atomic_access_fault_guard_aligned(
         &mut self,
         address: Self::XValue,
+        alignment: u64,
         reservation_set_option: ReservationSetOption,
     ) -> Self::IResult<()> {
-        let width = self.xvalue_of_imm(V::WIDTH as i64);
-        let remainder = address.modulus_unsigned(width, self);
+        let alignment = self.xvalue_of_imm(alignment as i64);
+        let remainder = address.modulus_unsigned(alignment, self);
 
         // The steps of taking the comparison are technically not needed, as cranelift will
         // treat any non-zero value as a take-branch (i.e. raise exception) value, so we could
@@ -500,14 +778,60 @@ impl<MC: MemoryConfig, JSA: JitStateAccess> ICB for Builder<'_, MC, JSA> {
         phys_address: Self::XValue,
         value: Self::XValue,
     ) -> Self::IResult<()> {
+        let Some(access) = JSA::ir_memory_direct_access::<MC, V>(
+            &mut self.jsa_call,
+            &mut self.builder,
+            self.core_ptr_val,
+            phys_address,
+            MemoryAccess::Store,
+        ) else {
+            let errno = self.jsa_call.memory_store::<V>(
+                &mut self.builder,
+                self.core_ptr_val,
+                phys_address,
+                value,
+            );
+
+            errno.handle(self);
+
+            return Some(());
+        };
+
+        let direct_block = self.builder.create_block();
+        let call_block = self.builder.create_block();
+        let post_block = self.builder.create_block();
+
+        self.builder
+            .ins()
+            .brif(access.allowed, direct_block, &[], call_block, &[]);
+
+        // Within bounds & writable: store directly to memory.
+        self.builder.switch_to_block(direct_block);
+        let narrowed = match V::WIDTH {
+            LoadStoreWidth::Byte | LoadStoreWidth::Half | LoadStoreWidth::Word => {
+                self.builder.ins().ireduce(V::IR_TYPE, value.0)
+            }
+            LoadStoreWidth::Double => value.0,
+        };
+        self.builder
+            .ins()
+            .store(guest_memory_flags(), narrowed, access.host_address, 0);
+        self.builder.ins().jump(post_block, &[]);
+        self.builder.seal_block(direct_block);
+
+        // Otherwise, fall back to the external call - which raises the exception.
+        self.builder.switch_to_block(call_block);
         let errno = self.jsa_call.memory_store::<V>(
             &mut self.builder,
             self.core_ptr_val,
             phys_address,
             value,
         );
-
         errno.handle(self);
+        self.builder.ins().jump(post_block, &[]);
+        self.builder.seal_block(call_block);
+
+        self.builder.switch_to_block(post_block);
 
         Some(())
     }
@@ -516,13 +840,56 @@ impl<MC: MemoryConfig, JSA: JitStateAccess> ICB for Builder<'_, MC, JSA> {
         &mut self,
         phys_address: Self::XValue,
     ) -> Self::IResult<Self::XValue> {
+        let Some(access) = JSA::ir_memory_direct_access::<MC, V>(
+            &mut self.jsa_call,
+            &mut self.builder,
+            self.core_ptr_val,
+            phys_address,
+            MemoryAccess::Load,
+        ) else {
+            let errno =
+                self.jsa_call
+                    .memory_load::<V>(&mut self.builder, self.core_ptr_val, phys_address);
+
+            return Some(errno.handle(self));
+        };
+
+        let direct_block = self.builder.create_block();
+        let call_block = self.builder.create_block();
+        let post_block = self.builder.create_block();
+        self.builder.append_block_param(post_block, ir::types::I64);
+
+        self.builder
+            .ins()
+            .brif(access.allowed, direct_block, &[], call_block, &[]);
+
+        // Within bounds & readable: load directly from memory.
+        self.builder.switch_to_block(direct_block);
+        let loaded =
+            self.builder
+                .ins()
+                .load(V::IR_TYPE, guest_memory_flags(), access.host_address, 0);
+        let direct = ir_extend_loaded::<V>(&mut self.builder, loaded);
+        self.builder
+            .ins()
+            .jump(post_block, &[BlockArg::Value(direct.0)]);
+        self.builder.seal_block(direct_block);
+
+        // Otherwise, fall back to the external call - which raises the exception.
+        self.builder.switch_to_block(call_block);
         let errno =
             self.jsa_call
                 .memory_load::<V>(&mut self.builder, self.core_ptr_val, phys_address);
+        let called = errno.handle(self);
+        self.builder
+            .ins()
+            .jump(post_block, &[BlockArg::Value(called.0)]);
+        self.builder.seal_block(call_block);
 
-        let res = errno.handle(self);
+        self.builder.switch_to_block(post_block);
+        let res = self.builder.block_params(post_block)[0];
 
-        Some(res)
+        Some(X64(res))
     }
 
     fn reservation_set_write(&mut self, address: Self::XValue) {
@@ -534,6 +901,31 @@ impl<MC: MemoryConfig, JSA: JitStateAccess> ICB for Builder<'_, MC, JSA> {
         self.jsa_call
             .reservation_set_read(&mut self.builder, self.core_ptr_val)
     }
+
+    fn csregister_read(&mut self, csr: CSRegister) -> Self::XValue {
+        self.jsa_call
+            .csregister_read(&mut self.builder, self.core_ptr_val, csr)
+    }
+
+    fn csregister_write(&mut self, csr: CSRegister, value: Self::XValue) {
+        self.jsa_call
+            .csregister_write(&mut self.builder, self.core_ptr_val, csr, value)
+    }
+
+    fn csregister_replace(&mut self, csr: CSRegister, value: Self::XValue) -> Self::XValue {
+        self.jsa_call
+            .csregister_replace(&mut self.builder, self.core_ptr_val, csr, value)
+    }
+
+    fn csregister_set_bits(&mut self, csr: CSRegister, bits: Self::XValue) -> Self::XValue {
+        self.jsa_call
+            .csregister_set_bits(&mut self.builder, self.core_ptr_val, csr, bits)
+    }
+
+    fn csregister_clear_bits(&mut self, csr: CSRegister, bits: Self::XValue) -> Self::XValue {
+        self.jsa_call
+            .csregister_clear_bits(&mut self.builder, self.core_ptr_val, csr, bits)
+    }
 }
 
 impl From<Predicate> for IntCC {
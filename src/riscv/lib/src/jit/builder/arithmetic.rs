@@ -64,6 +64,8 @@ impl<MC: MemoryConfig, JSA: JitStateAccess> Arithmetic<Builder<'_, MC, JSA>> for
             Shift::Left => X64(icb.builder.ins().ishl(self.0, amount.0)),
             Shift::RightUnsigned => X64(icb.builder.ins().ushr(self.0, amount.0)),
             Shift::RightSigned => X64(icb.builder.ins().sshr(self.0, amount.0)),
+            Shift::RotateLeft => X64(icb.builder.ins().rotl(self.0, amount.0)),
+            Shift::RotateRight => X64(icb.builder.ins().rotr(self.0, amount.0)),
         }
     }
 
@@ -78,6 +80,34 @@ impl<MC: MemoryConfig, JSA: JitStateAccess> Arithmetic<Builder<'_, MC, JSA>> for
     fn min_signed(self, other: Self, icb: &mut Builder<'_, MC, JSA>) -> Self {
         X64(icb.builder.ins().smin(self.0, other.0))
     }
+
+    fn min_unsigned(self, other: Self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X64(icb.builder.ins().umin(self.0, other.0))
+    }
+
+    fn max_signed(self, other: Self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X64(icb.builder.ins().smax(self.0, other.0))
+    }
+
+    fn max_unsigned(self, other: Self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X64(icb.builder.ins().umax(self.0, other.0))
+    }
+
+    fn leading_zeros(self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X64(icb.builder.ins().clz(self.0))
+    }
+
+    fn trailing_zeros(self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X64(icb.builder.ins().ctz(self.0))
+    }
+
+    fn count_ones(self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X64(icb.builder.ins().popcnt(self.0))
+    }
+
+    fn swap_bytes(self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X64(icb.builder.ins().bswap(self.0))
+    }
 }
 
 impl<MC: MemoryConfig, JSA: JitStateAccess> Arithmetic<Builder<'_, MC, JSA>> for X32 {
@@ -130,6 +160,8 @@ impl<MC: MemoryConfig, JSA: JitStateAccess> Arithmetic<Builder<'_, MC, JSA>> for
             Shift::Left => X32(icb.builder.ins().ishl(self.0, amount.0)),
             Shift::RightUnsigned => X32(icb.builder.ins().ushr(self.0, amount.0)),
             Shift::RightSigned => X32(icb.builder.ins().sshr(self.0, amount.0)),
+            Shift::RotateLeft => X32(icb.builder.ins().rotl(self.0, amount.0)),
+            Shift::RotateRight => X32(icb.builder.ins().rotr(self.0, amount.0)),
         }
     }
 
@@ -144,4 +176,32 @@ impl<MC: MemoryConfig, JSA: JitStateAccess> Arithmetic<Builder<'_, MC, JSA>> for
     fn min_signed(self, other: Self, icb: &mut Builder<'_, MC, JSA>) -> Self {
         X32(icb.builder.ins().smin(self.0, other.0))
     }
+
+    fn min_unsigned(self, other: Self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X32(icb.builder.ins().umin(self.0, other.0))
+    }
+
+    fn max_signed(self, other: Self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X32(icb.builder.ins().smax(self.0, other.0))
+    }
+
+    fn max_unsigned(self, other: Self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X32(icb.builder.ins().umax(self.0, other.0))
+    }
+
+    fn leading_zeros(self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X32(icb.builder.ins().clz(self.0))
+    }
+
+    fn trailing_zeros(self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X32(icb.builder.ins().ctz(self.0))
+    }
+
+    fn count_ones(self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X32(icb.builder.ins().popcnt(self.0))
+    }
+
+    fn swap_bytes(self, icb: &mut Builder<'_, MC, JSA>) -> Self {
+        X32(icb.builder.ins().bswap(self.0))
+    }
 }
@@ -5,10 +5,15 @@
 //! State that is kept per Cranelift-IR block.
 
 use cranelift::codegen::ir::InstBuilder;
+use cranelift::codegen::ir::Value;
+use cranelift::codegen::ir::types::I64;
 use cranelift::frontend::FunctionBuilder;
+use cranelift::frontend::Variable;
+use strum::IntoEnumIterator;
 
 use super::X64;
 use crate::machine_state::ProgramCounterUpdate;
+use crate::machine_state::registers::NonZeroXRegister;
 
 /// Program Counter update, within the context of JIT-compilation.
 ///
@@ -52,6 +57,12 @@ pub struct DynamicValues {
     /// The number of steps taken within the current compilation context.
     steps: usize,
 
+    /// Value representing the number of steps taken before the current member of a superblock.
+    ///
+    /// This depends on how the previous member exited, and is not known statically. `steps`
+    /// then counts the steps taken since the start of the member.
+    steps_val: Option<Value>,
+
     /// Value representing the last-updated value of `instr_pc`.
     ///
     /// Whenever the program counter is read, we implicitly update this value
@@ -64,6 +75,9 @@ pub struct DynamicValues {
     /// The current offset of the pc, as a result of steps taken
     /// so far.
     pc_offset: i64,
+
+    /// The registers currently held in their [`XRegisterVariables`].
+    xregs: CachedXRegisters,
 }
 
 impl DynamicValues {
@@ -72,7 +86,18 @@ impl DynamicValues {
         Self {
             pc_val,
             steps: 0,
+            steps_val: None,
             pc_offset: 0,
+            xregs: CachedXRegisters::default(),
+        }
+    }
+
+    /// Create a new set of values for the next member of a superblock, given its program counter
+    /// and the steps taken before it.
+    pub fn new_member(pc_val: X64, steps_val: Value) -> Self {
+        Self {
+            steps_val: Some(steps_val),
+            ..Self::new(pc_val)
         }
     }
 
@@ -114,7 +139,112 @@ impl DynamicValues {
 
     /// The number of steps that have been taken thus far in the compilation process,
     /// where 'step' maps to the lowering of an instruction.
-    pub fn steps(&self) -> usize {
-        self.steps
+    pub fn read_steps(&self, builder: &mut FunctionBuilder<'_>) -> Value {
+        match self.steps_val {
+            None => builder.ins().iconst(I64, self.steps as i64),
+            Some(steps_val) => builder.ins().iadd_imm(steps_val, self.steps as i64),
+        }
+    }
+
+    /// The registers currently held in their [`XRegisterVariables`].
+    pub fn xregs(&self) -> CachedXRegisters {
+        self.xregs
+    }
+
+    /// Mutable access to the registers currently held in their [`XRegisterVariables`].
+    pub fn xregs_mut(&mut self) -> &mut CachedXRegisters {
+        &mut self.xregs
+    }
+}
+
+/// Cranelift variables holding the values of the [`NonZeroXRegister`]s, for the duration of a
+/// JIT-compiled block.
+///
+/// Which of these variables currently hold the value of their register is tracked by
+/// [`CachedXRegisters`].
+pub struct XRegisterVariables {
+    vars: [Variable; 31],
+}
+
+impl XRegisterVariables {
+    /// Declare a variable for each register in the function under construction.
+    pub fn new(builder: &mut FunctionBuilder<'_>) -> Self {
+        Self {
+            vars: std::array::from_fn(|_| builder.declare_var(I64)),
+        }
+    }
+
+    /// The variable holding the value of the given register.
+    pub fn get(&self, reg: NonZeroXRegister) -> Variable {
+        self.vars[reg as usize]
+    }
+}
+
+/// Tracks which [`NonZeroXRegister`]s are cached in their [`XRegisterVariables`].
+///
+/// Registers are loaded from the state lazily, on first use. Registers that are written are
+/// marked as dirty, and must be written back to the state before exiting the block.
+///
+/// A register is only ever marked as cached once its variable has been defined, in the current
+/// Cranelift-IR block or one that dominates it. This ensures that the variable is never used on
+/// a path where it has not been defined.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CachedXRegisters {
+    /// Bitset of registers whose variable holds their current value.
+    cached: u32,
+
+    /// Bitset of cached registers that have been written, but not yet written back to the state.
+    dirty: u32,
+}
+
+impl CachedXRegisters {
+    const fn bit(reg: NonZeroXRegister) -> u32 {
+        1 << reg as u32
+    }
+
+    /// Whether the register's variable holds its current value.
+    pub fn is_cached(&self, reg: NonZeroXRegister) -> bool {
+        self.cached & Self::bit(reg) != 0
+    }
+
+    /// Mark the register as having been loaded from the state into its variable.
+    pub fn loaded(&mut self, reg: NonZeroXRegister) {
+        self.cached |= Self::bit(reg);
+    }
+
+    /// Mark the register as having been written to its variable.
+    pub fn written(&mut self, reg: NonZeroXRegister) {
+        self.cached |= Self::bit(reg);
+        self.dirty |= Self::bit(reg);
+    }
+
+    /// Registers that have been written, but not yet written back to the state.
+    pub fn dirty(self) -> impl Iterator<Item = NonZeroXRegister> {
+        NonZeroXRegister::iter().filter(move |reg| self.dirty & Self::bit(*reg) != 0)
+    }
+
+    /// Dirty registers that were not cached at the point `outer` was taken.
+    ///
+    /// When two paths of execution merge, these must be written back to the state beforehand, as
+    /// the variable may not have been defined on the other path.
+    pub fn dirty_since(self, outer: Self) -> impl Iterator<Item = NonZeroXRegister> {
+        self.dirty().filter(move |reg| !outer.is_cached(*reg))
+    }
+
+    /// Mark all registers as having been written back to the state.
+    pub fn clean(&mut self) {
+        self.dirty = 0;
+    }
+
+    /// Merge the registers cached on two paths of execution, that both started from `outer`.
+    ///
+    /// Only registers cached in `outer` remain cached, as their variables are defined on both
+    /// paths. They are dirty if they were dirty on either path. Any other dirty registers must
+    /// have been written back before merging - see [`Self::dirty_since`].
+    pub fn merge(outer: Self, lhs: Self, rhs: Self) -> Self {
+        Self {
+            cached: outer.cached,
+            dirty: outer.cached & (lhs.dirty | rhs.dirty),
+        }
     }
 }
@@ -0,0 +1,327 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Persistent, on-disk cache of JIT-compiled code.
+//!
+//! Compiled functions are stored as machine code prior to relocation, along with their
+//! relocations. Entries therefore remain valid across runs even though the [`JitStateAccess`]
+//! functions are found at different addresses.
+//!
+//! Entries are looked up before a block is lowered to Cranelift IR, so that loading a block
+//! skips lowering and codegen altogether. They are hence keyed on what the lowering depends on:
+//! entries are stored under a directory per build, named after a hash of the running executable,
+//! of the host ISA & Cranelift settings, and of the memory configuration & manager the code is
+//! compiled for - as the lowered code embeds memory bounds and offsets into the machine state.
+//! Within that, each entry is named after the hash of the block's instructions.
+//!
+//! Every entry is prefixed with a checksum of its contents, which is validated on load. Entries
+//! that fail validation are ignored, and overwritten once the block is recompiled.
+//!
+//! [`JitStateAccess`]: super::state_access::JitStateAccess
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use cranelift::codegen::CompiledCode;
+use cranelift::codegen::binemit::Reloc;
+use cranelift::codegen::ir::Function;
+use cranelift::codegen::isa::TargetIsa;
+use cranelift_module::FuncId;
+use cranelift_module::ModuleReloc;
+use cranelift_module::ModuleRelocTarget;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::state_backend::hash::DIGEST_SIZE;
+use crate::state_backend::hash::Hash;
+
+/// Distinguishes temporary files written by concurrent compiler threads.
+static TMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Directory under which compiled code is persisted, for the running build.
+#[derive(Debug, Clone)]
+pub struct CodeCache {
+    dir: PathBuf,
+
+    /// Number of blocks loaded from the cache.
+    hits: usize,
+
+    /// Number of blocks looked up, but not found in the cache.
+    misses: usize,
+}
+
+impl CodeCache {
+    /// Open the code cache stored under `dir` for code compiled for the given ISA, creating the
+    /// directory if it does not exist.
+    ///
+    /// `lowering` describes anything else the compiled code depends on, other than the running
+    /// executable and the ISA. Code cached under a different description is never loaded.
+    pub fn open(dir: impl AsRef<Path>, isa: &dyn TargetIsa, lowering: &str) -> io::Result<Self> {
+        let isa_flags = isa
+            .isa_flags()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let target = format!(
+            "{}\n{}\n{}\n{}",
+            isa.triple(),
+            isa.flags(),
+            isa_flags.join("\n"),
+            lowering
+        );
+
+        let executable = executable_hash()?;
+        let build = Hash::blake2b_hash_bytes(target.as_bytes())
+            .and_then(|target| Hash::combine(&[executable, target]))
+            .map_err(io::Error::other)?;
+
+        let dir = dir.as_ref().join(hex::encode(build));
+        fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            dir,
+            hits: 0,
+            misses: 0,
+        })
+    }
+
+    /// Number of blocks loaded from the cache so far.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of blocks that were looked up so far, but had to be compiled.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    fn entry_path(&self, block: &Hash) -> PathBuf {
+        self.dir.join(hex::encode(block))
+    }
+
+    /// Load the code compiled for the block with the given instruction hash, if persisted.
+    pub(super) fn get(&mut self, block: &Hash) -> Option<CachedCode> {
+        let code = self.read_entry(block);
+
+        match code {
+            Some(_) => self.hits += 1,
+            None => self.misses += 1,
+        }
+
+        code
+    }
+
+    fn read_entry(&self, block: &Hash) -> Option<CachedCode> {
+        let mut contents = fs::read(self.entry_path(block)).ok()?;
+
+        if contents.len() < DIGEST_SIZE {
+            return None;
+        }
+
+        let value = contents.split_off(DIGEST_SIZE);
+        let checksum = Hash::blake2b_hash_bytes(&value).ok()?;
+
+        if checksum.as_ref() != contents.as_slice() {
+            return None;
+        }
+
+        bincode::deserialize(&value).ok()
+    }
+
+    /// Persist the code compiled for the block with the given instruction hash.
+    ///
+    /// The cache is best-effort: failing to persist an entry only means the block will be
+    /// compiled again next time.
+    pub(super) fn insert(&self, block: &Hash, code: &CachedCode) {
+        let _ = self.write_entry(block, code);
+    }
+
+    /// Write the entry to a temporary file first, so that concurrent readers never observe a
+    /// partially written entry.
+    fn write_entry(&self, block: &Hash, code: &CachedCode) -> io::Result<()> {
+        let value = bincode::serialize(code).map_err(io::Error::other)?;
+        let checksum = Hash::blake2b_hash_bytes(&value).map_err(io::Error::other)?;
+
+        let mut contents = Vec::with_capacity(DIGEST_SIZE + value.len());
+        contents.extend_from_slice(checksum.as_ref());
+        contents.extend_from_slice(&value);
+
+        let path = self.entry_path(block);
+        let tmp_path = path.with_extension(format!(
+            "tmp-{}-{}",
+            std::process::id(),
+            TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&tmp_path, contents)?;
+        fs::rename(&tmp_path, &path)
+    }
+}
+
+/// Hash of the running executable, which determines how blocks are lowered.
+fn executable_hash() -> io::Result<Hash> {
+    static EXECUTABLE_HASH: OnceLock<Hash> = OnceLock::new();
+
+    if let Some(hash) = EXECUTABLE_HASH.get() {
+        return Ok(*hash);
+    }
+
+    let executable = fs::read(std::env::current_exe()?)?;
+    let hash = Hash::blake2b_hash_bytes(&executable).map_err(io::Error::other)?;
+
+    Ok(*EXECUTABLE_HASH.get_or_init(|| hash))
+}
+
+/// Machine code of a compiled function, prior to relocation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct CachedCode {
+    alignment: u64,
+    code: Vec<u8>,
+    relocs: Vec<CachedReloc>,
+}
+
+impl CachedCode {
+    /// Capture the code compiled for `func`. Returns `None` if the code refers to symbols that
+    /// cannot be persisted.
+    pub(super) fn new(func: &Function, code: &CompiledCode) -> Option<Self> {
+        // The function being defined is not known yet: offsets into it are captured as such.
+        let placeholder = FuncId::from_u32(u32::MAX);
+
+        let relocs = code
+            .buffer
+            .relocs()
+            .iter()
+            .map(|reloc| {
+                let reloc = ModuleReloc::from_mach_reloc(reloc, func, placeholder);
+                let target = match reloc.name {
+                    ModuleRelocTarget::User { namespace, index } => {
+                        CachedRelocTarget::User { namespace, index }
+                    }
+                    ModuleRelocTarget::FunctionOffset(id, offset) if id == placeholder => {
+                        CachedRelocTarget::FunctionOffset(offset)
+                    }
+                    _ => return None,
+                };
+
+                Some(CachedReloc {
+                    offset: reloc.offset,
+                    kind: reloc.kind,
+                    target,
+                    addend: reloc.addend,
+                })
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Self {
+            alignment: code.buffer.alignment as u64,
+            code: code.code_buffer().to_vec(),
+            relocs,
+        })
+    }
+
+    pub(super) fn alignment(&self) -> u64 {
+        self.alignment
+    }
+
+    pub(super) fn code(&self) -> &[u8] {
+        &self.code
+    }
+
+    /// Relocations of the code, once defined as the function `id`.
+    pub(super) fn relocs(&self, id: FuncId) -> Vec<ModuleReloc> {
+        self.relocs
+            .iter()
+            .map(|reloc| ModuleReloc {
+                offset: reloc.offset,
+                kind: reloc.kind,
+                name: match reloc.target {
+                    CachedRelocTarget::User { namespace, index } => {
+                        ModuleRelocTarget::User { namespace, index }
+                    }
+                    CachedRelocTarget::FunctionOffset(offset) => {
+                        ModuleRelocTarget::FunctionOffset(id, offset)
+                    }
+                },
+                addend: reloc.addend,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct CachedReloc {
+    offset: u32,
+    kind: Reloc,
+    target: CachedRelocTarget,
+    addend: i64,
+}
+
+/// Symbol referred to by a [`CachedReloc`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum CachedRelocTarget {
+    /// A function imported by the module, such as a [`JitStateAccess`] function. These are
+    /// declared in the same order by every module, hence have the same index across runs.
+    ///
+    /// [`JitStateAccess`]: super::state_access::JitStateAccess
+    User { namespace: u32, index: u32 },
+
+    /// An offset into the function itself.
+    FunctionOffset(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_validated_on_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let isa = cranelift_native::builder()
+            .unwrap()
+            .finish(cranelift::codegen::settings::Flags::new(
+                cranelift::codegen::settings::builder(),
+            ))
+            .unwrap();
+        let mut cache = CodeCache::open(dir.path(), isa.as_ref(), "lowering").unwrap();
+
+        let block = Hash::blake2b_hash_bytes(b"block").unwrap();
+        let code = CachedCode {
+            alignment: 16,
+            code: b"compiled code".to_vec(),
+            relocs: vec![CachedReloc {
+                offset: 4,
+                kind: Reloc::Abs8,
+                target: CachedRelocTarget::User {
+                    namespace: 0,
+                    index: 1,
+                },
+                addend: 0,
+            }],
+        };
+
+        assert!(cache.get(&block).is_none());
+
+        cache.insert(&block, &code);
+        assert_eq!(cache.get(&block), Some(code.clone()));
+
+        // entries for other blocks are distinct
+        let other = Hash::blake2b_hash_bytes(b"other").unwrap();
+        assert!(cache.get(&other).is_none());
+
+        // corrupted entries are rejected
+        let path = cache.entry_path(&block);
+        let mut contents = fs::read(&path).unwrap();
+        *contents.last_mut().unwrap() ^= 1;
+        fs::write(&path, contents).unwrap();
+
+        assert!(cache.get(&block).is_none());
+
+        assert_eq!(cache.hits(), 1);
+        assert_eq!(cache.misses(), 3);
+    }
+}
@@ -0,0 +1,327 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Dumps of JIT-compiled code, for debugging.
+//!
+//! For every function compiled, the following files are written under a directory named after
+//! the hash of its instructions:
+//!
+//! | File                | Contents                                                           |
+//! |---------------------|--------------------------------------------------------------------|
+//! | `instructions.txt`  | The RISC-V instructions, one per line                              |
+//! | `instructions.json` | The RISC-V instructions, as loaded by [`CodeDump::instructions`]   |
+//! | `ir.clif`           | The Cranelift IR, as lowered from the instructions                 |
+//! | `ir.opt.clif`       | The Cranelift IR, after optimisation                               |
+//! | `disasm.s`          | The host machine code, as disassembled by Cranelift                |
+//!
+//! The optimised IR and disassembly are only written when codegen took place - not when the
+//! function failed to compile. Functions loaded from the [code cache] are not dumped, as they
+//! are never lowered.
+//!
+//! Dumped blocks can be run again with [`replay_block`], comparing the state resulting from
+//! the interpreter against that resulting from the JIT.
+//!
+//! [code cache]: super::code_cache
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use cranelift::codegen;
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+
+use super::JIT;
+use super::JitError;
+use crate::machine_state::MachineCoreState;
+use crate::machine_state::MachineCoreStateLayout;
+use crate::machine_state::StepManyResult;
+use crate::machine_state::block_cache::block::Block;
+use crate::machine_state::block_cache::block::Interpreted;
+use crate::machine_state::block_cache::block::InterpretedBlockBuilder;
+use crate::machine_state::instruction::Instruction;
+use crate::machine_state::memory::Address;
+use crate::machine_state::memory::Memory;
+use crate::machine_state::memory::MemoryConfig;
+use crate::machine_state::memory::Permissions;
+use crate::state::NewState;
+use crate::state_backend::AllocatedOf;
+use crate::state_backend::FnManagerIdent;
+use crate::state_backend::Ref;
+use crate::state_backend::hash::Hash;
+use crate::state_backend::owned_backend::Owned;
+use crate::traps::EnvironException;
+
+/// Directory under which compiled code is dumped.
+#[derive(Debug, Clone)]
+pub struct CodeDump {
+    dir: PathBuf,
+}
+
+impl CodeDump {
+    /// Dump compiled code under `dir`, creating the directory if it does not exist.
+    pub fn open(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        Ok(Self { dir })
+    }
+
+    /// Load the instructions dumped for the function with the given hash.
+    pub fn instructions(&self, hash: &Hash) -> io::Result<Vec<Instruction>> {
+        let contents = fs::read(self.function_dir(hash).join("instructions.json"))?;
+        Ok(serde_json::from_slice(&contents)?)
+    }
+
+    fn function_dir(&self, hash: &Hash) -> PathBuf {
+        self.dir.join(hex::encode(hash))
+    }
+
+    /// Dump the function held by `ctx`, once codegen has been attempted. `ir` is the Cranelift
+    /// IR prior to codegen.
+    pub(super) fn write(
+        &self,
+        hash: &Hash,
+        instr: &[Instruction],
+        ir: &str,
+        ctx: &codegen::Context,
+    ) -> io::Result<()> {
+        let dir = self.function_dir(hash);
+        fs::create_dir_all(&dir)?;
+
+        let listing = instr
+            .iter()
+            .map(|instr| format!("{instr:?}\n"))
+            .collect::<String>();
+        fs::write(dir.join("instructions.txt"), listing)?;
+        fs::write(
+            dir.join("instructions.json"),
+            serde_json::to_vec_pretty(instr)?,
+        )?;
+        fs::write(dir.join("ir.clif"), ir)?;
+
+        // The disassembly is only produced when codegen took place, in which case the IR has
+        // also been optimised.
+        if let Some(disasm) = ctx.compiled_code().and_then(|code| code.vcode.as_ref()) {
+            fs::write(dir.join("ir.opt.clif"), ctx.func.display().to_string())?;
+            fs::write(dir.join("disasm.s"), disasm)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Errors that may arise when replaying a block.
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    /// The JIT could not be initialised.
+    #[error(transparent)]
+    Jit(#[from] JitError),
+    /// The block could not be compiled by the JIT.
+    #[error("The block could not be compiled by the JIT")]
+    NotCompiled,
+    /// The resulting states could not be compared.
+    #[error("Unable to compare the resulting states: {0}")]
+    Comparison(#[from] serde_json::Error),
+}
+
+/// A part of the machine state that differs between the interpreted and JIT-compiled runs of a
+/// block.
+#[derive(Debug)]
+pub struct StateDifference {
+    /// Location of the part within the serialised state, as a JSON pointer.
+    pub path: String,
+    /// Value after the interpreted run.
+    pub interpreted: Value,
+    /// Value after the JIT-compiled run.
+    pub jitted: Value,
+}
+
+/// Outcome of running a block under both the interpreter and the JIT.
+#[derive(Debug)]
+pub struct BlockReplay {
+    /// Result of the interpreted run.
+    pub interpreted: StepManyResult<EnvironException>,
+    /// Result of the JIT-compiled run.
+    pub jitted: StepManyResult<EnvironException>,
+    /// Differences between the resulting machine states.
+    pub differences: Vec<StateDifference>,
+}
+
+impl BlockReplay {
+    /// Whether both runs behaved identically.
+    pub fn is_identical(&self) -> bool {
+        self.interpreted == self.jitted && self.differences.is_empty()
+    }
+}
+
+/// Run a block at `pc` under both the interpreter and the JIT, starting from a fresh machine
+/// state with all memory readable and writeable, and compare the resulting states.
+pub fn replay_block<MC: MemoryConfig>(
+    instr: &[Instruction],
+    pc: Address,
+) -> Result<BlockReplay, ReplayError>
+where
+    for<'a> AllocatedOf<MachineCoreStateLayout<MC>, Ref<'a, Owned>>: Serialize,
+{
+    let new_core = || {
+        let mut core = MachineCoreState::<MC, Owned>::new(&mut Owned);
+        core.main_memory
+            .protect_pages(0, MC::TOTAL_BYTES, Permissions::READ_WRITE)
+            .expect("The whole memory is page-aligned");
+        core.hart.pc.write(pc);
+        core
+    };
+
+    let mut block = Interpreted::<MC, Owned>::new(&mut Owned);
+    block.start_block();
+    for instr in instr {
+        block.push_instr(*instr);
+    }
+
+    let mut interpreted_core = new_core();
+    let interpreted = unsafe {
+        // SAFETY: interpreted blocks are always callable
+        block.run_block(&mut interpreted_core, pc, &mut InterpretedBlockBuilder)
+    };
+
+    let mut jit = JIT::<MC, Owned>::new()?;
+    let fun = jit.compile(instr).ok_or(ReplayError::NotCompiled)?;
+
+    let mut jitted_core = new_core();
+    let mut jitted_res = Ok(());
+    let steps = unsafe {
+        // SAFETY: the JIT is alive for the duration of the call.
+        (fun)(
+            std::ptr::null(),
+            &mut jitted_core,
+            pc,
+            &mut jitted_res,
+            std::ptr::null(),
+        )
+    };
+    let jitted = StepManyResult {
+        steps,
+        error: jitted_res.err(),
+    };
+
+    let mut differences = Vec::new();
+    diff_values(
+        &mut String::new(),
+        &serde_json::to_value(interpreted_core.struct_ref::<FnManagerIdent>())?,
+        &serde_json::to_value(jitted_core.struct_ref::<FnManagerIdent>())?,
+        &mut differences,
+    );
+
+    Ok(BlockReplay {
+        interpreted,
+        jitted,
+        differences,
+    })
+}
+
+/// Record the differences between two serialised states, descending into objects and arrays.
+fn diff_values(path: &mut String, lhs: &Value, rhs: &Value, out: &mut Vec<StateDifference>) {
+    let len = path.len();
+
+    match (lhs, rhs) {
+        (Value::Object(lhs), Value::Object(rhs)) if lhs.len() == rhs.len() => {
+            for (key, lhs_value) in lhs {
+                let Some(rhs_value) = rhs.get(key) else {
+                    out.push(StateDifference {
+                        path: path.clone(),
+                        interpreted: Value::Object(lhs.clone()),
+                        jitted: Value::Object(rhs.clone()),
+                    });
+                    return;
+                };
+
+                path.push('/');
+                path.push_str(key);
+                diff_values(path, lhs_value, rhs_value, out);
+                path.truncate(len);
+            }
+        }
+
+        (Value::Array(lhs), Value::Array(rhs)) if lhs.len() == rhs.len() => {
+            for (index, (lhs, rhs)) in lhs.iter().zip(rhs).enumerate() {
+                path.push('/');
+                path.push_str(&index.to_string());
+                diff_values(path, lhs, rhs, out);
+                path.truncate(len);
+            }
+        }
+
+        (lhs, rhs) if lhs != rhs => out.push(StateDifference {
+            path: path.clone(),
+            interpreted: lhs.clone(),
+            jitted: rhs.clone(),
+        }),
+
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::machine_state::memory::M4K;
+    use crate::machine_state::registers::nz;
+    use crate::parser::instruction::InstrWidth;
+
+    #[test]
+    fn test_dump_and_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let dump = CodeDump::open(dir.path()).unwrap();
+
+        let instr = [
+            Instruction::new_li(nz::a1, 42, InstrWidth::Compressed),
+            Instruction::new_addi(nz::a2, nz::a1, 1, InstrWidth::Uncompressed),
+        ];
+        let hash = Hash::blake2b_hash(instr.as_slice()).unwrap();
+
+        let mut jit = JIT::<M4K, Owned>::new().unwrap();
+        jit.enable_dump(dir.path()).unwrap();
+        jit.compile(&instr)
+            .expect("Compilation of block should succeed.");
+
+        let function_dir = dump.function_dir(&hash);
+        for file in [
+            "instructions.txt",
+            "instructions.json",
+            "ir.clif",
+            "ir.opt.clif",
+            "disasm.s",
+        ] {
+            assert!(
+                function_dir.join(file).exists(),
+                "Expected {file} to be dumped"
+            );
+        }
+
+        let loaded = dump.instructions(&hash).unwrap();
+        assert_eq!(loaded, instr);
+
+        let replay = replay_block::<M4K>(&loaded, 0x100).unwrap();
+        assert_eq!(replay.interpreted.steps, 2);
+        assert!(replay.is_identical(), "{replay:?}");
+    }
+
+    #[test]
+    fn test_diff_values() {
+        let lhs = serde_json::json!({ "hart": { "pc": 4, "regs": [1, 2, 3] }, "memory": [0] });
+        let rhs = serde_json::json!({ "hart": { "pc": 8, "regs": [1, 5, 3] }, "memory": [0] });
+
+        let mut differences = Vec::new();
+        diff_values(&mut String::new(), &lhs, &rhs, &mut differences);
+
+        let paths = differences
+            .iter()
+            .map(|diff| diff.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["/hart/pc", "/hart/regs/1"]);
+    }
+}
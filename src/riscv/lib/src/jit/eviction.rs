@@ -0,0 +1,124 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Bounded memory for JIT-compiled code.
+//!
+//! A [`JITModule`] only frees the code it holds all at once. Compiled functions are therefore
+//! grouped into _generations_, each with a module of its own. New functions are always added to
+//! the current generation.
+//!
+//! When a code budget is set, at most two generations are kept: once the code of the current
+//! generation exceeds half of the budget, it becomes the previous generation - and the previous
+//! generation is evicted. Functions that are still called are compiled again into the new
+//! generation, whilst functions that have gone cold are dropped along with their generation.
+//! Blocks that failed to compile are likewise recorded in the current generation, and only
+//! attempted again once it has been evicted.
+//!
+//! The code of evicted functions is not freed by the JIT itself, as those functions may still be
+//! referenced from dispatch targets - possibly on a different thread. Instead, it is handed out as
+//! [`Evicted`], to be freed once nothing refers to the functions anymore.
+
+use cranelift::codegen::isa::OwnedTargetIsa;
+use cranelift_jit::JITBuilder;
+use cranelift_jit::JITModule;
+use cranelift_module::ModuleResult;
+
+use super::state_access::JitStateAccess;
+use super::state_access::JsaImports;
+use super::state_access::register_jsa_symbols;
+use crate::machine_state::memory::MemoryConfig;
+use crate::state_backend::ManagerBase;
+use crate::state_backend::hash::Hash;
+
+/// A generation of compiled functions, sharing a module.
+pub(super) struct Generation<MC: MemoryConfig, M: ManagerBase> {
+    /// The module, with the jit backend, which manages the JIT'd
+    /// functions.
+    pub(super) module: JITModule,
+
+    /// Imported [JitStateAccess] functions.
+    pub(super) jsa_imports: JsaImports<MC, M>,
+
+    /// Total size of the code of the functions in this generation, in bytes.
+    code_size: usize,
+
+    /// Hashes and addresses of the functions in this generation.
+    functions: Vec<(Hash, usize)>,
+
+    /// Hashes of the blocks that failed to compile in this generation.
+    failures: Vec<Hash>,
+}
+
+impl<MC: MemoryConfig, M: JitStateAccess> Generation<MC, M> {
+    /// Create an empty generation, compiling for the given ISA.
+    pub(super) fn new(isa: OwnedTargetIsa) -> ModuleResult<Self> {
+        let mut builder = JITBuilder::with_isa(isa, cranelift_module::default_libcall_names());
+        register_jsa_symbols::<MC, M>(&mut builder);
+
+        let mut module = JITModule::new(builder);
+        let jsa_imports = JsaImports::declare_in_module(&mut module)?;
+
+        Ok(Self {
+            module,
+            jsa_imports,
+            code_size: 0,
+            functions: Vec::new(),
+            failures: Vec::new(),
+        })
+    }
+}
+
+impl<MC: MemoryConfig, M: ManagerBase> Generation<MC, M> {
+    /// Record a function compiled in this generation.
+    pub(super) fn record(&mut self, hash: Hash, fun: usize, code_size: usize) {
+        self.functions.push((hash, fun));
+        self.code_size = self.code_size.saturating_add(code_size);
+    }
+
+    /// Record a block that failed to compile in this generation.
+    pub(super) fn record_failure(&mut self, hash: Hash) {
+        self.failures.push(hash);
+    }
+
+    /// Hashes of the blocks that failed to compile in this generation.
+    pub(super) fn failures(&self) -> &[Hash] {
+        &self.failures
+    }
+
+    /// Total size of the code of the functions in this generation, in bytes.
+    pub(super) fn code_size(&self) -> usize {
+        self.code_size
+    }
+
+    /// Evict every function in this generation.
+    pub(super) fn evict(self) -> Evicted {
+        Evicted {
+            module: self.module,
+            functions: self.functions,
+        }
+    }
+}
+
+/// Functions evicted from the JIT, whose code has yet to be freed.
+pub struct Evicted {
+    module: JITModule,
+    functions: Vec<(Hash, usize)>,
+}
+
+impl Evicted {
+    /// Hashes and addresses of the evicted functions.
+    pub fn functions(&self) -> &[(Hash, usize)] {
+        &self.functions
+    }
+
+    /// Free the code of the evicted functions.
+    ///
+    /// # Safety
+    ///
+    /// None of the evicted functions may be running, nor be called afterwards.
+    pub unsafe fn free(self) {
+        // SAFETY: the caller guarantees the functions are no longer used.
+        unsafe { self.module.free_memory() }
+    }
+}
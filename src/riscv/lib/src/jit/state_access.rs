@@ -32,7 +32,9 @@ use cranelift::codegen::ir::FuncRef;
 use cranelift::codegen::ir::InstBuilder;
 use cranelift::codegen::ir::Type;
 use cranelift::codegen::ir::Value;
+use cranelift::codegen::ir::condcodes::IntCC;
 use cranelift::codegen::ir::types::I8;
+use cranelift::codegen::ir::types::I32;
 use cranelift::frontend::FunctionBuilder;
 use cranelift::prelude::MemFlags;
 use cranelift_jit::JITBuilder;
@@ -40,28 +42,42 @@ use cranelift_jit::JITModule;
 use cranelift_module::FuncId;
 use cranelift_module::Module;
 use cranelift_module::ModuleResult;
+use rustc_apfloat::FloatConvert;
+use rustc_apfloat::ieee::Double;
+use rustc_apfloat::ieee::Single;
 
 use super::builder::F64;
 use super::builder::X64;
 use super::builder::errno::Errno;
 use super::builder::errno::ErrnoImpl;
+use crate::instruction_context::FArith;
+use crate::instruction_context::FBinary;
+use crate::instruction_context::FCompare;
+use crate::instruction_context::FIntFormat;
+use crate::instruction_context::FloatFormat;
 use crate::instruction_context::ICB;
 use crate::instruction_context::LoadStoreWidth;
 use crate::instruction_context::StoreLoadInt;
+use crate::interpreter::float;
+use crate::interpreter::float::FloatExt;
 use crate::machine_state::MachineCoreState;
+use crate::machine_state::csregisters::CSRegister;
 use crate::machine_state::memory::Address;
 use crate::machine_state::memory::BadMemoryAccess;
 use crate::machine_state::memory::Memory;
 use crate::machine_state::memory::MemoryConfig;
+use crate::machine_state::memory::OFFSET_BITS;
 use crate::machine_state::registers::FRegister;
 use crate::machine_state::registers::FValue;
 use crate::machine_state::registers::NonZeroXRegister;
 use crate::machine_state::registers::XRegisters;
 use crate::machine_state::registers::XValue;
+use crate::parser::instruction::InstrRoundingMode;
 use crate::state_backend::Elem;
 use crate::state_backend::ManagerBase;
 use crate::state_backend::ManagerReadWrite;
 use crate::state_backend::owned_backend::Owned;
+use crate::state_backend::owned_backend::PAGE_MODIFIED;
 use crate::state_backend::proof_backend::ProofGen;
 use crate::traps::EnvironException;
 use crate::traps::Exception;
@@ -129,6 +145,27 @@ register_jsa_functions!(
     memory_load_u64 => (memory_load::<u64, MC, JSA>, AbiCall<4>::args),
     reservation_set_write => (reservation_set_write::<MC, JSA>, AbiCall<2>::args),
     reservation_set_read => (reservation_set_read::<MC, JSA>, AbiCall<1>::args),
+    csregister_read => (csregister_read::<MC, JSA>, AbiCall<2>::args),
+    csregister_write => (csregister_write::<MC, JSA>, AbiCall<3>::args),
+    csregister_replace => (csregister_replace::<MC, JSA>, AbiCall<3>::args),
+    csregister_set_bits => (csregister_set_bits::<MC, JSA>, AbiCall<3>::args),
+    csregister_clear_bits => (csregister_clear_bits::<MC, JSA>, AbiCall<3>::args),
+    f32_arith => (f_arith::<Single, MC, JSA>, AbiCall<8>::args),
+    f64_arith => (f_arith::<Double, MC, JSA>, AbiCall<8>::args),
+    f32_binary => (f_binary::<Single, MC, JSA>, AbiCall<4>::args),
+    f64_binary => (f_binary::<Double, MC, JSA>, AbiCall<4>::args),
+    f32_compare => (f_compare::<Single, MC, JSA>, AbiCall<4>::args),
+    f64_compare => (f_compare::<Double, MC, JSA>, AbiCall<4>::args),
+    f32_classify => (f_classify::<Single>, AbiCall<1>::args),
+    f64_classify => (f_classify::<Double>, AbiCall<1>::args),
+    f32_from_int => (f_from_int::<Single, MC, JSA>, AbiCall<6>::args),
+    f64_from_int => (f_from_int::<Double, MC, JSA>, AbiCall<6>::args),
+    f32_to_int => (f_to_int::<Single, MC, JSA>, AbiCall<6>::args),
+    f64_to_int => (f_to_int::<Double, MC, JSA>, AbiCall<6>::args),
+    f32_to_f32 => (f_convert::<Single, Single, MC, JSA>, AbiCall<5>::args),
+    f32_to_f64 => (f_convert::<Single, Double, MC, JSA>, AbiCall<5>::args),
+    f64_to_f32 => (f_convert::<Double, Single, MC, JSA>, AbiCall<5>::args),
+    f64_to_f64 => (f_convert::<Double, Double, MC, JSA>, AbiCall<5>::args),
 );
 
 /// Update the instruction pc in the state.
@@ -308,6 +345,193 @@ extern "C" fn reservation_set_read<MC: MemoryConfig, M: ManagerReadWrite>(
     <MachineCoreState<MC, M> as ICB>::reservation_set_read(core)
 }
 
+/// Read the value of a CSR, triggering any read side effects.
+extern "C" fn csregister_read<MC: MemoryConfig, M: ManagerReadWrite>(
+    core: &mut MachineCoreState<MC, M>,
+    csr: CSRegister,
+) -> XValue {
+    <MachineCoreState<MC, M> as ICB>::csregister_read(core, csr)
+}
+
+/// Write a value to a CSR, triggering any write side effects.
+extern "C" fn csregister_write<MC: MemoryConfig, M: ManagerReadWrite>(
+    core: &mut MachineCoreState<MC, M>,
+    csr: CSRegister,
+    value: XValue,
+) {
+    <MachineCoreState<MC, M> as ICB>::csregister_write(core, csr, value)
+}
+
+/// Replace the value of a CSR, returning the previous value.
+extern "C" fn csregister_replace<MC: MemoryConfig, M: ManagerReadWrite>(
+    core: &mut MachineCoreState<MC, M>,
+    csr: CSRegister,
+    value: XValue,
+) -> XValue {
+    <MachineCoreState<MC, M> as ICB>::csregister_replace(core, csr, value)
+}
+
+/// Set the given bits of a CSR, returning the previous value.
+extern "C" fn csregister_set_bits<MC: MemoryConfig, M: ManagerReadWrite>(
+    core: &mut MachineCoreState<MC, M>,
+    csr: CSRegister,
+    bits: XValue,
+) -> XValue {
+    <MachineCoreState<MC, M> as ICB>::csregister_set_bits(core, csr, bits)
+}
+
+/// Clear the given bits of a CSR, returning the previous value.
+extern "C" fn csregister_clear_bits<MC: MemoryConfig, M: ManagerReadWrite>(
+    core: &mut MachineCoreState<MC, M>,
+    csr: CSRegister,
+    bits: XValue,
+) -> XValue {
+    <MachineCoreState<MC, M> as ICB>::csregister_clear_bits(core, csr, bits)
+}
+
+/// Write the result of a fallible operation to the given out-parameters.
+///
+/// On success, the value is written to `value_out` and `false` is returned. On failure, the
+/// exception is written to `exception_out` and `true` is returned, to indicate exception handling
+/// will be necessary.
+fn write_result<T>(
+    res: Result<T, Exception>,
+    value_out: &mut MaybeUninit<T>,
+    exception_out: &mut MaybeUninit<Exception>,
+) -> bool {
+    match res {
+        Ok(value) => {
+            value_out.write(value);
+            false
+        }
+        Err(exception) => {
+            exception_out.write(exception);
+            true
+        }
+    }
+}
+
+/// Decode a rounding mode, encoded as the `rm` field of an instruction.
+///
+/// See [`InstrRoundingMode::to_rm`].
+fn decode_rounding_mode(rm: u32) -> Result<InstrRoundingMode, Exception> {
+    InstrRoundingMode::from_rm(rm).ok_or(Exception::IllegalInstruction)
+}
+
+/// Perform the rounding floating-point operation `op`, over values of format `F`.
+///
+/// If the operation is successful, the result is written to `fval_out` and `false` is
+/// returned. Otherwise, an exception is written to `exception_out` and `true` is returned.
+///
+/// See [`ICB::f_arith`].
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Fused operations have three operands"
+)]
+extern "C" fn f_arith<F: FloatExt, MC: MemoryConfig, M: ManagerReadWrite>(
+    core: &mut MachineCoreState<MC, M>,
+    op: FArith,
+    rval1: FValue,
+    rval2: FValue,
+    rval3: FValue,
+    rm: u32,
+    fval_out: &mut MaybeUninit<FValue>,
+    exception_out: &mut MaybeUninit<Exception>,
+) -> bool {
+    let res =
+        decode_rounding_mode(rm).and_then(|rm| core.hart.f_arith::<F>(op, rval1, rval2, rval3, rm));
+    write_result(res, fval_out, exception_out)
+}
+
+/// Perform the exact floating-point operation `op`, over values of format `F`.
+///
+/// See [`ICB::f_binary`].
+extern "C" fn f_binary<F: FloatExt, MC: MemoryConfig, M: ManagerReadWrite>(
+    core: &mut MachineCoreState<MC, M>,
+    op: FBinary,
+    rval1: FValue,
+    rval2: FValue,
+) -> FValue {
+    core.hart.f_binary::<F>(op, rval1, rval2)
+}
+
+/// Compare two floating-point values of format `F`.
+///
+/// See [`ICB::f_compare`].
+extern "C" fn f_compare<F: FloatExt, MC: MemoryConfig, M: ManagerReadWrite>(
+    core: &mut MachineCoreState<MC, M>,
+    op: FCompare,
+    rval1: FValue,
+    rval2: FValue,
+) -> XValue {
+    core.hart.f_compare::<F>(op, rval1, rval2)
+}
+
+/// Classify a floating-point value of format `F`.
+///
+/// See [`ICB::f_classify`].
+extern "C" fn f_classify<F: FloatExt>(rval: FValue) -> XValue {
+    float::f_classify::<F>(rval)
+}
+
+/// Convert an integer to a floating-point value of format `F`.
+///
+/// If the conversion is successful, the result is written to `fval_out` and `false` is
+/// returned. Otherwise, an exception is written to `exception_out` and `true` is returned.
+///
+/// See [`ICB::f_from_int`].
+extern "C" fn f_from_int<F: FloatExt, MC: MemoryConfig, M: ManagerReadWrite>(
+    core: &mut MachineCoreState<MC, M>,
+    int: FIntFormat,
+    rval: XValue,
+    rm: u32,
+    fval_out: &mut MaybeUninit<FValue>,
+    exception_out: &mut MaybeUninit<Exception>,
+) -> bool {
+    let res = decode_rounding_mode(rm).and_then(|rm| core.hart.f_from_int::<F>(int, rval, rm));
+    write_result(res, fval_out, exception_out)
+}
+
+/// Convert a floating-point value of format `F` to an integer.
+///
+/// If the conversion is successful, the result is written to `xval_out` and `false` is
+/// returned. Otherwise, an exception is written to `exception_out` and `true` is returned.
+///
+/// See [`ICB::f_to_int`].
+extern "C" fn f_to_int<F: FloatExt, MC: MemoryConfig, M: ManagerReadWrite>(
+    core: &mut MachineCoreState<MC, M>,
+    int: FIntFormat,
+    rval: FValue,
+    rm: u32,
+    xval_out: &mut MaybeUninit<XValue>,
+    exception_out: &mut MaybeUninit<Exception>,
+) -> bool {
+    let res = decode_rounding_mode(rm).and_then(|rm| core.hart.f_to_int::<F>(int, rval, rm));
+    write_result(res, xval_out, exception_out)
+}
+
+/// Convert a floating-point value from format `F` to format `T`.
+///
+/// If the conversion is successful, the result is written to `fval_out` and `false` is
+/// returned. Otherwise, an exception is written to `exception_out` and `true` is returned.
+///
+/// See [`ICB::f_convert`].
+extern "C" fn f_convert<
+    F: FloatExt + FloatConvert<T>,
+    T: FloatExt,
+    MC: MemoryConfig,
+    M: ManagerReadWrite,
+>(
+    core: &mut MachineCoreState<MC, M>,
+    rval: FValue,
+    rm: u32,
+    fval_out: &mut MaybeUninit<FValue>,
+    exception_out: &mut MaybeUninit<Exception>,
+) -> bool {
+    let res = decode_rounding_mode(rm).and_then(|rm| core.hart.f_convert::<F, T>(rval, rm));
+    write_result(res, fval_out, exception_out)
+}
+
 /// State Access that a JIT-compiled block may use
 ///
 /// Methods in this trait are used to provide access to parts of the state. It can be specialised by
@@ -383,6 +607,21 @@ pub trait JitStateAccess: ManagerReadWrite {
         let reg = builder.ins().iconst(I8, reg as i64);
         builder.ins().call(*freg_write, &[core_ptr, reg, value.0]);
     }
+
+    /// Emit the required IR to check whether an access of `V` to main memory, at the given
+    /// physical address, may be performed directly by the JIT-compiled code.
+    ///
+    /// Returns `None` if memory cannot be accessed directly, in which case all accesses call out
+    /// to [`JsaCalls::memory_load`] and [`JsaCalls::memory_store`].
+    fn ir_memory_direct_access<MC: MemoryConfig, V: StoreLoadInt>(
+        _jsa_calls: &mut JsaCalls<'_, MC, Self>,
+        _builder: &mut FunctionBuilder<'_>,
+        _core_ptr: Value,
+        _phys_address: X64,
+        _access: MemoryAccess,
+    ) -> Option<DirectMemoryAccess> {
+        None
+    }
 }
 
 impl JitStateAccess for Owned {
@@ -420,10 +659,124 @@ impl JitStateAccess for Owned {
             .ins()
             .store(MemFlags::trusted(), value.0, core_ptr, offset as i32);
     }
+
+    fn ir_memory_direct_access<MC: MemoryConfig, V: StoreLoadInt>(
+        jsa_calls: &mut JsaCalls<'_, MC, Self>,
+        builder: &mut FunctionBuilder<'_>,
+        core_ptr: Value,
+        phys_address: X64,
+        access: MemoryAccess,
+    ) -> Option<DirectMemoryAccess> {
+        let width = V::WIDTH as i64;
+        let memory = std::mem::offset_of!(MachineCoreState<MC, Self>, main_memory);
+        let layout = MC::OWNED_LAYOUT;
+        let pages = match access {
+            MemoryAccess::Load => layout.readable_pages,
+            MemoryAccess::Store => layout.writable_pages,
+        };
+
+        // The access must lie entirely within the memory, mirroring `Memory::read/write`.
+        let in_bounds = builder.ins().icmp_imm(
+            IntCC::UnsignedLessThanOrEqual,
+            phys_address.0,
+            MC::TOTAL_BYTES as i64 - width,
+        );
+
+        // Out-of-bounds addresses are clamped, so that the page permissions may always be read.
+        let zero = builder.ins().iconst(ir::types::I64, 0);
+        let address = builder.ins().select(in_bounds, phys_address.0, zero);
+
+        let first_page = builder.ins().ushr_imm(address, OFFSET_BITS as i64);
+        let last_byte = builder.ins().iadd_imm(address, width - 1);
+        let last_page = builder.ins().ushr_imm(last_byte, OFFSET_BITS as i64);
+
+        // page permissions are stored as one `bool` per page, behind a pointer
+        // - known to be aligned and non-trapping
+        let pages_ptr = builder.ins().load(
+            jsa_calls.ptr_type,
+            MemFlags::trusted(),
+            core_ptr,
+            (memory + pages) as i32,
+        );
+        let first_ptr = builder.ins().iadd(pages_ptr, first_page);
+        let first = builder.ins().load(I8, MemFlags::trusted(), first_ptr, 0);
+        let last_ptr = builder.ins().iadd(pages_ptr, last_page);
+        let last = builder.ins().load(I8, MemFlags::trusted(), last_ptr, 0);
+
+        let permitted = builder.ins().band(first, last);
+        let allowed = builder.ins().band(in_bounds, permitted);
+
+        // Pages written to directly are marked as modified, as `Owned::dyn_region_write` would.
+        // Marking pages of a store that ends up not being allowed is harmless: touched pages
+        // are merely no longer known to be zero, nor to be shared with a snapshot.
+        if let MemoryAccess::Store = access {
+            let touched_ptr = builder.ins().load(
+                jsa_calls.ptr_type,
+                MemFlags::trusted(),
+                core_ptr,
+                (memory + layout.touched_pages) as i32,
+            );
+            let touched = builder.ins().iconst(I8, PAGE_MODIFIED as i64);
+            let first_ptr = builder.ins().iadd(touched_ptr, first_page);
+            builder
+                .ins()
+                .store(MemFlags::trusted(), touched, first_ptr, 0);
+            let last_ptr = builder.ins().iadd(touched_ptr, last_page);
+            builder
+                .ins()
+                .store(MemFlags::trusted(), touched, last_ptr, 0);
+        }
+
+        let data_ptr = builder.ins().load(
+            jsa_calls.ptr_type,
+            MemFlags::trusted(),
+            core_ptr,
+            (memory + layout.data) as i32,
+        );
+        let host_address = builder.ins().iadd(data_ptr, phys_address.0);
+
+        Some(DirectMemoryAccess {
+            allowed,
+            host_address,
+        })
+    }
 }
 
 impl<M: ManagerReadWrite> JitStateAccess for ProofGen<M> {}
 
+/// Kind of access to main memory.
+#[derive(Debug, Clone, Copy)]
+pub enum MemoryAccess {
+    Load,
+    Store,
+}
+
+/// Direct access to main memory from JIT-compiled code, see
+/// [`JitStateAccess::ir_memory_direct_access`].
+pub struct DirectMemoryAccess {
+    /// Whether the access is within bounds & permitted, and may therefore be performed directly.
+    pub allowed: Value,
+
+    /// The native address to access - only valid when `allowed` holds.
+    pub host_address: Value,
+}
+
+/// Emit the required IR to extend a value of `V`, loaded from memory, to an [`X64`].
+pub(super) fn ir_extend_loaded<V: StoreLoadInt>(
+    builder: &mut FunctionBuilder<'_>,
+    xval: Value,
+) -> X64 {
+    let xval = if V::IR_TYPE == ir::types::I64 {
+        xval
+    } else if V::SIGNED {
+        builder.ins().sextend(ir::types::I64, xval)
+    } else {
+        builder.ins().uextend(ir::types::I64, xval)
+    };
+
+    X64(xval)
+}
+
 /// References to locally imported [`JitStateAccess`] methods, used to directly call
 /// these accessor methods in the JIT-compilation context.
 pub struct JsaCalls<'a, MC: MemoryConfig, M: ManagerBase> {
@@ -453,6 +806,27 @@ pub struct JsaCalls<'a, MC: MemoryConfig, M: ManagerBase> {
     memory_load_u64: Option<FuncRef>,
     reservation_set_write: Option<FuncRef>,
     reservation_set_read: Option<FuncRef>,
+    csregister_read: Option<FuncRef>,
+    csregister_write: Option<FuncRef>,
+    csregister_replace: Option<FuncRef>,
+    csregister_set_bits: Option<FuncRef>,
+    csregister_clear_bits: Option<FuncRef>,
+    f32_arith: Option<FuncRef>,
+    f64_arith: Option<FuncRef>,
+    f32_binary: Option<FuncRef>,
+    f64_binary: Option<FuncRef>,
+    f32_compare: Option<FuncRef>,
+    f64_compare: Option<FuncRef>,
+    f32_classify: Option<FuncRef>,
+    f64_classify: Option<FuncRef>,
+    f32_from_int: Option<FuncRef>,
+    f64_from_int: Option<FuncRef>,
+    f32_to_int: Option<FuncRef>,
+    f64_to_int: Option<FuncRef>,
+    f32_to_f32: Option<FuncRef>,
+    f32_to_f64: Option<FuncRef>,
+    f64_to_f32: Option<FuncRef>,
+    f64_to_f64: Option<FuncRef>,
     _pd: PhantomData<(MC, M)>,
 }
 
@@ -490,6 +864,27 @@ impl<'a, MC: MemoryConfig, M: JitStateAccess> JsaCalls<'a, MC, M> {
             memory_load_u64: None,
             reservation_set_write: None,
             reservation_set_read: None,
+            csregister_read: None,
+            csregister_write: None,
+            csregister_replace: None,
+            csregister_set_bits: None,
+            csregister_clear_bits: None,
+            f32_arith: None,
+            f64_arith: None,
+            f32_binary: None,
+            f64_binary: None,
+            f32_compare: None,
+            f64_compare: None,
+            f32_classify: None,
+            f64_classify: None,
+            f32_from_int: None,
+            f64_from_int: None,
+            f32_to_int: None,
+            f64_to_int: None,
+            f32_to_f32: None,
+            f32_to_f64: None,
+            f64_to_f32: None,
+            f64_to_f64: None,
             _pd: PhantomData,
         }
     }
@@ -736,15 +1131,7 @@ impl<'a, MC: MemoryConfig, M: JitStateAccess> JsaCalls<'a, MC, M> {
                 .ins()
                 .load(V::IR_TYPE, MemFlags::trusted(), xval_ptr, 0);
 
-            let xval = if V::IR_TYPE == ir::types::I64 {
-                xval
-            } else if V::SIGNED {
-                builder.ins().sextend(ir::types::I64, xval)
-            } else {
-                builder.ins().uextend(ir::types::I64, xval)
-            };
-
-            X64(xval)
+            ir_extend_loaded::<V>(builder, xval)
         })
     }
 
@@ -783,6 +1170,404 @@ impl<'a, MC: MemoryConfig, M: JitStateAccess> JsaCalls<'a, MC, M> {
         let call = builder.ins().call(*reservation_set_read, &[core_ptr]);
         X64(builder.inst_results(call)[0])
     }
+
+    /// Emit the required IR to call `csregister_read`.
+    ///
+    /// Returns the value of the given CSR.
+    pub(super) fn csregister_read(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        core_ptr: Value,
+        csr: CSRegister,
+    ) -> X64 {
+        let csregister_read = self.csregister_read.get_or_insert_with(|| {
+            self.module
+                .declare_func_in_func(self.imports.csregister_read, builder.func)
+        });
+
+        let csr = builder.ins().iconst(ir::types::I64, csr as i64);
+        let call = builder.ins().call(*csregister_read, &[core_ptr, csr]);
+        X64(builder.inst_results(call)[0])
+    }
+
+    /// Emit the required IR to call `csregister_write`.
+    ///
+    /// Writes the value to the given CSR.
+    pub(super) fn csregister_write(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        core_ptr: Value,
+        csr: CSRegister,
+        value: X64,
+    ) {
+        let csregister_write = self.csregister_write.get_or_insert_with(|| {
+            self.module
+                .declare_func_in_func(self.imports.csregister_write, builder.func)
+        });
+
+        let csr = builder.ins().iconst(ir::types::I64, csr as i64);
+        builder
+            .ins()
+            .call(*csregister_write, &[core_ptr, csr, value.0]);
+    }
+
+    /// Emit the required IR to call `csregister_replace`.
+    ///
+    /// Returns the previous value of the given CSR.
+    pub(super) fn csregister_replace(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        core_ptr: Value,
+        csr: CSRegister,
+        value: X64,
+    ) -> X64 {
+        let csregister_replace = self.csregister_replace.get_or_insert_with(|| {
+            self.module
+                .declare_func_in_func(self.imports.csregister_replace, builder.func)
+        });
+
+        let csr = builder.ins().iconst(ir::types::I64, csr as i64);
+        let call = builder
+            .ins()
+            .call(*csregister_replace, &[core_ptr, csr, value.0]);
+        X64(builder.inst_results(call)[0])
+    }
+
+    /// Emit the required IR to call `csregister_set_bits`.
+    ///
+    /// Returns the previous value of the given CSR.
+    pub(super) fn csregister_set_bits(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        core_ptr: Value,
+        csr: CSRegister,
+        bits: X64,
+    ) -> X64 {
+        let csregister_set_bits = self.csregister_set_bits.get_or_insert_with(|| {
+            self.module
+                .declare_func_in_func(self.imports.csregister_set_bits, builder.func)
+        });
+
+        let csr = builder.ins().iconst(ir::types::I64, csr as i64);
+        let call = builder
+            .ins()
+            .call(*csregister_set_bits, &[core_ptr, csr, bits.0]);
+        X64(builder.inst_results(call)[0])
+    }
+
+    /// Emit the required IR to call `csregister_clear_bits`.
+    ///
+    /// Returns the previous value of the given CSR.
+    pub(super) fn csregister_clear_bits(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        core_ptr: Value,
+        csr: CSRegister,
+        bits: X64,
+    ) -> X64 {
+        let csregister_clear_bits = self.csregister_clear_bits.get_or_insert_with(|| {
+            self.module
+                .declare_func_in_func(self.imports.csregister_clear_bits, builder.func)
+        });
+
+        let csr = builder.ins().iconst(ir::types::I64, csr as i64);
+        let call = builder
+            .ins()
+            .call(*csregister_clear_bits, &[core_ptr, csr, bits.0]);
+        X64(builder.inst_results(call)[0])
+    }
+
+    /// Emit the required IR to call `f_arith`.
+    ///
+    /// Returns `errno` - on success, the result of the operation is returned.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "Fused operations have three operands"
+    )]
+    pub(super) fn f_arith(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        core_ptr: Value,
+        format: FloatFormat,
+        op: FArith,
+        rval1: F64,
+        rval2: F64,
+        rval3: F64,
+        rm: InstrRoundingMode,
+    ) -> impl Errno<F64, MC, M> + 'static {
+        let f_arith = match format {
+            FloatFormat::Single => self.f32_arith.get_or_insert_with(|| {
+                self.module
+                    .declare_func_in_func(self.imports.f32_arith, builder.func)
+            }),
+            FloatFormat::Double => self.f64_arith.get_or_insert_with(|| {
+                self.module
+                    .declare_func_in_func(self.imports.f64_arith, builder.func)
+            }),
+        };
+
+        let exception_slot = stack::Slot::<Exception>::new(self.ptr_type, builder);
+        let exception_ptr = exception_slot.ptr(builder);
+
+        let fval_ptr = stack::Slot::<FValue>::new(self.ptr_type, builder).ptr(builder);
+
+        let op = builder.ins().iconst(I8, op as i64);
+        let rm = builder.ins().iconst(I32, rm.to_rm() as i64);
+
+        let call = builder.ins().call(*f_arith, &[
+            core_ptr,
+            op,
+            rval1.0,
+            rval2.0,
+            rval3.0,
+            rm,
+            fval_ptr,
+            exception_ptr,
+        ]);
+
+        let errno = builder.inst_results(call)[0];
+
+        ErrnoImpl::new(errno, exception_ptr, move |builder| {
+            F64(builder
+                .ins()
+                .load(ir::types::I64, MemFlags::trusted(), fval_ptr, 0))
+        })
+    }
+
+    /// Emit the required IR to call `f_binary`.
+    pub(super) fn f_binary(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        core_ptr: Value,
+        format: FloatFormat,
+        op: FBinary,
+        rval1: F64,
+        rval2: F64,
+    ) -> F64 {
+        let f_binary = match format {
+            FloatFormat::Single => self.f32_binary.get_or_insert_with(|| {
+                self.module
+                    .declare_func_in_func(self.imports.f32_binary, builder.func)
+            }),
+            FloatFormat::Double => self.f64_binary.get_or_insert_with(|| {
+                self.module
+                    .declare_func_in_func(self.imports.f64_binary, builder.func)
+            }),
+        };
+
+        let op = builder.ins().iconst(I8, op as i64);
+        let call = builder
+            .ins()
+            .call(*f_binary, &[core_ptr, op, rval1.0, rval2.0]);
+        F64(builder.inst_results(call)[0])
+    }
+
+    /// Emit the required IR to call `f_compare`.
+    pub(super) fn f_compare(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        core_ptr: Value,
+        format: FloatFormat,
+        op: FCompare,
+        rval1: F64,
+        rval2: F64,
+    ) -> X64 {
+        let f_compare = match format {
+            FloatFormat::Single => self.f32_compare.get_or_insert_with(|| {
+                self.module
+                    .declare_func_in_func(self.imports.f32_compare, builder.func)
+            }),
+            FloatFormat::Double => self.f64_compare.get_or_insert_with(|| {
+                self.module
+                    .declare_func_in_func(self.imports.f64_compare, builder.func)
+            }),
+        };
+
+        let op = builder.ins().iconst(I8, op as i64);
+        let call = builder
+            .ins()
+            .call(*f_compare, &[core_ptr, op, rval1.0, rval2.0]);
+        X64(builder.inst_results(call)[0])
+    }
+
+    /// Emit the required IR to call `f_classify`.
+    pub(super) fn f_classify(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        format: FloatFormat,
+        rval: F64,
+    ) -> X64 {
+        let f_classify = match format {
+            FloatFormat::Single => self.f32_classify.get_or_insert_with(|| {
+                self.module
+                    .declare_func_in_func(self.imports.f32_classify, builder.func)
+            }),
+            FloatFormat::Double => self.f64_classify.get_or_insert_with(|| {
+                self.module
+                    .declare_func_in_func(self.imports.f64_classify, builder.func)
+            }),
+        };
+
+        let call = builder.ins().call(*f_classify, &[rval.0]);
+        X64(builder.inst_results(call)[0])
+    }
+
+    /// Emit the required IR to call `f_from_int`.
+    ///
+    /// Returns `errno` - on success, the converted value is returned.
+    pub(super) fn f_from_int(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        core_ptr: Value,
+        format: FloatFormat,
+        int: FIntFormat,
+        rval: X64,
+        rm: InstrRoundingMode,
+    ) -> impl Errno<F64, MC, M> + 'static {
+        let f_from_int = match format {
+            FloatFormat::Single => self.f32_from_int.get_or_insert_with(|| {
+                self.module
+                    .declare_func_in_func(self.imports.f32_from_int, builder.func)
+            }),
+            FloatFormat::Double => self.f64_from_int.get_or_insert_with(|| {
+                self.module
+                    .declare_func_in_func(self.imports.f64_from_int, builder.func)
+            }),
+        };
+
+        let exception_slot = stack::Slot::<Exception>::new(self.ptr_type, builder);
+        let exception_ptr = exception_slot.ptr(builder);
+
+        let fval_ptr = stack::Slot::<FValue>::new(self.ptr_type, builder).ptr(builder);
+
+        let int = builder.ins().iconst(I8, int as i64);
+        let rm = builder.ins().iconst(I32, rm.to_rm() as i64);
+
+        let call = builder.ins().call(*f_from_int, &[
+            core_ptr,
+            int,
+            rval.0,
+            rm,
+            fval_ptr,
+            exception_ptr,
+        ]);
+
+        let errno = builder.inst_results(call)[0];
+
+        ErrnoImpl::new(errno, exception_ptr, move |builder| {
+            F64(builder
+                .ins()
+                .load(ir::types::I64, MemFlags::trusted(), fval_ptr, 0))
+        })
+    }
+
+    /// Emit the required IR to call `f_to_int`.
+    ///
+    /// Returns `errno` - on success, the converted value is returned.
+    pub(super) fn f_to_int(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        core_ptr: Value,
+        format: FloatFormat,
+        int: FIntFormat,
+        rval: F64,
+        rm: InstrRoundingMode,
+    ) -> impl Errno<X64, MC, M> + 'static {
+        let f_to_int = match format {
+            FloatFormat::Single => self.f32_to_int.get_or_insert_with(|| {
+                self.module
+                    .declare_func_in_func(self.imports.f32_to_int, builder.func)
+            }),
+            FloatFormat::Double => self.f64_to_int.get_or_insert_with(|| {
+                self.module
+                    .declare_func_in_func(self.imports.f64_to_int, builder.func)
+            }),
+        };
+
+        let exception_slot = stack::Slot::<Exception>::new(self.ptr_type, builder);
+        let exception_ptr = exception_slot.ptr(builder);
+
+        let xval_ptr = stack::Slot::<XValue>::new(self.ptr_type, builder).ptr(builder);
+
+        let int = builder.ins().iconst(I8, int as i64);
+        let rm = builder.ins().iconst(I32, rm.to_rm() as i64);
+
+        let call = builder.ins().call(*f_to_int, &[
+            core_ptr,
+            int,
+            rval.0,
+            rm,
+            xval_ptr,
+            exception_ptr,
+        ]);
+
+        let errno = builder.inst_results(call)[0];
+
+        ErrnoImpl::new(errno, exception_ptr, move |builder| {
+            X64(builder
+                .ins()
+                .load(ir::types::I64, MemFlags::trusted(), xval_ptr, 0))
+        })
+    }
+
+    /// Emit the required IR to call `f_convert`.
+    ///
+    /// Returns `errno` - on success, the converted value is returned.
+    pub(super) fn f_convert(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        core_ptr: Value,
+        from: FloatFormat,
+        to: FloatFormat,
+        rval: F64,
+        rm: InstrRoundingMode,
+    ) -> impl Errno<F64, MC, M> + 'static {
+        let f_convert = match (from, to) {
+            (FloatFormat::Single, FloatFormat::Single) => {
+                self.f32_to_f32.get_or_insert_with(|| {
+                    self.module
+                        .declare_func_in_func(self.imports.f32_to_f32, builder.func)
+                })
+            }
+            (FloatFormat::Single, FloatFormat::Double) => {
+                self.f32_to_f64.get_or_insert_with(|| {
+                    self.module
+                        .declare_func_in_func(self.imports.f32_to_f64, builder.func)
+                })
+            }
+            (FloatFormat::Double, FloatFormat::Single) => {
+                self.f64_to_f32.get_or_insert_with(|| {
+                    self.module
+                        .declare_func_in_func(self.imports.f64_to_f32, builder.func)
+                })
+            }
+            (FloatFormat::Double, FloatFormat::Double) => {
+                self.f64_to_f64.get_or_insert_with(|| {
+                    self.module
+                        .declare_func_in_func(self.imports.f64_to_f64, builder.func)
+                })
+            }
+        };
+
+        let exception_slot = stack::Slot::<Exception>::new(self.ptr_type, builder);
+        let exception_ptr = exception_slot.ptr(builder);
+
+        let fval_ptr = stack::Slot::<FValue>::new(self.ptr_type, builder).ptr(builder);
+
+        let rm = builder.ins().iconst(I32, rm.to_rm() as i64);
+
+        let call = builder
+            .ins()
+            .call(*f_convert, &[core_ptr, rval.0, rm, fval_ptr, exception_ptr]);
+
+        let errno = builder.inst_results(call)[0];
+
+        ErrnoImpl::new(errno, exception_ptr, move |builder| {
+            F64(builder
+                .ins()
+                .load(ir::types::I64, MemFlags::trusted(), fval_ptr, 0))
+        })
+    }
 }
 
 /// Outcome of handling an exception.
@@ -18,6 +18,11 @@ use cranelift_module::Linkage;
 use cranelift_module::Module;
 use cranelift_module::ModuleResult;
 
+use crate::instruction_context::FArith;
+use crate::instruction_context::FBinary;
+use crate::instruction_context::FCompare;
+use crate::instruction_context::FIntFormat;
+use crate::machine_state::csregisters::CSRegister;
 use crate::machine_state::registers::FRegister;
 use crate::machine_state::registers::FValue;
 use crate::machine_state::registers::NonZeroXRegister;
@@ -93,6 +98,18 @@ impl AbiCall<4> {
     impl_abicall!(A1, A2, A3, A4);
 }
 
+impl AbiCall<5> {
+    impl_abicall!(A1, A2, A3, A4, A5);
+}
+
+impl AbiCall<6> {
+    impl_abicall!(A1, A2, A3, A4, A5, A6);
+}
+
+impl AbiCall<8> {
+    impl_abicall!(A1, A2, A3, A4, A5, A6, A7, A8);
+}
+
 /// Holds the IR representation of a function parameter's type, which is needed for
 /// registering the function's [`Signature`] in the [`JITModule`].
 pub(super) enum CraneliftRepr {
@@ -186,6 +203,26 @@ impl ToCraneliftRepr for FValue {
     const CRANELIFT_TYPE: CraneliftRepr = get_repr::<Self>();
 }
 
+impl ToCraneliftRepr for FArith {
+    const CRANELIFT_TYPE: CraneliftRepr = get_repr::<Self>();
+}
+
+impl ToCraneliftRepr for FBinary {
+    const CRANELIFT_TYPE: CraneliftRepr = get_repr::<Self>();
+}
+
+impl ToCraneliftRepr for FCompare {
+    const CRANELIFT_TYPE: CraneliftRepr = get_repr::<Self>();
+}
+
+impl ToCraneliftRepr for FIntFormat {
+    const CRANELIFT_TYPE: CraneliftRepr = get_repr::<Self>();
+}
+
+impl ToCraneliftRepr for CSRegister {
+    const CRANELIFT_TYPE: CraneliftRepr = get_repr::<Self>();
+}
+
 /// A valid return type for an external function call.
 pub(super) trait Returnable {
     /// Convert the return type of a function to a cranelift IR type.
@@ -54,6 +54,7 @@ use cranelift::prelude::types::I8;
 use cranelift::prelude::types::I16;
 use cranelift::prelude::types::I32;
 
+use crate::machine_state::registers::FValue;
 use crate::traps::Exception;
 
 /// Any value of type `T: StackAddressable` may be placed on the stack, and
@@ -129,6 +130,10 @@ impl_stackable_int!(16);
 impl_stackable_int!(32);
 impl_stackable_int!(64);
 
+impl StackAddressable for FValue {
+    type Underlying = FValue;
+}
+
 /// Helper definition for storing/loading an address to/from the stack.
 pub(super) struct Address;
 
@@ -11,6 +11,7 @@ pub mod memory;
 pub(crate) mod mode;
 pub(crate) mod registers;
 pub(crate) mod reservation_set;
+pub(crate) mod vector;
 
 use std::ops::Bound;
 
@@ -399,6 +400,11 @@ impl<MC: memory::MemoryConfig, BCC: BlockCacheConfig, B: Block<MC, M>, M: backen
             InstrUncacheable::CEbreak => run_syscall_instr!(core, run_cebreak),
 
             // Zifencei instructions
+            //
+            // `fence.i` invalidates the block cache, which blocks (compiled or otherwise) cannot
+            // access while they are being run. It therefore remains uncacheable, ending any block:
+            // it is not lowered by the JIT, but run here once the preceding block has returned -
+            // invalidating the block cache exactly as when interpreting.
             InstrUncacheable::FenceI => run_no_args_instr!(self, instr, run_fencei),
         }
     }
@@ -629,8 +635,11 @@ mod tests {
 
     use super::MachineState;
     use super::MachineStateLayout;
+    use super::block_cache::block::Block;
+    use super::block_cache::block::InlineCompiler;
     use super::block_cache::block::Interpreted;
     use super::block_cache::block::InterpretedBlockBuilder;
+    use super::block_cache::block::Jitted;
     use super::instruction::Instruction;
     use super::instruction::OpCode;
     use super::instruction::tagged_instruction::TaggedArgs;
@@ -658,6 +667,7 @@ mod tests {
     use crate::parser::instruction::SplitITypeArgs;
     use crate::parser::parse_block;
     use crate::state_backend::FnManagerIdent;
+    use crate::state_backend::owned_backend::Owned;
     use crate::state_backend::test_helpers::TestBackendFactory;
     use crate::state_backend::test_helpers::assert_eq_struct;
     use crate::state_backend::test_helpers::copy_via_serde;
@@ -1158,4 +1168,65 @@ mod tests {
             );
         }
     });
+    /// `fence.i` is not lowered by the JIT: it remains uncacheable, ending the block before it.
+    /// Blocks modified in memory keep running as cached until `fence.i` is run, after which the
+    /// modified instructions are run - under the JIT as when interpreting.
+    #[test]
+    fn test_fence_i_invalidates_compiled_blocks() {
+        const CODE: [u32; 3] = [
+            0x00150513, // addi a0, a0, 1
+            0x0000100f, // fence.i
+            0xff9ff06f, // j -8
+        ];
+
+        const ADDI_10: u32 = 0x00a50513; // addi a0, a0, 10
+
+        fn run_loop<B: Block<M4K, Owned>>(
+            state: &mut MachineState<M4K, DefaultCacheConfig, B, Owned>,
+            iterations: usize,
+        ) -> u64 {
+            let steps = CODE.len() * iterations;
+            let result = state.step_max(Bound::Included(steps));
+
+            assert_eq!(result.steps, steps);
+            assert_eq!(result.error, None);
+            assert_eq!(state.core.hart.pc.read(), 0x100);
+
+            state.core.hart.xregisters.read(a0)
+        }
+
+        fn run<B: Block<M4K, Owned>>(block_builder: B::BlockBuilder) -> [u64; 3] {
+            let mut state =
+                MachineState::<M4K, DefaultCacheConfig, B, Owned>::new(&mut Owned, block_builder);
+
+            state
+                .core
+                .main_memory
+                .write_instruction_unchecked(0x100, CODE)
+                .unwrap();
+            state.core.hart.pc.write(0x100);
+
+            let before = run_loop(&mut state, 10);
+
+            // The block is modified, but runs as cached until `fence.i` is run.
+            state
+                .core
+                .main_memory
+                .write_instruction_unchecked(0x100, ADDI_10)
+                .unwrap();
+
+            let stale = run_loop(&mut state, 1);
+            let after = run_loop(&mut state, 10);
+
+            [before, stale, after]
+        }
+
+        let interpreted = run::<Interpreted<M4K, Owned>>(InterpretedBlockBuilder);
+        assert_eq!(interpreted, [10, 11, 111]);
+
+        // Blocks are compiled the first time they are run, see `TieringPolicy::eager`.
+        let jitted =
+            run::<Jitted<InlineCompiler<M4K, Owned>, M4K, Owned>>(InlineCompiler::default());
+        assert_eq!(jitted, interpreted);
+    }
 }
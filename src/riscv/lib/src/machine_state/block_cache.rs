@@ -72,6 +72,18 @@
 //! Since we now guarantee that we always execute the _same_ set of instructions,
 //! no matter how many steps are remaining, we solve this possible divergence.
 //!
+//! ## Superblocks
+//!
+//! Every block returns to the dispatch loop once run, even when the block at the resulting
+//! program counter is compiled too. Compiled blocks cannot tail-call one another, as they share
+//! the C calling convention of the interpreted dispatch functions, for which Cranelift does not
+//! support tail calls.
+//!
+//! Instead, the path taken from a hot compiled block may be compiled into a
+//! [`superblock::Superblock`], which is run in place of that block. Superblocks are only run when
+//! their whole path can be run in full, and run exactly the blocks the dispatch loop would have run
+//! one by one - so again the same instructions are executed.
+//!
 //! # Dispatch
 //!
 //! The method of dispatch for Blocks can be one of several mechanisms, the current
@@ -83,12 +95,18 @@ pub mod block;
 mod config;
 pub mod metrics;
 mod state;
+pub mod superblock;
 
 use std::marker::PhantomData;
 
 use self::block::Block;
 pub use self::config::DefaultCacheConfig;
 pub use self::config::TestCacheConfig;
+use self::superblock::MAX_SUPERBLOCK_BLOCKS;
+use self::superblock::Member;
+use self::superblock::Recording;
+use self::superblock::Superblock;
+use self::superblock::TraceBlock;
 use super::MachineCoreState;
 use super::ProgramCounterUpdate;
 use super::StepManyResult;
@@ -96,6 +114,7 @@ use super::instruction::Instruction;
 use super::instruction::RunInstr;
 use super::memory::Address;
 use super::memory::MemoryConfig;
+use crate::cache_utils::FenceCounter;
 use crate::machine_state::instruction::Args;
 use crate::state_backend;
 use crate::state_backend::AllocatedOf;
@@ -172,16 +191,28 @@ impl<'a, MC: MemoryConfig, M: ManagerReadWrite> From<&'a Instruction> for ICall<
 /// As a result, before starting to run blocks from the block cache, you must first ensure that
 /// any left-over partially-run block is cleared up with [`BlockCache::complete_current_block`].
 pub struct BlockCall<'a, B: Block<MC, M>, MC: MemoryConfig, M: ManagerBase> {
-    entry: &'a mut state::Cached<MC, B, M>,
+    /// All entries of the block cache, to run superblocks along paths between blocks.
+    entries: &'a mut [state::Cached<MC, B, M>],
+    /// Index of the entry in `entries` that holds the block to run.
+    index: usize,
+    /// Compute the index of the entry in `entries` that may hold the block at a given address.
+    cache_index: fn(Address) -> usize,
+    /// Fence counter of the block cache, used to check blocks along a path are still valid.
+    fence_counter: FenceCounter,
     /// # Safety
     ///
     /// The same block builder must always be passed through to `run_block`.
     builder: &'a mut B::BlockBuilder,
     partial: &'a mut state::PartialBlock<M>,
+    /// Path being recorded to form a superblock, if any.
+    recording: &'a mut Option<Recording>,
 }
 
 impl<B: Block<MC, M>, MC: MemoryConfig, M: ManagerReadWrite> BlockCall<'_, B, MC, M> {
     /// Run a block, either fully or partially, depending on the number of steps remaining.
+    ///
+    /// Blocks heading a [`Superblock`] that can be run in full are replaced by the superblock.
+    /// The path taken from hot compiled blocks is recorded across calls, to form new superblocks.
     #[inline(always)]
     pub fn run_block(
         &mut self,
@@ -189,12 +220,169 @@ impl<B: Block<MC, M>, MC: MemoryConfig, M: ManagerReadWrite> BlockCall<'_, B, MC
         instr_pc: Address,
         max_steps: usize,
     ) -> StepManyResult<EnvironException> {
-        if self.entry.block.num_instr() <= max_steps {
-            // Safety: the same block builder is passed through every time.
-            unsafe { self.entry.block.run_block(core, instr_pc, self.builder) }
+        if let Some(recording) = self.recording.take() {
+            if self.continues_recording(&recording, instr_pc, max_steps) {
+                return self.run_recorded(recording.path, core, instr_pc);
+            }
+
+            self.form_superblock(recording.path);
+        }
+
+        let entry = &mut self.entries[self.index];
+
+        if entry.block.num_instr() > max_steps {
+            return self.partial.run_block_partial(core, max_steps, entry);
+        }
+
+        if let Some(superblock) = self.runnable_superblock(max_steps) {
+            // Safety: the superblock was compiled by the same block builder.
+            return unsafe { superblock.run(core, instr_pc) };
+        }
+
+        if self.entries[self.index].should_record_superblock(self.builder) {
+            return self.run_recorded(Vec::with_capacity(MAX_SUPERBLOCK_BLOCKS), core, instr_pc);
+        }
+
+        self.run_entry(core, instr_pc)
+    }
+
+    /// Whether the path being recorded continues with the block to run.
+    ///
+    /// The path ends once long enough, on returning to a block already along it, or when the
+    /// block to run is not the one run straight after the path, is not compiled, or cannot be run
+    /// in full.
+    #[inline(always)]
+    fn continues_recording(
+        &self,
+        recording: &Recording,
+        instr_pc: Address,
+        max_steps: usize,
+    ) -> bool {
+        let entry = &self.entries[self.index];
+
+        recording.exit_pc == instr_pc
+            && recording.path.len() < MAX_SUPERBLOCK_BLOCKS
+            && !recording.path.contains(&instr_pc)
+            && entry.block.is_compiled()
+            && entry.block.num_instr() <= max_steps
+    }
+
+    /// Run the block in full, adding it to the path being recorded. Superblocks are not run
+    /// whilst recording, so that the path is recorded block by block.
+    #[inline(always)]
+    fn run_recorded(
+        &mut self,
+        mut path: Vec<Address>,
+        core: &mut MachineCoreState<MC, M>,
+        instr_pc: Address,
+    ) -> StepManyResult<EnvironException> {
+        path.push(instr_pc);
+
+        let result = self.run_entry(core, instr_pc);
+
+        if result.error.is_some() {
+            self.form_superblock(path);
         } else {
-            self.partial.run_block_partial(core, max_steps, self.entry)
+            *self.recording = Some(Recording {
+                path,
+                exit_pc: core.hart.pc.read(),
+            });
         }
+
+        result
+    }
+
+    /// Run the block on its own.
+    #[inline(always)]
+    fn run_entry(
+        &mut self,
+        core: &mut MachineCoreState<MC, M>,
+        instr_pc: Address,
+    ) -> StepManyResult<EnvironException> {
+        // Safety: the same block builder is passed through every time.
+        unsafe {
+            self.entries[self.index]
+                .block
+                .run_block(core, instr_pc, self.builder)
+        }
+    }
+
+    /// The superblock headed by the block to run, if it can be run in full with the given number
+    /// of steps.
+    ///
+    /// Superblocks whose path no longer matches the blocks in the cache, or whose compiled
+    /// function has been evicted from the JIT, are discarded.
+    #[inline(always)]
+    fn runnable_superblock(&mut self, max_steps: usize) -> Option<&Superblock<MC, M>> {
+        let superblock = self.entries[self.index].superblock()?;
+
+        if superblock.is_evicted() {
+            self.entries[self.index].discard_superblock();
+            return None;
+        }
+
+        if !superblock.is_compiled() || superblock.num_instr() > max_steps {
+            return None;
+        }
+
+        let path_unchanged = superblock.members().iter().all(|member| {
+            self.entries[(self.cache_index)(member.address)].holds(member, self.fence_counter)
+        });
+
+        if !path_unchanged {
+            self.entries[self.index].discard_superblock();
+            return None;
+        }
+
+        self.entries[self.index].superblock()
+    }
+
+    /// Form a superblock from the path recorded, headed by the first block along it.
+    #[cold]
+    fn form_superblock(&mut self, path: Vec<Address>) {
+        // A single block gains nothing from being compiled as a superblock.
+        if path.len() < 2 {
+            return;
+        }
+
+        let mut members = Vec::with_capacity(path.len() - 1);
+        let mut trace = Vec::with_capacity(path.len());
+
+        for &address in path.iter() {
+            let entry = &self.entries[(self.cache_index)(address)];
+            let member = Member {
+                address,
+                generation: entry.generation(),
+            };
+
+            // Running blocks does not modify the block cache, but err on the side of caution.
+            if !entry.holds(&member, self.fence_counter) {
+                return;
+            }
+
+            let instr = entry
+                .block
+                .instr()
+                .iter()
+                .map(|i| i.read_stored())
+                .collect();
+            trace.push(TraceBlock { address, instr });
+            members.push(member);
+        }
+
+        // The head is not a member - the superblock is discarded with it.
+        members.remove(0);
+
+        let head = (self.cache_index)(path[0]);
+        self.entries[head].form_superblock(members, trace, self.builder);
+    }
+}
+
+#[cfg(test)]
+impl<B: Block<MC, M>, MC: MemoryConfig, M: ManagerBase> BlockCall<'_, B, MC, M> {
+    /// *TEST ONLY* - retrieve the entry holding the block to run.
+    fn entry(&mut self) -> &mut state::Cached<MC, B, M> {
+        &mut self.entries[self.index]
     }
 }
 
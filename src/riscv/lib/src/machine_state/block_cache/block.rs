@@ -8,6 +8,7 @@
 pub(crate) mod dispatch;
 mod interpreted;
 mod jitted;
+mod tiering;
 
 pub use dispatch::DispatchFn;
 pub use dispatch::InlineCompiler;
@@ -15,10 +16,15 @@ pub use dispatch::OutlineCompiler;
 pub use interpreted::Interpreted;
 pub use interpreted::InterpretedBlockBuilder;
 pub use jitted::Jitted;
+pub use tiering::DEFAULT_THRESHOLD;
+pub use tiering::TieringMode;
+pub use tiering::TieringPolicy;
 
 use super::CACHE_INSTR;
 use super::ICallPlaced;
 use super::run_instr;
+use super::superblock::Superblock;
+use super::superblock::TraceBlock;
 use crate::machine_state::MachineCoreState;
 use crate::machine_state::ProgramCounterUpdate;
 use crate::machine_state::StepManyResult;
@@ -78,6 +84,28 @@ pub trait Block<MC: MemoryConfig, M: ManagerBase>: NewState<M> {
     where
         M: ManagerRead;
 
+    /// Whether the block has been compiled to native code.
+    ///
+    /// Only paths between compiled blocks are recorded to form superblocks - see
+    /// [`BlockCall::run_block`].
+    ///
+    /// [`BlockCall::run_block`]: super::BlockCall::run_block
+    fn is_compiled(&self) -> bool;
+
+    /// Whether the path taken from a compiled block, that has been run `runs` times, should be
+    /// recorded to form a [`Superblock`] headed by it.
+    fn should_record_superblock(block_builder: &mut Self::BlockBuilder, runs: usize) -> bool;
+
+    /// Compile a superblock from the blocks along its path.
+    ///
+    /// The compiled function is stored in the [`Superblock::target`], which may happen after
+    /// this call returns.
+    fn compile_superblock(
+        block_builder: &mut Self::BlockBuilder,
+        superblock: &Superblock<MC, M>,
+        trace: Vec<TraceBlock>,
+    );
+
     /// Invalidate a block, meaning it should no longer be run.
     fn invalidate(&mut self)
     where
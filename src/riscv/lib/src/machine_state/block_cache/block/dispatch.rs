@@ -8,23 +8,38 @@
 //! Currently, this is only 'inline' jit, but will soon be expanded to 'outline' jit also;
 //! where 'outline' means any JIT compilation occurs in a separate thread.
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::num::NonZeroUsize;
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::sync::Weak;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::mpsc;
+use std::sync::mpsc::Receiver;
 use std::sync::mpsc::Sender;
 
 use super::Jitted;
+use super::TieringPolicy;
+use super::tiering::Decay;
+use super::tiering::Tiering;
 use crate::jit::JIT;
+use crate::jit::JitError;
 use crate::jit::JitFn;
+use crate::jit::eviction::Evicted;
 use crate::jit::state_access::JitStateAccess;
 use crate::machine_state::MachineCoreState;
+#[cfg(feature = "metrics")]
+use crate::machine_state::block_cache::metrics::block_metrics;
+use crate::machine_state::block_cache::superblock::Superblock;
+use crate::machine_state::block_cache::superblock::TraceBlock;
 use crate::machine_state::instruction::Instruction;
 use crate::machine_state::memory::Address;
 use crate::machine_state::memory::MemoryConfig;
 use crate::state_backend::ManagerBase;
+use crate::state_backend::hash::Hash;
 use crate::traps::EnvironException;
 
 /// The function signature for dispatching a block run.
@@ -55,7 +70,18 @@ pub struct DispatchTarget<D: DispatchCompiler<MC, M>, MC: MemoryConfig, M: Manag
     /// See <https://doc.rust-lang.org/std/primitive.fn.html#casting-to-and-from-integers> for
     /// considerations taken whilst converting pointer <--> usize.
     fun: Arc<AtomicUsize>,
-    remaining_calls: usize,
+
+    /// Number of calls to the block since it was last reset, whilst not compiled.
+    ///
+    /// See [`TieringPolicy`].
+    pub(super) calls: usize,
+
+    /// Decay epoch at which `calls` was last updated.
+    pub(super) epoch: u64,
+
+    /// Hash of the instructions of the block, once computed. See [`DispatchTarget::instr_hash`].
+    hash: Option<Hash>,
+
     _pd: PhantomData<(D, MC, M)>,
 }
 
@@ -71,7 +97,19 @@ impl<D: DispatchCompiler<MC, M>, MC: MemoryConfig, M: JitStateAccess> DispatchTa
             Jitted::<D, MC, M>::run_block_interpreted as usize,
         ));
 
-        self.remaining_calls = 1000;
+        self.calls = 0;
+        self.epoch = 0;
+        self.hash = None;
+    }
+
+    /// Hash of the instructions of the block, which is only computed once until the target is
+    /// reset. Compiled code of the block may be evicted and compiled again meanwhile.
+    pub(super) fn instr_hash(&mut self, instr: &[Instruction]) -> Option<Hash> {
+        if self.hash.is_none() {
+            self.hash = Hash::blake2b_hash(instr).ok();
+        }
+
+        self.hash
     }
 
     /// Set the dispatch target to use the given `block_run` function.
@@ -83,6 +121,14 @@ impl<D: DispatchCompiler<MC, M>, MC: MemoryConfig, M: JitStateAccess> DispatchTa
         self.fun.store(fun, Ordering::Release);
     }
 
+    /// Whether the dispatch target's current `block_run` function is a JIT-compiled block.
+    pub fn is_compiled(&self) -> bool {
+        let fun = self.fun.load(Ordering::Acquire);
+
+        fun != Jitted::<D, MC, M>::run_block_interpreted as usize
+            && fun != Jitted::<D, MC, M>::run_block_not_compiled as usize
+    }
+
     /// Get the dispatch target's current `block_run` function.
     pub fn get(&self) -> DispatchFn<D, MC, M> {
         // load using Acquire ordering - so that it will see the previous store which was with
@@ -105,7 +151,9 @@ impl<D: DispatchCompiler<MC, M>, MC: MemoryConfig, M: JitStateAccess> Default
             fun: Arc::new(AtomicUsize::new(
                 Jitted::<D, MC, M>::run_block_interpreted as usize,
             )),
-            remaining_calls: 1000,
+            calls: 0,
+            epoch: 0,
+            hash: None,
             _pd: PhantomData,
         }
     }
@@ -115,7 +163,7 @@ impl<D: DispatchCompiler<MC, M>, MC: MemoryConfig, M: JitStateAccess> Default
 /// said block in the given dispatch target.
 pub trait DispatchCompiler<MC: MemoryConfig, M: ManagerBase>: Default + Sized {
     /// Whether compilation should be attempted for the block.
-    fn should_compile(&self, target: &mut DispatchTarget<Self, MC, M>) -> bool;
+    fn should_compile(&mut self, target: &mut DispatchTarget<Self, MC, M>) -> bool;
 
     /// Compile a block, hot-swapping the `run_block` function contained in `target` in
     /// the process. This could be to an interpreted execution method, and/or jit-compiled
@@ -131,11 +179,27 @@ pub trait DispatchCompiler<MC: MemoryConfig, M: ManagerBase>: Default + Sized {
     ) -> DispatchFn<Self, MC, M>
     where
         M: JitStateAccess;
+
+    /// Whether the path taken from a compiled block, that has been run `runs` times, should be
+    /// recorded to form a superblock. See [`TieringPolicy::superblock_threshold`].
+    fn should_record_superblock(&mut self, runs: usize) -> bool;
+
+    /// Compile a superblock from the blocks along its path, storing the compiled function in its
+    /// [`Superblock::target`].
+    ///
+    /// As with [`DispatchCompiler::compile`], this may occur after this function returns.
+    fn compile_superblock(&mut self, superblock: &Superblock<MC, M>, trace: Vec<TraceBlock>)
+    where
+        M: JitStateAccess;
 }
 
 /// JIT compiler for blocks that performs compilation inline, in the same thread as execution.
+///
+/// By default, every block is compiled the first time it is called. See [`TieringPolicy::eager`].
 pub struct InlineCompiler<MC: MemoryConfig, M: ManagerBase> {
     jit: JIT<MC, M::ManagerRoot>,
+    tiering: Tiering,
+    compiled: CompiledTargets,
 }
 
 impl<MC: MemoryConfig, M: ManagerBase> Default for InlineCompiler<MC, M>
@@ -143,10 +207,40 @@ where
     M::ManagerRoot: JitStateAccess,
 {
     fn default() -> Self {
+        Self::with_jit(JIT::default())
+    }
+}
+
+impl<MC: MemoryConfig, M: ManagerBase> InlineCompiler<MC, M>
+where
+    M::ManagerRoot: JitStateAccess,
+{
+    /// Create a compiler that persists compiled code under the given directory.
+    ///
+    /// See [`JIT::with_code_cache`].
+    pub fn with_code_cache(dir: impl AsRef<Path>) -> Result<Self, JitError> {
+        Ok(Self::with_jit(JIT::with_code_cache(dir)?))
+    }
+
+    /// Create a compiler that compiles blocks with the given JIT.
+    pub fn with_jit(jit: JIT<MC, M::ManagerRoot>) -> Self {
         Self {
-            jit: JIT::default(),
+            jit,
+            tiering: Tiering::new(TieringPolicy::eager()),
+            compiled: CompiledTargets::default(),
         }
     }
+
+    /// Set the policy deciding when blocks are compiled.
+    pub fn set_tiering(&mut self, policy: TieringPolicy) {
+        self.tiering.set_policy(policy);
+    }
+
+    /// Make every compilation fail during codegen, see [`JIT::inject_codegen_failures`].
+    #[cfg(test)]
+    pub(crate) fn inject_codegen_failures(&mut self) {
+        self.jit.inject_codegen_failures();
+    }
 }
 
 impl<MC: MemoryConfig, M: ManagerBase> DispatchCompiler<MC, M> for InlineCompiler<MC, M>
@@ -154,10 +248,8 @@ where
     M::ManagerRoot: JitStateAccess,
 {
     #[inline]
-    fn should_compile(&self, _target: &mut DispatchTarget<Self, MC, M>) -> bool {
-        // every block must be compiled immediately for inline jit, as it's used for testing
-        // jit compatibility with interpreted mode.
-        true
+    fn should_compile(&mut self, target: &mut DispatchTarget<Self, MC, M>) -> bool {
+        self.tiering.record_call(target)
     }
 
     fn compile(
@@ -168,8 +260,20 @@ where
     where
         M: JitStateAccess,
     {
+        if !self.tiering.may_compile(target, &instr) {
+            let fun = Jitted::run_block_not_compiled;
+            target.set(fun);
+            return fun;
+        }
+
         let fun = match self.jit.compile(&instr) {
             Some(jitfn) => {
+                self.compiled.record(
+                    &target.fun,
+                    jitfn as usize,
+                    Jitted::<Self, MC, M>::run_block_interpreted as usize,
+                );
+
                 // Safety: the two function signatures are identical, apart from the first and
                 // last parameters. These are both thin-pointers, and ignored by the JitFn.
                 //
@@ -185,9 +289,122 @@ where
         };
 
         target.set(fun);
+        self.apply_evictions();
 
         fun
     }
+
+    fn should_record_superblock(&mut self, runs: usize) -> bool {
+        self.tiering.should_record_superblock(runs)
+    }
+
+    fn compile_superblock(&mut self, superblock: &Superblock<MC, M>, trace: Vec<TraceBlock>)
+    where
+        M: JitStateAccess,
+    {
+        if let Some(jitfn) = self.jit.compile_trace(&trace) {
+            superblock.target().store(jitfn as usize, Ordering::Release);
+            self.compiled.record(
+                superblock.target(),
+                jitfn as usize,
+                Superblock::<MC, M>::EVICTED,
+            );
+        }
+
+        self.apply_evictions();
+    }
+}
+
+impl<MC: MemoryConfig, M: ManagerBase> InlineCompiler<MC, M>
+where
+    M::ManagerRoot: JitStateAccess,
+{
+    /// Reset the dispatch targets of functions evicted from the JIT, and free their code.
+    fn apply_evictions(&mut self) {
+        for eviction in self.compiled.take_evicted(&mut self.jit) {
+            // Safety: compilation happens on the execution thread, between runs of blocks.
+            unsafe { eviction.apply() }
+        }
+    }
+}
+
+/// Dispatch targets set to JIT-compiled functions, so that they can be reset once their
+/// function is evicted from the [`JIT`]. See [`crate::jit::eviction`].
+#[derive(Default)]
+struct CompiledTargets {
+    /// Targets by the address of the function they were set to, along with the value to reset
+    /// them to.
+    targets: HashMap<usize, Vec<(Weak<AtomicUsize>, usize)>>,
+}
+
+impl CompiledTargets {
+    /// Record that `target` has been set to the compiled function `fun`. Once the function is
+    /// evicted, the target is reset to `reset`.
+    fn record(&mut self, target: &Arc<AtomicUsize>, fun: usize, reset: usize) {
+        self.targets
+            .entry(fun)
+            .or_default()
+            .push((Arc::downgrade(target), reset));
+    }
+
+    /// Take the functions evicted from the JIT, along with the targets set to them.
+    fn take_evicted<MC: MemoryConfig, M: JitStateAccess>(
+        &mut self,
+        jit: &mut JIT<MC, M>,
+    ) -> Vec<Eviction> {
+        jit.take_evicted()
+            .into_iter()
+            .map(|evicted| {
+                let targets = evicted
+                    .functions()
+                    .iter()
+                    .flat_map(|&(_, fun)| {
+                        self.targets
+                            .remove(&fun)
+                            .into_iter()
+                            .flatten()
+                            .map(move |(target, reset)| (target, fun, reset))
+                    })
+                    .collect();
+
+                Eviction { evicted, targets }
+            })
+            .collect()
+    }
+}
+
+/// Functions evicted from a [`JIT`], along with the dispatch targets set to them.
+struct Eviction {
+    evicted: Evicted,
+
+    /// Targets, the evicted function they were set to, and the value to reset them to.
+    targets: Vec<(Weak<AtomicUsize>, usize, usize)>,
+}
+
+impl Eviction {
+    /// Reset the dispatch targets still set to evicted functions, then free their code.
+    ///
+    /// # Safety
+    ///
+    /// This must be called from the execution thread, whilst no JIT-compiled function is
+    /// running.
+    unsafe fn apply(self) {
+        for (target, fun, reset) in self.targets {
+            if let Some(target) = target.upgrade() {
+                // The target may since have been set to a different function.
+                let _ = target.compare_exchange(fun, reset, Ordering::AcqRel, Ordering::Acquire);
+            }
+        }
+
+        #[cfg(feature = "metrics")]
+        for (hash, _) in self.evicted.functions() {
+            block_metrics!(hash = hash, record_evicted);
+        }
+
+        // Safety: no dispatch target refers to the evicted functions anymore, and none of them
+        // are running.
+        unsafe { self.evicted.free() }
+    }
 }
 
 /// Unsafe Rust escape hatches
@@ -200,6 +417,13 @@ mod internal_corro {
     }
 
     impl<T> SendWrapper<T> {
+        /// Wrap a value, to make it `Send`.
+        pub(super) fn new(value: T) -> Self {
+            Self {
+                _no_please_no: value,
+            }
+        }
+
         /// Obtain a mutable reference to the inner value.
         ///
         /// # Safety
@@ -209,51 +433,181 @@ mod internal_corro {
         pub(super) unsafe fn as_mut(&mut self) -> &mut T {
             &mut self._no_please_no
         }
+
+        /// Obtain the inner value.
+        ///
+        /// # Safety
+        ///
+        /// Ensure that this is only called from the thread the [`SendWrapper`] was handed over to.
+        /// The thread that wrapped the value must no longer use it.
+        pub(super) unsafe fn into_inner(self) -> T {
+            self._no_please_no
+        }
     }
 
     // We know that the main thread does not actually use the JIT compilation state. The only thing it
-    // may do is drop it when it is the only owner left.
+    // may do is drop it when it is the only owner left - or free the code of evicted functions,
+    // once handed over by the compiler thread.
     unsafe impl<T> Send for SendWrapper<T> {}
 }
 
-/// JIT compiler for blocks that performs compilation in a
-/// background thread.
+/// JIT compiler for blocks that performs compilation in a pool of
+/// background threads.
+///
+/// Each thread has its own [`JIT`], and therefore its own Cranelift context & module. Blocks are
+/// hot-swapped into their [`DispatchTarget`] by whichever thread compiled them.
+///
+/// Requests are routed to a thread by the hash of what is compiled. Identical blocks are thus
+/// always compiled by the same thread, whose [`JIT`] reuses the function it compiled first rather
+/// than compiling it again - and counting it twice against its code budget.
 pub struct OutlineCompiler<MC: MemoryConfig, M: ManagerBase> {
-    // We will not touch the jit from the execution thread, however we must maintain
-    // a reference to it - to ensure it is not dropped before we are done with execution,
-    // even if the background compilation thread panics.
-    _do_not_use_this_is_for_drop_only: Arc<Mutex<internal_corro::SendWrapper<JIT<MC, M>>>>,
-    sender: Sender<CompilationRequest>,
+    // We will not touch the jits from the execution thread, however we must maintain
+    // a reference to them - to ensure they are not dropped before we are done with execution,
+    // even if a background compilation thread panics.
+    _do_not_use_this_is_for_drop_only: Vec<Arc<Mutex<internal_corro::SendWrapper<JIT<MC, M>>>>>,
+
+    /// Queues of the compiler threads, see [`OutlineCompiler::send`].
+    senders: Vec<Sender<CompilationRequest>>,
+    tiering: Tiering,
+
+    /// Functions evicted by the compiler threads, to be applied on the execution thread.
+    evictions: Receiver<internal_corro::SendWrapper<Eviction>>,
 }
 
 impl<MC: MemoryConfig + Send, M: JitStateAccess + Send + 'static> OutlineCompiler<MC, M> {
-    fn new() -> Self {
-        let (sender, receiver) = mpsc::channel();
-        let jit: Arc<Mutex<internal_corro::SendWrapper<JIT<MC, M>>>> = Default::default();
+    /// Maximum number of compiler threads used by default.
+    const MAX_DEFAULT_THREADS: usize = 4;
 
-        let compiler = Self {
-            _do_not_use_this_is_for_drop_only: jit.clone(),
-            sender,
-        };
+    /// Number of compiler threads used by default: one per available core, leaving one core
+    /// for execution.
+    fn default_threads() -> NonZeroUsize {
+        let threads = std::thread::available_parallelism()
+            .map_or(1, |cores| cores.get().saturating_sub(1))
+            .clamp(1, Self::MAX_DEFAULT_THREADS);
+
+        NonZeroUsize::new(threads).unwrap_or(NonZeroUsize::MIN)
+    }
 
-        std::thread::spawn(move || {
-            {
+    /// Create a compiler with the given number of compiler threads.
+    pub fn with_threads(threads: NonZeroUsize) -> Self {
+        Self::with_jits((0..threads.get()).map(|_| JIT::default()).collect())
+    }
+
+    /// Create a compiler that persists compiled code under the given directory.
+    ///
+    /// See [`JIT::with_code_cache`].
+    pub fn with_code_cache(dir: impl AsRef<Path>) -> Result<Self, JitError> {
+        Self::with_threads_and_code_cache(Self::default_threads(), dir)
+    }
+
+    /// Create a compiler with the given number of compiler threads, that persists compiled code
+    /// under the given directory.
+    ///
+    /// See [`JIT::with_code_cache`].
+    pub fn with_threads_and_code_cache(
+        threads: NonZeroUsize,
+        dir: impl AsRef<Path>,
+    ) -> Result<Self, JitError> {
+        Self::with_jit_factory(Some(threads), || JIT::with_code_cache(dir.as_ref()))
+    }
+
+    /// Create a compiler with the given number of compiler threads - or the default number if
+    /// `None` - each compiling blocks with a JIT created by `make_jit`.
+    pub fn with_jit_factory(
+        threads: Option<NonZeroUsize>,
+        make_jit: impl FnMut() -> Result<JIT<MC, M>, JitError>,
+    ) -> Result<Self, JitError> {
+        let threads = threads.unwrap_or_else(Self::default_threads);
+        let jits = std::iter::repeat_with(make_jit)
+            .take(threads.get())
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self::with_jits(jits))
+    }
+
+    /// Set the policy deciding when blocks are compiled.
+    pub fn set_tiering(&mut self, policy: TieringPolicy) {
+        self.tiering.set_policy(policy);
+    }
+
+    /// Spawn a compiler thread for each of the given JITs.
+    fn with_jits(jits: Vec<JIT<MC, M>>) -> Self {
+        let (evictions_sender, evictions) = mpsc::channel();
+        let tiering = Tiering::new(TieringPolicy::default());
+
+        let jits = jits
+            .into_iter()
+            .map(|jit| Arc::new(Mutex::new(internal_corro::SendWrapper::new(jit))))
+            .collect::<Vec<_>>();
+
+        let mut senders = Vec::with_capacity(jits.len());
+
+        for jit in jits.iter().cloned() {
+            let (sender, receiver) = mpsc::channel::<CompilationRequest>();
+            senders.push(sender);
+
+            let queued = tiering.queued().clone();
+            let evictions_sender = evictions_sender.clone();
+
+            std::thread::spawn(move || {
                 let mut jit_guard = jit.lock().expect("Only this thread locks the JIT");
 
-                // SAFETY: We are the only thread that may access the JIT compilation state.
+                // SAFETY: We are the only thread that may access this JIT's compilation state.
                 let jit = unsafe { jit_guard.as_mut() };
 
-                while let Ok(msg) = receiver.recv() {
-                    if let Some(jitfn) = jit.compile(&msg.instr) {
+                let mut compiled = CompiledTargets::default();
+
+                loop {
+                    // because we used blocking recv with an asynchronous channel, this only fails
+                    // when the other end of the channel has been dropped.
+                    //
+                    // This means the BlockBuilder has been dropped - and thus execution has
+                    // stopped. We are therefore safe to drop the JIT.
+                    let Ok(msg) = receiver.recv() else {
+                        break;
+                    };
+
+                    queued.fetch_sub(1, Ordering::AcqRel);
+
+                    if msg.is_discarded() {
+                        continue;
+                    }
+
+                    if msg.is_cold() {
+                        // Hand the block back to the interpreter, which counts its calls again.
+                        // The execution thread only replaces the target by resetting it, which
+                        // would have discarded the request.
+                        let _ = msg.fun.compare_exchange(
+                            Jitted::<Self, MC, M>::run_block_not_compiled as usize,
+                            Jitted::<Self, MC, M>::run_block_interpreted as usize,
+                            Ordering::AcqRel,
+                            Ordering::Acquire,
+                        );
+                        continue;
+                    }
+
+                    let (jitfn, not_compiled, evicted) = match &msg.unit {
+                        CompilationUnit::Block(instr) => (
+                            jit.compile(instr),
+                            Jitted::<Self, MC, M>::run_block_not_compiled as usize,
+                            Jitted::<Self, MC, M>::run_block_interpreted as usize,
+                        ),
+                        CompilationUnit::Superblock(trace) => {
+                            (jit.compile_trace(trace), 0, Superblock::<MC, M>::EVICTED)
+                        }
+                    };
+
+                    if let Some(jitfn) = jitfn {
                         debug_assert_eq!(
                             msg.fun.load(Ordering::Acquire),
-                            Jitted::<Self, MC, M>::run_block_not_compiled as usize,
+                            not_compiled,
                             "Unexpected function pointer in dispatch target"
                         );
 
-                        // Safety: this function will be retrieved as a DispatchFn, rather than a
-                        // JitFn. The two function signatures are identical, apart from the first and
-                        // last parameters. These are both thin-pointers, and ignored by the JitFn.
+                        // Safety: for blocks, this function will be retrieved as a DispatchFn,
+                        // rather than a JitFn. The two function signatures are identical, apart
+                        // from the first and last parameters. These are both thin-pointers, and
+                        // ignored by the JitFn.
                         //
                         // It's therefore safe to cast this function pointer to an identical ABI, where
                         // this first and last parameter are thin-references to any value. This is the
@@ -262,17 +616,24 @@ impl<MC: MemoryConfig + Send, M: JitStateAccess + Send + 'static> OutlineCompile
                         // See <https://doc.rust-lang.org/std/primitive.fn.html#abi-compatibility> for more
                         // information on ABI compatability.
                         msg.fun.store(jitfn as usize, Ordering::Release);
+                        compiled.record(&msg.fun, jitfn as usize, evicted);
                     };
+
+                    // The code of evicted functions is freed by the execution thread, once it
+                    // has reset the dispatch targets set to them.
+                    for eviction in compiled.take_evicted(jit) {
+                        let _ = evictions_sender.send(internal_corro::SendWrapper::new(eviction));
+                    }
                 }
-            }
-            // because we used blocking recv with an asynchronous channel, this only fails when the
-            // other end of the channel has been dropped.
-            //
-            // This means the BlockBuilder has been dropped - and thus execution has stopped.
-            // We are therefore safe to drop the JIT.
-        });
+            });
+        }
 
-        compiler
+        Self {
+            _do_not_use_this_is_for_drop_only: jits,
+            senders,
+            tiering,
+            evictions,
+        }
     }
 }
 
@@ -280,24 +641,16 @@ impl<MC: MemoryConfig + Send, M: JitStateAccess + Send + 'static> Default
     for OutlineCompiler<MC, M>
 {
     fn default() -> Self {
-        Self::new()
+        Self::with_threads(Self::default_threads())
     }
 }
 
 impl<MC: MemoryConfig + Send, M: JitStateAccess + Send + 'static> DispatchCompiler<MC, M>
     for OutlineCompiler<MC, M>
 {
-    fn should_compile(&self, target: &mut DispatchTarget<Self, MC, M>) -> bool {
-        unsafe {
-            match target.remaining_calls {
-                0 => true,
-                _ => {
-                    //SAFETY: `remaining_calls` (a usize) can only be a positive, non-zero integer
-                    target.remaining_calls = target.remaining_calls.unchecked_sub(1);
-                    false
-                }
-            }
-        }
+    fn should_compile(&mut self, target: &mut DispatchTarget<Self, MC, M>) -> bool {
+        self.apply_evictions();
+        self.tiering.record_call(target)
     }
 
     fn compile(
@@ -308,27 +661,191 @@ impl<MC: MemoryConfig + Send, M: JitStateAccess + Send + 'static> DispatchCompil
         let fun = Jitted::run_block_not_compiled;
         target.set(fun);
 
-        let request = CompilationRequest {
-            instr,
+        if !self.tiering.may_compile(target, &instr) {
+            return fun;
+        }
+
+        // Blocks whose instructions cannot be hashed are not compiled by the JIT either.
+        let Some(hash) = target.instr_hash(&instr) else {
+            return fun;
+        };
+
+        let decay = self.tiering.decay(target);
+        self.send(hash, CompilationRequest {
+            unit: CompilationUnit::Block(instr),
             fun: target.fun.clone(),
+            decay,
+        });
+
+        fun
+    }
+
+    fn should_record_superblock(&mut self, runs: usize) -> bool {
+        self.tiering.should_record_superblock(runs)
+    }
+
+    fn compile_superblock(&mut self, superblock: &Superblock<MC, M>, trace: Vec<TraceBlock>) {
+        let Ok(hash) = Hash::blake2b_hash(&trace) else {
+            return;
         };
 
+        self.send(hash, CompilationRequest {
+            unit: CompilationUnit::Superblock(trace),
+            fun: superblock.target().clone(),
+            decay: None,
+        });
+    }
+}
+
+impl<MC: MemoryConfig, M: ManagerBase> OutlineCompiler<MC, M> {
+    /// Reset the dispatch targets of functions evicted by the compiler threads, and free their
+    /// code.
+    ///
+    /// This happens whenever an uncompiled block is called, or compilation is requested. Until
+    /// then, evicted code remains in memory.
+    fn apply_evictions(&mut self) {
+        for eviction in self.evictions.try_iter() {
+            // Safety: the compiler thread handed the eviction over, and no longer uses it.
+            let eviction = unsafe { eviction.into_inner() };
+
+            // Safety: this is only called from the execution thread, between runs of blocks.
+            unsafe { eviction.apply() }
+        }
+    }
+
+    /// Queue a request for compilation by the background thread that compiles everything with
+    /// the given hash.
+    fn send(&mut self, hash: Hash, request: CompilationRequest) {
+        self.apply_evictions();
+
+        let mut prefix = [0; 8];
+        prefix.copy_from_slice(&hash.as_ref()[..prefix.len()]);
+        let thread = u64::from_le_bytes(prefix) % self.senders.len() as u64;
+
         // This will always succeed, unless the compilation thread has panicked
         // (as this would result in the receiving end of the channel being closed).
         //
-        // If it has, execution must still continue - but everything will fallback
-        // to interpreted mode.
+        // If it has, execution must still continue - but everything routed to it will
+        // fallback to interpreted mode.
         //
         // NB - any blocks already JIT compiled are safe to keep calling, as the
         // data behind the mutex (the JIT) is kept alive for as long as we maintain
         // our reference to it, despite the lock itself being poisoned.
-        let _ = self.sender.send(request);
-
-        fun
+        self.tiering.queued().fetch_add(1, Ordering::AcqRel);
+        if self.senders[thread as usize].send(request).is_err() {
+            self.tiering.queued().fetch_sub(1, Ordering::AcqRel);
+        }
     }
 }
 
+/// What a [`CompilationRequest`] asks to be compiled.
+enum CompilationUnit {
+    /// The instructions of a single block.
+    Block(Vec<Instruction>),
+
+    /// The blocks along the path of a superblock.
+    Superblock(Vec<TraceBlock>),
+}
+
 struct CompilationRequest {
-    instr: Vec<Instruction>,
+    unit: CompilationUnit,
     fun: Arc<AtomicUsize>,
+
+    /// Call count of the block when compilation was requested. `None` for superblocks, and when
+    /// call counts do not decay.
+    decay: Option<Decay>,
+}
+
+impl CompilationRequest {
+    /// Whether the target has been discarded since compilation was requested.
+    ///
+    /// Resetting a [`DispatchTarget`] replaces its function pointer, leaving the request as the
+    /// only remaining reference to the previous one. Likewise for discarded superblocks.
+    /// Compiling the block would then be wasted work.
+    fn is_discarded(&self) -> bool {
+        Arc::strong_count(&self.fun) == 1
+    }
+
+    /// Whether the call count of the block has decayed since compilation was requested, such
+    /// that it would no longer be compiled. See [`Decay::is_cold`].
+    fn is_cold(&self) -> bool {
+        self.decay.as_ref().is_some_and(Decay::is_cold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+    use std::time::Duration;
+    use std::time::Instant;
+
+    use super::*;
+    use crate::machine_state::memory::M4K;
+    use crate::parser::instruction::InstrWidth;
+    use crate::state_backend::owned_backend::Owned;
+
+    type Compiler = OutlineCompiler<M4K, Owned>;
+    type Target = DispatchTarget<Compiler, M4K, Owned>;
+
+    #[test]
+    fn test_outline_identical_blocks_compiled_once() {
+        let mut compiler = Compiler::with_threads(NonZeroUsize::new(4).unwrap());
+        let instr = vec![Instruction::new_nop(InstrWidth::Compressed); 3];
+
+        let targets = std::iter::repeat_with(|| {
+            let mut target = Target::default();
+            compiler.compile(&mut target, instr.clone());
+            target
+        })
+        .take(8)
+        .collect::<Vec<_>>();
+
+        let deadline = Instant::now() + Duration::from_secs(60);
+        while !targets.iter().all(Target::is_compiled) {
+            assert!(
+                Instant::now() < deadline,
+                "Blocks were not compiled in time"
+            );
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        // All requests were routed to the same thread, which compiled the block only once.
+        let fun = targets[0].fun.load(Ordering::Acquire);
+        for target in targets.iter() {
+            assert_eq!(target.fun.load(Ordering::Acquire), fun);
+        }
+    }
+
+    #[test]
+    fn test_outline_cold_block_not_compiled() {
+        let mut compiler = Compiler::with_threads(NonZeroUsize::MIN);
+        compiler.set_tiering(TieringPolicy {
+            decay_interval: NonZeroU64::new(4),
+            ..TieringPolicy::with_threshold(2)
+        });
+
+        let mut target = Target::default();
+        while !compiler.should_compile(&mut target) {}
+
+        // Calls to another block decay the call count of the first, before it is queued
+        let mut other = Target::default();
+        for _ in 0..8 {
+            compiler.should_compile(&mut other);
+        }
+
+        let instr = vec![Instruction::new_nop(InstrWidth::Compressed); 3];
+        compiler.compile(&mut target, instr);
+
+        // The block is handed back to the interpreter, rather than compiled
+        let interpreted = Jitted::<Compiler, M4K, Owned>::run_block_interpreted as usize;
+        let deadline = Instant::now() + Duration::from_secs(60);
+        while target.fun.load(Ordering::Acquire) != interpreted {
+            assert!(!target.is_compiled(), "Cold block was compiled");
+            assert!(
+                Instant::now() < deadline,
+                "Block was not handed back in time"
+            );
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
 }
@@ -13,6 +13,8 @@ use crate::machine_state::block_cache::ICallPlaced;
 use crate::machine_state::block_cache::block::Block;
 use crate::machine_state::block_cache::block::BlockLayout;
 use crate::machine_state::block_cache::block::run_block_inner;
+use crate::machine_state::block_cache::superblock::Superblock;
+use crate::machine_state::block_cache::superblock::TraceBlock;
 use crate::machine_state::instruction::Instruction;
 use crate::machine_state::memory::Address;
 use crate::machine_state::memory::MemoryConfig;
@@ -68,6 +70,22 @@ impl<MC: MemoryConfig, M: ManagerBase> Block<MC, M> for Interpreted<MC, M> {
         self.len_instr.read() as usize
     }
 
+    fn is_compiled(&self) -> bool {
+        false
+    }
+
+    fn should_record_superblock(_block_builder: &mut Self::BlockBuilder, _runs: usize) -> bool {
+        false
+    }
+
+    fn compile_superblock(
+        _block_builder: &mut Self::BlockBuilder,
+        _superblock: &Superblock<MC, M>,
+        _trace: Vec<TraceBlock>,
+    ) {
+        // Interpreted blocks are never compiled, and so never head a superblock.
+    }
+
     #[inline]
     fn instr(&self) -> &[EnrichedCell<ICallPlaced<MC, M>, M>]
     where
@@ -14,6 +14,8 @@ use crate::machine_state::block_cache::block::BlockLayout;
 use crate::machine_state::block_cache::block::dispatch::DispatchCompiler;
 use crate::machine_state::block_cache::block::dispatch::DispatchTarget;
 use crate::machine_state::block_cache::block::interpreted;
+use crate::machine_state::block_cache::superblock::Superblock;
+use crate::machine_state::block_cache::superblock::TraceBlock;
 use crate::machine_state::instruction::Instruction;
 use crate::machine_state::memory::Address;
 use crate::machine_state::memory::MemoryConfig;
@@ -216,6 +218,22 @@ impl<D: DispatchCompiler<MC, M>, MC: MemoryConfig, M: JitStateAccess> Block<MC,
     {
         self.fallback.num_instr()
     }
+
+    fn is_compiled(&self) -> bool {
+        self.dispatch.is_compiled()
+    }
+
+    fn should_record_superblock(block_builder: &mut Self::BlockBuilder, runs: usize) -> bool {
+        block_builder.should_record_superblock(runs)
+    }
+
+    fn compile_superblock(
+        block_builder: &mut Self::BlockBuilder,
+        superblock: &Superblock<MC, M>,
+        trace: Vec<TraceBlock>,
+    ) {
+        block_builder.compile_superblock(superblock, trace)
+    }
 }
 
 impl<D: DispatchCompiler<MC, M>, MC: MemoryConfig, M: JitStateAccess + ManagerClone> Clone
@@ -0,0 +1,439 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Policies deciding when blocks are JIT-compiled.
+//!
+//! Every block counts the number of times it has been called without being compiled. Once this
+//! exceeds the threshold of the [`TieringPolicy`], the block is compiled.
+//!
+//! ## Decay
+//!
+//! Call counts of blocks may decay over time, so that blocks which were only briefly hot are not
+//! compiled. Decay is measured in calls to uncompiled blocks across the whole block cache: every
+//! `decay_interval` such calls, the call count of every block is halved. This is done lazily,
+//! the next time the block is called.
+//!
+//! Blocks may also go cold whilst waiting to be compiled. A compiler running in the background
+//! checks the [`Decay`] of each block before compiling it, and hands blocks whose call count
+//! has since decayed back to the interpreter instead.
+//!
+//! ## Adaptive mode
+//!
+//! Compilation is only worthwhile if its cost is amortised over enough calls to the compiled
+//! block. Short-lived kernels rarely recoup the cost, while long-running kernels benefit from
+//! compiling early. In [`TieringMode::Adaptive`], the threshold is therefore halved every time the
+//! total number of calls - as recorded by [`CallCounts`] - doubles.
+//!
+//! ## Superblocks
+//!
+//! Once compiled, blocks count the number of times they are run. Once this exceeds the
+//! superblock threshold, the path taken from the block is recorded to form a [`Superblock`].
+//!
+//! [`Superblock`]: crate::machine_state::block_cache::superblock::Superblock
+
+use std::collections::HashSet;
+use std::num::NonZeroU64;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use super::dispatch::DispatchCompiler;
+use super::dispatch::DispatchTarget;
+use crate::jit::state_access::JitStateAccess;
+use crate::machine_state::block_cache::metrics::CallCounts;
+use crate::machine_state::instruction::Instruction;
+use crate::machine_state::memory::MemoryConfig;
+use crate::state_backend::ManagerBase;
+use crate::state_backend::hash::Hash;
+
+/// Default number of calls to a block, before it is compiled by the [`OutlineCompiler`].
+///
+/// [`OutlineCompiler`]: super::OutlineCompiler
+pub const DEFAULT_THRESHOLD: usize = 1000;
+
+/// How the compilation threshold of a [`TieringPolicy`] evolves during execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieringMode {
+    /// The threshold is fixed.
+    #[default]
+    Fixed,
+
+    /// The threshold is lowered as the kernel keeps running, but never below `min_threshold`.
+    Adaptive { min_threshold: usize },
+}
+
+/// Policy deciding when blocks are JIT-compiled.
+#[derive(Debug, Clone)]
+pub struct TieringPolicy {
+    /// Number of calls to a block, after which it is compiled.
+    pub threshold: usize,
+
+    /// Number of calls to uncompiled blocks, after which the call count of every block is
+    /// halved. No decay occurs when `None`.
+    pub decay_interval: Option<NonZeroU64>,
+
+    /// Maximum number of blocks waiting to be compiled. Blocks that become hot whilst the queue is
+    /// full are compiled once there is room. Unbounded when `None`.
+    pub max_queued: Option<NonZeroUsize>,
+
+    /// Blocks that are never compiled, identified by the hash of their instructions.
+    pub never_compile: HashSet<Hash>,
+
+    /// How the threshold evolves during execution.
+    pub mode: TieringMode,
+
+    /// Number of runs of a compiled block, after which the path taken from it is recorded to
+    /// form a superblock. No superblocks are formed when `None`.
+    pub superblock_threshold: Option<usize>,
+}
+
+impl TieringPolicy {
+    /// Policy that compiles every block the first time it is called.
+    pub fn eager() -> Self {
+        Self {
+            threshold: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Policy that compiles blocks after `threshold` calls.
+    pub fn with_threshold(threshold: usize) -> Self {
+        Self {
+            threshold,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for TieringPolicy {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_THRESHOLD,
+            decay_interval: None,
+            max_queued: None,
+            never_compile: HashSet::new(),
+            mode: TieringMode::default(),
+            superblock_threshold: None,
+        }
+    }
+}
+
+/// Applies a [`TieringPolicy`] over the blocks of a [`DispatchCompiler`].
+pub(super) struct Tiering {
+    policy: TieringPolicy,
+    calls: CallCounts,
+
+    /// Number of blocks waiting to be compiled, shared with the compiler threads.
+    queued: Arc<AtomicUsize>,
+
+    /// Current decay epoch, shared with the compiler threads.
+    epoch: Arc<AtomicU64>,
+}
+
+impl Tiering {
+    pub(super) fn new(policy: TieringPolicy) -> Self {
+        Self {
+            policy,
+            calls: CallCounts::default(),
+            queued: Arc::default(),
+            epoch: Arc::default(),
+        }
+    }
+
+    /// Replace the policy, keeping the counts recorded so far.
+    pub(super) fn set_policy(&mut self, policy: TieringPolicy) {
+        self.policy = policy;
+    }
+
+    /// Counter of the blocks waiting to be compiled.
+    ///
+    /// The compiler must increment this when queueing a block, and decrement it once the block
+    /// is taken from the queue.
+    pub(super) fn queued(&self) -> &Arc<AtomicUsize> {
+        &self.queued
+    }
+
+    /// Record a call to an uncompiled block, returning whether it should now be compiled.
+    pub(super) fn record_call<D, MC, M>(&mut self, target: &mut DispatchTarget<D, MC, M>) -> bool
+    where
+        D: DispatchCompiler<MC, M>,
+        MC: MemoryConfig,
+        M: ManagerBase,
+    {
+        self.calls.record_call();
+
+        if let Some(interval) = self.policy.decay_interval {
+            let epoch = self.calls.total() / interval.get();
+            target.calls = decayed(target.calls, epoch.saturating_sub(target.epoch));
+            target.epoch = epoch;
+            self.epoch.store(epoch, Ordering::Release);
+        }
+
+        target.calls = target.calls.saturating_add(1);
+
+        target.calls > self.threshold() && !self.queue_full()
+    }
+
+    /// Call count of a block about to be queued for compilation, against which a compiler thread
+    /// can check whether it has gone cold meanwhile. `None` when call counts do not decay.
+    pub(super) fn decay<D, MC, M>(&self, target: &DispatchTarget<D, MC, M>) -> Option<Decay>
+    where
+        D: DispatchCompiler<MC, M>,
+        MC: MemoryConfig,
+        M: ManagerBase,
+    {
+        self.policy.decay_interval?;
+
+        Some(Decay {
+            current: self.epoch.clone(),
+            epoch: target.epoch,
+            calls: target.calls,
+            threshold: self.threshold(),
+        })
+    }
+
+    /// Whether the block with the given instructions may be compiled at all.
+    pub(super) fn may_compile<D, MC, M>(
+        &self,
+        target: &mut DispatchTarget<D, MC, M>,
+        instr: &[Instruction],
+    ) -> bool
+    where
+        D: DispatchCompiler<MC, M>,
+        MC: MemoryConfig,
+        M: JitStateAccess,
+    {
+        if self.policy.never_compile.is_empty() {
+            return true;
+        }
+
+        target
+            .instr_hash(instr)
+            .is_some_and(|hash| !self.policy.never_compile.contains(&hash))
+    }
+
+    /// Whether the path taken from a compiled block, that has been run `runs` times, should be
+    /// recorded to form a superblock.
+    pub(super) fn should_record_superblock(&self, runs: usize) -> bool {
+        self.policy
+            .superblock_threshold
+            .is_some_and(|threshold| runs > threshold)
+            && !self.queue_full()
+    }
+
+    fn threshold(&self) -> usize {
+        match self.policy.mode {
+            TieringMode::Fixed => self.policy.threshold,
+            TieringMode::Adaptive { min_threshold } => {
+                let threshold = self.policy.threshold;
+
+                // Number of times the total calls have doubled, beyond the threshold
+                let windows = self.calls.total() / threshold.max(1) as u64;
+                let halvings = u64::BITS - windows.leading_zeros();
+
+                threshold
+                    .checked_shr(halvings)
+                    .unwrap_or(0)
+                    .max(min_threshold)
+            }
+        }
+    }
+
+    fn queue_full(&self) -> bool {
+        self.policy
+            .max_queued
+            .is_some_and(|max| self.queued.load(Ordering::Acquire) >= max.get())
+    }
+}
+
+/// Call count of a block when it was queued for compilation. See [`Tiering::decay`].
+pub(super) struct Decay {
+    current: Arc<AtomicU64>,
+    epoch: u64,
+    calls: usize,
+    threshold: usize,
+}
+
+impl Decay {
+    /// Whether the call count of the block has decayed to the threshold since it was queued, so
+    /// that it would no longer be compiled.
+    ///
+    /// Calls to a block are not counted whilst it is queued, so this only accounts for the decay.
+    pub(super) fn is_cold(&self) -> bool {
+        let elapsed = self
+            .current
+            .load(Ordering::Acquire)
+            .saturating_sub(self.epoch);
+
+        decayed(self.calls, elapsed) <= self.threshold
+    }
+}
+
+/// Call count after `elapsed` decay epochs.
+fn decayed(calls: usize, elapsed: u64) -> usize {
+    u32::try_from(elapsed)
+        .ok()
+        .and_then(|elapsed| calls.checked_shr(elapsed))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::machine_state::block_cache::block::InlineCompiler;
+    use crate::machine_state::memory::M4K;
+    use crate::parser::instruction::InstrWidth;
+    use crate::state_backend::owned_backend::Owned;
+
+    type Target = DispatchTarget<InlineCompiler<M4K, Owned>, M4K, Owned>;
+
+    /// Number of calls to a fresh block before it should be compiled.
+    fn calls_until_compiled(tiering: &mut Tiering) -> usize {
+        let mut target = Target::default();
+
+        (1..).find(|_| tiering.record_call(&mut target)).unwrap()
+    }
+
+    #[test]
+    fn test_fixed_threshold() {
+        let mut tiering = Tiering::new(TieringPolicy::eager());
+        assert_eq!(calls_until_compiled(&mut tiering), 1);
+
+        let mut tiering = Tiering::new(TieringPolicy::with_threshold(10));
+        assert_eq!(calls_until_compiled(&mut tiering), 11);
+        assert_eq!(calls_until_compiled(&mut tiering), 11);
+    }
+
+    #[test]
+    fn test_decay() {
+        let mut tiering = Tiering::new(TieringPolicy {
+            decay_interval: NonZeroU64::new(8),
+            ..TieringPolicy::with_threshold(10)
+        });
+
+        let mut hot = Target::default();
+        for _ in 0..7 {
+            assert!(!tiering.record_call(&mut hot));
+        }
+
+        // Calls to other blocks cause the count of the first to decay
+        let mut other = Target::default();
+        for _ in 0..9 {
+            tiering.record_call(&mut other);
+        }
+
+        // 7 calls, halved twice, followed by one more
+        assert!(!tiering.record_call(&mut hot));
+        assert_eq!(hot.calls, 2);
+    }
+
+    #[test]
+    fn test_decay_while_queued() {
+        let mut tiering = Tiering::new(TieringPolicy {
+            decay_interval: NonZeroU64::new(8),
+            ..TieringPolicy::with_threshold(2)
+        });
+
+        let mut hot = Target::default();
+        while !tiering.record_call(&mut hot) {}
+
+        let decay = tiering.decay(&hot).unwrap();
+        assert!(!decay.is_cold());
+
+        // Halving the 3 calls once takes them below the threshold
+        let mut other = Target::default();
+        while tiering.epoch.load(Ordering::Acquire) == hot.epoch {
+            tiering.record_call(&mut other);
+        }
+        assert!(decay.is_cold());
+
+        // Without decay, blocks never go cold
+        let tiering = Tiering::new(TieringPolicy::with_threshold(2));
+        assert!(tiering.decay(&hot).is_none());
+    }
+
+    #[test]
+    fn test_max_queued() {
+        let mut tiering = Tiering::new(TieringPolicy {
+            max_queued: NonZeroUsize::new(1),
+            ..TieringPolicy::eager()
+        });
+
+        let mut target = Target::default();
+        assert!(tiering.record_call(&mut target));
+
+        tiering.queued().fetch_add(1, Ordering::AcqRel);
+        assert!(!tiering.record_call(&mut target));
+
+        tiering.queued().fetch_sub(1, Ordering::AcqRel);
+        assert!(tiering.record_call(&mut target));
+    }
+
+    #[test]
+    fn test_adaptive_threshold() {
+        let min_threshold = 2;
+        let mut tiering = Tiering::new(TieringPolicy {
+            mode: TieringMode::Adaptive { min_threshold },
+            ..TieringPolicy::with_threshold(16)
+        });
+
+        let first = calls_until_compiled(&mut tiering);
+        let second = calls_until_compiled(&mut tiering);
+        let third = calls_until_compiled(&mut tiering);
+
+        assert!(second < first, "Expected {second} < {first}");
+        assert!(third <= second, "Expected {third} <= {second}");
+
+        for _ in 0..100 {
+            calls_until_compiled(&mut tiering);
+        }
+
+        // The threshold never drops below the minimum: blocks are compiled on the first call
+        // exceeding it.
+        assert_eq!(calls_until_compiled(&mut tiering), min_threshold + 1);
+    }
+
+    #[test]
+    fn test_never_compile() {
+        let instr = [Instruction::new_nop(InstrWidth::Compressed)];
+        let hash = Hash::blake2b_hash(instr.as_slice()).unwrap();
+
+        let mut target = Target::default();
+
+        let tiering = Tiering::new(TieringPolicy::eager());
+        assert!(tiering.may_compile(&mut target, &instr));
+
+        let tiering = Tiering::new(TieringPolicy {
+            never_compile: HashSet::from([hash]),
+            ..TieringPolicy::eager()
+        });
+        assert!(!tiering.may_compile(&mut target, &instr));
+
+        // The hash of the instructions is computed once, until the target is reset.
+        assert!(!tiering.may_compile(&mut target, &[]));
+
+        target.reset();
+        assert!(tiering.may_compile(&mut target, &[]));
+    }
+
+    #[test]
+    fn test_superblock_threshold() {
+        let tiering = Tiering::new(TieringPolicy::eager());
+        assert!(!tiering.should_record_superblock(usize::MAX));
+
+        let tiering = Tiering::new(TieringPolicy {
+            superblock_threshold: Some(10),
+            max_queued: NonZeroUsize::new(1),
+            ..TieringPolicy::eager()
+        });
+        assert!(!tiering.should_record_superblock(10));
+        assert!(tiering.should_record_superblock(11));
+
+        // Superblocks are not recorded whilst the queue is full
+        tiering.queued().fetch_add(1, Ordering::AcqRel);
+        assert!(!tiering.should_record_superblock(11));
+    }
+}
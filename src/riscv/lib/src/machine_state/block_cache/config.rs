@@ -91,6 +91,7 @@ impl<const SIZE: usize> super::BlockCacheConfig for BlockCacheConfig<SIZE> {
                 .try_into()
                 .map_err(|_| "mismatching vector lengths for instruction cache")
                 .unwrap(),
+            recording: None,
             block_builder,
         }
     }
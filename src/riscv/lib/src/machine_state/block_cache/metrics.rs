@@ -8,12 +8,14 @@
 //! - the op-codes contained in a block
 //! - the number of times it has been constructed and called
 //! - whether or not the block is supported by the JIT
+//! - the number of times its compiled code has been evicted from the JIT
 //!
 //! The macro [`block_metrics`] is used to record metrics.
 
 /// Record a block metric - namely that a block has been
 /// - constructed
 /// - successfully JIT-compiled
+/// - evicted from the JIT
 /// - called
 ///
 /// This macro has no effect when the `metrics` feature is disabled.
@@ -23,6 +25,8 @@ macro_rules! block_metrics {
 
     (hash = $hash:expr, record_jitted) => {};
 
+    (hash = $hash:expr, record_evicted) => {};
+
     (hash = $hash:expr, record_called) => {};
 }
 
@@ -65,6 +69,7 @@ pub mod core {
     /// Record a block metric - namely that a block has been
     /// - constructed
     /// - successfully JIT-compiled
+    /// - evicted from the JIT
     /// - called
     ///
     /// This macro has no effect when the `metrics` feature is disabled.
@@ -81,6 +86,12 @@ pub mod core {
             );
         };
 
+        (hash = $hash:expr, record_evicted) => {
+            $crate::machine_state::block_cache::metrics::core::BlockCacheMetrics::with_borrow_mut(
+                |bm| bm.record_evicted($hash),
+            );
+        };
+
         (hash = $hash:expr, record_called) => {
             $crate::machine_state::block_cache::metrics::core::BlockCacheMetrics::with_borrow_mut(
                 |bm| bm.record_called($hash),
@@ -92,7 +103,7 @@ pub mod core {
 
     /// Write block metrics to the given file, in the following format:
     /// ```
-    /// [OpCodes] | JIT-compiled | times_constructed | times_called
+    /// [OpCodes] | JIT-compiled | times_constructed | times_called | times_evicted
     /// ```
     ///
     /// If `exclude_supported_instructions = true` is passed, any opcode that is supported
@@ -150,6 +161,7 @@ pub mod core {
                 jit_compiled: false,
                 called_count: 0,
                 constructed_count: 1,
+                evicted_count: 0,
             };
 
             self.entries.insert(*hash, metrics);
@@ -157,13 +169,23 @@ pub mod core {
 
         /// Record that the block identified by `hash` has been
         /// successfully jit-compiled.
+        ///
+        /// Superblocks, and blocks compiled on a different thread than the one
+        /// they were constructed on, have no metrics - and are ignored.
         pub fn record_jitted(&mut self, hash: &Hash) {
-            let entry = self
-                .entries
-                .get_mut(hash)
-                .expect("all blocks called are first constructed");
+            if let Some(entry) = self.entries.get_mut(hash) {
+                entry.jit_compiled = true;
+            }
+        }
 
-            entry.jit_compiled = true;
+        /// Record that the compiled code of the block identified by `hash`
+        /// has been evicted from the JIT.
+        ///
+        /// As with [`Self::record_jitted`], unknown blocks are ignored.
+        pub fn record_evicted(&mut self, hash: &Hash) {
+            if let Some(entry) = self.entries.get_mut(hash) {
+                entry.evicted_count += 1;
+            }
         }
 
         /// Record that the block identified by `hash` has been called.
@@ -212,8 +234,12 @@ pub mod core {
                     .collect::<Vec<_>>();
                 writeln!(
                     &mut stats_file,
-                    "{:?} | {} | {} | {}",
-                    instr, stats.jit_compiled, stats.constructed_count, stats.called_count
+                    "{:?} | {} | {} | {} | {}",
+                    instr,
+                    stats.jit_compiled,
+                    stats.constructed_count,
+                    stats.called_count,
+                    stats.evicted_count
                 )?;
             }
 
@@ -223,15 +249,16 @@ pub mod core {
 
     /// Corresponds to a line in the block metrics output.
     ///
-    /// Exclude `jit_compiled` from the implementations of `Eq` and `Ord`,
-    /// to ensure consistent ordering between JIT-enabled and Interpreted
-    /// metric files (as `jit_compiled` is always false in Interpreted runs).
+    /// Exclude `jit_compiled` and `evicted_count` from the implementations of
+    /// `Eq` and `Ord`, to ensure consistent ordering between JIT-enabled and
+    /// Interpreted metric files (as both are always unset in Interpreted runs).
     #[derive(Default, Clone)]
     pub struct BlockMetrics {
         instr: Vec<Instruction>,
         constructed_count: usize,
         called_count: usize,
         jit_compiled: bool,
+        evicted_count: usize,
     }
 
     impl PartialEq for BlockMetrics {
@@ -271,6 +298,29 @@ pub mod core {
     }
 }
 
+/// Running count of calls to blocks that have not been compiled, across the whole block cache.
+///
+/// Unlike the metrics above, these are always recorded, as they inform JIT tiering decisions.
+/// See [`TieringPolicy`].
+///
+/// [`TieringPolicy`]: super::block::TieringPolicy
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CallCounts {
+    total: u64,
+}
+
+impl CallCounts {
+    /// Record a call to a block that has not been compiled.
+    pub fn record_call(&mut self) {
+        self.total = self.total.saturating_add(1);
+    }
+
+    /// Total number of calls recorded.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
 /// Wrapper type that can be used to instrument any `B: Block` with metrics.
 pub struct BlockMetrics<B> {
     block: B,
@@ -341,6 +391,22 @@ impl<B: Block<MC, M>, MC: MemoryConfig, M: ManagerBase> Block<MC, M> for BlockMe
         self.block.num_instr()
     }
 
+    fn is_compiled(&self) -> bool {
+        self.block.is_compiled()
+    }
+
+    fn should_record_superblock(block_builder: &mut Self::BlockBuilder, runs: usize) -> bool {
+        B::should_record_superblock(block_builder, runs)
+    }
+
+    fn compile_superblock(
+        block_builder: &mut Self::BlockBuilder,
+        superblock: &super::superblock::Superblock<MC, M>,
+        trace: Vec<super::superblock::TraceBlock>,
+    ) {
+        B::compile_superblock(block_builder, superblock, trace)
+    }
+
     fn struct_ref<'a, F: crate::state_backend::FnManager<crate::state_backend::Ref<'a, M>>>(
         &'a self,
     ) -> crate::state_backend::AllocatedOf<super::block::BlockLayout, F::Output> {
@@ -13,6 +13,10 @@ use crate::machine_state::block_cache::BlockCall;
 use crate::machine_state::block_cache::CACHE_INSTR;
 use crate::machine_state::block_cache::block::Block;
 use crate::machine_state::block_cache::config::BlockCacheConfig;
+use crate::machine_state::block_cache::superblock::Member;
+use crate::machine_state::block_cache::superblock::Recording;
+use crate::machine_state::block_cache::superblock::Superblock;
+use crate::machine_state::block_cache::superblock::TraceBlock;
 use crate::machine_state::instruction::Instruction;
 use crate::machine_state::memory::Address;
 use crate::machine_state::memory::MemoryConfig;
@@ -212,10 +216,22 @@ pub type CachedLayout = (
 ///
 /// Contains the physical address & fence counter for validity checks, the
 /// underlying [`Block`] state.
+///
+/// Entries also hold the [`Superblock`] headed by their block, if any. This is not part of the
+/// state, and is discarded whenever the block is modified.
 pub struct Cached<MC: MemoryConfig, B, M: ManagerBase> {
     pub(super) block: B,
     address: Cell<Address, M>,
     fence_counter: Cell<FenceCounter, M>,
+
+    /// Incremented whenever the block is modified, see [`Member::generation`].
+    generation: u64,
+
+    /// Number of runs of the compiled block since it was last considered as the head of a
+    /// superblock.
+    runs: usize,
+
+    superblock: Option<Superblock<MC, M>>,
     _pd: PhantomData<MC>,
 }
 
@@ -229,6 +245,9 @@ impl<MC: MemoryConfig, B: Block<MC, M>, M: ManagerBase> Cached<MC, B, M> {
             address: space.0,
             fence_counter: space.1,
             block: B::bind((space.2, space.3)),
+            generation: 0,
+            runs: 0,
+            superblock: None,
             _pd: PhantomData,
         }
     }
@@ -248,10 +267,92 @@ impl<MC: MemoryConfig, B: Block<MC, M>, M: ManagerBase> Cached<MC, B, M> {
         )
     }
 
+    /// Whether this entry holds a valid, non-empty block for the given address.
+    fn is_valid_for(&self, addr: Address, fence_counter: FenceCounter) -> bool
+    where
+        M: ManagerRead,
+    {
+        self.address.read() == addr
+            && self.fence_counter.read() == fence_counter
+            && self.block.num_instr() > 0
+    }
+
+    /// Generation of this entry, see [`Member::generation`].
+    pub(super) fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Whether this entry still holds the given member of a superblock, unmodified.
+    pub(super) fn holds(&self, member: &Member, fence_counter: FenceCounter) -> bool
+    where
+        M: ManagerRead,
+    {
+        self.generation == member.generation && self.is_valid_for(member.address, fence_counter)
+    }
+
+    /// The superblock headed by this entry's block, if any.
+    pub(super) fn superblock(&self) -> Option<&Superblock<MC, M>> {
+        self.superblock.as_ref()
+    }
+
+    /// Discard the superblock headed by this entry's block, allowing a new one to be formed.
+    pub(super) fn discard_superblock(&mut self) {
+        self.superblock = None;
+        self.runs = 0;
+    }
+
+    /// Record a run of this entry's block, returning whether the path taken from it should be
+    /// recorded, to form a superblock.
+    pub(super) fn should_record_superblock(&mut self, block_builder: &mut B::BlockBuilder) -> bool {
+        if self.superblock.is_some() || !self.block.is_compiled() {
+            return false;
+        }
+
+        self.runs = self.runs.saturating_add(1);
+
+        if B::should_record_superblock(block_builder, self.runs) {
+            self.runs = 0;
+            return true;
+        }
+
+        false
+    }
+
+    /// Compile a superblock headed by this entry's block, from the path recorded.
+    pub(super) fn form_superblock(
+        &mut self,
+        members: Vec<Member>,
+        trace: Vec<TraceBlock>,
+        block_builder: &mut B::BlockBuilder,
+    ) {
+        let num_instr = trace.iter().map(|block| block.instr.len()).sum();
+        let superblock = Superblock::new(members, num_instr);
+
+        B::compile_superblock(block_builder, &superblock, trace);
+
+        self.superblock = Some(superblock);
+    }
+
+    /// The block is about to be modified, moving on to a new generation.
+    fn modified(&mut self) {
+        self.generation += 1;
+        self.discard_superblock();
+    }
+
+    /// Push an instruction to the block.
+    fn push_instr(&mut self, instr: Instruction)
+    where
+        M: ManagerReadWrite,
+    {
+        self.modified();
+        self.block.push_instr(instr);
+    }
+
     fn invalidate(&mut self)
     where
         M: ManagerWrite,
     {
+        self.modified();
         self.address.write(!0);
         self.block.invalidate();
     }
@@ -260,6 +361,7 @@ impl<MC: MemoryConfig, B: Block<MC, M>, M: ManagerBase> Cached<MC, B, M> {
     where
         M: ManagerReadWrite,
     {
+        self.modified();
         self.address.write(!0);
         self.fence_counter.write(FenceCounter::INITIAL);
         self.block.reset();
@@ -269,6 +371,7 @@ impl<MC: MemoryConfig, B: Block<MC, M>, M: ManagerBase> Cached<MC, B, M> {
     where
         M: ManagerWrite,
     {
+        self.modified();
         self.address.write(block_addr);
         self.block.start_block();
         self.fence_counter.write(fence_counter);
@@ -284,6 +387,9 @@ impl<MC: MemoryConfig, B: Block<MC, M>, M: ManagerBase> NewState<M> for Cached<M
             address: Cell::new_with(manager, !0),
             block: B::new(manager),
             fence_counter: Cell::new(manager),
+            generation: 0,
+            runs: 0,
+            superblock: None,
             _pd: PhantomData,
         }
     }
@@ -295,6 +401,9 @@ impl<MC: MemoryConfig, B: Clone, M: ManagerClone> Clone for Cached<MC, B, M> {
             address: self.address.clone(),
             block: self.block.clone(),
             fence_counter: self.fence_counter.clone(),
+            generation: 0,
+            runs: 0,
+            superblock: None,
             _pd: PhantomData,
         }
     }
@@ -322,6 +431,9 @@ pub struct BlockCache<const SIZE: usize, B: Block<MC, M>, MC: MemoryConfig, M: M
     /// Block entries
     pub(super) entries: Entries<SIZE, MC, B, M>,
 
+    /// Path being recorded to form a superblock, see [`BlockCall::run_block`].
+    pub(super) recording: Option<Recording>,
+
     /// The block builder is the mechanism used to construct blocks for calling in a
     /// (potentially) more efficient manner. For example - by JIT compiling them.
     pub block_builder: B::BlockBuilder,
@@ -381,7 +493,7 @@ impl<const SIZE: usize, B: Block<MC, M>, MC: MemoryConfig, M: ManagerBase>
             len_instr = 0;
         }
 
-        entry.block.push_instr(instr);
+        entry.push_instr(instr);
         let new_len = len_instr + 1;
 
         let next_addr = addr + WIDTH;
@@ -403,7 +515,7 @@ impl<const SIZE: usize, B: Block<MC, M>, MC: MemoryConfig, M: ManagerBase>
                 // Need to resolve the target block again because we may only keep one reference at a time
                 // to `self.entries`.
                 let current_entry = Self::entry_mut(&mut self.entries, block_addr);
-                current_entry.block.push_instr(new_instr);
+                current_entry.push_instr(new_instr);
             }
             self.next_instr_addr.write(!0);
             self.current_block_addr.write(!0);
@@ -436,6 +548,7 @@ impl<const SIZE: usize, MC: MemoryConfig, B: Block<MC, M>, M: ManagerBase>
             fence_counter: Cell::new(manager),
             partial_block: PartialBlock::new(manager),
             entries: NewState::new(manager),
+            recording: None,
             block_builder,
         }
     }
@@ -459,6 +572,7 @@ impl<const SIZE: usize, MC: MemoryConfig, B: Block<MC, M>, M: ManagerBase>
                 .try_into()
                 .map_err(|_| "mismatching vector lengths in block cache")
                 .unwrap(),
+            recording: None,
             block_builder: Default::default(),
         }
     }
@@ -471,6 +585,7 @@ impl<const SIZE: usize, MC: MemoryConfig, B: Block<MC, M>, M: ManagerBase>
         self.reset_to(!0);
         self.entries.iter_mut().for_each(Cached::reset);
         self.partial_block.reset();
+        self.recording = None;
     }
 
     fn invalidate(&mut self)
@@ -480,6 +595,7 @@ impl<const SIZE: usize, MC: MemoryConfig, B: Block<MC, M>, M: ManagerBase>
         let counter = self.fence_counter.read();
         self.fence_counter.write(counter.next());
         self.reset_to(!0);
+        self.recording = None;
         Self::entry_mut(&mut self.entries, counter.0 as Address).invalidate();
     }
 
@@ -492,16 +608,18 @@ impl<const SIZE: usize, MC: MemoryConfig, B: Block<MC, M>, M: ManagerBase>
             "Get block was called with a partial block in progress"
         );
 
-        let entry = Self::entry_mut(&mut self.entries, addr);
+        let fence_counter = self.fence_counter.read();
+        let index = BlockCacheConfig::<SIZE>::cache_index(addr);
 
-        if entry.address.read() == addr
-            && self.fence_counter.read() == entry.fence_counter.read()
-            && entry.block.num_instr() > 0
-        {
+        if self.entries[index].is_valid_for(addr, fence_counter) {
             Some(BlockCall {
-                entry,
+                entries: &mut self.entries[..],
+                index,
+                cache_index: BlockCacheConfig::<SIZE>::cache_index,
+                fence_counter,
                 builder: &mut self.block_builder,
                 partial: &mut self.partial_block,
+                recording: &mut self.recording,
             })
         } else {
             None
@@ -576,17 +694,24 @@ impl<const SIZE: usize, MC: MemoryConfig, B: Block<MC, M>, M: ManagerBase>
 
 #[cfg(test)]
 mod tests {
+    use std::num::NonZeroUsize;
+
     use crate::backend_test;
     use crate::default::ConstDefault;
+    use crate::jit::JIT;
     use crate::machine_state::MachineCoreState;
+    use crate::machine_state::MachineCoreStateLayout;
     use crate::machine_state::MachineState;
     use crate::machine_state::StepManyResult;
     use crate::machine_state::block_cache::BlockCache;
     use crate::machine_state::block_cache::BlockCacheConfig;
     use crate::machine_state::block_cache::CACHE_INSTR;
     use crate::machine_state::block_cache::block::Block;
+    use crate::machine_state::block_cache::block::InlineCompiler;
     use crate::machine_state::block_cache::block::Interpreted;
     use crate::machine_state::block_cache::block::InterpretedBlockBuilder;
+    use crate::machine_state::block_cache::block::Jitted;
+    use crate::machine_state::block_cache::block::TieringPolicy;
     use crate::machine_state::block_cache::config::TestCacheConfig;
     use crate::machine_state::instruction::Instruction;
     use crate::machine_state::instruction::OpCode;
@@ -599,11 +724,14 @@ mod tests {
     use crate::machine_state::memory::PAGE_SIZE;
     use crate::machine_state::registers::XRegister;
     use crate::machine_state::registers::a1;
+    use crate::machine_state::registers::a2;
     use crate::machine_state::registers::nz;
     use crate::machine_state::registers::t0;
     use crate::machine_state::registers::t1;
     use crate::parser::instruction::InstrWidth;
     use crate::state::NewState;
+    use crate::state_backend::CommitmentLayout;
+    use crate::state_backend::FnManagerIdent;
     use crate::state_backend::owned_backend::Owned;
     use crate::traps::EnvironException;
 
@@ -633,7 +761,7 @@ mod tests {
 
         let block = state.get_block(addr);
         assert!(block.is_some());
-        assert_eq!(CACHE_INSTR, block.unwrap().entry.block.num_instr());
+        assert_eq!(CACHE_INSTR, block.unwrap().entry().block.num_instr());
     });
 
     backend_test!(test_writing_full_block_fetchable_compressed, F, {
@@ -660,7 +788,7 @@ mod tests {
 
         let block = state.get_block(addr);
         assert!(block.is_some());
-        assert_eq!(CACHE_INSTR, block.unwrap().entry.block.num_instr());
+        assert_eq!(CACHE_INSTR, block.unwrap().entry().block.num_instr());
     });
 
     // writing instructions immediately creates block
@@ -688,7 +816,7 @@ mod tests {
 
         let block = state.get_block(addr);
         assert!(block.is_some());
-        assert_eq!(CACHE_INSTR / 2, block.unwrap().entry.block.num_instr());
+        assert_eq!(CACHE_INSTR / 2, block.unwrap().entry().block.num_instr());
     });
 
     backend_test!(test_writing_two_blocks_fetchable_compressed, F, {
@@ -715,11 +843,11 @@ mod tests {
 
         let block = state.get_block(addr);
         assert!(block.is_some());
-        assert_eq!(CACHE_INSTR, block.unwrap().entry.block.num_instr());
+        assert_eq!(CACHE_INSTR, block.unwrap().entry().block.num_instr());
 
         let block = state.get_block(addr + 2 * CACHE_INSTR as u64);
         assert!(block.is_some());
-        assert_eq!(CACHE_INSTR, block.unwrap().entry.block.num_instr());
+        assert_eq!(CACHE_INSTR, block.unwrap().entry().block.num_instr());
     });
 
     // writing across pages offset two blocks next to each other
@@ -747,11 +875,11 @@ mod tests {
 
         let block = state.get_block(addr);
         assert!(block.is_some());
-        assert_eq!(5, block.unwrap().entry.block.num_instr());
+        assert_eq!(5, block.unwrap().entry().block.num_instr());
 
         let block = state.get_block(addr + 10);
         assert!(block.is_some());
-        assert_eq!(5, block.unwrap().entry.block.num_instr());
+        assert_eq!(5, block.unwrap().entry().block.num_instr());
     });
 
     backend_test!(test_partial_block_executes, F, {
@@ -780,11 +908,11 @@ mod tests {
         core_state.hart.pc.write(block_addr);
 
         // Execute the first 5 instructions
-        let block = block_state.get_block(block_addr).unwrap();
+        let mut block = block_state.get_block(block_addr).unwrap();
         let StepManyResult { steps, error: None } =
             block
                 .partial
-                .run_block_partial(&mut core_state, 5, block.entry)
+                .run_block_partial(&mut core_state, 5, &mut block.entries[block.index])
         else {
             panic!()
         };
@@ -864,11 +992,11 @@ mod tests {
 
         let block = state.get_block(addr - 20);
         assert!(block.is_some());
-        assert_eq!(CACHE_INSTR, block.unwrap().entry.block.num_instr());
+        assert_eq!(CACHE_INSTR, block.unwrap().entry().block.num_instr());
 
         let old_block = state.get_block(addr);
         assert!(old_block.is_some());
-        assert_eq!(15, old_block.unwrap().entry.block.num_instr());
+        assert_eq!(15, old_block.unwrap().entry().block.num_instr());
     });
 
     backend_test!(test_concat_blocks_too_big, F, {
@@ -902,17 +1030,256 @@ mod tests {
         assert!(first_block.is_some());
         assert_eq!(
             preceding_num_instr,
-            first_block.unwrap().entry.block.num_instr() as u64
+            first_block.unwrap().entry().block.num_instr() as u64
         );
 
         let second_block = state.get_block(addr);
         assert!(second_block.is_some());
         assert_eq!(
             CACHE_INSTR - preceding_num_instr as usize + 1,
-            second_block.unwrap().entry.block.num_instr()
+            second_block.unwrap().entry().block.num_instr()
         );
     });
 
+    /// Blocks that fail to compile are run in interpreted mode instead, resulting in the same
+    /// state as when compilation succeeds.
+    #[test]
+    fn test_codegen_failure_falls_back_to_interpreted() {
+        type JitState = <TestCacheConfig as BlockCacheConfig>::State<
+            M4K,
+            Jitted<InlineCompiler<M4K, Owned>, M4K, Owned>,
+            Owned,
+        >;
+
+        let run = |compiler: InlineCompiler<M4K, Owned>| {
+            let mut core_state = MachineCoreState::<M4K, _>::new(&mut Owned);
+            let mut block_state = JitState::new(&mut Owned, compiler);
+
+            let addi = Instruction::new_addi(nz::a1, nz::a1, 1, InstrWidth::Uncompressed);
+
+            // Two blocks of two instructions, each jumping to the start of the other.
+            let first: Address = 0x100;
+            let second: Address = 0x200;
+
+            block_state.push_instr_uncompressed(first, addi);
+            block_state.push_instr_uncompressed(
+                first + 4,
+                Instruction::new_j(0x100 - 4, InstrWidth::Uncompressed),
+            );
+            block_state.push_instr_uncompressed(second, addi);
+            block_state.push_instr_uncompressed(
+                second + 4,
+                Instruction::new_j(-0x100 - 4, InstrWidth::Uncompressed),
+            );
+
+            core_state.hart.pc.write(first);
+            let mut steps = 0;
+            while steps < 100 {
+                let pc = core_state.hart.pc.read();
+                let mut block = block_state.get_block(pc).unwrap();
+                let result = block.run_block(&mut core_state, pc, 100 - steps);
+
+                assert_eq!(result.error, None);
+                steps += result.steps;
+            }
+
+            assert_eq!(steps, 100);
+            assert_eq!(core_state.hart.xregisters.read(a1), 50);
+
+            MachineCoreStateLayout::<M4K>::state_hash(core_state.struct_ref::<FnManagerIdent>())
+                .unwrap()
+        };
+
+        let mut failing = InlineCompiler::default();
+        failing.inject_codegen_failures();
+
+        assert_eq!(run(InlineCompiler::default()), run(failing));
+    }
+
+    /// Superblocks are formed along hot paths between compiled blocks, and discarded once any
+    /// block along the path is modified. The same instructions are executed as when running each
+    /// block on its own.
+    #[test]
+    fn test_superblocks() {
+        type JitState = <TestCacheConfig as BlockCacheConfig>::State<
+            M4K,
+            Jitted<InlineCompiler<M4K, Owned>, M4K, Owned>,
+            Owned,
+        >;
+
+        let run = |superblock_threshold: Option<usize>| {
+            let mut compiler = InlineCompiler::default();
+            compiler.set_tiering(TieringPolicy {
+                superblock_threshold,
+                ..TieringPolicy::eager()
+            });
+
+            let mut core_state = MachineCoreState::<M4K, _>::new(&mut Owned);
+            let mut block_state = JitState::new(&mut Owned, compiler);
+
+            let addi = Instruction::new_addi(nz::a1, nz::a1, 1, InstrWidth::Uncompressed);
+
+            // Two blocks of two instructions, each jumping to the start of the other.
+            let first: Address = 0x100;
+            let second: Address = 0x200;
+
+            block_state.push_instr_uncompressed(first, addi);
+            block_state.push_instr_uncompressed(
+                first + 4,
+                Instruction::new_j(0x100 - 4, InstrWidth::Uncompressed),
+            );
+            block_state.push_instr_uncompressed(second, addi);
+            block_state.push_instr_uncompressed(
+                second + 4,
+                Instruction::new_j(-0x100 - 4, InstrWidth::Uncompressed),
+            );
+
+            // Once both blocks are compiled, the path from the first block is recorded across
+            // runs, and run as a superblock from then on.
+            core_state.hart.pc.write(first);
+            let mut steps = 0;
+            while steps < 100 {
+                let pc = core_state.hart.pc.read();
+                let mut block = block_state.get_block(pc).unwrap();
+                let result = block.run_block(&mut core_state, pc, 100 - steps);
+
+                assert_eq!(result.error, None);
+                steps += result.steps;
+            }
+
+            assert_eq!(steps, 100);
+            assert_eq!(core_state.hart.pc.read(), first);
+            assert_eq!(core_state.hart.xregisters.read(a1), 50);
+
+            let mut block = block_state.get_block(first).unwrap();
+            assert_eq!(
+                block.entry().superblock().is_some(),
+                superblock_threshold.is_some()
+            );
+
+            // Superblocks are not run when the steps remaining do not cover the whole path.
+            let result = block.run_block(&mut core_state, first, 3);
+
+            assert_eq!(result.steps, 2);
+            assert_eq!(result.error, None);
+            assert_eq!(core_state.hart.pc.read(), second);
+            assert_eq!(core_state.hart.xregisters.read(a1), 51);
+
+            // Modifying the second block discards the superblock headed by the first.
+            block_state.push_instr_uncompressed(
+                second,
+                Instruction::new_addi(nz::a2, nz::a2, 1, InstrWidth::Uncompressed),
+            );
+            block_state.push_instr_uncompressed(
+                second + 4,
+                Instruction::new_j(-0x100 - 4, InstrWidth::Uncompressed),
+            );
+
+            let mut block = block_state.get_block(second).unwrap();
+            let result = block.run_block(&mut core_state, second, 2);
+
+            assert_eq!(result.steps, 2);
+            assert_eq!(core_state.hart.pc.read(), first);
+            assert_eq!(core_state.hart.xregisters.read(a2), 1);
+
+            let mut block = block_state.get_block(first).unwrap();
+            let result = block.run_block(&mut core_state, first, 4);
+
+            assert_eq!(result.steps, 2);
+            assert_eq!(core_state.hart.pc.read(), second);
+            assert!(block.entry().superblock().is_none());
+
+            let mut block = block_state.get_block(second).unwrap();
+            let result = block.run_block(&mut core_state, second, 2);
+
+            assert_eq!(result.steps, 2);
+            assert_eq!(core_state.hart.xregisters.read(a1), 52);
+            assert_eq!(core_state.hart.xregisters.read(a2), 2);
+
+            MachineCoreStateLayout::<M4K>::state_hash(core_state.struct_ref::<FnManagerIdent>())
+                .unwrap()
+        };
+
+        assert_eq!(run(None), run(Some(0)));
+    }
+
+    /// Blocks whose compiled code is evicted from the JIT are reset to interpreted execution, and
+    /// compiled again once called. The same instructions are executed as without a code budget.
+    #[test]
+    fn test_code_budget_eviction() {
+        type JitState = <TestCacheConfig as BlockCacheConfig>::State<
+            M4K,
+            Jitted<InlineCompiler<M4K, Owned>, M4K, Owned>,
+            Owned,
+        >;
+
+        let run = |code_budget: Option<NonZeroUsize>| {
+            let mut jit = JIT::new().unwrap();
+            jit.set_code_budget(code_budget);
+
+            let mut core_state = MachineCoreState::<M4K, _>::new(&mut Owned);
+            let mut block_state = JitState::new(&mut Owned, InlineCompiler::with_jit(jit));
+
+            // Three blocks of two instructions, each jumping to the start of the next.
+            let blocks: [Address; 3] = [0x100, 0x200, 0x300];
+            for (index, &address) in blocks.iter().enumerate() {
+                let next = blocks[(index + 1) % blocks.len()];
+
+                block_state.push_instr_uncompressed(
+                    address,
+                    Instruction::new_addi(nz::a1, nz::a1, 1, InstrWidth::Uncompressed),
+                );
+                block_state.push_instr_uncompressed(
+                    address + 4,
+                    Instruction::new_j(next as i64 - address as i64 - 4, InstrWidth::Uncompressed),
+                );
+            }
+
+            core_state.hart.pc.write(blocks[0]);
+
+            // Run each block once, compiling it.
+            for address in blocks {
+                let mut block = block_state.get_block(address).unwrap();
+                let result = block.run_block(&mut core_state, address, 2);
+                assert_eq!(result.steps, 2);
+            }
+
+            // Every function exceeds a budget of a single byte, so compiling a block evicts the
+            // block compiled two blocks earlier.
+            let compiled = blocks.map(|address| {
+                block_state
+                    .get_block(address)
+                    .unwrap()
+                    .entry()
+                    .block
+                    .is_compiled()
+            });
+            let expected = match code_budget {
+                None => [true, true, true],
+                Some(_) => [false, false, true],
+            };
+            assert_eq!(compiled, expected);
+
+            // Evicted blocks are run again, and compiled once more.
+            for _ in 0..30 {
+                let address = core_state.hart.pc.read();
+                let mut block = block_state.get_block(address).unwrap();
+                let result = block.run_block(&mut core_state, address, 2);
+
+                assert_eq!(result.steps, 2);
+                assert_eq!(result.error, None);
+            }
+
+            assert_eq!(core_state.hart.pc.read(), blocks[0]);
+            assert_eq!(core_state.hart.xregisters.read(a1), 33);
+
+            MachineCoreStateLayout::<M4K>::state_hash(core_state.struct_ref::<FnManagerIdent>())
+                .unwrap()
+        };
+
+        assert_eq!(run(None), run(NonZeroUsize::new(1)));
+    }
+
     /// The initialised block cache must not return any blocks. This is especially important for
     /// blocks at address 0 which at one point were accidentally valid but empty which caused loops.
     #[test]
@@ -0,0 +1,180 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Superblocks are compilation units spanning several blocks.
+//!
+//! Blocks are compiled in isolation, and stop at page boundaries and taken branches. Hot paths
+//! through a program therefore often span many small blocks - for example in interpreters running
+//! inside the PVM.
+//!
+//! A superblock is formed from the path taken after a hot block - its _head_ - as recorded at
+//! runtime, while the dispatch loop runs compiled blocks one after another. The blocks along that
+//! path are compiled into a single function, in the order they were run. Each block is followed by
+//! a check that the program counter matches the address of the next block along the path. Where it
+//! does not, execution leaves the superblock through a _side exit_, returning to the dispatch loop
+//! as if the block had been run on its own.
+//!
+//! A superblock is only run in place of its head when
+//! - the blocks along its path are all still held by the block cache, unmodified since the path
+//!   was recorded, and
+//! - sufficient steps remain to run every block along its path in full.
+//!
+//! Execution then continues from one block to the next exactly when the dispatch loop would have
+//! run the next block in full.
+//! The same instructions are executed no matter how many steps remain - see the determinism notes
+//! of the [block cache].
+//!
+//! [block cache]: super
+
+use std::marker::PhantomData;
+use std::ptr::null;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use serde::Serialize;
+
+use crate::jit::JitFn;
+use crate::machine_state::MachineCoreState;
+use crate::machine_state::StepManyResult;
+use crate::machine_state::instruction::Instruction;
+use crate::machine_state::memory::Address;
+use crate::machine_state::memory::MemoryConfig;
+use crate::state_backend::ManagerBase;
+use crate::traps::EnvironException;
+
+/// The maximum number of blocks along the path of a superblock.
+pub const MAX_SUPERBLOCK_BLOCKS: usize = 8;
+
+/// A block along the path of a superblock, as given to the JIT for compilation.
+#[derive(Debug, Clone, Serialize)]
+pub struct TraceBlock {
+    /// Address of the block.
+    pub address: Address,
+
+    /// Instructions of the block.
+    pub instr: Vec<Instruction>,
+}
+
+/// A block along the path of a superblock, following its head.
+#[derive(Debug, Clone, Copy)]
+pub struct Member {
+    /// Address of the block.
+    pub address: Address,
+
+    /// Generation of the block cache entry when the path was recorded. The entry moves on to a new
+    /// generation whenever its block is modified.
+    pub generation: u64,
+}
+
+/// Path taken from the head of a superblock, whilst it is being recorded.
+#[derive(Debug, Clone)]
+pub(super) struct Recording {
+    /// Addresses of the blocks run along the path so far, starting with the head.
+    pub path: Vec<Address>,
+
+    /// Program counter after running the last block along the path. The path only continues
+    /// with the block at this address, when run next.
+    pub exit_pc: Address,
+}
+
+/// A superblock, formed from the path taken after its head.
+pub struct Superblock<MC: MemoryConfig, M: ManagerBase> {
+    /// Blocks following the head along the path.
+    members: Vec<Member>,
+
+    /// Total number of instructions along the path, including the head.
+    num_instr: usize,
+
+    /// Function pointer of the compiled superblock, stored as an atomic usize. This is zero
+    /// until compilation has completed, and [`Superblock::EVICTED`] once the compiled function
+    /// has been evicted from the JIT.
+    ///
+    /// Like the function pointer of a [`DispatchTarget`], this may be updated from a background
+    /// thread.
+    ///
+    /// [`DispatchTarget`]: super::block::dispatch::DispatchTarget
+    fun: Arc<AtomicUsize>,
+
+    _pd: PhantomData<(MC, M)>,
+}
+
+impl<MC: MemoryConfig, M: ManagerBase> Superblock<MC, M> {
+    /// Value of the target once the compiled function has been evicted from the JIT. This is
+    /// never a valid function pointer.
+    pub(crate) const EVICTED: usize = 1;
+
+    /// Create a superblock that is not yet compiled.
+    pub(super) fn new(members: Vec<Member>, num_instr: usize) -> Self {
+        Self {
+            members,
+            num_instr,
+            fun: Arc::new(AtomicUsize::new(0)),
+            _pd: PhantomData,
+        }
+    }
+
+    /// Blocks following the head along the path.
+    pub fn members(&self) -> &[Member] {
+        &self.members
+    }
+
+    /// Total number of instructions along the path, including the head.
+    pub fn num_instr(&self) -> usize {
+        self.num_instr
+    }
+
+    /// Whether the superblock has been compiled, and may be run.
+    pub fn is_compiled(&self) -> bool {
+        let fun = self.fun.load(Ordering::Acquire);
+        fun != 0 && fun != Self::EVICTED
+    }
+
+    /// Whether the compiled function of the superblock has been evicted from the JIT. An evicted
+    /// superblock can no longer be run, and should be discarded.
+    pub fn is_evicted(&self) -> bool {
+        self.fun.load(Ordering::Acquire) == Self::EVICTED
+    }
+
+    /// Target for the compiled function of the superblock.
+    ///
+    /// Once compiled, the [`JitFn`] must be stored here - see [`DispatchCompiler::compile_superblock`].
+    ///
+    /// [`DispatchCompiler::compile_superblock`]: super::block::dispatch::DispatchCompiler::compile_superblock
+    pub(crate) fn target(&self) -> &Arc<AtomicUsize> {
+        &self.fun
+    }
+
+    /// Run the compiled superblock against the machine state.
+    ///
+    /// # Safety
+    ///
+    /// The superblock must have been compiled, by the block builder of the block cache holding its
+    /// head. That block builder must still be alive.
+    pub(super) unsafe fn run(
+        &self,
+        core: &mut MachineCoreState<MC, M>,
+        instr_pc: Address,
+    ) -> StepManyResult<EnvironException> {
+        // load using Acquire ordering - so that it will see the previous store which was with
+        // Release.
+        let fun = self.fun.load(Ordering::Acquire);
+        debug_assert_ne!(fun, 0, "Superblock has not been compiled");
+        debug_assert_ne!(fun, Self::EVICTED, "Superblock has been evicted");
+
+        // Safety: the pointer is indeed a function pointer, stored from a `JitFn`.
+        let fun = unsafe { std::mem::transmute::<*const (), JitFn<MC, M>>(fun as *const ()) };
+
+        let mut result = Ok(());
+
+        // Safety: the JIT that compiled the function is kept alive by the block builder. The
+        // first and last parameters are ignored by JIT-compiled functions.
+        let steps = unsafe { (fun)(null(), core, instr_pc, &mut result, null()) };
+
+        StepManyResult {
+            steps,
+            error: result.err(),
+        }
+    }
+}
@@ -14,6 +14,8 @@ use values::CSRValuesLayout;
 
 use self::values::CSRValue;
 use crate::bits::Bits64;
+use crate::machine_state::vector::VLENB;
+use crate::machine_state::vector::VTYPE_VILL;
 use crate::state::NewState;
 use crate::state_backend as backend;
 use crate::traps::Exception;
@@ -51,6 +53,12 @@ pub enum CSRegister {
     frm = 0x002,
     fcsr = 0x003,
 
+    // Unprivileged Vector CSRs
+    vstart = 0x008,
+    vxsat = 0x009,
+    vxrm = 0x00A,
+    vcsr = 0x00F,
+
     // Unprivileged Counter/Timers
     cycle = 0xC00,
     time = 0xC01,
@@ -84,6 +92,11 @@ pub enum CSRegister {
     hpmcounter29 = 0xC1D,
     hpmcounter30 = 0xC1E,
     hpmcounter31 = 0xC1F,
+
+    // Unprivileged Vector CSRs
+    vl = 0xC20,
+    vtype = 0xC21,
+    vlenb = 0xC22,
 }
 
 impl CSRegister {
@@ -205,6 +218,18 @@ impl CSRegister {
     /// FFLAGS mask
     const FFLAGS_MASK: CSRRepr = 0b11111;
 
+    /// VCSR mask
+    const VCSR_MASK: CSRRepr = Self::VXRM_MASK | Self::VXSAT_MASK;
+
+    /// VXRM mask
+    const VXRM_MASK: CSRRepr = 0b11 << Self::VXRM_SHIFT;
+
+    /// VXRM is bits 1..2
+    const VXRM_SHIFT: usize = 1;
+
+    /// VXSAT mask
+    const VXSAT_MASK: CSRRepr = 0b1;
+
     /// Get the default value for the register.
     fn default_value(&self) -> u64 {
         match self {
@@ -260,6 +285,27 @@ impl CSRegister {
                 // fcsr is a combination of fflags and fcsr
                 CSRegister::fflags.default_value() & (CSRegister::frm.default_value() << 5)
             }
+
+            CSRegister::vstart | CSRegister::vxsat | CSRegister::vxrm | CSRegister::vcsr => {
+                // Vector operations start from the first element, with no saturation and
+                // rounding towards nearest, ties up
+                0
+            }
+
+            CSRegister::vl => {
+                // No elements are active until a vset{i}vl{i} instruction configures the unit
+                0
+            }
+
+            CSRegister::vtype => {
+                // The vector unit starts in an illegal configuration
+                VTYPE_VILL
+            }
+
+            CSRegister::vlenb => {
+                // The vector register length in bytes is fixed
+                VLENB as u64
+            }
         }
     }
 
@@ -273,6 +319,12 @@ impl CSRegister {
             0x002 => Some(frm),
             0x003 => Some(fcsr),
 
+            // Unprivileged Vector CSRs
+            0x008 => Some(vstart),
+            0x009 => Some(vxsat),
+            0x00A => Some(vxrm),
+            0x00F => Some(vcsr),
+
             // Unprivileged Counter/Timers
             0xC00 => Some(cycle),
             0xC01 => Some(time),
@@ -306,6 +358,9 @@ impl CSRegister {
             0xC1D => Some(hpmcounter29),
             0xC1E => Some(hpmcounter30),
             0xC1F => Some(hpmcounter31),
+            0xC20 => Some(vl),
+            0xC21 => Some(vtype),
+            0xC22 => Some(vlenb),
 
             _ => None,
         }
@@ -362,6 +417,17 @@ impl<M: backend::ManagerBase> CSRegisters<M> {
                 let fcsr = fcsr & !CSRegister::FFLAGS_MASK;
                 (value & CSRegister::FFLAGS_MASK) | fcsr
             }
+            CSRegister::vcsr => value & CSRegister::VCSR_MASK,
+            CSRegister::vxrm => {
+                let vcsr = self.registers.vcsr.read();
+                let vcsr = vcsr & !CSRegister::VXRM_MASK;
+                ((value << CSRegister::VXRM_SHIFT) & CSRegister::VXRM_MASK) | vcsr
+            }
+            CSRegister::vxsat => {
+                let vcsr = self.registers.vcsr.read();
+                let vcsr = vcsr & !CSRegister::VXSAT_MASK;
+                (value & CSRegister::VXSAT_MASK) | vcsr
+            }
             _ => value,
         }
     }
@@ -387,6 +453,11 @@ impl<M: backend::ManagerBase> CSRegisters<M> {
             CSRegister::fcsr => source_reg_value & CSRegister::FCSR_MASK,
             CSRegister::frm => (source_reg_value & CSRegister::FRM_MASK) >> CSRegister::FRM_SHIFT,
             CSRegister::fflags => source_reg_value & CSRegister::FFLAGS_MASK,
+            CSRegister::vcsr => source_reg_value & CSRegister::VCSR_MASK,
+            CSRegister::vxrm => {
+                (source_reg_value & CSRegister::VXRM_MASK) >> CSRegister::VXRM_SHIFT
+            }
+            CSRegister::vxsat => source_reg_value & CSRegister::VXSAT_MASK,
             _ => source_reg_value,
         }
     }
@@ -591,4 +662,36 @@ mod tests {
         assert_eq!(0b010, csrs.read::<CSRRepr>(CSRegister::frm));
         assert_eq!(0b01010, csrs.read::<CSRRepr>(CSRegister::fflags));
     });
+
+    backend_test!(test_vcsr, F, {
+        let mut csrs = CSRegisters::new(&mut F::manager());
+        csrs.reset();
+
+        // check starting values
+        assert_eq!(0, csrs.read::<CSRRepr>(CSRegister::vcsr));
+        assert_eq!(0, csrs.read::<CSRRepr>(CSRegister::vl));
+        assert_eq!(1 << 63, csrs.read::<CSRRepr>(CSRegister::vtype));
+        assert_eq!(16, csrs.read::<CSRRepr>(CSRegister::vlenb));
+
+        // writing to vcsr is reflected in vxrm/vxsat
+        csrs.write(CSRegister::vcsr, u64::MAX);
+
+        assert_eq!(0b111, csrs.read::<CSRRepr>(CSRegister::vcsr));
+        assert_eq!(0b11, csrs.read::<CSRRepr>(CSRegister::vxrm));
+        assert_eq!(0b1, csrs.read::<CSRRepr>(CSRegister::vxsat));
+
+        // writing to vxrm is reflected in vcsr
+        csrs.write(CSRegister::vxrm, 0b10);
+
+        assert_eq!(0b101, csrs.read::<CSRRepr>(CSRegister::vcsr));
+        assert_eq!(0b10, csrs.read::<CSRRepr>(CSRegister::vxrm));
+        assert_eq!(0b1, csrs.read::<CSRRepr>(CSRegister::vxsat));
+
+        // writing to vxsat is reflected in vcsr
+        csrs.write(CSRegister::vxsat, 0);
+
+        assert_eq!(0b100, csrs.read::<CSRRepr>(CSRegister::vcsr));
+        assert_eq!(0b10, csrs.read::<CSRRepr>(CSRegister::vxrm));
+        assert_eq!(0, csrs.read::<CSRRepr>(CSRegister::vxsat));
+    });
 }
@@ -18,6 +18,13 @@ pub(super) enum RootCSRegister {
     // Unprivileged Floating-Point CSRs
     fcsr,
 
+    // Unprivileged Vector CSRs
+    vstart,
+    vcsr,
+    vl,
+    vtype,
+    vlenb,
+
     // Unprivileged Counter/Timers
     cycle,
     time,
@@ -62,6 +69,15 @@ impl From<CSRegister> for RootCSRegister {
             // frm is a shadow of fcsr
             CSRegister::frm => RootCSRegister::fcsr,
             CSRegister::fcsr => RootCSRegister::fcsr,
+            CSRegister::vstart => RootCSRegister::vstart,
+            // vxsat is a shadow of vcsr
+            CSRegister::vxsat => RootCSRegister::vcsr,
+            // vxrm is a shadow of vcsr
+            CSRegister::vxrm => RootCSRegister::vcsr,
+            CSRegister::vcsr => RootCSRegister::vcsr,
+            CSRegister::vl => RootCSRegister::vl,
+            CSRegister::vtype => RootCSRegister::vtype,
+            CSRegister::vlenb => RootCSRegister::vlenb,
             CSRegister::cycle => RootCSRegister::cycle,
             CSRegister::time => RootCSRegister::time,
             CSRegister::instret => RootCSRegister::instret,
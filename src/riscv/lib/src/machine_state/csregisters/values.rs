@@ -225,6 +225,11 @@ pub struct CSRValuesF<Raw> {
     pub hpmcounter30: Raw,
     pub hpmcounter31: Raw,
     pub fcsr: Raw,
+    pub vstart: Raw,
+    pub vcsr: Raw,
+    pub vl: Raw,
+    pub vtype: Raw,
+    pub vlenb: Raw,
 }
 
 impl<Raw> CSRValuesF<Raw> {
@@ -272,6 +277,11 @@ impl<Raw> CSRValuesF<Raw> {
             hpmcounter30: map_raw(self.hpmcounter30),
             hpmcounter31: map_raw(self.hpmcounter31),
             fcsr: map_raw(self.fcsr),
+            vstart: map_raw(self.vstart),
+            vcsr: map_raw(self.vcsr),
+            vl: map_raw(self.vl),
+            vtype: map_raw(self.vtype),
+            vlenb: map_raw(self.vlenb),
         }
     }
 
@@ -315,6 +325,11 @@ impl<Raw> CSRValuesF<Raw> {
             hpmcounter30: try_map_raw(self.hpmcounter30)?,
             hpmcounter31: try_map_raw(self.hpmcounter31)?,
             fcsr: try_map_raw(self.fcsr)?,
+            vstart: try_map_raw(self.vstart)?,
+            vcsr: try_map_raw(self.vcsr)?,
+            vl: try_map_raw(self.vl)?,
+            vtype: try_map_raw(self.vtype)?,
+            vlenb: try_map_raw(self.vlenb)?,
         })
     }
 
@@ -355,6 +370,11 @@ impl<Raw> CSRValuesF<Raw> {
             hpmcounter30: &self.hpmcounter30,
             hpmcounter31: &self.hpmcounter31,
             fcsr: &self.fcsr,
+            vstart: &self.vstart,
+            vcsr: &self.vcsr,
+            vl: &self.vl,
+            vtype: &self.vtype,
+            vlenb: &self.vlenb,
         }
     }
 
@@ -395,6 +415,11 @@ impl<Raw> CSRValuesF<Raw> {
             RootCSRegister::hpmcounter30 => fold_raw(&self.hpmcounter30),
             RootCSRegister::hpmcounter31 => fold_raw(&self.hpmcounter31),
             RootCSRegister::fcsr => fold_raw(&self.fcsr),
+            RootCSRegister::vstart => fold_raw(&self.vstart),
+            RootCSRegister::vcsr => fold_raw(&self.vcsr),
+            RootCSRegister::vl => fold_raw(&self.vl),
+            RootCSRegister::vtype => fold_raw(&self.vtype),
+            RootCSRegister::vlenb => fold_raw(&self.vlenb),
         }
     }
 
@@ -435,6 +460,11 @@ impl<Raw> CSRValuesF<Raw> {
             RootCSRegister::hpmcounter30 => fold_raw(&mut self.hpmcounter30),
             RootCSRegister::hpmcounter31 => fold_raw(&mut self.hpmcounter31),
             RootCSRegister::fcsr => fold_raw(&mut self.fcsr),
+            RootCSRegister::vstart => fold_raw(&mut self.vstart),
+            RootCSRegister::vcsr => fold_raw(&mut self.vcsr),
+            RootCSRegister::vl => fold_raw(&mut self.vl),
+            RootCSRegister::vtype => fold_raw(&mut self.vtype),
+            RootCSRegister::vlenb => fold_raw(&mut self.vlenb),
         }
     }
 }
@@ -478,6 +508,11 @@ where
             self.hpmcounter30.aggregate_access_info(),
             self.hpmcounter31.aggregate_access_info(),
             self.fcsr.aggregate_access_info(),
+            self.vstart.aggregate_access_info(),
+            self.vcsr.aggregate_access_info(),
+            self.vl.aggregate_access_info(),
+            self.vtype.aggregate_access_info(),
+            self.vlenb.aggregate_access_info(),
         ];
         children.iter().any(|&x| x)
     }
@@ -8,6 +8,7 @@ use crate::machine_state::memory::Address;
 use crate::machine_state::registers;
 use crate::machine_state::reservation_set;
 use crate::machine_state::reservation_set::ReservationSet;
+use crate::machine_state::vector;
 use crate::state::NewState;
 use crate::state_backend as backend;
 use crate::state_backend::Atom;
@@ -29,6 +30,9 @@ pub struct HartState<M: backend::ManagerBase> {
 
     /// Reservation set address
     pub reservation_set: ReservationSet<M>,
+
+    /// Vector registers
+    pub vregisters: vector::VRegisters<M>,
 }
 
 /// Layout of [HartState]
@@ -38,6 +42,7 @@ pub type HartStateLayout = (
     csregisters::CSRegistersLayout,
     Atom<Address>,                         // Program counter layout
     reservation_set::ReservationSetLayout, // Reservation set layout
+    vector::VRegistersLayout,
 );
 
 impl<M: backend::ManagerBase> HartState<M> {
@@ -49,6 +54,7 @@ impl<M: backend::ManagerBase> HartState<M> {
             csregisters: csregisters::CSRegisters::bind(space.2),
             pc: space.3,
             reservation_set: ReservationSet::bind(space.4),
+            vregisters: vector::VRegisters::bind(space.5),
         }
     }
 
@@ -63,6 +69,7 @@ impl<M: backend::ManagerBase> HartState<M> {
             self.csregisters.struct_ref::<F>(),
             self.pc.struct_ref::<F>(),
             self.reservation_set.struct_ref::<F>(),
+            self.vregisters.struct_ref::<F>(),
         )
     }
 
@@ -76,6 +83,7 @@ impl<M: backend::ManagerBase> HartState<M> {
         self.csregisters.reset();
         self.pc.write(pc);
         self.reservation_set.reset();
+        self.vregisters.reset();
     }
 }
 
@@ -90,6 +98,7 @@ impl<M: backend::ManagerBase> NewState<M> for HartState<M> {
             csregisters: csregisters::CSRegisters::new(manager),
             pc: Cell::new(manager),
             reservation_set: ReservationSet::new(manager),
+            vregisters: vector::VRegisters::new(manager),
         }
     }
 }
@@ -102,6 +111,7 @@ impl<M: backend::ManagerClone> Clone for HartState<M> {
             csregisters: self.csregisters.clone(),
             pc: self.pc.clone(),
             reservation_set: self.reservation_set.clone(),
+            vregisters: self.vregisters.clone(),
         }
     }
 }
@@ -35,7 +35,13 @@ use super::registers::NonZeroXRegister;
 use super::registers::XRegister;
 use super::registers::nz;
 use super::registers::sp;
+use super::vector::VRegister;
 use crate::default::ConstDefault;
+use crate::instruction_context::FArith;
+use crate::instruction_context::FBinary;
+use crate::instruction_context::FCompare;
+use crate::instruction_context::FIntFormat;
+use crate::instruction_context::FloatFormat;
 use crate::instruction_context::ICB;
 use crate::instruction_context::IcbFnResult;
 use crate::instruction_context::IcbLoweringFn;
@@ -43,9 +49,13 @@ use crate::instruction_context::MulHighType;
 use crate::instruction_context::Predicate;
 use crate::instruction_context::Shift;
 use crate::interpreter::atomics;
+use crate::interpreter::bitmanip;
 use crate::interpreter::branching;
+use crate::interpreter::crypto;
+use crate::interpreter::float;
 use crate::interpreter::integer;
 use crate::interpreter::load_store;
+use crate::interpreter::rv64zicsr;
 use crate::machine_state::ProgramCounterUpdate::Next;
 use crate::parser::instruction::AmoArgs;
 use crate::parser::instruction::CIBDTypeArgs;
@@ -57,6 +67,7 @@ use crate::parser::instruction::CRJTypeArgs;
 use crate::parser::instruction::CRTypeArgs;
 use crate::parser::instruction::CSSDTypeArgs;
 use crate::parser::instruction::CSSTypeArgs;
+use crate::parser::instruction::CboArgs;
 use crate::parser::instruction::CsrArgs;
 use crate::parser::instruction::CsriArgs;
 use crate::parser::instruction::FCmpArgs;
@@ -75,8 +86,19 @@ use crate::parser::instruction::NonZeroRdRTypeArgs;
 use crate::parser::instruction::NonZeroRdUJTypeArgs;
 use crate::parser::instruction::RTypeArgs;
 use crate::parser::instruction::UJTypeArgs;
+use crate::parser::instruction::VIArgs;
+use crate::parser::instruction::VImmArgs;
+use crate::parser::instruction::VLoadArgs;
+use crate::parser::instruction::VRegToVRegArgs;
+use crate::parser::instruction::VRegToXRegArgs;
+use crate::parser::instruction::VStoreArgs;
+use crate::parser::instruction::VVArgs;
+use crate::parser::instruction::VXArgs;
+use crate::parser::instruction::VsetivliArgs;
+use crate::parser::instruction::VsetvliArgs;
 use crate::parser::instruction::XRegToFRegArgs;
 use crate::parser::instruction::XRegToFRegArgsWithRounding;
+use crate::parser::instruction::XRegToVRegArgs;
 use crate::state_backend::ManagerReadWrite;
 use crate::traps::Exception;
 
@@ -146,6 +168,21 @@ impl Debug for Instruction {
                         debug_struct.field("rs1", &self.args.rs1.x);
                         debug_struct.field("rs2", &self.args.rs2.x);
                     }
+                    ArgsShape::VSrcVDest => {
+                        debug_struct.field("rd", &self.args.rd.v);
+                        debug_struct.field("rs1", &self.args.rs1.v);
+                        debug_struct.field("rs2", &self.args.rs2.v);
+                    }
+                    ArgsShape::XSrcVDest => {
+                        debug_struct.field("rd", &self.args.rd.v);
+                        debug_struct.field("rs1", &self.args.rs1.x);
+                        debug_struct.field("rs2", &self.args.rs2.v);
+                    }
+                    ArgsShape::VSrcXDest => {
+                        debug_struct.field("rd", &self.args.rd.x);
+                        debug_struct.field("rs1", &self.args.rs1.v);
+                        debug_struct.field("rs2", &self.args.rs2.v);
+                    }
                 }
             }
             debug_struct
@@ -266,25 +303,30 @@ pub enum OpCode {
     X32AtomicLoad,
     X32AtomicStore,
     X32AtomicSwap,
-    Amoaddw,
-    Amoxorw,
-    Amoandw,
-    Amoorw,
-    Amominw,
-    Amomaxw,
-    Amominuw,
-    Amomaxuw,
+    X32AtomicAdd,
+    X32AtomicXor,
+    X32AtomicAnd,
+    X32AtomicOr,
+    X32AtomicMinSigned,
+    X32AtomicMaxSigned,
+    X32AtomicMinUnsigned,
+    X32AtomicMaxUnsigned,
     X64AtomicLoad,
     X64AtomicStore,
     X64AtomicSwap,
     X64AtomicAdd,
-    Amoxord,
-    Amoandd,
-    Amoord,
+    X64AtomicXor,
+    X64AtomicAnd,
+    X64AtomicOr,
     X64AtomicMinSigned,
-    Amomaxd,
-    Amominud,
-    Amomaxud,
+    X64AtomicMaxSigned,
+    X64AtomicMinUnsigned,
+    X64AtomicMaxUnsigned,
+
+    // Zacas atomic compare-and-swap instructions
+    X32AtomicCompareAndSwap,
+    X64AtomicCompareAndSwap,
+    X128AtomicCompareAndSwap,
 
     // RV64M division instructions
     X64RemSigned,
@@ -301,6 +343,72 @@ pub enum OpCode {
     X64MulHighUnsigned,
     X32Mul,
 
+    // Zba address generation instructions
+    Shift1Add,
+    Shift2Add,
+    Shift3Add,
+    AddUnsignedWord,
+    Shift1AddUnsignedWord,
+    Shift2AddUnsignedWord,
+    Shift3AddUnsignedWord,
+    ShiftLeftImmediateUnsignedWord,
+
+    // Zbb basic bit-manipulation instructions
+    AndNot,
+    OrNot,
+    XorNot,
+    CountLeadingZeros,
+    CountTrailingZeros,
+    CountSetBits,
+    X32CountLeadingZeros,
+    X32CountTrailingZeros,
+    X32CountSetBits,
+    MaxSigned,
+    MaxUnsigned,
+    MinSigned,
+    MinUnsigned,
+    SignExtendByte,
+    SignExtendHalf,
+    ZeroExtendHalf,
+    RotateLeft,
+    RotateRight,
+    RotateRightImmediate,
+    X32RotateLeft,
+    X32RotateRight,
+    X32RotateRightImmediate,
+    OrCombineBytes,
+    ByteReverse,
+
+    // Zbs single-bit instructions
+    BitClear,
+    BitClearImmediate,
+    BitExtract,
+    BitExtractImmediate,
+    BitInvert,
+    BitInvertImmediate,
+    BitSet,
+    BitSetImmediate,
+
+    // Zbkb bit-manipulation instructions for cryptography
+    Pack,
+    PackByte,
+    X32Pack,
+    ReverseBitsInBytes,
+
+    // Zknh SHA-2 hash function instructions
+    Sha256Sum0,
+    Sha256Sum1,
+    Sha256Sig0,
+    Sha256Sig1,
+    Sha512Sum0,
+    Sha512Sum1,
+    Sha512Sig0,
+    Sha512Sig1,
+
+    // Zicond conditional-zero instructions
+    ConditionalZeroEqualZero,
+    ConditionalZeroNotEqualZero,
+
     // RV64F instructions
     FclassS,
     Feqs,
@@ -367,6 +475,98 @@ pub enum OpCode {
     FmvXD,
     FmvDX,
 
+    // Zfh half-precision instructions
+    FclassH,
+    Feqh,
+    Fleh,
+    Flth,
+    Faddh,
+    Fsubh,
+    Fmulh,
+    Fdivh,
+    Fsqrth,
+    Fminh,
+    Fmaxh,
+    Fmaddh,
+    Fmsubh,
+    Fnmsubh,
+    Fnmaddh,
+    Flh,
+    Fsh,
+    Fcvthw,
+    Fcvthwu,
+    Fcvthl,
+    Fcvthlu,
+    Fcvtwh,
+    Fcvtwuh,
+    Fcvtlh,
+    Fcvtluh,
+    Fcvtsh,
+    Fcvths,
+    Fcvtdh,
+    Fcvthd,
+    Fsgnjh,
+    Fsgnjnh,
+    Fsgnjxh,
+    FmvXH,
+    FmvHX,
+
+    // Zve64x vector instructions
+    Vsetvli,
+    Vsetivli,
+    Vsetvl,
+    Vle8,
+    Vle16,
+    Vle32,
+    Vle64,
+    Vse8,
+    Vse16,
+    Vse32,
+    Vse64,
+    VaddVV,
+    VaddVX,
+    VaddVI,
+    VsubVV,
+    VsubVX,
+    VrsubVX,
+    VrsubVI,
+    VandVV,
+    VandVX,
+    VandVI,
+    VorVV,
+    VorVX,
+    VorVI,
+    VxorVV,
+    VxorVX,
+    VxorVI,
+    VsllVV,
+    VsllVX,
+    VsllVI,
+    VsrlVV,
+    VsrlVX,
+    VsrlVI,
+    VsraVV,
+    VsraVX,
+    VsraVI,
+    VminuVV,
+    VminuVX,
+    VminVV,
+    VminVX,
+    VmaxuVV,
+    VmaxuVX,
+    VmaxVV,
+    VmaxVX,
+    VmulVV,
+    VmulVX,
+    VmvVV,
+    VmvVX,
+    VmvVI,
+    VmvXS,
+    VmvSX,
+
+    // Zicboz cache-block zeroing
+    CboZero,
+
     // Zicsr instructions
     Csrrw,
     Csrrs,
@@ -485,25 +685,28 @@ impl OpCode {
             Self::X32AtomicLoad => Args::run_x32_atomic_load,
             Self::X32AtomicStore => Args::run_x32_atomic_store,
             Self::X32AtomicSwap => Args::run_x32_atomic_swap,
-            Self::Amoaddw => Args::run_amoaddw,
-            Self::Amoxorw => Args::run_amoxorw,
-            Self::Amoandw => Args::run_amoandw,
-            Self::Amoorw => Args::run_amoorw,
-            Self::Amominw => Args::run_amominw,
-            Self::Amomaxw => Args::run_amomaxw,
-            Self::Amominuw => Args::run_amominuw,
-            Self::Amomaxuw => Args::run_amomaxuw,
+            Self::X32AtomicAdd => Args::run_x32_atomic_add,
+            Self::X32AtomicXor => Args::run_x32_atomic_xor,
+            Self::X32AtomicAnd => Args::run_x32_atomic_and,
+            Self::X32AtomicOr => Args::run_x32_atomic_or,
+            Self::X32AtomicMinSigned => Args::run_x32_atomic_min_signed,
+            Self::X32AtomicMaxSigned => Args::run_x32_atomic_max_signed,
+            Self::X32AtomicMinUnsigned => Args::run_x32_atomic_min_unsigned,
+            Self::X32AtomicMaxUnsigned => Args::run_x32_atomic_max_unsigned,
             Self::X64AtomicLoad => Args::run_x64_atomic_load,
             Self::X64AtomicStore => Args::run_x64_atomic_store,
             Self::X64AtomicSwap => Args::run_x64_atomic_swap,
             Self::X64AtomicAdd => Args::run_x64_atomic_add,
-            Self::Amoxord => Args::run_amoxord,
-            Self::Amoandd => Args::run_amoandd,
-            Self::Amoord => Args::run_amoord,
+            Self::X64AtomicXor => Args::run_x64_atomic_xor,
+            Self::X64AtomicAnd => Args::run_x64_atomic_and,
+            Self::X64AtomicOr => Args::run_x64_atomic_or,
             Self::X64AtomicMinSigned => Args::run_x64_atomic_min_signed,
-            Self::Amomaxd => Args::run_amomaxd,
-            Self::Amominud => Args::run_amominud,
-            Self::Amomaxud => Args::run_amomaxud,
+            Self::X64AtomicMaxSigned => Args::run_x64_atomic_max_signed,
+            Self::X64AtomicMinUnsigned => Args::run_x64_atomic_min_unsigned,
+            Self::X64AtomicMaxUnsigned => Args::run_x64_atomic_max_unsigned,
+            Self::X32AtomicCompareAndSwap => Args::run_x32_atomic_compare_and_swap,
+            Self::X64AtomicCompareAndSwap => Args::run_x64_atomic_compare_and_swap,
+            Self::X128AtomicCompareAndSwap => Args::run_x128_atomic_compare_and_swap,
             Self::X64RemSigned => Args::run_x64_rem_signed,
             Self::X64RemUnsigned => Args::run_x64_rem_unsigned,
             Self::X32RemSigned => Args::run_x32_rem_signed,
@@ -517,6 +720,60 @@ impl OpCode {
             Self::X64MulHighSignedUnsigned => Args::run_x64_mul_high_signed_unsigned,
             Self::X64MulHighUnsigned => Args::run_x64_mul_high_unsigned,
             Self::X32Mul => Args::run_x32_mul,
+            Self::Shift1Add => Args::run_shift1_add,
+            Self::Shift2Add => Args::run_shift2_add,
+            Self::Shift3Add => Args::run_shift3_add,
+            Self::AddUnsignedWord => Args::run_add_unsigned_word,
+            Self::Shift1AddUnsignedWord => Args::run_shift1_add_unsigned_word,
+            Self::Shift2AddUnsignedWord => Args::run_shift2_add_unsigned_word,
+            Self::Shift3AddUnsignedWord => Args::run_shift3_add_unsigned_word,
+            Self::ShiftLeftImmediateUnsignedWord => Args::run_shift_left_immediate_unsigned_word,
+            Self::AndNot => Args::run_and_not,
+            Self::OrNot => Args::run_or_not,
+            Self::XorNot => Args::run_xor_not,
+            Self::CountLeadingZeros => Args::run_count_leading_zeros,
+            Self::CountTrailingZeros => Args::run_count_trailing_zeros,
+            Self::CountSetBits => Args::run_count_set_bits,
+            Self::X32CountLeadingZeros => Args::run_x32_count_leading_zeros,
+            Self::X32CountTrailingZeros => Args::run_x32_count_trailing_zeros,
+            Self::X32CountSetBits => Args::run_x32_count_set_bits,
+            Self::MaxSigned => Args::run_max_signed,
+            Self::MaxUnsigned => Args::run_max_unsigned,
+            Self::MinSigned => Args::run_min_signed,
+            Self::MinUnsigned => Args::run_min_unsigned,
+            Self::SignExtendByte => Args::run_sign_extend_byte,
+            Self::SignExtendHalf => Args::run_sign_extend_half,
+            Self::ZeroExtendHalf => Args::run_zero_extend_half,
+            Self::RotateLeft => Args::run_rotate_left,
+            Self::RotateRight => Args::run_rotate_right,
+            Self::RotateRightImmediate => Args::run_rotate_right_immediate,
+            Self::X32RotateLeft => Args::run_x32_rotate_left,
+            Self::X32RotateRight => Args::run_x32_rotate_right,
+            Self::X32RotateRightImmediate => Args::run_x32_rotate_right_immediate,
+            Self::OrCombineBytes => Args::run_or_combine_bytes,
+            Self::ByteReverse => Args::run_byte_reverse,
+            Self::BitClear => Args::run_bit_clear,
+            Self::BitClearImmediate => Args::run_bit_clear_immediate,
+            Self::BitExtract => Args::run_bit_extract,
+            Self::BitExtractImmediate => Args::run_bit_extract_immediate,
+            Self::BitInvert => Args::run_bit_invert,
+            Self::BitInvertImmediate => Args::run_bit_invert_immediate,
+            Self::BitSet => Args::run_bit_set,
+            Self::BitSetImmediate => Args::run_bit_set_immediate,
+            Self::Pack => Args::run_pack,
+            Self::PackByte => Args::run_pack_byte,
+            Self::X32Pack => Args::run_x32_pack,
+            Self::ReverseBitsInBytes => Args::run_reverse_bits_in_bytes,
+            Self::Sha256Sum0 => Args::run_sha256_sum0,
+            Self::Sha256Sum1 => Args::run_sha256_sum1,
+            Self::Sha256Sig0 => Args::run_sha256_sig0,
+            Self::Sha256Sig1 => Args::run_sha256_sig1,
+            Self::Sha512Sum0 => Args::run_sha512_sum0,
+            Self::Sha512Sum1 => Args::run_sha512_sum1,
+            Self::Sha512Sig0 => Args::run_sha512_sig0,
+            Self::Sha512Sig1 => Args::run_sha512_sig1,
+            Self::ConditionalZeroEqualZero => Args::run_conditional_zero_equal_zero,
+            Self::ConditionalZeroNotEqualZero => Args::run_conditional_zero_not_equal_zero,
             Self::FclassS => Args::run_fclass_s,
             Self::Feqs => Args::run_feq_s,
             Self::Fles => Args::run_fle_s,
@@ -579,6 +836,92 @@ impl OpCode {
             Self::Fsgnjxd => Args::run_fsgnjx_d,
             Self::FmvXD => Args::run_fmv_x_d,
             Self::FmvDX => Args::run_fmv_d_x,
+            Self::FclassH => Args::run_fclass_h,
+            Self::Feqh => Args::run_feq_h,
+            Self::Fleh => Args::run_fle_h,
+            Self::Flth => Args::run_flt_h,
+            Self::Faddh => Args::run_fadd_h,
+            Self::Fsubh => Args::run_fsub_h,
+            Self::Fmulh => Args::run_fmul_h,
+            Self::Fdivh => Args::run_fdiv_h,
+            Self::Fsqrth => Args::run_fsqrt_h,
+            Self::Fminh => Args::run_fmin_h,
+            Self::Fmaxh => Args::run_fmax_h,
+            Self::Fmaddh => Args::run_fmadd_h,
+            Self::Fmsubh => Args::run_fmsub_h,
+            Self::Fnmsubh => Args::run_fnmsub_h,
+            Self::Fnmaddh => Args::run_fnmadd_h,
+            Self::Flh => Args::run_flh,
+            Self::Fsh => Args::run_fsh,
+            Self::Fcvthw => Args::run_fcvt_h_w,
+            Self::Fcvthwu => Args::run_fcvt_h_wu,
+            Self::Fcvthl => Args::run_fcvt_h_l,
+            Self::Fcvthlu => Args::run_fcvt_h_lu,
+            Self::Fcvtwh => Args::run_fcvt_w_h,
+            Self::Fcvtwuh => Args::run_fcvt_wu_h,
+            Self::Fcvtlh => Args::run_fcvt_l_h,
+            Self::Fcvtluh => Args::run_fcvt_lu_h,
+            Self::Fcvtsh => Args::run_fcvt_s_h,
+            Self::Fcvths => Args::run_fcvt_h_s,
+            Self::Fcvtdh => Args::run_fcvt_d_h,
+            Self::Fcvthd => Args::run_fcvt_h_d,
+            Self::Fsgnjh => Args::run_fsgnj_h,
+            Self::Fsgnjnh => Args::run_fsgnjn_h,
+            Self::Fsgnjxh => Args::run_fsgnjx_h,
+            Self::FmvXH => Args::run_fmv_x_h,
+            Self::FmvHX => Args::run_fmv_h_x,
+            Self::Vsetvli => Args::run_vsetvli,
+            Self::Vsetivli => Args::run_vsetivli,
+            Self::Vsetvl => Args::run_vsetvl,
+            Self::Vle8 => Args::run_vle8,
+            Self::Vle16 => Args::run_vle16,
+            Self::Vle32 => Args::run_vle32,
+            Self::Vle64 => Args::run_vle64,
+            Self::Vse8 => Args::run_vse8,
+            Self::Vse16 => Args::run_vse16,
+            Self::Vse32 => Args::run_vse32,
+            Self::Vse64 => Args::run_vse64,
+            Self::VaddVV => Args::run_vadd_vv,
+            Self::VaddVX => Args::run_vadd_vx,
+            Self::VaddVI => Args::run_vadd_vi,
+            Self::VsubVV => Args::run_vsub_vv,
+            Self::VsubVX => Args::run_vsub_vx,
+            Self::VrsubVX => Args::run_vrsub_vx,
+            Self::VrsubVI => Args::run_vrsub_vi,
+            Self::VandVV => Args::run_vand_vv,
+            Self::VandVX => Args::run_vand_vx,
+            Self::VandVI => Args::run_vand_vi,
+            Self::VorVV => Args::run_vor_vv,
+            Self::VorVX => Args::run_vor_vx,
+            Self::VorVI => Args::run_vor_vi,
+            Self::VxorVV => Args::run_vxor_vv,
+            Self::VxorVX => Args::run_vxor_vx,
+            Self::VxorVI => Args::run_vxor_vi,
+            Self::VsllVV => Args::run_vsll_vv,
+            Self::VsllVX => Args::run_vsll_vx,
+            Self::VsllVI => Args::run_vsll_vi,
+            Self::VsrlVV => Args::run_vsrl_vv,
+            Self::VsrlVX => Args::run_vsrl_vx,
+            Self::VsrlVI => Args::run_vsrl_vi,
+            Self::VsraVV => Args::run_vsra_vv,
+            Self::VsraVX => Args::run_vsra_vx,
+            Self::VsraVI => Args::run_vsra_vi,
+            Self::VminuVV => Args::run_vminu_vv,
+            Self::VminuVX => Args::run_vminu_vx,
+            Self::VminVV => Args::run_vmin_vv,
+            Self::VminVX => Args::run_vmin_vx,
+            Self::VmaxuVV => Args::run_vmaxu_vv,
+            Self::VmaxuVX => Args::run_vmaxu_vx,
+            Self::VmaxVV => Args::run_vmax_vv,
+            Self::VmaxVX => Args::run_vmax_vx,
+            Self::VmulVV => Args::run_vmul_vv,
+            Self::VmulVX => Args::run_vmul_vx,
+            Self::VmvVV => Args::run_vmv_v_v,
+            Self::VmvVX => Args::run_vmv_v_x,
+            Self::VmvVI => Args::run_vmv_v_i,
+            Self::VmvXS => Args::run_vmv_x_s,
+            Self::VmvSX => Args::run_vmv_s_x,
+            Self::CboZero => Args::run_cbo_zero,
             Self::Csrrw => Args::run_csrrw,
             Self::Csrrs => Args::run_csrrs,
             Self::Csrrc => Args::run_csrrc,
@@ -633,6 +976,62 @@ impl OpCode {
             Self::X64XorImm => Some(Args::run_x64_xor_immediate),
             Self::Mul => Some(Args::run_mul),
             Self::X32Mul => Some(Args::run_x32_mul),
+            Self::Shift1Add => Some(Args::run_shift1_add),
+            Self::Shift2Add => Some(Args::run_shift2_add),
+            Self::Shift3Add => Some(Args::run_shift3_add),
+            Self::AddUnsignedWord => Some(Args::run_add_unsigned_word),
+            Self::Shift1AddUnsignedWord => Some(Args::run_shift1_add_unsigned_word),
+            Self::Shift2AddUnsignedWord => Some(Args::run_shift2_add_unsigned_word),
+            Self::Shift3AddUnsignedWord => Some(Args::run_shift3_add_unsigned_word),
+            Self::ShiftLeftImmediateUnsignedWord => {
+                Some(Args::run_shift_left_immediate_unsigned_word)
+            }
+            Self::AndNot => Some(Args::run_and_not),
+            Self::OrNot => Some(Args::run_or_not),
+            Self::XorNot => Some(Args::run_xor_not),
+            Self::CountLeadingZeros => Some(Args::run_count_leading_zeros),
+            Self::CountTrailingZeros => Some(Args::run_count_trailing_zeros),
+            Self::CountSetBits => Some(Args::run_count_set_bits),
+            Self::X32CountLeadingZeros => Some(Args::run_x32_count_leading_zeros),
+            Self::X32CountTrailingZeros => Some(Args::run_x32_count_trailing_zeros),
+            Self::X32CountSetBits => Some(Args::run_x32_count_set_bits),
+            Self::MaxSigned => Some(Args::run_max_signed),
+            Self::MaxUnsigned => Some(Args::run_max_unsigned),
+            Self::MinSigned => Some(Args::run_min_signed),
+            Self::MinUnsigned => Some(Args::run_min_unsigned),
+            Self::SignExtendByte => Some(Args::run_sign_extend_byte),
+            Self::SignExtendHalf => Some(Args::run_sign_extend_half),
+            Self::ZeroExtendHalf => Some(Args::run_zero_extend_half),
+            Self::RotateLeft => Some(Args::run_rotate_left),
+            Self::RotateRight => Some(Args::run_rotate_right),
+            Self::RotateRightImmediate => Some(Args::run_rotate_right_immediate),
+            Self::X32RotateLeft => Some(Args::run_x32_rotate_left),
+            Self::X32RotateRight => Some(Args::run_x32_rotate_right),
+            Self::X32RotateRightImmediate => Some(Args::run_x32_rotate_right_immediate),
+            Self::OrCombineBytes => Some(Args::run_or_combine_bytes),
+            Self::ByteReverse => Some(Args::run_byte_reverse),
+            Self::BitClear => Some(Args::run_bit_clear),
+            Self::BitClearImmediate => Some(Args::run_bit_clear_immediate),
+            Self::BitExtract => Some(Args::run_bit_extract),
+            Self::BitExtractImmediate => Some(Args::run_bit_extract_immediate),
+            Self::BitInvert => Some(Args::run_bit_invert),
+            Self::BitInvertImmediate => Some(Args::run_bit_invert_immediate),
+            Self::BitSet => Some(Args::run_bit_set),
+            Self::BitSetImmediate => Some(Args::run_bit_set_immediate),
+            Self::Pack => Some(Args::run_pack),
+            Self::PackByte => Some(Args::run_pack_byte),
+            Self::X32Pack => Some(Args::run_x32_pack),
+            Self::ReverseBitsInBytes => Some(Args::run_reverse_bits_in_bytes),
+            Self::Sha256Sum0 => Some(Args::run_sha256_sum0),
+            Self::Sha256Sum1 => Some(Args::run_sha256_sum1),
+            Self::Sha256Sig0 => Some(Args::run_sha256_sig0),
+            Self::Sha256Sig1 => Some(Args::run_sha256_sig1),
+            Self::Sha512Sum0 => Some(Args::run_sha512_sum0),
+            Self::Sha512Sum1 => Some(Args::run_sha512_sum1),
+            Self::Sha512Sig0 => Some(Args::run_sha512_sig0),
+            Self::Sha512Sig1 => Some(Args::run_sha512_sig1),
+            Self::ConditionalZeroEqualZero => Some(Args::run_conditional_zero_equal_zero),
+            Self::ConditionalZeroNotEqualZero => Some(Args::run_conditional_zero_not_equal_zero),
             Self::X64MulHighSigned => Some(Args::run_x64_mul_high_signed),
             Self::X64MulHighSignedUnsigned => Some(Args::run_x64_mul_high_signed_unsigned),
             Self::X64MulHighUnsigned => Some(Args::run_x64_mul_high_unsigned),
@@ -717,6 +1116,103 @@ impl OpCode {
             Self::X32AtomicSwap => Some(Args::run_x32_atomic_swap),
             Self::X64AtomicSwap => Some(Args::run_x64_atomic_swap),
             Self::X64AtomicMinSigned => Some(Args::run_x64_atomic_min_signed),
+            Self::X32AtomicAdd => Some(Args::run_x32_atomic_add),
+            Self::X32AtomicXor => Some(Args::run_x32_atomic_xor),
+            Self::X32AtomicAnd => Some(Args::run_x32_atomic_and),
+            Self::X32AtomicOr => Some(Args::run_x32_atomic_or),
+            Self::X32AtomicMinSigned => Some(Args::run_x32_atomic_min_signed),
+            Self::X32AtomicMaxSigned => Some(Args::run_x32_atomic_max_signed),
+            Self::X32AtomicMinUnsigned => Some(Args::run_x32_atomic_min_unsigned),
+            Self::X32AtomicMaxUnsigned => Some(Args::run_x32_atomic_max_unsigned),
+            Self::X64AtomicXor => Some(Args::run_x64_atomic_xor),
+            Self::X64AtomicAnd => Some(Args::run_x64_atomic_and),
+            Self::X64AtomicOr => Some(Args::run_x64_atomic_or),
+            Self::X64AtomicMaxSigned => Some(Args::run_x64_atomic_max_signed),
+            Self::X64AtomicMinUnsigned => Some(Args::run_x64_atomic_min_unsigned),
+            Self::X64AtomicMaxUnsigned => Some(Args::run_x64_atomic_max_unsigned),
+            Self::X32AtomicCompareAndSwap => Some(Args::run_x32_atomic_compare_and_swap),
+            Self::X64AtomicCompareAndSwap => Some(Args::run_x64_atomic_compare_and_swap),
+            Self::X128AtomicCompareAndSwap => Some(Args::run_x128_atomic_compare_and_swap),
+
+            // Floating-point loads & stores
+            Self::Flw => Some(Args::run_f32_load),
+            Self::Fsw => Some(Args::run_f32_store),
+            Self::Fld => Some(Args::run_f64_load),
+            Self::Fsd => Some(Args::run_f64_store),
+            Self::CFld => Some(Args::run_f64_load),
+            Self::CFldsp => Some(Args::run_f64_load_sp),
+            Self::CFsd => Some(Args::run_f64_store),
+            Self::CFsdsp => Some(Args::run_f64_store_sp),
+
+            // Single-precision floating-point
+            Self::Fadds => Some(Args::run_f32_add),
+            Self::Fsubs => Some(Args::run_f32_sub),
+            Self::Fmuls => Some(Args::run_f32_mul),
+            Self::Fdivs => Some(Args::run_f32_div),
+            Self::Fsqrts => Some(Args::run_f32_sqrt),
+            Self::Fmadds => Some(Args::run_f32_mul_add),
+            Self::Fmsubs => Some(Args::run_f32_mul_sub),
+            Self::Fnmsubs => Some(Args::run_f32_neg_mul_sub),
+            Self::Fnmadds => Some(Args::run_f32_neg_mul_add),
+            Self::Fmins => Some(Args::run_f32_min),
+            Self::Fmaxs => Some(Args::run_f32_max),
+            Self::Fsgnjs => Some(Args::run_f32_sign_inject),
+            Self::Fsgnjns => Some(Args::run_f32_sign_inject_negate),
+            Self::Fsgnjxs => Some(Args::run_f32_sign_inject_xor),
+            Self::Feqs => Some(Args::run_f32_equal),
+            Self::Flts => Some(Args::run_f32_less_than),
+            Self::Fles => Some(Args::run_f32_less_than_or_equal),
+            Self::FclassS => Some(Args::run_f32_classify),
+            Self::FmvXW => Some(Args::run_f32_move_to_x),
+            Self::FmvWX => Some(Args::run_f32_move_from_x),
+            Self::Fcvtsw => Some(Args::run_f32_from_x32_signed),
+            Self::Fcvtswu => Some(Args::run_f32_from_x32_unsigned),
+            Self::Fcvtsl => Some(Args::run_f32_from_x64_signed),
+            Self::Fcvtslu => Some(Args::run_f32_from_x64_unsigned),
+            Self::Fcvtws => Some(Args::run_x32_signed_from_f32),
+            Self::Fcvtwus => Some(Args::run_x32_unsigned_from_f32),
+            Self::Fcvtls => Some(Args::run_x64_signed_from_f32),
+            Self::Fcvtlus => Some(Args::run_x64_unsigned_from_f32),
+            Self::Fcvtsd => Some(Args::run_f32_from_f64),
+
+            // Double-precision floating-point
+            Self::Faddd => Some(Args::run_f64_add),
+            Self::Fsubd => Some(Args::run_f64_sub),
+            Self::Fmuld => Some(Args::run_f64_mul),
+            Self::Fdivd => Some(Args::run_f64_div),
+            Self::Fsqrtd => Some(Args::run_f64_sqrt),
+            Self::Fmaddd => Some(Args::run_f64_mul_add),
+            Self::Fmsubd => Some(Args::run_f64_mul_sub),
+            Self::Fnmsubd => Some(Args::run_f64_neg_mul_sub),
+            Self::Fnmaddd => Some(Args::run_f64_neg_mul_add),
+            Self::Fmind => Some(Args::run_f64_min),
+            Self::Fmaxd => Some(Args::run_f64_max),
+            Self::Fsgnjd => Some(Args::run_f64_sign_inject),
+            Self::Fsgnjnd => Some(Args::run_f64_sign_inject_negate),
+            Self::Fsgnjxd => Some(Args::run_f64_sign_inject_xor),
+            Self::Feqd => Some(Args::run_f64_equal),
+            Self::Fltd => Some(Args::run_f64_less_than),
+            Self::Fled => Some(Args::run_f64_less_than_or_equal),
+            Self::FclassD => Some(Args::run_f64_classify),
+            Self::FmvXD => Some(Args::run_f64_move_to_x),
+            Self::FmvDX => Some(Args::run_f64_move_from_x),
+            Self::Fcvtdw => Some(Args::run_f64_from_x32_signed),
+            Self::Fcvtdwu => Some(Args::run_f64_from_x32_unsigned),
+            Self::Fcvtdl => Some(Args::run_f64_from_x64_signed),
+            Self::Fcvtdlu => Some(Args::run_f64_from_x64_unsigned),
+            Self::Fcvtwd => Some(Args::run_x32_signed_from_f64),
+            Self::Fcvtwud => Some(Args::run_x32_unsigned_from_f64),
+            Self::Fcvtld => Some(Args::run_x64_signed_from_f64),
+            Self::Fcvtlud => Some(Args::run_x64_unsigned_from_f64),
+            Self::Fcvtds => Some(Args::run_f64_from_f32),
+
+            // Zicsr instructions
+            Self::Csrrw => Some(Args::run_csrrw),
+            Self::Csrrs => Some(Args::run_csrrs),
+            Self::Csrrc => Some(Args::run_csrrc),
+            Self::Csrrwi => Some(Args::run_csrrwi),
+            Self::Csrrsi => Some(Args::run_csrrsi),
+            Self::Csrrci => Some(Args::run_csrrci),
 
             // Errors
             Self::Unknown => Some(Args::run_illegal),
@@ -744,6 +1240,7 @@ pub union Register {
     pub x: XRegister,
     pub f: FRegister,
     pub nzx: NonZeroXRegister,
+    pub v: VRegister,
 }
 
 impl ConstDefault for Register {
@@ -770,6 +1267,12 @@ impl From<NonZeroXRegister> for Register {
     }
 }
 
+impl From<VRegister> for Register {
+    fn from(v: VRegister) -> Self {
+        Self { v }
+    }
+}
+
 impl PartialEq for Register {
     fn eq(&self, other: &Self) -> bool {
         // SAFETY: XRegister and FRegister are the same size as u8 and directly mappable to it.
@@ -888,6 +1391,15 @@ macro_rules! impl_r_type {
         }
     };
 
+    ($impl: path, $fn: ident, unary) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            $impl(icb, unsafe { self.rs1.x }, unsafe { self.rd.nzx });
+            icb.ok(Next(self.width))
+        }
+    };
+
     ($fn: ident, $shift: ident) => {
         /// SAFETY: This function must only be called on an `Args` belonging
         /// to the same OpCode as the OpCode used to derive this function.
@@ -946,6 +1458,23 @@ macro_rules! impl_x64_mul_high_type {
     };
 }
 
+macro_rules! impl_shift_add_type {
+    ($impl: path, $fn: ident, $shift: literal) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            $impl(
+                icb,
+                $shift,
+                unsafe { self.rs1.x },
+                unsafe { self.rs2.x },
+                unsafe { self.rd.nzx },
+            );
+            icb.ok(Next(self.width))
+        }
+    };
+}
+
 macro_rules! impl_i_type {
     ($fn: ident, non_zero) => {
         /// SAFETY: This function must only be called on an `Args` belonging
@@ -1135,24 +1664,6 @@ macro_rules! impl_branch_compare_zero {
 }
 
 macro_rules! impl_amo_type {
-    ($fn: ident) => {
-        /// SAFETY: This function must only be called on an `Args` belonging
-        /// to the same OpCode as the OpCode used to derive this function.
-        unsafe fn $fn<MC: MemoryConfig, M: ManagerReadWrite>(
-            &self,
-            core: &mut MachineCoreState<MC, M>,
-        ) -> Result<ProgramCounterUpdate<Address>, Exception> {
-            core.$fn(
-                unsafe { self.rs1.x },
-                unsafe { self.rs2.x },
-                unsafe { self.rd.x },
-                self.rl,
-                self.aq,
-            )
-            .map(|_| Next(self.width))
-        }
-    };
-
     ($impl: path, $fn: ident) => {
         /// SAFETY: This function must only be called on an `Args` belonging
         /// to the same OpCode as the OpCode used to derive this function.
@@ -1233,31 +1744,23 @@ macro_rules! impl_fcss_type {
 }
 
 macro_rules! impl_csr_type {
-    ($fn: ident) => {
+    ($impl: path, $fn: ident) => {
         /// SAFETY: This function must only be called on an `Args` belonging
         /// to the same OpCode as the OpCode used to derive this function.
-        unsafe fn $fn<MC: MemoryConfig, M: ManagerReadWrite>(
-            &self,
-            core: &mut MachineCoreState<MC, M>,
-        ) -> Result<ProgramCounterUpdate<Address>, Exception> {
-            core.hart
-                .$fn(self.csr, unsafe { self.rs1.x }, unsafe { self.rd.x })
-                .map(|_| Next(self.width))
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            let res = $impl(icb, self.csr, unsafe { self.rs1.x }, unsafe { self.rd.x });
+            I::map(res, |_| Next(self.width))
         }
     };
 }
 
 macro_rules! impl_csr_imm_type {
-    ($fn: ident) => {
+    ($impl: path, $fn: ident) => {
         /// SAFETY: This function must only be called on an `Args` belonging
         /// to the same OpCode as the OpCode used to derive this function.
-        unsafe fn $fn<MC: MemoryConfig, M: ManagerReadWrite>(
-            &self,
-            core: &mut MachineCoreState<MC, M>,
-        ) -> Result<ProgramCounterUpdate<Address>, Exception> {
-            core.hart
-                .$fn(self.csr, self.imm as u64, unsafe { self.rd.x })
-                .map(|_| Next(self.width))
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            let res = $impl(icb, self.csr, self.imm, unsafe { self.rd.x });
+            I::map(res, |_| Next(self.width))
         }
     };
 }
@@ -1372,873 +1875,2206 @@ macro_rules! impl_f_r_type {
     };
 }
 
-impl Args {
-    // RV64I R-type instructions
-    impl_r_type!(integer::run_add, run_add, non_zero);
-    impl_r_type!(integer::run_sub, run_sub, non_zero);
-    impl_r_type!(integer::run_x64_xor, run_x64_xor, non_zero);
-    impl_r_type!(integer::run_and, run_and, non_zero);
-    impl_r_type!(integer::run_or, run_or, non_zero);
-    impl_r_type!(run_shift_left, Left);
-    impl_r_type!(run_shift_right_unsigned, RightUnsigned);
-    impl_r_type!(run_shift_right_signed, RightSigned);
-    impl_r_type!(
-        integer::run_set_less_than_signed,
-        run_set_less_than_signed,
-        non_zero_rd
-    );
-    impl_r_type!(
-        integer::run_set_less_than_unsigned,
-        run_set_less_than_unsigned,
-        non_zero_rd
-    );
-    impl_r_type!(integer::run_add_word, run_add_word, non_zero_rd);
-    impl_r_type!(integer::run_sub_word, run_sub_word, non_zero_rd);
-    impl_x32_shift_type!(Left, run_x32_shift_left, reg);
-    impl_x32_shift_type!(RightUnsigned, run_x32_shift_right_unsigned, reg);
-    impl_x32_shift_type!(RightSigned, run_x32_shift_right_signed, reg);
+macro_rules! impl_f_arith_type {
+    ($fn: ident, $format: ident, $op: ident, unary) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            let res = float::run_f_arith_1(
+                icb,
+                FloatFormat::$format,
+                FArith::$op,
+                unsafe { self.rs1.f },
+                self.rm,
+                unsafe { self.rd.f },
+            );
+            I::map(res, |_| Next(self.width))
+        }
+    };
 
-    // RV64I I-type instructions
-    impl_i_type!(integer::run_addi, run_addi, non_zero);
-    impl_i_type!(
-        integer::run_add_word_immediate,
-        run_add_word_immediate,
-        non_zero_rd
-    );
-    impl_i_type!(
-        integer::run_x64_xor_immediate,
-        run_x64_xor_immediate,
-        non_zero
-    );
-    impl_i_type!(
-        integer::run_x64_or_immediate,
-        run_x64_or_immediate,
-        non_zero
-    );
-    impl_i_type!(integer::run_andi, run_andi, non_zero);
-    impl_i_type!(run_shift_left_immediate, Shift::Left);
-    impl_i_type!(run_shift_right_immediate_unsigned, Shift::RightUnsigned);
-    impl_i_type!(run_shift_right_immediate_signed, Shift::RightSigned);
-    impl_x32_shift_type!(Left, run_x32_shift_left_imm, imm);
-    impl_x32_shift_type!(RightUnsigned, run_x32_shift_right_imm_unsigned, imm);
-    impl_x32_shift_type!(RightSigned, run_x32_shift_right_imm_signed, imm);
-    impl_i_type!(
-        integer::run_set_less_than_immediate_signed,
-        run_set_less_than_immediate_signed,
-        non_zero_rd
-    );
-    impl_i_type!(
-        integer::run_set_less_than_immediate_unsigned,
-        run_set_less_than_immediate_unsigned,
-        non_zero_rd
-    );
-    impl_load_type!(run_x8_load_unsigned, u8);
-    impl_load_type!(run_x16_load_unsigned, u16);
-    impl_load_type!(run_x32_load_unsigned, u32);
-    impl_load_type!(run_x64_load_signed, i64);
-    impl_load_type!(run_x32_load_signed, i32);
-    impl_load_type!(run_x16_load_signed, i16);
-    impl_load_type!(run_x8_load_signed, i8);
+    ($fn: ident, $format: ident, $op: ident) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            let res = float::run_f_arith_2(
+                icb,
+                FloatFormat::$format,
+                FArith::$op,
+                unsafe { self.rs1.f },
+                unsafe { self.rs2.f },
+                self.rm,
+                unsafe { self.rd.f },
+            );
+            I::map(res, |_| Next(self.width))
+        }
+    };
 
-    // RV64I S-type instructions
-    impl_store_type!(run_x64_store, u64);
-    impl_store_type!(run_x32_store, u32);
-    impl_store_type!(run_x16_store, u16);
-    impl_store_type!(run_x8_store, u8);
+    ($fn: ident, $format: ident, $op: ident, fused) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            let res = float::run_f_arith_3(
+                icb,
+                FloatFormat::$format,
+                FArith::$op,
+                unsafe { self.rs1.f },
+                unsafe { self.rs2.f },
+                self.rs3f,
+                self.rm,
+                unsafe { self.rd.f },
+            );
+            I::map(res, |_| Next(self.width))
+        }
+    };
+}
 
-    // Branching instructions
-    impl_branch!(run_branch_equal, Predicate::Equal);
-    impl_branch!(run_branch_not_equal, Predicate::NotEqual);
-    impl_branch!(run_branch_less_than_signed, Predicate::LessThanSigned);
-    impl_branch!(run_branch_less_than_unsigned, Predicate::LessThanUnsigned);
-    impl_branch!(
-        run_branch_greater_than_or_equal_signed,
-        Predicate::GreaterThanOrEqualSigned
-    );
-    impl_branch!(
-        run_branch_greater_than_or_equal_unsigned,
-        Predicate::GreaterThanOrEqualUnsigned
-    );
-    impl_branch_compare_zero!(run_branch_equal_zero, Predicate::Equal);
-    impl_branch_compare_zero!(run_branch_not_equal_zero, Predicate::NotEqual);
-    impl_branch_compare_zero!(run_branch_less_than_zero, Predicate::LessThanSigned);
-    impl_branch_compare_zero!(
-        run_branch_greater_than_or_equal_zero,
-        Predicate::GreaterThanOrEqualSigned
-    );
-    impl_branch_compare_zero!(
-        run_branch_less_than_equal_zero,
-        Predicate::LessThanOrEqualSigned
-    );
-    impl_branch_compare_zero!(run_branch_greater_than_zero, Predicate::GreaterThanSigned);
+macro_rules! impl_f_binary_type {
+    ($fn: ident, $format: ident, $op: ident) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            float::run_f_binary(
+                icb,
+                FloatFormat::$format,
+                FBinary::$op,
+                unsafe { self.rs1.f },
+                unsafe { self.rs2.f },
+                unsafe { self.rd.f },
+            );
+            icb.ok(Next(self.width))
+        }
+    };
+}
 
-    // RV64I U-type instructions
-    /// SAFETY: This function must only be called on an `Args` belonging
-    /// to the same OpCode as the OpCode used to derive this function.
-    unsafe fn run_add_immediate_to_pc<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
-        branching::run_add_immediate_to_pc(icb, self.imm, unsafe { self.rd.nzx });
-        icb.ok(Next(self.width))
-    }
+macro_rules! impl_f_compare_type {
+    ($fn: ident, $format: ident, $op: ident) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            float::run_f_compare(
+                icb,
+                FloatFormat::$format,
+                FCompare::$op,
+                unsafe { self.rs1.f },
+                unsafe { self.rs2.f },
+                unsafe { self.rd.x },
+            );
+            icb.ok(Next(self.width))
+        }
+    };
+}
 
-    // RV64A atomic instructions
-    impl_amo_type!(atomics::run_x32_atomic_load, run_x32_atomic_load);
-    impl_amo_type!(atomics::run_x32_atomic_store, run_x32_atomic_store);
-    impl_amo_type!(atomics::run_x32_atomic_swap, run_x32_atomic_swap);
-    impl_amo_type!(run_amoaddw);
-    impl_amo_type!(run_amoxorw);
-    impl_amo_type!(run_amoandw);
-    impl_amo_type!(run_amoorw);
-    impl_amo_type!(run_amominw);
-    impl_amo_type!(run_amomaxw);
-    impl_amo_type!(run_amominuw);
-    impl_amo_type!(run_amomaxuw);
-    impl_amo_type!(atomics::run_x64_atomic_load, run_x64_atomic_load);
-    impl_amo_type!(atomics::run_x64_atomic_store, run_x64_atomic_store);
-    impl_amo_type!(atomics::run_x64_atomic_swap, run_x64_atomic_swap);
-    impl_amo_type!(atomics::run_x64_atomic_add, run_x64_atomic_add);
-    impl_amo_type!(run_amoxord);
-    impl_amo_type!(run_amoandd);
-    impl_amo_type!(run_amoord);
-    impl_amo_type!(
-        atomics::run_x64_atomic_min_signed,
-        run_x64_atomic_min_signed
-    );
-    impl_amo_type!(run_amomaxd);
-    impl_amo_type!(run_amominud);
-    impl_amo_type!(run_amomaxud);
+macro_rules! impl_f_int_type {
+    (from_int, $fn: ident, $format: ident, $int: ident) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            let res = float::run_f_from_int(
+                icb,
+                FloatFormat::$format,
+                FIntFormat::$int,
+                unsafe { self.rs1.x },
+                self.rm,
+                unsafe { self.rd.f },
+            );
+            I::map(res, |_| Next(self.width))
+        }
+    };
 
-    // RV64M multiplication and division instructions
-    impl_r_type!(integer::run_x64_rem_signed, run_x64_rem_signed, non_zero_rd);
-    impl_r_type!(
-        integer::run_x64_rem_unsigned,
-        run_x64_rem_unsigned,
-        non_zero_rd
-    );
-    impl_r_type!(integer::run_x32_rem_signed, run_x32_rem_signed, non_zero_rd);
-    impl_r_type!(
-        integer::run_x32_rem_unsigned,
-        run_x32_rem_unsigned,
-        non_zero_rd
-    );
-    impl_r_type!(integer::run_x64_div_signed, run_x64_div_signed, non_zero_rd);
-    impl_r_type!(
-        integer::run_x64_div_unsigned,
-        run_x64_div_unsigned,
-        non_zero_rd
-    );
-    impl_r_type!(integer::run_x32_div_signed, run_x32_div_signed, non_zero_rd);
-    impl_r_type!(
-        integer::run_x32_div_unsigned,
-        run_x32_div_unsigned,
-        non_zero_rd
-    );
-    impl_r_type!(integer::run_mul, run_mul, non_zero);
-    impl_x64_mul_high_type!(run_x64_mul_high_signed, Signed);
-    impl_x64_mul_high_type!(run_x64_mul_high_signed_unsigned, SignedUnsigned);
-    impl_x64_mul_high_type!(run_x64_mul_high_unsigned, Unsigned);
-    impl_r_type!(integer::run_x32_mul, run_x32_mul, non_zero_rd);
+    (to_int, $fn: ident, $format: ident, $int: ident) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            let res = float::run_f_to_int(
+                icb,
+                FloatFormat::$format,
+                FIntFormat::$int,
+                unsafe { self.rs1.f },
+                self.rm,
+                unsafe { self.rd.x },
+            );
+            I::map(res, |_| Next(self.width))
+        }
+    };
+}
 
-    // RV64F instructions
-    impl_fload_type!(run_flw);
-    impl_fstore_type!(run_fsw);
-    impl_f_r_type!(run_feq_s, (rd, x));
-    impl_f_r_type!(run_fle_s, (rd, x));
-    impl_f_r_type!(run_flt_s, (rd, x));
-    impl_f_r_type!(run_fadd_s, (rs2, f), rm);
-    impl_f_r_type!(run_fsub_s, (rs2, f), rm);
-    impl_f_r_type!(run_fmul_s, (rs2, f), rm);
-    impl_f_r_type!(run_fdiv_s, (rs2, f), rm);
-    impl_f_r_type!(run_fsqrt_s, rm);
-    impl_f_r_type!(run_fmin_s);
-    impl_f_r_type!(run_fmax_s);
-    impl_f_r_type!(run_fsgnj_s);
-    impl_f_r_type!(run_fsgnjn_s);
-    impl_f_r_type!(run_fsgnjx_s);
-    impl_f_r_type!(run_fmadd_s, (rs2, f), rs3f, rm);
-    impl_f_r_type!(run_fmsub_s, (rs2, f), rs3f, rm);
-    impl_f_r_type!(run_fnmsub_s, (rs2, f), rs3f, rm);
-    impl_f_r_type!(run_fnmadd_s, (rs2, f), rs3f, rm);
-    impl_x_f_type!(run_fclass_s);
-    impl_x_f_type!(run_fmv_x_w);
-    impl_f_x_type!(run_fmv_w_x);
-    impl_f_x_type!(run_fcvt_s_w, rm);
-    impl_f_x_type!(run_fcvt_s_wu, rm);
-    impl_f_x_type!(run_fcvt_s_l, rm);
-    impl_f_x_type!(run_fcvt_s_lu, rm);
-    impl_x_f_type!(run_fcvt_w_s, rm);
-    impl_x_f_type!(run_fcvt_wu_s, rm);
-    impl_x_f_type!(run_fcvt_l_s, rm);
-    impl_x_f_type!(run_fcvt_lu_s, rm);
+macro_rules! impl_f_convert_type {
+    ($fn: ident, $from: ident, $to: ident) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            let res = float::run_f_convert(
+                icb,
+                FloatFormat::$from,
+                FloatFormat::$to,
+                unsafe { self.rs1.f },
+                self.rm,
+                unsafe { self.rd.f },
+            );
+            I::map(res, |_| Next(self.width))
+        }
+    };
+}
 
-    // RV64D instructions
-    impl_fload_type!(run_fld);
-    impl_fstore_type!(run_fsd);
-    impl_f_r_type!(run_feq_d, (rd, x));
-    impl_f_r_type!(run_fle_d, (rd, x));
-    impl_f_r_type!(run_flt_d, (rd, x));
-    impl_f_r_type!(run_fadd_d, (rs2, f), rm);
-    impl_f_r_type!(run_fsub_d, (rs2, f), rm);
-    impl_f_r_type!(run_fmul_d, (rs2, f), rm);
-    impl_f_r_type!(run_fdiv_d, (rs2, f), rm);
-    impl_f_r_type!(run_fsqrt_d, rm);
-    impl_f_r_type!(run_fmin_d);
-    impl_f_r_type!(run_fmax_d);
-    impl_f_r_type!(run_fsgnj_d);
-    impl_f_r_type!(run_fsgnjn_d);
-    impl_f_r_type!(run_fsgnjx_d);
-    impl_f_r_type!(run_fcvt_d_s, rm);
-    impl_f_r_type!(run_fcvt_s_d, rm);
-    impl_f_r_type!(run_fmadd_d, (rs2, f), rs3f, rm);
-    impl_f_r_type!(run_fmsub_d, (rs2, f), rs3f, rm);
-    impl_f_r_type!(run_fnmsub_d, (rs2, f), rs3f, rm);
-    impl_f_r_type!(run_fnmadd_d, (rs2, f), rs3f, rm);
-    impl_x_f_type!(run_fclass_d);
-    impl_f_x_type!(run_fcvt_d_w, rm);
-    impl_f_x_type!(run_fcvt_d_wu, rm);
-    impl_f_x_type!(run_fcvt_d_l, rm);
-    impl_f_x_type!(run_fcvt_d_lu, rm);
-    impl_x_f_type!(run_fcvt_w_d, rm);
-    impl_x_f_type!(run_fcvt_wu_d, rm);
-    impl_x_f_type!(run_fcvt_l_d, rm);
-    impl_x_f_type!(run_fcvt_lu_d, rm);
-    impl_x_f_type!(run_fmv_x_d);
-    impl_f_x_type!(run_fmv_d_x);
+macro_rules! impl_f_move_type {
+    ($impl: path, $fn: ident, $format: ident, ($rs1: ident, $rd: ident)) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            $impl(
+                icb,
+                FloatFormat::$format,
+                unsafe { self.rs1.$rs1 },
+                unsafe { self.rd.$rd },
+            );
+            icb.ok(Next(self.width))
+        }
+    };
+}
 
-    // Zicsr instructions
-    impl_csr_type!(run_csrrw);
-    impl_csr_type!(run_csrrs);
-    impl_csr_type!(run_csrrc);
-    impl_csr_imm_type!(run_csrrwi);
-    impl_csr_imm_type!(run_csrrsi);
-    impl_csr_imm_type!(run_csrrci);
+macro_rules! impl_f_load_store_type {
+    (load, $fn: ident, $format: ident) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            let res = float::run_f_load(
+                icb,
+                FloatFormat::$format,
+                self.imm,
+                unsafe { self.rs1.x },
+                unsafe { self.rd.f },
+            );
+            I::map(res, |_| Next(self.width))
+        }
+    };
 
-    // RV32C compressed instructions
-    impl_cr_nz_type!(integer::run_mv, run_mv);
-    impl_cr_nz_type!(integer::run_neg, run_neg);
-    impl_ci_type!(load_store::run_li, run_li, non_zero);
+    (load_sp, $fn: ident, $format: ident) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            let res = float::run_f_load(icb, FloatFormat::$format, self.imm, sp, unsafe {
+                self.rd.f
+            });
+            I::map(res, |_| Next(self.width))
+        }
+    };
 
-    fn run_j<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
-        let addr = branching::run_j(icb, self.imm);
-        let pcu = ProgramCounterUpdate::Set(addr);
-        icb.ok(pcu)
-    }
+    (store, $fn: ident, $format: ident) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            let res = float::run_f_store(
+                icb,
+                FloatFormat::$format,
+                self.imm,
+                unsafe { self.rs1.x },
+                unsafe { self.rs2.f },
+            );
+            I::map(res, |_| Next(self.width))
+        }
+    };
 
-    fn run_j_absolute<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
-        let addr = branching::run_j_absolute(icb, self.imm);
-        let pcu = ProgramCounterUpdate::Set(addr);
-        icb.ok(pcu)
-    }
+    (store_sp, $fn: ident, $format: ident) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+            let res = float::run_f_store(icb, FloatFormat::$format, self.imm, sp, unsafe {
+                self.rs2.f
+            });
+            I::map(res, |_| Next(self.width))
+        }
+    };
+}
 
-    /// SAFETY: This function must only be called on an `Args` belonging
-    /// to the same OpCode as the OpCode used to derive this function.
-    unsafe fn run_jr<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
-        let rs1 = unsafe { self.rs1.nzx };
-        let addr = branching::run_jr(icb, rs1);
-        let pcu = ProgramCounterUpdate::Set(addr);
-        icb.ok(pcu)
-    }
+macro_rules! impl_v_type {
+    ($fn: ident, vv) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<MC: MemoryConfig, M: ManagerReadWrite>(
+            &self,
+            core: &mut MachineCoreState<MC, M>,
+        ) -> Result<ProgramCounterUpdate<Address>, Exception> {
+            core.hart
+                .$fn(unsafe { self.rs1.v }, unsafe { self.rs2.v }, unsafe {
+                    self.rd.v
+                })
+                .map(|_| Next(self.width))
+        }
+    };
 
-    /// SAFETY: This function must only be called on an `Args` belonging
-    /// to the same OpCode as the OpCode used to derive this function.
-    unsafe fn run_jr_imm<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
-        let rs1 = unsafe { self.rs1.nzx };
-        let addr = branching::run_jr_imm(icb, self.imm, rs1);
-        let pcu = ProgramCounterUpdate::Set(addr);
-        icb.ok(pcu)
-    }
+    ($fn: ident, vx) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<MC: MemoryConfig, M: ManagerReadWrite>(
+            &self,
+            core: &mut MachineCoreState<MC, M>,
+        ) -> Result<ProgramCounterUpdate<Address>, Exception> {
+            core.hart
+                .$fn(unsafe { self.rs1.x }, unsafe { self.rs2.v }, unsafe {
+                    self.rd.v
+                })
+                .map(|_| Next(self.width))
+        }
+    };
 
-    /// SAFETY: This function must only be called on an `Args` belonging
-    /// to the same OpCode as the OpCode used to derive this function.
-    unsafe fn run_jal<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
-        let rd = unsafe { self.rd.nzx };
-        let addr = branching::run_jal(icb, self.imm, rd, self.width);
-        let pcu = ProgramCounterUpdate::Set(addr);
-        icb.ok(pcu)
-    }
+    ($fn: ident, vi) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<MC: MemoryConfig, M: ManagerReadWrite>(
+            &self,
+            core: &mut MachineCoreState<MC, M>,
+        ) -> Result<ProgramCounterUpdate<Address>, Exception> {
+            core.hart
+                .$fn(self.imm, unsafe { self.rs2.v }, unsafe { self.rd.v })
+                .map(|_| Next(self.width))
+        }
+    };
 
-    /// SAFETY: This function must only be called on an `Args` belonging
-    /// to the same OpCode as the OpCode used to derive this function.
-    unsafe fn run_jalr<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
-        let rd = unsafe { self.rd.nzx };
-        let rs1 = unsafe { self.rs1.nzx };
-        let addr = branching::run_jalr(icb, rd, rs1, self.width);
-        let pcu = ProgramCounterUpdate::Set(addr);
-        icb.ok(pcu)
-    }
+    ($fn: ident, imm) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<MC: MemoryConfig, M: ManagerReadWrite>(
+            &self,
+            core: &mut MachineCoreState<MC, M>,
+        ) -> Result<ProgramCounterUpdate<Address>, Exception> {
+            core.hart
+                .$fn(self.imm, unsafe { self.rd.v })
+                .map(|_| Next(self.width))
+        }
+    };
 
-    /// SAFETY: This function must only be called on an `Args` belonging
-    /// to the same OpCode as the OpCode used to derive this function.
-    unsafe fn run_jalr_imm<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
-        let rs1 = unsafe { self.rs1.nzx };
-        let rd = unsafe { self.rd.nzx };
-        let addr = branching::run_jalr_imm(icb, self.imm, rs1, rd, self.width);
-        let pcu = ProgramCounterUpdate::Set(addr);
-        icb.ok(pcu)
-    }
+    ($fn: ident, (rs1, $rs1: ident)) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<MC: MemoryConfig, M: ManagerReadWrite>(
+            &self,
+            core: &mut MachineCoreState<MC, M>,
+        ) -> Result<ProgramCounterUpdate<Address>, Exception> {
+            core.hart
+                .$fn(unsafe { self.rs1.$rs1 }, unsafe { self.rd.v })
+                .map(|_| Next(self.width))
+        }
+    };
 
-    /// SAFETY: This function must only be called on an `Args` belonging
-    /// to the same OpCode as the OpCode used to derive this function.
-    unsafe fn run_jalr_absolute<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
-        let rd = unsafe { self.rd.nzx };
-        let addr = branching::run_jalr_absolute(icb, self.imm, rd, self.width);
-        let pcu = ProgramCounterUpdate::Set(addr);
-        icb.ok(pcu)
-    }
+    ($fn: ident, (rd, x)) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<MC: MemoryConfig, M: ManagerReadWrite>(
+            &self,
+            core: &mut MachineCoreState<MC, M>,
+        ) -> Result<ProgramCounterUpdate<Address>, Exception> {
+            core.hart
+                .$fn(unsafe { self.rs2.v }, unsafe { self.rd.x })
+                .map(|_| Next(self.width))
+        }
+    };
+}
 
-    fn run_nop<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
-        integer::run_nop(icb);
-        let pcu = ProgramCounterUpdate::Next(self.width);
-        icb.ok(pcu)
-    }
+macro_rules! impl_vload_type {
+    ($fn: ident) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<MC: MemoryConfig, M: ManagerReadWrite>(
+            &self,
+            core: &mut MachineCoreState<MC, M>,
+        ) -> Result<ProgramCounterUpdate<Address>, Exception> {
+            core.$fn(unsafe { self.rs1.x }, unsafe { self.rd.v })
+                .map(|_| Next(self.width))
+        }
+    };
+}
 
-    fn run_ecall<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
-        icb.ecall()
+macro_rules! impl_vstore_type {
+    ($fn: ident) => {
+        /// SAFETY: This function must only be called on an `Args` belonging
+        /// to the same OpCode as the OpCode used to derive this function.
+        unsafe fn $fn<MC: MemoryConfig, M: ManagerReadWrite>(
+            &self,
+            core: &mut MachineCoreState<MC, M>,
+        ) -> Result<ProgramCounterUpdate<Address>, Exception> {
+            core.$fn(unsafe { self.rs1.x }, unsafe { self.rs2.v })
+                .map(|_| Next(self.width))
+        }
+    };
+}
+
+impl Args {
+    // RV64I R-type instructions
+    impl_r_type!(integer::run_add, run_add, non_zero);
+    impl_r_type!(integer::run_sub, run_sub, non_zero);
+    impl_r_type!(integer::run_x64_xor, run_x64_xor, non_zero);
+    impl_r_type!(integer::run_and, run_and, non_zero);
+    impl_r_type!(integer::run_or, run_or, non_zero);
+    impl_r_type!(run_shift_left, Left);
+    impl_r_type!(run_shift_right_unsigned, RightUnsigned);
+    impl_r_type!(run_shift_right_signed, RightSigned);
+    impl_r_type!(
+        integer::run_set_less_than_signed,
+        run_set_less_than_signed,
+        non_zero_rd
+    );
+    impl_r_type!(
+        integer::run_set_less_than_unsigned,
+        run_set_less_than_unsigned,
+        non_zero_rd
+    );
+    impl_r_type!(integer::run_add_word, run_add_word, non_zero_rd);
+    impl_r_type!(integer::run_sub_word, run_sub_word, non_zero_rd);
+    impl_x32_shift_type!(Left, run_x32_shift_left, reg);
+    impl_x32_shift_type!(RightUnsigned, run_x32_shift_right_unsigned, reg);
+    impl_x32_shift_type!(RightSigned, run_x32_shift_right_signed, reg);
+
+    // RV64I I-type instructions
+    impl_i_type!(integer::run_addi, run_addi, non_zero);
+    impl_i_type!(
+        integer::run_add_word_immediate,
+        run_add_word_immediate,
+        non_zero_rd
+    );
+    impl_i_type!(
+        integer::run_x64_xor_immediate,
+        run_x64_xor_immediate,
+        non_zero
+    );
+    impl_i_type!(
+        integer::run_x64_or_immediate,
+        run_x64_or_immediate,
+        non_zero
+    );
+    impl_i_type!(integer::run_andi, run_andi, non_zero);
+    impl_i_type!(run_shift_left_immediate, Shift::Left);
+    impl_i_type!(run_shift_right_immediate_unsigned, Shift::RightUnsigned);
+    impl_i_type!(run_shift_right_immediate_signed, Shift::RightSigned);
+    impl_x32_shift_type!(Left, run_x32_shift_left_imm, imm);
+    impl_x32_shift_type!(RightUnsigned, run_x32_shift_right_imm_unsigned, imm);
+    impl_x32_shift_type!(RightSigned, run_x32_shift_right_imm_signed, imm);
+    impl_i_type!(
+        integer::run_set_less_than_immediate_signed,
+        run_set_less_than_immediate_signed,
+        non_zero_rd
+    );
+    impl_i_type!(
+        integer::run_set_less_than_immediate_unsigned,
+        run_set_less_than_immediate_unsigned,
+        non_zero_rd
+    );
+    impl_load_type!(run_x8_load_unsigned, u8);
+    impl_load_type!(run_x16_load_unsigned, u16);
+    impl_load_type!(run_x32_load_unsigned, u32);
+    impl_load_type!(run_x64_load_signed, i64);
+    impl_load_type!(run_x32_load_signed, i32);
+    impl_load_type!(run_x16_load_signed, i16);
+    impl_load_type!(run_x8_load_signed, i8);
+
+    // RV64I S-type instructions
+    impl_store_type!(run_x64_store, u64);
+    impl_store_type!(run_x32_store, u32);
+    impl_store_type!(run_x16_store, u16);
+    impl_store_type!(run_x8_store, u8);
+
+    // Branching instructions
+    impl_branch!(run_branch_equal, Predicate::Equal);
+    impl_branch!(run_branch_not_equal, Predicate::NotEqual);
+    impl_branch!(run_branch_less_than_signed, Predicate::LessThanSigned);
+    impl_branch!(run_branch_less_than_unsigned, Predicate::LessThanUnsigned);
+    impl_branch!(
+        run_branch_greater_than_or_equal_signed,
+        Predicate::GreaterThanOrEqualSigned
+    );
+    impl_branch!(
+        run_branch_greater_than_or_equal_unsigned,
+        Predicate::GreaterThanOrEqualUnsigned
+    );
+    impl_branch_compare_zero!(run_branch_equal_zero, Predicate::Equal);
+    impl_branch_compare_zero!(run_branch_not_equal_zero, Predicate::NotEqual);
+    impl_branch_compare_zero!(run_branch_less_than_zero, Predicate::LessThanSigned);
+    impl_branch_compare_zero!(
+        run_branch_greater_than_or_equal_zero,
+        Predicate::GreaterThanOrEqualSigned
+    );
+    impl_branch_compare_zero!(
+        run_branch_less_than_equal_zero,
+        Predicate::LessThanOrEqualSigned
+    );
+    impl_branch_compare_zero!(run_branch_greater_than_zero, Predicate::GreaterThanSigned);
+
+    // RV64I U-type instructions
+    /// SAFETY: This function must only be called on an `Args` belonging
+    /// to the same OpCode as the OpCode used to derive this function.
+    unsafe fn run_add_immediate_to_pc<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+        branching::run_add_immediate_to_pc(icb, self.imm, unsafe { self.rd.nzx });
+        icb.ok(Next(self.width))
     }
 
-    // RV64C compressed instructions
-    impl_fload_type!(run_cfld);
-    impl_cfload_sp_type!(run_cfldsp);
-    impl_fstore_type!(run_cfsd);
-    impl_fcss_type!(run_cfsdsp);
+    // RV64A atomic instructions
+    impl_amo_type!(atomics::run_x32_atomic_load, run_x32_atomic_load);
+    impl_amo_type!(atomics::run_x32_atomic_store, run_x32_atomic_store);
+    impl_amo_type!(atomics::run_x32_atomic_swap, run_x32_atomic_swap);
+    impl_amo_type!(atomics::run_x32_atomic_add, run_x32_atomic_add);
+    impl_amo_type!(atomics::run_x32_atomic_xor, run_x32_atomic_xor);
+    impl_amo_type!(atomics::run_x32_atomic_and, run_x32_atomic_and);
+    impl_amo_type!(atomics::run_x32_atomic_or, run_x32_atomic_or);
+    impl_amo_type!(
+        atomics::run_x32_atomic_min_signed,
+        run_x32_atomic_min_signed
+    );
+    impl_amo_type!(
+        atomics::run_x32_atomic_max_signed,
+        run_x32_atomic_max_signed
+    );
+    impl_amo_type!(
+        atomics::run_x32_atomic_min_unsigned,
+        run_x32_atomic_min_unsigned
+    );
+    impl_amo_type!(
+        atomics::run_x32_atomic_max_unsigned,
+        run_x32_atomic_max_unsigned
+    );
+    impl_amo_type!(atomics::run_x64_atomic_load, run_x64_atomic_load);
+    impl_amo_type!(atomics::run_x64_atomic_store, run_x64_atomic_store);
+    impl_amo_type!(atomics::run_x64_atomic_swap, run_x64_atomic_swap);
+    impl_amo_type!(atomics::run_x64_atomic_add, run_x64_atomic_add);
+    impl_amo_type!(atomics::run_x64_atomic_xor, run_x64_atomic_xor);
+    impl_amo_type!(atomics::run_x64_atomic_and, run_x64_atomic_and);
+    impl_amo_type!(atomics::run_x64_atomic_or, run_x64_atomic_or);
+    impl_amo_type!(
+        atomics::run_x64_atomic_min_signed,
+        run_x64_atomic_min_signed
+    );
+    impl_amo_type!(
+        atomics::run_x64_atomic_max_signed,
+        run_x64_atomic_max_signed
+    );
+    impl_amo_type!(
+        atomics::run_x64_atomic_min_unsigned,
+        run_x64_atomic_min_unsigned
+    );
+    impl_amo_type!(
+        atomics::run_x64_atomic_max_unsigned,
+        run_x64_atomic_max_unsigned
+    );
+
+    // Zacas atomic compare-and-swap instructions
+    impl_amo_type!(
+        atomics::run_x32_atomic_compare_and_swap,
+        run_x32_atomic_compare_and_swap
+    );
+    impl_amo_type!(
+        atomics::run_x64_atomic_compare_and_swap,
+        run_x64_atomic_compare_and_swap
+    );
+    impl_amo_type!(
+        atomics::run_x128_atomic_compare_and_swap,
+        run_x128_atomic_compare_and_swap
+    );
+
+    // RV64M multiplication and division instructions
+    impl_r_type!(integer::run_x64_rem_signed, run_x64_rem_signed, non_zero_rd);
+    impl_r_type!(
+        integer::run_x64_rem_unsigned,
+        run_x64_rem_unsigned,
+        non_zero_rd
+    );
+    impl_r_type!(integer::run_x32_rem_signed, run_x32_rem_signed, non_zero_rd);
+    impl_r_type!(
+        integer::run_x32_rem_unsigned,
+        run_x32_rem_unsigned,
+        non_zero_rd
+    );
+    impl_r_type!(integer::run_x64_div_signed, run_x64_div_signed, non_zero_rd);
+    impl_r_type!(
+        integer::run_x64_div_unsigned,
+        run_x64_div_unsigned,
+        non_zero_rd
+    );
+    impl_r_type!(integer::run_x32_div_signed, run_x32_div_signed, non_zero_rd);
+    impl_r_type!(
+        integer::run_x32_div_unsigned,
+        run_x32_div_unsigned,
+        non_zero_rd
+    );
+    impl_r_type!(integer::run_mul, run_mul, non_zero);
+    impl_x64_mul_high_type!(run_x64_mul_high_signed, Signed);
+    impl_x64_mul_high_type!(run_x64_mul_high_signed_unsigned, SignedUnsigned);
+    impl_x64_mul_high_type!(run_x64_mul_high_unsigned, Unsigned);
+    impl_r_type!(integer::run_x32_mul, run_x32_mul, non_zero_rd);
+
+    // Zba address generation instructions
+    impl_shift_add_type!(bitmanip::run_shift_add, run_shift1_add, 1);
+    impl_shift_add_type!(bitmanip::run_shift_add, run_shift2_add, 2);
+    impl_shift_add_type!(bitmanip::run_shift_add, run_shift3_add, 3);
+    impl_shift_add_type!(
+        bitmanip::run_shift_add_unsigned_word,
+        run_add_unsigned_word,
+        0
+    );
+    impl_shift_add_type!(
+        bitmanip::run_shift_add_unsigned_word,
+        run_shift1_add_unsigned_word,
+        1
+    );
+    impl_shift_add_type!(
+        bitmanip::run_shift_add_unsigned_word,
+        run_shift2_add_unsigned_word,
+        2
+    );
+    impl_shift_add_type!(
+        bitmanip::run_shift_add_unsigned_word,
+        run_shift3_add_unsigned_word,
+        3
+    );
+    impl_i_type!(
+        bitmanip::run_shift_left_immediate_unsigned_word,
+        run_shift_left_immediate_unsigned_word,
+        non_zero_rd
+    );
+
+    // Zbb basic bit-manipulation instructions
+    impl_r_type!(bitmanip::run_and_not, run_and_not, non_zero_rd);
+    impl_r_type!(bitmanip::run_or_not, run_or_not, non_zero_rd);
+    impl_r_type!(bitmanip::run_xor_not, run_xor_not, non_zero_rd);
+    impl_r_type!(
+        bitmanip::run_count_leading_zeros,
+        run_count_leading_zeros,
+        unary
+    );
+    impl_r_type!(
+        bitmanip::run_count_trailing_zeros,
+        run_count_trailing_zeros,
+        unary
+    );
+    impl_r_type!(bitmanip::run_count_set_bits, run_count_set_bits, unary);
+    impl_r_type!(
+        bitmanip::run_x32_count_leading_zeros,
+        run_x32_count_leading_zeros,
+        unary
+    );
+    impl_r_type!(
+        bitmanip::run_x32_count_trailing_zeros,
+        run_x32_count_trailing_zeros,
+        unary
+    );
+    impl_r_type!(
+        bitmanip::run_x32_count_set_bits,
+        run_x32_count_set_bits,
+        unary
+    );
+    impl_r_type!(bitmanip::run_max_signed, run_max_signed, non_zero_rd);
+    impl_r_type!(bitmanip::run_max_unsigned, run_max_unsigned, non_zero_rd);
+    impl_r_type!(bitmanip::run_min_signed, run_min_signed, non_zero_rd);
+    impl_r_type!(bitmanip::run_min_unsigned, run_min_unsigned, non_zero_rd);
+    impl_r_type!(bitmanip::run_sign_extend_byte, run_sign_extend_byte, unary);
+    impl_r_type!(bitmanip::run_sign_extend_half, run_sign_extend_half, unary);
+    impl_r_type!(bitmanip::run_zero_extend_half, run_zero_extend_half, unary);
+    impl_r_type!(run_rotate_left, RotateLeft);
+    impl_r_type!(run_rotate_right, RotateRight);
+    impl_i_type!(run_rotate_right_immediate, Shift::RotateRight);
+    impl_x32_shift_type!(RotateLeft, run_x32_rotate_left, reg);
+    impl_x32_shift_type!(RotateRight, run_x32_rotate_right, reg);
+    impl_x32_shift_type!(RotateRight, run_x32_rotate_right_immediate, imm);
+    impl_r_type!(bitmanip::run_or_combine_bytes, run_or_combine_bytes, unary);
+    impl_r_type!(bitmanip::run_byte_reverse, run_byte_reverse, unary);
+
+    // Zbs single-bit instructions
+    impl_r_type!(bitmanip::run_bit_clear, run_bit_clear, non_zero_rd);
+    impl_i_type!(
+        bitmanip::run_bit_clear_immediate,
+        run_bit_clear_immediate,
+        non_zero_rd
+    );
+    impl_r_type!(bitmanip::run_bit_extract, run_bit_extract, non_zero_rd);
+    impl_i_type!(
+        bitmanip::run_bit_extract_immediate,
+        run_bit_extract_immediate,
+        non_zero_rd
+    );
+    impl_r_type!(bitmanip::run_bit_invert, run_bit_invert, non_zero_rd);
+    impl_i_type!(
+        bitmanip::run_bit_invert_immediate,
+        run_bit_invert_immediate,
+        non_zero_rd
+    );
+    impl_r_type!(bitmanip::run_bit_set, run_bit_set, non_zero_rd);
+    impl_i_type!(
+        bitmanip::run_bit_set_immediate,
+        run_bit_set_immediate,
+        non_zero_rd
+    );
+
+    // Zbkb bit-manipulation instructions for cryptography
+    impl_r_type!(bitmanip::run_pack, run_pack, non_zero_rd);
+    impl_r_type!(bitmanip::run_pack_byte, run_pack_byte, non_zero_rd);
+    impl_r_type!(bitmanip::run_x32_pack, run_x32_pack, non_zero_rd);
+    impl_r_type!(
+        bitmanip::run_reverse_bits_in_bytes,
+        run_reverse_bits_in_bytes,
+        unary
+    );
+
+    // Zknh SHA-2 hash function instructions
+    impl_r_type!(crypto::run_sha256_sum0, run_sha256_sum0, unary);
+    impl_r_type!(crypto::run_sha256_sum1, run_sha256_sum1, unary);
+    impl_r_type!(crypto::run_sha256_sig0, run_sha256_sig0, unary);
+    impl_r_type!(crypto::run_sha256_sig1, run_sha256_sig1, unary);
+    impl_r_type!(crypto::run_sha512_sum0, run_sha512_sum0, unary);
+    impl_r_type!(crypto::run_sha512_sum1, run_sha512_sum1, unary);
+    impl_r_type!(crypto::run_sha512_sig0, run_sha512_sig0, unary);
+    impl_r_type!(crypto::run_sha512_sig1, run_sha512_sig1, unary);
+
+    // Zicond conditional-zero instructions
+    impl_r_type!(
+        integer::run_conditional_zero_equal_zero,
+        run_conditional_zero_equal_zero,
+        non_zero_rd
+    );
+    impl_r_type!(
+        integer::run_conditional_zero_not_equal_zero,
+        run_conditional_zero_not_equal_zero,
+        non_zero_rd
+    );
+
+    // RV64F instructions
+    impl_fload_type!(run_flw);
+    impl_fstore_type!(run_fsw);
+    impl_f_r_type!(run_feq_s, (rd, x));
+    impl_f_r_type!(run_fle_s, (rd, x));
+    impl_f_r_type!(run_flt_s, (rd, x));
+    impl_f_r_type!(run_fadd_s, (rs2, f), rm);
+    impl_f_r_type!(run_fsub_s, (rs2, f), rm);
+    impl_f_r_type!(run_fmul_s, (rs2, f), rm);
+    impl_f_r_type!(run_fdiv_s, (rs2, f), rm);
+    impl_f_r_type!(run_fsqrt_s, rm);
+    impl_f_r_type!(run_fmin_s);
+    impl_f_r_type!(run_fmax_s);
+    impl_f_r_type!(run_fsgnj_s);
+    impl_f_r_type!(run_fsgnjn_s);
+    impl_f_r_type!(run_fsgnjx_s);
+    impl_f_r_type!(run_fmadd_s, (rs2, f), rs3f, rm);
+    impl_f_r_type!(run_fmsub_s, (rs2, f), rs3f, rm);
+    impl_f_r_type!(run_fnmsub_s, (rs2, f), rs3f, rm);
+    impl_f_r_type!(run_fnmadd_s, (rs2, f), rs3f, rm);
+    impl_x_f_type!(run_fclass_s);
+    impl_x_f_type!(run_fmv_x_w);
+    impl_f_x_type!(run_fmv_w_x);
+    impl_f_x_type!(run_fcvt_s_w, rm);
+    impl_f_x_type!(run_fcvt_s_wu, rm);
+    impl_f_x_type!(run_fcvt_s_l, rm);
+    impl_f_x_type!(run_fcvt_s_lu, rm);
+    impl_x_f_type!(run_fcvt_w_s, rm);
+    impl_x_f_type!(run_fcvt_wu_s, rm);
+    impl_x_f_type!(run_fcvt_l_s, rm);
+    impl_x_f_type!(run_fcvt_lu_s, rm);
+
+    // RV64D instructions
+    impl_fload_type!(run_fld);
+    impl_fstore_type!(run_fsd);
+    impl_f_r_type!(run_feq_d, (rd, x));
+    impl_f_r_type!(run_fle_d, (rd, x));
+    impl_f_r_type!(run_flt_d, (rd, x));
+    impl_f_r_type!(run_fadd_d, (rs2, f), rm);
+    impl_f_r_type!(run_fsub_d, (rs2, f), rm);
+    impl_f_r_type!(run_fmul_d, (rs2, f), rm);
+    impl_f_r_type!(run_fdiv_d, (rs2, f), rm);
+    impl_f_r_type!(run_fsqrt_d, rm);
+    impl_f_r_type!(run_fmin_d);
+    impl_f_r_type!(run_fmax_d);
+    impl_f_r_type!(run_fsgnj_d);
+    impl_f_r_type!(run_fsgnjn_d);
+    impl_f_r_type!(run_fsgnjx_d);
+    impl_f_r_type!(run_fcvt_d_s, rm);
+    impl_f_r_type!(run_fcvt_s_d, rm);
+    impl_f_r_type!(run_fmadd_d, (rs2, f), rs3f, rm);
+    impl_f_r_type!(run_fmsub_d, (rs2, f), rs3f, rm);
+    impl_f_r_type!(run_fnmsub_d, (rs2, f), rs3f, rm);
+    impl_f_r_type!(run_fnmadd_d, (rs2, f), rs3f, rm);
+    impl_x_f_type!(run_fclass_d);
+    impl_f_x_type!(run_fcvt_d_w, rm);
+    impl_f_x_type!(run_fcvt_d_wu, rm);
+    impl_f_x_type!(run_fcvt_d_l, rm);
+    impl_f_x_type!(run_fcvt_d_lu, rm);
+    impl_x_f_type!(run_fcvt_w_d, rm);
+    impl_x_f_type!(run_fcvt_wu_d, rm);
+    impl_x_f_type!(run_fcvt_l_d, rm);
+    impl_x_f_type!(run_fcvt_lu_d, rm);
+    impl_x_f_type!(run_fmv_x_d);
+    impl_f_x_type!(run_fmv_d_x);
+
+    // RV64F & RV64D instructions, lowered through the ICB
+    impl_f_load_store_type!(load, run_f32_load, Single);
+    impl_f_load_store_type!(store, run_f32_store, Single);
+    impl_f_load_store_type!(load, run_f64_load, Double);
+    impl_f_load_store_type!(store, run_f64_store, Double);
+    impl_f_load_store_type!(load_sp, run_f64_load_sp, Double);
+    impl_f_load_store_type!(store_sp, run_f64_store_sp, Double);
+    impl_f_arith_type!(run_f32_add, Single, Add);
+    impl_f_arith_type!(run_f32_sub, Single, Sub);
+    impl_f_arith_type!(run_f32_mul, Single, Mul);
+    impl_f_arith_type!(run_f32_div, Single, Div);
+    impl_f_arith_type!(run_f32_sqrt, Single, Sqrt, unary);
+    impl_f_arith_type!(run_f32_mul_add, Single, MulAdd, fused);
+    impl_f_arith_type!(run_f32_mul_sub, Single, MulSub, fused);
+    impl_f_arith_type!(run_f32_neg_mul_sub, Single, NegMulSub, fused);
+    impl_f_arith_type!(run_f32_neg_mul_add, Single, NegMulAdd, fused);
+    impl_f_arith_type!(run_f64_add, Double, Add);
+    impl_f_arith_type!(run_f64_sub, Double, Sub);
+    impl_f_arith_type!(run_f64_mul, Double, Mul);
+    impl_f_arith_type!(run_f64_div, Double, Div);
+    impl_f_arith_type!(run_f64_sqrt, Double, Sqrt, unary);
+    impl_f_arith_type!(run_f64_mul_add, Double, MulAdd, fused);
+    impl_f_arith_type!(run_f64_mul_sub, Double, MulSub, fused);
+    impl_f_arith_type!(run_f64_neg_mul_sub, Double, NegMulSub, fused);
+    impl_f_arith_type!(run_f64_neg_mul_add, Double, NegMulAdd, fused);
+    impl_f_binary_type!(run_f32_min, Single, Min);
+    impl_f_binary_type!(run_f32_max, Single, Max);
+    impl_f_binary_type!(run_f32_sign_inject, Single, SignInject);
+    impl_f_binary_type!(run_f32_sign_inject_negate, Single, SignInjectNegate);
+    impl_f_binary_type!(run_f32_sign_inject_xor, Single, SignInjectXor);
+    impl_f_binary_type!(run_f64_min, Double, Min);
+    impl_f_binary_type!(run_f64_max, Double, Max);
+    impl_f_binary_type!(run_f64_sign_inject, Double, SignInject);
+    impl_f_binary_type!(run_f64_sign_inject_negate, Double, SignInjectNegate);
+    impl_f_binary_type!(run_f64_sign_inject_xor, Double, SignInjectXor);
+    impl_f_compare_type!(run_f32_equal, Single, Equal);
+    impl_f_compare_type!(run_f32_less_than, Single, LessThan);
+    impl_f_compare_type!(run_f32_less_than_or_equal, Single, LessThanOrEqual);
+    impl_f_compare_type!(run_f64_equal, Double, Equal);
+    impl_f_compare_type!(run_f64_less_than, Double, LessThan);
+    impl_f_compare_type!(run_f64_less_than_or_equal, Double, LessThanOrEqual);
+    impl_f_move_type!(float::run_f_classify, run_f32_classify, Single, (f, x));
+    impl_f_move_type!(float::run_f_classify, run_f64_classify, Double, (f, x));
+    impl_f_move_type!(float::run_fmv_x_f, run_f32_move_to_x, Single, (f, x));
+    impl_f_move_type!(float::run_fmv_x_f, run_f64_move_to_x, Double, (f, x));
+    impl_f_move_type!(float::run_fmv_f_x, run_f32_move_from_x, Single, (x, f));
+    impl_f_move_type!(float::run_fmv_f_x, run_f64_move_from_x, Double, (x, f));
+    impl_f_int_type!(from_int, run_f32_from_x32_signed, Single, Word);
+    impl_f_int_type!(from_int, run_f32_from_x32_unsigned, Single, WordUnsigned);
+    impl_f_int_type!(from_int, run_f32_from_x64_signed, Single, Long);
+    impl_f_int_type!(from_int, run_f32_from_x64_unsigned, Single, LongUnsigned);
+    impl_f_int_type!(from_int, run_f64_from_x32_signed, Double, Word);
+    impl_f_int_type!(from_int, run_f64_from_x32_unsigned, Double, WordUnsigned);
+    impl_f_int_type!(from_int, run_f64_from_x64_signed, Double, Long);
+    impl_f_int_type!(from_int, run_f64_from_x64_unsigned, Double, LongUnsigned);
+    impl_f_int_type!(to_int, run_x32_signed_from_f32, Single, Word);
+    impl_f_int_type!(to_int, run_x32_unsigned_from_f32, Single, WordUnsigned);
+    impl_f_int_type!(to_int, run_x64_signed_from_f32, Single, Long);
+    impl_f_int_type!(to_int, run_x64_unsigned_from_f32, Single, LongUnsigned);
+    impl_f_int_type!(to_int, run_x32_signed_from_f64, Double, Word);
+    impl_f_int_type!(to_int, run_x32_unsigned_from_f64, Double, WordUnsigned);
+    impl_f_int_type!(to_int, run_x64_signed_from_f64, Double, Long);
+    impl_f_int_type!(to_int, run_x64_unsigned_from_f64, Double, LongUnsigned);
+    impl_f_convert_type!(run_f64_from_f32, Single, Double);
+    impl_f_convert_type!(run_f32_from_f64, Double, Single);
+
+    // Zfh half-precision instructions
+    impl_x_f_type!(run_fclass_h);
+    impl_f_r_type!(run_feq_h, (rd, x));
+    impl_f_r_type!(run_fle_h, (rd, x));
+    impl_f_r_type!(run_flt_h, (rd, x));
+    impl_f_r_type!(run_fadd_h, (rs2, f), rm);
+    impl_f_r_type!(run_fsub_h, (rs2, f), rm);
+    impl_f_r_type!(run_fmul_h, (rs2, f), rm);
+    impl_f_r_type!(run_fdiv_h, (rs2, f), rm);
+    impl_f_r_type!(run_fsqrt_h, rm);
+    impl_f_r_type!(run_fmin_h);
+    impl_f_r_type!(run_fmax_h);
+    impl_f_r_type!(run_fmadd_h, (rs2, f), rs3f, rm);
+    impl_f_r_type!(run_fmsub_h, (rs2, f), rs3f, rm);
+    impl_f_r_type!(run_fnmsub_h, (rs2, f), rs3f, rm);
+    impl_f_r_type!(run_fnmadd_h, (rs2, f), rs3f, rm);
+    impl_fload_type!(run_flh);
+    impl_fstore_type!(run_fsh);
+    impl_f_x_type!(run_fcvt_h_w, rm);
+    impl_f_x_type!(run_fcvt_h_wu, rm);
+    impl_f_x_type!(run_fcvt_h_l, rm);
+    impl_f_x_type!(run_fcvt_h_lu, rm);
+    impl_x_f_type!(run_fcvt_w_h, rm);
+    impl_x_f_type!(run_fcvt_wu_h, rm);
+    impl_x_f_type!(run_fcvt_l_h, rm);
+    impl_x_f_type!(run_fcvt_lu_h, rm);
+    impl_f_r_type!(run_fcvt_s_h, rm);
+    impl_f_r_type!(run_fcvt_h_s, rm);
+    impl_f_r_type!(run_fcvt_d_h, rm);
+    impl_f_r_type!(run_fcvt_h_d, rm);
+    impl_f_r_type!(run_fsgnj_h);
+    impl_f_r_type!(run_fsgnjn_h);
+    impl_f_r_type!(run_fsgnjx_h);
+    impl_x_f_type!(run_fmv_x_h);
+    impl_f_x_type!(run_fmv_h_x);
+
+    // Zve64x vector instructions
+    /// SAFETY: This function must only be called on an `Args` belonging
+    /// to the same OpCode as the OpCode used to derive this function.
+    unsafe fn run_vsetvli<MC: MemoryConfig, M: ManagerReadWrite>(
+        &self,
+        core: &mut MachineCoreState<MC, M>,
+    ) -> Result<ProgramCounterUpdate<Address>, Exception> {
+        core.hart
+            .run_vsetvli(self.imm, unsafe { self.rs1.x }, unsafe { self.rd.x })
+            .map(|_| Next(self.width))
+    }
+
+    /// SAFETY: This function must only be called on an `Args` belonging
+    /// to the same OpCode as the OpCode used to derive this function.
+    unsafe fn run_vsetivli<MC: MemoryConfig, M: ManagerReadWrite>(
+        &self,
+        core: &mut MachineCoreState<MC, M>,
+    ) -> Result<ProgramCounterUpdate<Address>, Exception> {
+        let vtypei = self.imm & VSETIVLI_VTYPEI_MASK;
+        let avl = (self.imm >> VSETIVLI_AVL_SHIFT) as u64;
+        core.hart
+            .run_vsetivli(vtypei, avl, unsafe { self.rd.x })
+            .map(|_| Next(self.width))
+    }
+
+    /// SAFETY: This function must only be called on an `Args` belonging
+    /// to the same OpCode as the OpCode used to derive this function.
+    unsafe fn run_vsetvl<MC: MemoryConfig, M: ManagerReadWrite>(
+        &self,
+        core: &mut MachineCoreState<MC, M>,
+    ) -> Result<ProgramCounterUpdate<Address>, Exception> {
+        core.hart
+            .run_vsetvl(unsafe { self.rs1.x }, unsafe { self.rs2.x }, unsafe {
+                self.rd.x
+            })
+            .map(|_| Next(self.width))
+    }
+
+    impl_vload_type!(run_vle8);
+    impl_vload_type!(run_vle16);
+    impl_vload_type!(run_vle32);
+    impl_vload_type!(run_vle64);
+    impl_vstore_type!(run_vse8);
+    impl_vstore_type!(run_vse16);
+    impl_vstore_type!(run_vse32);
+    impl_vstore_type!(run_vse64);
+    impl_v_type!(run_vadd_vv, vv);
+    impl_v_type!(run_vadd_vx, vx);
+    impl_v_type!(run_vadd_vi, vi);
+    impl_v_type!(run_vsub_vv, vv);
+    impl_v_type!(run_vsub_vx, vx);
+    impl_v_type!(run_vrsub_vx, vx);
+    impl_v_type!(run_vrsub_vi, vi);
+    impl_v_type!(run_vand_vv, vv);
+    impl_v_type!(run_vand_vx, vx);
+    impl_v_type!(run_vand_vi, vi);
+    impl_v_type!(run_vor_vv, vv);
+    impl_v_type!(run_vor_vx, vx);
+    impl_v_type!(run_vor_vi, vi);
+    impl_v_type!(run_vxor_vv, vv);
+    impl_v_type!(run_vxor_vx, vx);
+    impl_v_type!(run_vxor_vi, vi);
+    impl_v_type!(run_vsll_vv, vv);
+    impl_v_type!(run_vsll_vx, vx);
+    impl_v_type!(run_vsll_vi, vi);
+    impl_v_type!(run_vsrl_vv, vv);
+    impl_v_type!(run_vsrl_vx, vx);
+    impl_v_type!(run_vsrl_vi, vi);
+    impl_v_type!(run_vsra_vv, vv);
+    impl_v_type!(run_vsra_vx, vx);
+    impl_v_type!(run_vsra_vi, vi);
+    impl_v_type!(run_vminu_vv, vv);
+    impl_v_type!(run_vminu_vx, vx);
+    impl_v_type!(run_vmin_vv, vv);
+    impl_v_type!(run_vmin_vx, vx);
+    impl_v_type!(run_vmaxu_vv, vv);
+    impl_v_type!(run_vmaxu_vx, vx);
+    impl_v_type!(run_vmax_vv, vv);
+    impl_v_type!(run_vmax_vx, vx);
+    impl_v_type!(run_vmul_vv, vv);
+    impl_v_type!(run_vmul_vx, vx);
+    impl_v_type!(run_vmv_v_v, (rs1, v));
+    impl_v_type!(run_vmv_v_x, (rs1, x));
+    impl_v_type!(run_vmv_v_i, imm);
+    impl_v_type!(run_vmv_x_s, (rd, x));
+    impl_v_type!(run_vmv_s_x, (rs1, x));
+
+    // Zicboz instructions
+    /// SAFETY: This function must only be called on an `Args` belonging
+    /// to the same OpCode as the OpCode used to derive this function.
+    unsafe fn run_cbo_zero<MC: MemoryConfig, M: ManagerReadWrite>(
+        &self,
+        core: &mut MachineCoreState<MC, M>,
+    ) -> Result<ProgramCounterUpdate<Address>, Exception> {
+        core.run_cbo_zero(unsafe { self.rs1.x })
+            .map(|_| Next(self.width))
+    }
+
+    // Zicsr instructions
+    impl_csr_type!(rv64zicsr::run_csrrw, run_csrrw);
+    impl_csr_type!(rv64zicsr::run_csrrs, run_csrrs);
+    impl_csr_type!(rv64zicsr::run_csrrc, run_csrrc);
+    impl_csr_imm_type!(rv64zicsr::run_csrrwi, run_csrrwi);
+    impl_csr_imm_type!(rv64zicsr::run_csrrsi, run_csrrsi);
+    impl_csr_imm_type!(rv64zicsr::run_csrrci, run_csrrci);
+
+    // RV32C compressed instructions
+    impl_cr_nz_type!(integer::run_mv, run_mv);
+    impl_cr_nz_type!(integer::run_neg, run_neg);
+    impl_ci_type!(load_store::run_li, run_li, non_zero);
+
+    fn run_j<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+        let addr = branching::run_j(icb, self.imm);
+        let pcu = ProgramCounterUpdate::Set(addr);
+        icb.ok(pcu)
+    }
+
+    fn run_j_absolute<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+        let addr = branching::run_j_absolute(icb, self.imm);
+        let pcu = ProgramCounterUpdate::Set(addr);
+        icb.ok(pcu)
+    }
+
+    /// SAFETY: This function must only be called on an `Args` belonging
+    /// to the same OpCode as the OpCode used to derive this function.
+    unsafe fn run_jr<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+        let rs1 = unsafe { self.rs1.nzx };
+        let addr = branching::run_jr(icb, rs1);
+        let pcu = ProgramCounterUpdate::Set(addr);
+        icb.ok(pcu)
+    }
+
+    /// SAFETY: This function must only be called on an `Args` belonging
+    /// to the same OpCode as the OpCode used to derive this function.
+    unsafe fn run_jr_imm<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+        let rs1 = unsafe { self.rs1.nzx };
+        let addr = branching::run_jr_imm(icb, self.imm, rs1);
+        let pcu = ProgramCounterUpdate::Set(addr);
+        icb.ok(pcu)
+    }
+
+    /// SAFETY: This function must only be called on an `Args` belonging
+    /// to the same OpCode as the OpCode used to derive this function.
+    unsafe fn run_jal<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+        let rd = unsafe { self.rd.nzx };
+        let addr = branching::run_jal(icb, self.imm, rd, self.width);
+        let pcu = ProgramCounterUpdate::Set(addr);
+        icb.ok(pcu)
+    }
+
+    /// SAFETY: This function must only be called on an `Args` belonging
+    /// to the same OpCode as the OpCode used to derive this function.
+    unsafe fn run_jalr<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+        let rd = unsafe { self.rd.nzx };
+        let rs1 = unsafe { self.rs1.nzx };
+        let addr = branching::run_jalr(icb, rd, rs1, self.width);
+        let pcu = ProgramCounterUpdate::Set(addr);
+        icb.ok(pcu)
+    }
+
+    /// SAFETY: This function must only be called on an `Args` belonging
+    /// to the same OpCode as the OpCode used to derive this function.
+    unsafe fn run_jalr_imm<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+        let rs1 = unsafe { self.rs1.nzx };
+        let rd = unsafe { self.rd.nzx };
+        let addr = branching::run_jalr_imm(icb, self.imm, rs1, rd, self.width);
+        let pcu = ProgramCounterUpdate::Set(addr);
+        icb.ok(pcu)
+    }
+
+    /// SAFETY: This function must only be called on an `Args` belonging
+    /// to the same OpCode as the OpCode used to derive this function.
+    unsafe fn run_jalr_absolute<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+        let rd = unsafe { self.rd.nzx };
+        let addr = branching::run_jalr_absolute(icb, self.imm, rd, self.width);
+        let pcu = ProgramCounterUpdate::Set(addr);
+        icb.ok(pcu)
+    }
+
+    fn run_nop<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+        integer::run_nop(icb);
+        let pcu = ProgramCounterUpdate::Next(self.width);
+        icb.ok(pcu)
+    }
+
+    fn run_ecall<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+        icb.ecall()
+    }
+
+    // RV64C compressed instructions
+    impl_fload_type!(run_cfld);
+    impl_cfload_sp_type!(run_cfldsp);
+    impl_fstore_type!(run_cfsd);
+    impl_fcss_type!(run_cfsdsp);
+
+    // Unknown
+    fn run_illegal<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
+        icb.err_illegal_instruction()
+    }
+}
+
+impl From<&InstrCacheable> for Instruction {
+    fn from(value: &InstrCacheable) -> Self {
+        match value {
+            // RV64I R-type instructions
+            InstrCacheable::Add(args) => Instruction::from_ic_add(args),
+            InstrCacheable::Sub(args) => Instruction::from_ic_sub(args),
+            InstrCacheable::Xor(args) => Instruction::from_ic_xor(args),
+            InstrCacheable::Or(args) => Instruction::from_ic_or(args),
+            InstrCacheable::And(args) => Instruction::from_ic_and(args),
+            InstrCacheable::Sll(args) => Instruction::from_ic_sll(args),
+            InstrCacheable::Srl(args) => Instruction::from_ic_srl(args),
+            InstrCacheable::Sra(args) => Instruction::from_ic_sra(args),
+            InstrCacheable::Slt(args) => {
+                Instruction::new_set_less_than_signed(args.rd, args.rs1, args.rs2)
+            }
+            InstrCacheable::Sltu(args) => {
+                Instruction::new_set_less_than_unsigned(args.rd, args.rs1, args.rs2)
+            }
+            InstrCacheable::Addw(args) => {
+                Instruction::new_add_word(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Subw(args) => {
+                Instruction::new_sub_word(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Sllw(args) => Instruction::new_x32_shift_left(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Srlw(args) => Instruction::new_x32_shift_right_unsigned(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Sraw(args) => Instruction::new_x32_shift_right_signed(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                InstrWidth::Uncompressed,
+            ),
+
+            // RV64I I-type instructions
+            InstrCacheable::Addi(args) => Instruction::from_ic_addi(args),
+            InstrCacheable::Addiw(args) => Instruction::new_add_word_immediate(
+                args.rd,
+                args.rs1,
+                args.imm,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Xori(args) => Instruction::from_ic_xori(args),
+            InstrCacheable::Ori(args) => Instruction::from_ic_ori(args),
+            InstrCacheable::Andi(args) => Instruction::from_ic_andi(args),
+            InstrCacheable::Slli(args) => Instruction::from_ic_slli(args),
+            InstrCacheable::Srli(args) => Instruction::from_ic_srli(args),
+            InstrCacheable::Srai(args) => Instruction::from_ic_srai(args),
+            InstrCacheable::Slliw(args) => Instruction::from_ic_x32_shift_left_immediate(args),
+            InstrCacheable::Srliw(args) => {
+                Instruction::from_ic_x32_shift_right_immediate_unsigned(args)
+            }
+            InstrCacheable::Sraiw(args) => {
+                Instruction::from_ic_x32_shift_right_immediate_signed(args)
+            }
+            InstrCacheable::Slti(args) => {
+                Instruction::new_set_less_than_immediate_signed(args.rd, args.rs1, args.imm)
+            }
+            InstrCacheable::Sltiu(args) => {
+                Instruction::new_set_less_than_immediate_unsigned(args.rd, args.rs1, args.imm)
+            }
+            InstrCacheable::Lb(args) => Instruction::new_x8_load_signed(
+                args.rd,
+                args.rs1,
+                args.imm,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Lh(args) => Instruction::new_x16_load_signed(
+                args.rd,
+                args.rs1,
+                args.imm,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Lw(args) => Instruction::new_x32_load_signed(
+                args.rd,
+                args.rs1,
+                args.imm,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Lbu(args) => Instruction::new_x8_load_unsigned(
+                args.rd,
+                args.rs1,
+                args.imm,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Lhu(args) => Instruction::new_x16_load_unsigned(
+                args.rd,
+                args.rs1,
+                args.imm,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Lwu(args) => Instruction::new_x32_load_unsigned(
+                args.rd,
+                args.rs1,
+                args.imm,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Ld(args) => Instruction::new_x64_load_signed(
+                args.rd,
+                args.rs1,
+                args.imm,
+                InstrWidth::Uncompressed,
+            ),
+            // RV64I S-type instructions
+            InstrCacheable::Sb(args) => {
+                Instruction::new_x8_store(args.rs1, args.rs2, args.imm, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Sh(args) => {
+                Instruction::new_x16_store(args.rs1, args.rs2, args.imm, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Sw(args) => {
+                Instruction::new_x32_store(args.rs1, args.rs2, args.imm, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Sd(args) => {
+                Instruction::new_x64_store(args.rs1, args.rs2, args.imm, InstrWidth::Uncompressed)
+            }
+
+            // RV64I B-type instructions
+            InstrCacheable::Beq(args) => Instruction::from_ic_beq(args),
+            InstrCacheable::Bne(args) => Instruction::from_ic_bne(args),
+            InstrCacheable::Blt(args) => Instruction::from_ic_blt(args),
+            InstrCacheable::Bge(args) => Instruction::from_ic_bge(args),
+            InstrCacheable::Bltu(args) => Instruction::from_ic_bltu(args),
+            InstrCacheable::Bgeu(args) => Instruction::from_ic_bgeu(args),
+
+            // RV64I U-type instructions
+            InstrCacheable::Lui(args) => {
+                Instruction::new_li(args.rd, args.imm, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Auipc(args) => {
+                Instruction::new_add_immediate_to_pc(args.rd, args.imm, InstrWidth::Uncompressed)
+            }
+
+            // RV64I jump instructions
+            InstrCacheable::Jal(args) => Instruction::from_ic_jal(args),
+            InstrCacheable::Jalr(args) => Instruction::from_ic_jalr(args),
+
+            // RV64A atomic instructions
+            InstrCacheable::Lrw(args) => Instruction::new_x32_atomic_load(
+                args.rd,
+                args.rs1,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Scw(args) => Instruction::new_x32_atomic_store(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amoswapw(args) => Instruction::new_x32_atomic_swap(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amoaddw(args) => Instruction::new_x32_atomic_add(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amoxorw(args) => Instruction::new_x32_atomic_xor(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amoandw(args) => Instruction::new_x32_atomic_and(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amoorw(args) => Instruction::new_x32_atomic_or(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amominw(args) => Instruction::new_x32_atomic_min_signed(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amomaxw(args) => Instruction::new_x32_atomic_max_signed(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amominuw(args) => Instruction::new_x32_atomic_min_unsigned(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amomaxuw(args) => Instruction::new_x32_atomic_max_unsigned(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Lrd(args) => Instruction::new_x64_atomic_load(
+                args.rd,
+                args.rs1,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Scd(args) => Instruction::new_x64_atomic_store(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amoswapd(args) => Instruction::new_x64_atomic_swap(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amoaddd(args) => Instruction::new_x64_atomic_add(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amoxord(args) => Instruction::new_x64_atomic_xor(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amoandd(args) => Instruction::new_x64_atomic_and(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amoord(args) => Instruction::new_x64_atomic_or(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amomind(args) => Instruction::new_x64_atomic_min_signed(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amomaxd(args) => Instruction::new_x64_atomic_max_signed(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amominud(args) => Instruction::new_x64_atomic_min_unsigned(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amomaxud(args) => Instruction::new_x64_atomic_max_unsigned(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
 
-    // Unknown
-    fn run_illegal<I: ICB>(&self, icb: &mut I) -> IcbFnResult<I> {
-        icb.err_illegal_instruction()
-    }
-}
+            // Zacas atomic compare-and-swap instructions
+            InstrCacheable::Amocasw(args) => Instruction::new_x32_atomic_compare_and_swap(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amocasd(args) => Instruction::new_x64_atomic_compare_and_swap(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Amocasq(args) => Instruction::new_x128_atomic_compare_and_swap(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                args.aq,
+                args.rl,
+                InstrWidth::Uncompressed,
+            ),
 
-impl From<&InstrCacheable> for Instruction {
-    fn from(value: &InstrCacheable) -> Self {
-        match value {
-            // RV64I R-type instructions
-            InstrCacheable::Add(args) => Instruction::from_ic_add(args),
-            InstrCacheable::Sub(args) => Instruction::from_ic_sub(args),
-            InstrCacheable::Xor(args) => Instruction::from_ic_xor(args),
-            InstrCacheable::Or(args) => Instruction::from_ic_or(args),
-            InstrCacheable::And(args) => Instruction::from_ic_and(args),
-            InstrCacheable::Sll(args) => Instruction::from_ic_sll(args),
-            InstrCacheable::Srl(args) => Instruction::from_ic_srl(args),
-            InstrCacheable::Sra(args) => Instruction::from_ic_sra(args),
-            InstrCacheable::Slt(args) => {
-                Instruction::new_set_less_than_signed(args.rd, args.rs1, args.rs2)
+            // RV64M multiplication and division instructions
+            InstrCacheable::Rem(args) => Instruction::from_ic_rem(args),
+            InstrCacheable::Remu(args) => Instruction::from_ic_remu(args),
+            InstrCacheable::Remw(args) => Instruction::from_ic_remw(args),
+            InstrCacheable::Remuw(args) => Instruction::from_ic_remuw(args),
+            InstrCacheable::Div(args) => Instruction::from_ic_div(args),
+            InstrCacheable::Divu(args) => Instruction::from_ic_divu(args),
+            InstrCacheable::Divw(args) => Instruction::from_ic_divw(args),
+            InstrCacheable::Divuw(args) => Instruction::from_ic_divuw(args),
+            InstrCacheable::Mul(args) => Instruction::from_ic_mul(args),
+            InstrCacheable::Mulh(args) => Instruction::from_ic_mulh(args),
+            InstrCacheable::Mulhsu(args) => Instruction::from_ic_mulhsu(args),
+            InstrCacheable::Mulhu(args) => Instruction::from_ic_mulhu(args),
+            InstrCacheable::Mulw(args) => {
+                Instruction::new_x32_mul(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
             }
-            InstrCacheable::Sltu(args) => {
-                Instruction::new_set_less_than_unsigned(args.rd, args.rs1, args.rs2)
+
+            // Zba address generation instructions
+            InstrCacheable::Sh1add(args) => {
+                Instruction::new_shift1_add(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
             }
-            InstrCacheable::Addw(args) => {
-                Instruction::new_add_word(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
+            InstrCacheable::Sh2add(args) => {
+                Instruction::new_shift2_add(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
             }
-            InstrCacheable::Subw(args) => {
-                Instruction::new_sub_word(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
+            InstrCacheable::Sh3add(args) => {
+                Instruction::new_shift3_add(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
             }
-            InstrCacheable::Sllw(args) => Instruction::new_x32_shift_left(
+            InstrCacheable::AddUw(args) => Instruction::new_add_unsigned_word(
                 args.rd,
                 args.rs1,
                 args.rs2,
                 InstrWidth::Uncompressed,
             ),
-            InstrCacheable::Srlw(args) => Instruction::new_x32_shift_right_unsigned(
+            InstrCacheable::Sh1addUw(args) => Instruction::new_shift1_add_unsigned_word(
                 args.rd,
                 args.rs1,
                 args.rs2,
                 InstrWidth::Uncompressed,
             ),
-            InstrCacheable::Sraw(args) => Instruction::new_x32_shift_right_signed(
+            InstrCacheable::Sh2addUw(args) => Instruction::new_shift2_add_unsigned_word(
                 args.rd,
                 args.rs1,
                 args.rs2,
                 InstrWidth::Uncompressed,
             ),
-
-            // RV64I I-type instructions
-            InstrCacheable::Addi(args) => Instruction::from_ic_addi(args),
-            InstrCacheable::Addiw(args) => Instruction::new_add_word_immediate(
+            InstrCacheable::Sh3addUw(args) => Instruction::new_shift3_add_unsigned_word(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::SlliUw(args) => Instruction::new_shift_left_immediate_unsigned_word(
                 args.rd,
                 args.rs1,
                 args.imm,
                 InstrWidth::Uncompressed,
             ),
-            InstrCacheable::Xori(args) => Instruction::from_ic_xori(args),
-            InstrCacheable::Ori(args) => Instruction::from_ic_ori(args),
-            InstrCacheable::Andi(args) => Instruction::from_ic_andi(args),
-            InstrCacheable::Slli(args) => Instruction::from_ic_slli(args),
-            InstrCacheable::Srli(args) => Instruction::from_ic_srli(args),
-            InstrCacheable::Srai(args) => Instruction::from_ic_srai(args),
-            InstrCacheable::Slliw(args) => Instruction::from_ic_x32_shift_left_immediate(args),
-            InstrCacheable::Srliw(args) => {
-                Instruction::from_ic_x32_shift_right_immediate_unsigned(args)
+
+            // Zbb basic bit-manipulation instructions
+            InstrCacheable::Andn(args) => {
+                Instruction::new_and_not(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
             }
-            InstrCacheable::Sraiw(args) => {
-                Instruction::from_ic_x32_shift_right_immediate_signed(args)
+            InstrCacheable::Orn(args) => {
+                Instruction::new_or_not(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
             }
-            InstrCacheable::Slti(args) => {
-                Instruction::new_set_less_than_immediate_signed(args.rd, args.rs1, args.imm)
+            InstrCacheable::Xnor(args) => {
+                Instruction::new_xor_not(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
             }
-            InstrCacheable::Sltiu(args) => {
-                Instruction::new_set_less_than_immediate_unsigned(args.rd, args.rs1, args.imm)
+            InstrCacheable::Clz(args) => {
+                Instruction::new_count_leading_zeros(args.rd, args.rs1, InstrWidth::Uncompressed)
             }
-            InstrCacheable::Lb(args) => Instruction::new_x8_load_signed(
+            InstrCacheable::Ctz(args) => {
+                Instruction::new_count_trailing_zeros(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Cpop(args) => {
+                Instruction::new_count_set_bits(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Clzw(args) => Instruction::new_x32_count_leading_zeros(
                 args.rd,
                 args.rs1,
-                args.imm,
                 InstrWidth::Uncompressed,
             ),
-            InstrCacheable::Lh(args) => Instruction::new_x16_load_signed(
+            InstrCacheable::Ctzw(args) => Instruction::new_x32_count_trailing_zeros(
                 args.rd,
                 args.rs1,
-                args.imm,
                 InstrWidth::Uncompressed,
             ),
-            InstrCacheable::Lw(args) => Instruction::new_x32_load_signed(
+            InstrCacheable::Cpopw(args) => {
+                Instruction::new_x32_count_set_bits(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Max(args) => {
+                Instruction::new_max_signed(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Maxu(args) => {
+                Instruction::new_max_unsigned(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Min(args) => {
+                Instruction::new_min_signed(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Minu(args) => {
+                Instruction::new_min_unsigned(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::SextB(args) => {
+                Instruction::new_sign_extend_byte(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::SextH(args) => {
+                Instruction::new_sign_extend_half(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::ZextH(args) => {
+                Instruction::new_zero_extend_half(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Rol(args) => Instruction::from_ic_rol(args),
+            InstrCacheable::Ror(args) => Instruction::from_ic_ror(args),
+            InstrCacheable::Rori(args) => Instruction::from_ic_rori(args),
+            InstrCacheable::Rolw(args) => Instruction::new_x32_rotate_left(
                 args.rd,
                 args.rs1,
-                args.imm,
+                args.rs2,
                 InstrWidth::Uncompressed,
             ),
-            InstrCacheable::Lbu(args) => Instruction::new_x8_load_unsigned(
+            InstrCacheable::Rorw(args) => Instruction::new_x32_rotate_right(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::Roriw(args) => Instruction::from_ic_roriw(args),
+            InstrCacheable::OrcB(args) => {
+                Instruction::new_or_combine_bytes(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Rev8(args) => {
+                Instruction::new_byte_reverse(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+
+            // Zbs single-bit instructions
+            InstrCacheable::Bclr(args) => {
+                Instruction::new_bit_clear(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Bclri(args) => Instruction::new_bit_clear_immediate(
                 args.rd,
                 args.rs1,
                 args.imm,
                 InstrWidth::Uncompressed,
             ),
-            InstrCacheable::Lhu(args) => Instruction::new_x16_load_unsigned(
+            InstrCacheable::Bext(args) => {
+                Instruction::new_bit_extract(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Bexti(args) => Instruction::new_bit_extract_immediate(
                 args.rd,
                 args.rs1,
                 args.imm,
                 InstrWidth::Uncompressed,
             ),
-            InstrCacheable::Lwu(args) => Instruction::new_x32_load_unsigned(
+            InstrCacheable::Binv(args) => {
+                Instruction::new_bit_invert(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Binvi(args) => Instruction::new_bit_invert_immediate(
                 args.rd,
                 args.rs1,
                 args.imm,
                 InstrWidth::Uncompressed,
             ),
-            InstrCacheable::Ld(args) => Instruction::new_x64_load_signed(
+            InstrCacheable::Bset(args) => {
+                Instruction::new_bit_set(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Bseti(args) => Instruction::new_bit_set_immediate(
                 args.rd,
                 args.rs1,
                 args.imm,
                 InstrWidth::Uncompressed,
             ),
-            // RV64I S-type instructions
-            InstrCacheable::Sb(args) => {
-                Instruction::new_x8_store(args.rs1, args.rs2, args.imm, InstrWidth::Uncompressed)
+
+            // Zbkb bit-manipulation instructions for cryptography
+            InstrCacheable::Pack(args) => {
+                Instruction::new_pack(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
             }
-            InstrCacheable::Sh(args) => {
-                Instruction::new_x16_store(args.rs1, args.rs2, args.imm, InstrWidth::Uncompressed)
+            InstrCacheable::Packh(args) => {
+                Instruction::new_pack_byte(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
             }
-            InstrCacheable::Sw(args) => {
-                Instruction::new_x32_store(args.rs1, args.rs2, args.imm, InstrWidth::Uncompressed)
+            InstrCacheable::Packw(args) => {
+                Instruction::new_x32_pack(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
             }
-            InstrCacheable::Sd(args) => {
-                Instruction::new_x64_store(args.rs1, args.rs2, args.imm, InstrWidth::Uncompressed)
+            InstrCacheable::Brev8(args) => {
+                Instruction::new_reverse_bits_in_bytes(args.rd, args.rs1, InstrWidth::Uncompressed)
             }
 
-            // RV64I B-type instructions
-            InstrCacheable::Beq(args) => Instruction::from_ic_beq(args),
-            InstrCacheable::Bne(args) => Instruction::from_ic_bne(args),
-            InstrCacheable::Blt(args) => Instruction::from_ic_blt(args),
-            InstrCacheable::Bge(args) => Instruction::from_ic_bge(args),
-            InstrCacheable::Bltu(args) => Instruction::from_ic_bltu(args),
-            InstrCacheable::Bgeu(args) => Instruction::from_ic_bgeu(args),
-
-            // RV64I U-type instructions
-            InstrCacheable::Lui(args) => {
-                Instruction::new_li(args.rd, args.imm, InstrWidth::Uncompressed)
+            // Zknh SHA-2 hash function instructions
+            InstrCacheable::Sha256sum0(args) => {
+                Instruction::new_sha256_sum0(args.rd, args.rs1, InstrWidth::Uncompressed)
             }
-            InstrCacheable::Auipc(args) => {
-                Instruction::new_add_immediate_to_pc(args.rd, args.imm, InstrWidth::Uncompressed)
+            InstrCacheable::Sha256sum1(args) => {
+                Instruction::new_sha256_sum1(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Sha256sig0(args) => {
+                Instruction::new_sha256_sig0(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Sha256sig1(args) => {
+                Instruction::new_sha256_sig1(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Sha512sum0(args) => {
+                Instruction::new_sha512_sum0(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Sha512sum1(args) => {
+                Instruction::new_sha512_sum1(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Sha512sig0(args) => {
+                Instruction::new_sha512_sig0(args.rd, args.rs1, InstrWidth::Uncompressed)
+            }
+            InstrCacheable::Sha512sig1(args) => {
+                Instruction::new_sha512_sig1(args.rd, args.rs1, InstrWidth::Uncompressed)
             }
 
-            // RV64I jump instructions
-            InstrCacheable::Jal(args) => Instruction::from_ic_jal(args),
-            InstrCacheable::Jalr(args) => Instruction::from_ic_jalr(args),
+            // Zicond conditional-zero instructions
+            InstrCacheable::CzeroEqz(args) => Instruction::new_conditional_zero_equal_zero(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                InstrWidth::Uncompressed,
+            ),
+            InstrCacheable::CzeroNez(args) => Instruction::new_conditional_zero_not_equal_zero(
+                args.rd,
+                args.rs1,
+                args.rs2,
+                InstrWidth::Uncompressed,
+            ),
+
+            // RV64F instructions
+            InstrCacheable::Flw(args) => Instruction {
+                opcode: OpCode::Flw,
+                args: args.to_args(InstrWidth::Uncompressed),
+            },
+            InstrCacheable::Fsw(args) => Instruction {
+                opcode: OpCode::Fsw,
+                args: args.to_args(InstrWidth::Uncompressed),
+            },
+            InstrCacheable::Feqs(args) => Instruction {
+                opcode: OpCode::Feqs,
+                args: args.into(),
+            },
+            InstrCacheable::Fles(args) => Instruction {
+                opcode: OpCode::Fles,
+                args: args.into(),
+            },
+            InstrCacheable::Flts(args) => Instruction {
+                opcode: OpCode::Flts,
+                args: args.into(),
+            },
+            InstrCacheable::Fadds(args) => Instruction {
+                opcode: OpCode::Fadds,
+                args: args.into(),
+            },
+            InstrCacheable::Fsubs(args) => Instruction {
+                opcode: OpCode::Fsubs,
+                args: args.into(),
+            },
+            InstrCacheable::Fmuls(args) => Instruction {
+                opcode: OpCode::Fmuls,
+                args: args.into(),
+            },
+            InstrCacheable::Fdivs(args) => Instruction {
+                opcode: OpCode::Fdivs,
+                args: args.into(),
+            },
+            InstrCacheable::Fsqrts(args) => Instruction {
+                opcode: OpCode::Fsqrts,
+                args: args.into(),
+            },
+            InstrCacheable::Fmins(args) => Instruction {
+                opcode: OpCode::Fmins,
+                args: args.into(),
+            },
+            InstrCacheable::Fmaxs(args) => Instruction {
+                opcode: OpCode::Fmaxs,
+                args: args.into(),
+            },
+            InstrCacheable::Fsgnjs(args) => Instruction {
+                opcode: OpCode::Fsgnjs,
+                args: args.into(),
+            },
+            InstrCacheable::Fsgnjns(args) => Instruction {
+                opcode: OpCode::Fsgnjns,
+                args: args.into(),
+            },
+            InstrCacheable::Fsgnjxs(args) => Instruction {
+                opcode: OpCode::Fsgnjxs,
+                args: args.into(),
+            },
+            InstrCacheable::Fmadds(args) => Instruction {
+                opcode: OpCode::Fmadds,
+                args: args.into(),
+            },
+            InstrCacheable::Fmsubs(args) => Instruction {
+                opcode: OpCode::Fmsubs,
+                args: args.into(),
+            },
+            InstrCacheable::Fnmsubs(args) => Instruction {
+                opcode: OpCode::Fnmsubs,
+                args: args.into(),
+            },
+            InstrCacheable::Fnmadds(args) => Instruction {
+                opcode: OpCode::Fnmadds,
+                args: args.into(),
+            },
+            InstrCacheable::FclassS(args) => Instruction {
+                opcode: OpCode::FclassS,
+                args: args.into(),
+            },
+            InstrCacheable::FmvXW(args) => Instruction {
+                opcode: OpCode::FmvXW,
+                args: args.into(),
+            },
+            InstrCacheable::FmvWX(args) => Instruction {
+                opcode: OpCode::FmvWX,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtsw(args) => Instruction {
+                opcode: OpCode::Fcvtsw,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtswu(args) => Instruction {
+                opcode: OpCode::Fcvtswu,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtsl(args) => Instruction {
+                opcode: OpCode::Fcvtsl,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtslu(args) => Instruction {
+                opcode: OpCode::Fcvtslu,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtws(args) => Instruction {
+                opcode: OpCode::Fcvtws,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtwus(args) => Instruction {
+                opcode: OpCode::Fcvtwus,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtls(args) => Instruction {
+                opcode: OpCode::Fcvtls,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtlus(args) => Instruction {
+                opcode: OpCode::Fcvtlus,
+                args: args.into(),
+            },
+
+            // RV64D instructions
+            InstrCacheable::Fld(args) => Instruction {
+                opcode: OpCode::Fld,
+                args: args.to_args(InstrWidth::Uncompressed),
+            },
+            InstrCacheable::Fsd(args) => Instruction {
+                opcode: OpCode::Fsd,
+                args: args.to_args(InstrWidth::Uncompressed),
+            },
+            InstrCacheable::Feqd(args) => Instruction {
+                opcode: OpCode::Feqd,
+                args: args.into(),
+            },
+            InstrCacheable::Fled(args) => Instruction {
+                opcode: OpCode::Fled,
+                args: args.into(),
+            },
+            InstrCacheable::Fltd(args) => Instruction {
+                opcode: OpCode::Fltd,
+                args: args.into(),
+            },
+            InstrCacheable::Faddd(args) => Instruction {
+                opcode: OpCode::Faddd,
+                args: args.into(),
+            },
+            InstrCacheable::Fsubd(args) => Instruction {
+                opcode: OpCode::Fsubd,
+                args: args.into(),
+            },
+            InstrCacheable::Fmuld(args) => Instruction {
+                opcode: OpCode::Fmuld,
+                args: args.into(),
+            },
+            InstrCacheable::Fdivd(args) => Instruction {
+                opcode: OpCode::Fdivd,
+                args: args.into(),
+            },
+            InstrCacheable::Fsqrtd(args) => Instruction {
+                opcode: OpCode::Fsqrtd,
+                args: args.into(),
+            },
+            InstrCacheable::Fmind(args) => Instruction {
+                opcode: OpCode::Fmind,
+                args: args.into(),
+            },
+            InstrCacheable::Fmaxd(args) => Instruction {
+                opcode: OpCode::Fmaxd,
+                args: args.into(),
+            },
+            InstrCacheable::Fsgnjd(args) => Instruction {
+                opcode: OpCode::Fsgnjd,
+                args: args.into(),
+            },
+            InstrCacheable::Fsgnjnd(args) => Instruction {
+                opcode: OpCode::Fsgnjnd,
+                args: args.into(),
+            },
+            InstrCacheable::Fsgnjxd(args) => Instruction {
+                opcode: OpCode::Fsgnjxd,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtds(args) => Instruction {
+                opcode: OpCode::Fcvtds,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtsd(args) => Instruction {
+                opcode: OpCode::Fcvtsd,
+                args: args.into(),
+            },
+            InstrCacheable::Fmaddd(args) => Instruction {
+                opcode: OpCode::Fmaddd,
+                args: args.into(),
+            },
+            InstrCacheable::Fmsubd(args) => Instruction {
+                opcode: OpCode::Fmsubd,
+                args: args.into(),
+            },
+            InstrCacheable::Fnmsubd(args) => Instruction {
+                opcode: OpCode::Fnmsubd,
+                args: args.into(),
+            },
+            InstrCacheable::Fnmaddd(args) => Instruction {
+                opcode: OpCode::Fnmaddd,
+                args: args.into(),
+            },
+            InstrCacheable::FclassD(args) => Instruction {
+                opcode: OpCode::FclassD,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtdw(args) => Instruction {
+                opcode: OpCode::Fcvtdw,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtdwu(args) => Instruction {
+                opcode: OpCode::Fcvtdwu,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtdl(args) => Instruction {
+                opcode: OpCode::Fcvtdl,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtdlu(args) => Instruction {
+                opcode: OpCode::Fcvtdlu,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtwd(args) => Instruction {
+                opcode: OpCode::Fcvtwd,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtwud(args) => Instruction {
+                opcode: OpCode::Fcvtwud,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtld(args) => Instruction {
+                opcode: OpCode::Fcvtld,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvtlud(args) => Instruction {
+                opcode: OpCode::Fcvtlud,
+                args: args.into(),
+            },
+            InstrCacheable::FmvXD(args) => Instruction {
+                opcode: OpCode::FmvXD,
+                args: args.into(),
+            },
+            InstrCacheable::FmvDX(args) => Instruction {
+                opcode: OpCode::FmvDX,
+                args: args.into(),
+            },
 
-            // RV64A atomic instructions
-            InstrCacheable::Lrw(args) => Instruction::new_x32_atomic_load(
-                args.rd,
-                args.rs1,
-                args.aq,
-                args.rl,
-                InstrWidth::Uncompressed,
-            ),
-            InstrCacheable::Scw(args) => Instruction::new_x32_atomic_store(
-                args.rd,
-                args.rs1,
-                args.rs2,
-                args.aq,
-                args.rl,
-                InstrWidth::Uncompressed,
-            ),
-            InstrCacheable::Amoswapw(args) => Instruction::new_x32_atomic_swap(
-                args.rd,
-                args.rs1,
-                args.rs2,
-                args.aq,
-                args.rl,
-                InstrWidth::Uncompressed,
-            ),
-            InstrCacheable::Amoaddw(args) => Instruction {
-                opcode: OpCode::Amoaddw,
+            // Zfh half-precision instructions
+            InstrCacheable::FclassH(args) => Instruction {
+                opcode: OpCode::FclassH,
                 args: args.into(),
             },
-            InstrCacheable::Amoxorw(args) => Instruction {
-                opcode: OpCode::Amoxorw,
+            InstrCacheable::Feqh(args) => Instruction {
+                opcode: OpCode::Feqh,
                 args: args.into(),
             },
-            InstrCacheable::Amoandw(args) => Instruction {
-                opcode: OpCode::Amoandw,
+            InstrCacheable::Fleh(args) => Instruction {
+                opcode: OpCode::Fleh,
                 args: args.into(),
             },
-            InstrCacheable::Amoorw(args) => Instruction {
-                opcode: OpCode::Amoorw,
+            InstrCacheable::Flth(args) => Instruction {
+                opcode: OpCode::Flth,
                 args: args.into(),
             },
-            InstrCacheable::Amominw(args) => Instruction {
-                opcode: OpCode::Amominw,
+            InstrCacheable::Faddh(args) => Instruction {
+                opcode: OpCode::Faddh,
                 args: args.into(),
             },
-            InstrCacheable::Amomaxw(args) => Instruction {
-                opcode: OpCode::Amomaxw,
+            InstrCacheable::Fsubh(args) => Instruction {
+                opcode: OpCode::Fsubh,
                 args: args.into(),
             },
-            InstrCacheable::Amominuw(args) => Instruction {
-                opcode: OpCode::Amominuw,
+            InstrCacheable::Fmulh(args) => Instruction {
+                opcode: OpCode::Fmulh,
                 args: args.into(),
             },
-            InstrCacheable::Amomaxuw(args) => Instruction {
-                opcode: OpCode::Amomaxuw,
+            InstrCacheable::Fdivh(args) => Instruction {
+                opcode: OpCode::Fdivh,
                 args: args.into(),
             },
-            InstrCacheable::Lrd(args) => Instruction::new_x64_atomic_load(
-                args.rd,
-                args.rs1,
-                args.aq,
-                args.rl,
-                InstrWidth::Uncompressed,
-            ),
-            InstrCacheable::Scd(args) => Instruction::new_x64_atomic_store(
-                args.rd,
-                args.rs1,
-                args.rs2,
-                args.aq,
-                args.rl,
-                InstrWidth::Uncompressed,
-            ),
-            InstrCacheable::Amoswapd(args) => Instruction::new_x64_atomic_swap(
-                args.rd,
-                args.rs1,
-                args.rs2,
-                args.aq,
-                args.rl,
-                InstrWidth::Uncompressed,
-            ),
-            InstrCacheable::Amoaddd(args) => Instruction::new_x64_atomic_add(
-                args.rd,
-                args.rs1,
-                args.rs2,
-                args.aq,
-                args.rl,
-                InstrWidth::Uncompressed,
-            ),
-            InstrCacheable::Amoxord(args) => Instruction {
-                opcode: OpCode::Amoxord,
+            InstrCacheable::Fsqrth(args) => Instruction {
+                opcode: OpCode::Fsqrth,
                 args: args.into(),
             },
-            InstrCacheable::Amoandd(args) => Instruction {
-                opcode: OpCode::Amoandd,
+            InstrCacheable::Fminh(args) => Instruction {
+                opcode: OpCode::Fminh,
                 args: args.into(),
             },
-            InstrCacheable::Amoord(args) => Instruction {
-                opcode: OpCode::Amoord,
+            InstrCacheable::Fmaxh(args) => Instruction {
+                opcode: OpCode::Fmaxh,
                 args: args.into(),
             },
-            InstrCacheable::Amomind(args) => Instruction::new_x64_atomic_min_signed(
-                args.rd,
-                args.rs1,
-                args.rs2,
-                args.aq,
-                args.rl,
-                InstrWidth::Uncompressed,
-            ),
-            InstrCacheable::Amomaxd(args) => Instruction {
-                opcode: OpCode::Amomaxd,
+            InstrCacheable::Fmaddh(args) => Instruction {
+                opcode: OpCode::Fmaddh,
+                args: args.into(),
+            },
+            InstrCacheable::Fmsubh(args) => Instruction {
+                opcode: OpCode::Fmsubh,
+                args: args.into(),
+            },
+            InstrCacheable::Fnmsubh(args) => Instruction {
+                opcode: OpCode::Fnmsubh,
+                args: args.into(),
+            },
+            InstrCacheable::Fnmaddh(args) => Instruction {
+                opcode: OpCode::Fnmaddh,
+                args: args.into(),
+            },
+            InstrCacheable::Flh(args) => Instruction {
+                opcode: OpCode::Flh,
+                args: args.to_args(InstrWidth::Uncompressed),
+            },
+            InstrCacheable::Fsh(args) => Instruction {
+                opcode: OpCode::Fsh,
+                args: args.to_args(InstrWidth::Uncompressed),
+            },
+            InstrCacheable::Fcvthw(args) => Instruction {
+                opcode: OpCode::Fcvthw,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvthwu(args) => Instruction {
+                opcode: OpCode::Fcvthwu,
+                args: args.into(),
+            },
+            InstrCacheable::Fcvthl(args) => Instruction {
+                opcode: OpCode::Fcvthl,
                 args: args.into(),
             },
-            InstrCacheable::Amominud(args) => Instruction {
-                opcode: OpCode::Amominud,
+            InstrCacheable::Fcvthlu(args) => Instruction {
+                opcode: OpCode::Fcvthlu,
                 args: args.into(),
             },
-            InstrCacheable::Amomaxud(args) => Instruction {
-                opcode: OpCode::Amomaxud,
+            InstrCacheable::Fcvtwh(args) => Instruction {
+                opcode: OpCode::Fcvtwh,
                 args: args.into(),
             },
-
-            // RV64M multiplication and division instructions
-            InstrCacheable::Rem(args) => Instruction::from_ic_rem(args),
-            InstrCacheable::Remu(args) => Instruction::from_ic_remu(args),
-            InstrCacheable::Remw(args) => Instruction::from_ic_remw(args),
-            InstrCacheable::Remuw(args) => Instruction::from_ic_remuw(args),
-            InstrCacheable::Div(args) => Instruction::from_ic_div(args),
-            InstrCacheable::Divu(args) => Instruction::from_ic_divu(args),
-            InstrCacheable::Divw(args) => Instruction::from_ic_divw(args),
-            InstrCacheable::Divuw(args) => Instruction::from_ic_divuw(args),
-            InstrCacheable::Mul(args) => Instruction::from_ic_mul(args),
-            InstrCacheable::Mulh(args) => Instruction::from_ic_mulh(args),
-            InstrCacheable::Mulhsu(args) => Instruction::from_ic_mulhsu(args),
-            InstrCacheable::Mulhu(args) => Instruction::from_ic_mulhu(args),
-            InstrCacheable::Mulw(args) => {
-                Instruction::new_x32_mul(args.rd, args.rs1, args.rs2, InstrWidth::Uncompressed)
-            }
-
-            // RV64F instructions
-            InstrCacheable::Flw(args) => Instruction {
-                opcode: OpCode::Flw,
-                args: args.to_args(InstrWidth::Uncompressed),
+            InstrCacheable::Fcvtwuh(args) => Instruction {
+                opcode: OpCode::Fcvtwuh,
+                args: args.into(),
             },
-            InstrCacheable::Fsw(args) => Instruction {
-                opcode: OpCode::Fsw,
-                args: args.to_args(InstrWidth::Uncompressed),
+            InstrCacheable::Fcvtlh(args) => Instruction {
+                opcode: OpCode::Fcvtlh,
+                args: args.into(),
             },
-            InstrCacheable::Feqs(args) => Instruction {
-                opcode: OpCode::Feqs,
+            InstrCacheable::Fcvtluh(args) => Instruction {
+                opcode: OpCode::Fcvtluh,
                 args: args.into(),
             },
-            InstrCacheable::Fles(args) => Instruction {
-                opcode: OpCode::Fles,
+            InstrCacheable::Fcvtsh(args) => Instruction {
+                opcode: OpCode::Fcvtsh,
                 args: args.into(),
             },
-            InstrCacheable::Flts(args) => Instruction {
-                opcode: OpCode::Flts,
+            InstrCacheable::Fcvths(args) => Instruction {
+                opcode: OpCode::Fcvths,
                 args: args.into(),
             },
-            InstrCacheable::Fadds(args) => Instruction {
-                opcode: OpCode::Fadds,
+            InstrCacheable::Fcvtdh(args) => Instruction {
+                opcode: OpCode::Fcvtdh,
                 args: args.into(),
             },
-            InstrCacheable::Fsubs(args) => Instruction {
-                opcode: OpCode::Fsubs,
+            InstrCacheable::Fcvthd(args) => Instruction {
+                opcode: OpCode::Fcvthd,
                 args: args.into(),
             },
-            InstrCacheable::Fmuls(args) => Instruction {
-                opcode: OpCode::Fmuls,
+            InstrCacheable::Fsgnjh(args) => Instruction {
+                opcode: OpCode::Fsgnjh,
                 args: args.into(),
             },
-            InstrCacheable::Fdivs(args) => Instruction {
-                opcode: OpCode::Fdivs,
+            InstrCacheable::Fsgnjnh(args) => Instruction {
+                opcode: OpCode::Fsgnjnh,
                 args: args.into(),
             },
-            InstrCacheable::Fsqrts(args) => Instruction {
-                opcode: OpCode::Fsqrts,
+            InstrCacheable::Fsgnjxh(args) => Instruction {
+                opcode: OpCode::Fsgnjxh,
                 args: args.into(),
             },
-            InstrCacheable::Fmins(args) => Instruction {
-                opcode: OpCode::Fmins,
+            InstrCacheable::FmvXH(args) => Instruction {
+                opcode: OpCode::FmvXH,
                 args: args.into(),
             },
-            InstrCacheable::Fmaxs(args) => Instruction {
-                opcode: OpCode::Fmaxs,
+            InstrCacheable::FmvHX(args) => Instruction {
+                opcode: OpCode::FmvHX,
                 args: args.into(),
             },
-            InstrCacheable::Fsgnjs(args) => Instruction {
-                opcode: OpCode::Fsgnjs,
+
+            // Zve64x vector instructions
+            InstrCacheable::Vsetvli(args) => Instruction {
+                opcode: OpCode::Vsetvli,
                 args: args.into(),
             },
-            InstrCacheable::Fsgnjns(args) => Instruction {
-                opcode: OpCode::Fsgnjns,
+            InstrCacheable::Vsetivli(args) => Instruction {
+                opcode: OpCode::Vsetivli,
                 args: args.into(),
             },
-            InstrCacheable::Fsgnjxs(args) => Instruction {
-                opcode: OpCode::Fsgnjxs,
+            InstrCacheable::Vsetvl(args) => Instruction {
+                opcode: OpCode::Vsetvl,
                 args: args.into(),
             },
-            InstrCacheable::Fmadds(args) => Instruction {
-                opcode: OpCode::Fmadds,
+            InstrCacheable::Vle8(args) => Instruction {
+                opcode: OpCode::Vle8,
                 args: args.into(),
             },
-            InstrCacheable::Fmsubs(args) => Instruction {
-                opcode: OpCode::Fmsubs,
+            InstrCacheable::Vle16(args) => Instruction {
+                opcode: OpCode::Vle16,
                 args: args.into(),
             },
-            InstrCacheable::Fnmsubs(args) => Instruction {
-                opcode: OpCode::Fnmsubs,
+            InstrCacheable::Vle32(args) => Instruction {
+                opcode: OpCode::Vle32,
                 args: args.into(),
             },
-            InstrCacheable::Fnmadds(args) => Instruction {
-                opcode: OpCode::Fnmadds,
+            InstrCacheable::Vle64(args) => Instruction {
+                opcode: OpCode::Vle64,
                 args: args.into(),
             },
-            InstrCacheable::FclassS(args) => Instruction {
-                opcode: OpCode::FclassS,
+            InstrCacheable::Vse8(args) => Instruction {
+                opcode: OpCode::Vse8,
                 args: args.into(),
             },
-            InstrCacheable::FmvXW(args) => Instruction {
-                opcode: OpCode::FmvXW,
+            InstrCacheable::Vse16(args) => Instruction {
+                opcode: OpCode::Vse16,
                 args: args.into(),
             },
-            InstrCacheable::FmvWX(args) => Instruction {
-                opcode: OpCode::FmvWX,
+            InstrCacheable::Vse32(args) => Instruction {
+                opcode: OpCode::Vse32,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtsw(args) => Instruction {
-                opcode: OpCode::Fcvtsw,
+            InstrCacheable::Vse64(args) => Instruction {
+                opcode: OpCode::Vse64,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtswu(args) => Instruction {
-                opcode: OpCode::Fcvtswu,
+            InstrCacheable::VaddVV(args) => Instruction {
+                opcode: OpCode::VaddVV,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtsl(args) => Instruction {
-                opcode: OpCode::Fcvtsl,
+            InstrCacheable::VaddVX(args) => Instruction {
+                opcode: OpCode::VaddVX,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtslu(args) => Instruction {
-                opcode: OpCode::Fcvtslu,
+            InstrCacheable::VaddVI(args) => Instruction {
+                opcode: OpCode::VaddVI,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtws(args) => Instruction {
-                opcode: OpCode::Fcvtws,
+            InstrCacheable::VsubVV(args) => Instruction {
+                opcode: OpCode::VsubVV,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtwus(args) => Instruction {
-                opcode: OpCode::Fcvtwus,
+            InstrCacheable::VsubVX(args) => Instruction {
+                opcode: OpCode::VsubVX,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtls(args) => Instruction {
-                opcode: OpCode::Fcvtls,
+            InstrCacheable::VrsubVX(args) => Instruction {
+                opcode: OpCode::VrsubVX,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtlus(args) => Instruction {
-                opcode: OpCode::Fcvtlus,
+            InstrCacheable::VrsubVI(args) => Instruction {
+                opcode: OpCode::VrsubVI,
                 args: args.into(),
             },
-
-            // RV64D instructions
-            InstrCacheable::Fld(args) => Instruction {
-                opcode: OpCode::Fld,
-                args: args.to_args(InstrWidth::Uncompressed),
+            InstrCacheable::VandVV(args) => Instruction {
+                opcode: OpCode::VandVV,
+                args: args.into(),
             },
-            InstrCacheable::Fsd(args) => Instruction {
-                opcode: OpCode::Fsd,
-                args: args.to_args(InstrWidth::Uncompressed),
+            InstrCacheable::VandVX(args) => Instruction {
+                opcode: OpCode::VandVX,
+                args: args.into(),
             },
-            InstrCacheable::Feqd(args) => Instruction {
-                opcode: OpCode::Feqd,
+            InstrCacheable::VandVI(args) => Instruction {
+                opcode: OpCode::VandVI,
                 args: args.into(),
             },
-            InstrCacheable::Fled(args) => Instruction {
-                opcode: OpCode::Fled,
+            InstrCacheable::VorVV(args) => Instruction {
+                opcode: OpCode::VorVV,
                 args: args.into(),
             },
-            InstrCacheable::Fltd(args) => Instruction {
-                opcode: OpCode::Fltd,
+            InstrCacheable::VorVX(args) => Instruction {
+                opcode: OpCode::VorVX,
                 args: args.into(),
             },
-            InstrCacheable::Faddd(args) => Instruction {
-                opcode: OpCode::Faddd,
+            InstrCacheable::VorVI(args) => Instruction {
+                opcode: OpCode::VorVI,
                 args: args.into(),
             },
-            InstrCacheable::Fsubd(args) => Instruction {
-                opcode: OpCode::Fsubd,
+            InstrCacheable::VxorVV(args) => Instruction {
+                opcode: OpCode::VxorVV,
                 args: args.into(),
             },
-            InstrCacheable::Fmuld(args) => Instruction {
-                opcode: OpCode::Fmuld,
+            InstrCacheable::VxorVX(args) => Instruction {
+                opcode: OpCode::VxorVX,
                 args: args.into(),
             },
-            InstrCacheable::Fdivd(args) => Instruction {
-                opcode: OpCode::Fdivd,
+            InstrCacheable::VxorVI(args) => Instruction {
+                opcode: OpCode::VxorVI,
                 args: args.into(),
             },
-            InstrCacheable::Fsqrtd(args) => Instruction {
-                opcode: OpCode::Fsqrtd,
+            InstrCacheable::VsllVV(args) => Instruction {
+                opcode: OpCode::VsllVV,
                 args: args.into(),
             },
-            InstrCacheable::Fmind(args) => Instruction {
-                opcode: OpCode::Fmind,
+            InstrCacheable::VsllVX(args) => Instruction {
+                opcode: OpCode::VsllVX,
                 args: args.into(),
             },
-            InstrCacheable::Fmaxd(args) => Instruction {
-                opcode: OpCode::Fmaxd,
+            InstrCacheable::VsllVI(args) => Instruction {
+                opcode: OpCode::VsllVI,
                 args: args.into(),
             },
-            InstrCacheable::Fsgnjd(args) => Instruction {
-                opcode: OpCode::Fsgnjd,
+            InstrCacheable::VsrlVV(args) => Instruction {
+                opcode: OpCode::VsrlVV,
                 args: args.into(),
             },
-            InstrCacheable::Fsgnjnd(args) => Instruction {
-                opcode: OpCode::Fsgnjnd,
+            InstrCacheable::VsrlVX(args) => Instruction {
+                opcode: OpCode::VsrlVX,
                 args: args.into(),
             },
-            InstrCacheable::Fsgnjxd(args) => Instruction {
-                opcode: OpCode::Fsgnjxd,
+            InstrCacheable::VsrlVI(args) => Instruction {
+                opcode: OpCode::VsrlVI,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtds(args) => Instruction {
-                opcode: OpCode::Fcvtds,
+            InstrCacheable::VsraVV(args) => Instruction {
+                opcode: OpCode::VsraVV,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtsd(args) => Instruction {
-                opcode: OpCode::Fcvtsd,
+            InstrCacheable::VsraVX(args) => Instruction {
+                opcode: OpCode::VsraVX,
                 args: args.into(),
             },
-            InstrCacheable::Fmaddd(args) => Instruction {
-                opcode: OpCode::Fmaddd,
+            InstrCacheable::VsraVI(args) => Instruction {
+                opcode: OpCode::VsraVI,
                 args: args.into(),
             },
-            InstrCacheable::Fmsubd(args) => Instruction {
-                opcode: OpCode::Fmsubd,
+            InstrCacheable::VminuVV(args) => Instruction {
+                opcode: OpCode::VminuVV,
                 args: args.into(),
             },
-            InstrCacheable::Fnmsubd(args) => Instruction {
-                opcode: OpCode::Fnmsubd,
+            InstrCacheable::VminuVX(args) => Instruction {
+                opcode: OpCode::VminuVX,
                 args: args.into(),
             },
-            InstrCacheable::Fnmaddd(args) => Instruction {
-                opcode: OpCode::Fnmaddd,
+            InstrCacheable::VminVV(args) => Instruction {
+                opcode: OpCode::VminVV,
                 args: args.into(),
             },
-            InstrCacheable::FclassD(args) => Instruction {
-                opcode: OpCode::FclassD,
+            InstrCacheable::VminVX(args) => Instruction {
+                opcode: OpCode::VminVX,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtdw(args) => Instruction {
-                opcode: OpCode::Fcvtdw,
+            InstrCacheable::VmaxuVV(args) => Instruction {
+                opcode: OpCode::VmaxuVV,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtdwu(args) => Instruction {
-                opcode: OpCode::Fcvtdwu,
+            InstrCacheable::VmaxuVX(args) => Instruction {
+                opcode: OpCode::VmaxuVX,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtdl(args) => Instruction {
-                opcode: OpCode::Fcvtdl,
+            InstrCacheable::VmaxVV(args) => Instruction {
+                opcode: OpCode::VmaxVV,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtdlu(args) => Instruction {
-                opcode: OpCode::Fcvtdlu,
+            InstrCacheable::VmaxVX(args) => Instruction {
+                opcode: OpCode::VmaxVX,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtwd(args) => Instruction {
-                opcode: OpCode::Fcvtwd,
+            InstrCacheable::VmulVV(args) => Instruction {
+                opcode: OpCode::VmulVV,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtwud(args) => Instruction {
-                opcode: OpCode::Fcvtwud,
+            InstrCacheable::VmulVX(args) => Instruction {
+                opcode: OpCode::VmulVX,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtld(args) => Instruction {
-                opcode: OpCode::Fcvtld,
+            InstrCacheable::VmvVV(args) => Instruction {
+                opcode: OpCode::VmvVV,
                 args: args.into(),
             },
-            InstrCacheable::Fcvtlud(args) => Instruction {
-                opcode: OpCode::Fcvtlud,
+            InstrCacheable::VmvVX(args) => Instruction {
+                opcode: OpCode::VmvVX,
                 args: args.into(),
             },
-            InstrCacheable::FmvXD(args) => Instruction {
-                opcode: OpCode::FmvXD,
+            InstrCacheable::VmvVI(args) => Instruction {
+                opcode: OpCode::VmvVI,
                 args: args.into(),
             },
-            InstrCacheable::FmvDX(args) => Instruction {
-                opcode: OpCode::FmvDX,
+            InstrCacheable::VmvXS(args) => Instruction {
+                opcode: OpCode::VmvXS,
+                args: args.into(),
+            },
+            InstrCacheable::VmvSX(args) => Instruction {
+                opcode: OpCode::VmvSX,
+                args: args.into(),
+            },
+
+            // Zicboz instructions
+            InstrCacheable::CboZero(args) => Instruction {
+                opcode: OpCode::CboZero,
                 args: args.into(),
             },
 
@@ -2738,6 +4574,147 @@ impl From<&FCmpArgs> for Args {
     }
 }
 
+impl From<&VVArgs> for Args {
+    fn from(value: &VVArgs) -> Self {
+        Self {
+            rd: value.vd.into(),
+            rs1: value.vs1.into(),
+            rs2: value.vs2.into(),
+            width: InstrWidth::Uncompressed,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+impl From<&VXArgs> for Args {
+    fn from(value: &VXArgs) -> Self {
+        Self {
+            rd: value.vd.into(),
+            rs1: value.rs1.into(),
+            rs2: value.vs2.into(),
+            width: InstrWidth::Uncompressed,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+impl From<&VIArgs> for Args {
+    fn from(value: &VIArgs) -> Self {
+        Self {
+            rd: value.vd.into(),
+            rs2: value.vs2.into(),
+            imm: value.imm,
+            width: InstrWidth::Uncompressed,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+impl From<&VRegToVRegArgs> for Args {
+    fn from(value: &VRegToVRegArgs) -> Self {
+        Self {
+            rd: value.vd.into(),
+            rs1: value.vs1.into(),
+            width: InstrWidth::Uncompressed,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+impl From<&XRegToVRegArgs> for Args {
+    fn from(value: &XRegToVRegArgs) -> Self {
+        Self {
+            rd: value.vd.into(),
+            rs1: value.rs1.into(),
+            width: InstrWidth::Uncompressed,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+impl From<&VImmArgs> for Args {
+    fn from(value: &VImmArgs) -> Self {
+        Self {
+            rd: value.vd.into(),
+            imm: value.imm,
+            width: InstrWidth::Uncompressed,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+impl From<&VRegToXRegArgs> for Args {
+    fn from(value: &VRegToXRegArgs) -> Self {
+        Self {
+            rd: value.rd.into(),
+            rs2: value.vs2.into(),
+            width: InstrWidth::Uncompressed,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+impl From<&VLoadArgs> for Args {
+    fn from(value: &VLoadArgs) -> Self {
+        Self {
+            rd: value.vd.into(),
+            rs1: value.rs1.into(),
+            width: InstrWidth::Uncompressed,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+impl From<&VStoreArgs> for Args {
+    fn from(value: &VStoreArgs) -> Self {
+        Self {
+            rs1: value.rs1.into(),
+            rs2: value.vs3.into(),
+            width: InstrWidth::Uncompressed,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+impl From<&VsetvliArgs> for Args {
+    fn from(value: &VsetvliArgs) -> Self {
+        Self {
+            rd: value.rd.into(),
+            rs1: value.rs1.into(),
+            imm: value.vtypei,
+            width: InstrWidth::Uncompressed,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+/// The `vtypei` immediate of `vsetivli` occupies the lowest 10 bits of [`Args::imm`].
+const VSETIVLI_VTYPEI_MASK: i64 = 0x3FF;
+
+/// The AVL immediate of `vsetivli` is packed into [`Args::imm`] above `vtypei`.
+const VSETIVLI_AVL_SHIFT: u32 = 10;
+
+impl From<&VsetivliArgs> for Args {
+    fn from(value: &VsetivliArgs) -> Self {
+        Self {
+            rd: value.rd.into(),
+            imm: (value.uimm << VSETIVLI_AVL_SHIFT) | (value.vtypei & VSETIVLI_VTYPEI_MASK),
+            width: InstrWidth::Uncompressed,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+impl From<&CboArgs> for Args {
+    fn from(value: &CboArgs) -> Self {
+        Self {
+            rs1: value.rs1.into(),
+            width: InstrWidth::Uncompressed,
+            ..Self::DEFAULT
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Register;
@@ -1424,102 +1424,1315 @@ impl Instruction {
         }
     }
 
-    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicLoad`].
-    pub(crate) fn new_x32_atomic_load(
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicAdd`].
+    pub(crate) fn new_x32_atomic_add(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32AtomicAdd,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicXor`].
+    pub(crate) fn new_x32_atomic_xor(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32AtomicXor,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicAnd`].
+    pub(crate) fn new_x32_atomic_and(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32AtomicAnd,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicOr`].
+    pub(crate) fn new_x32_atomic_or(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32AtomicOr,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicMinSigned`].
+    pub(crate) fn new_x32_atomic_min_signed(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32AtomicMinSigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicMaxSigned`].
+    pub(crate) fn new_x32_atomic_max_signed(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32AtomicMaxSigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicMinUnsigned`].
+    pub(crate) fn new_x32_atomic_min_unsigned(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32AtomicMinUnsigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicMaxUnsigned`].
+    pub(crate) fn new_x32_atomic_max_unsigned(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32AtomicMaxUnsigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicXor`].
+    pub(crate) fn new_x64_atomic_xor(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X64AtomicXor,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicAnd`].
+    pub(crate) fn new_x64_atomic_and(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X64AtomicAnd,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicOr`].
+    pub(crate) fn new_x64_atomic_or(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X64AtomicOr,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicMaxSigned`].
+    pub(crate) fn new_x64_atomic_max_signed(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X64AtomicMaxSigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicMinUnsigned`].
+    pub(crate) fn new_x64_atomic_min_unsigned(
         rd: XRegister,
         rs1: XRegister,
-        aq: bool,
-        rl: bool,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X64AtomicMinUnsigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicMaxUnsigned`].
+    pub(crate) fn new_x64_atomic_max_unsigned(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X64AtomicMaxUnsigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicLoad`].
+    pub(crate) fn new_x32_atomic_load(
+        rd: XRegister,
+        rs1: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32AtomicLoad,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicStore`].
+    pub(crate) fn new_x32_atomic_store(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32AtomicStore,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicLoad`].
+    pub(crate) fn new_x64_atomic_load(
+        rd: XRegister,
+        rs1: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X64AtomicLoad,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicStore`].
+    pub(crate) fn new_x64_atomic_store(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X64AtomicStore,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    pub(crate) fn new_x64_rem_signed(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X64RemSigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64RemUnsigned`].
+    pub(crate) fn new_x64_rem_unsigned(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X64RemUnsigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32RemSigned`].
+    pub(crate) fn new_x32_rem_signed(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32RemSigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32RemUnsigned`].
+    pub(crate) fn new_x32_rem_unsigned(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32RemUnsigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicSwap`].
+    pub(crate) fn new_x32_atomic_swap(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32AtomicSwap,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicSwap`].
+    pub(crate) fn new_x64_atomic_swap(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X64AtomicSwap,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicCompareAndSwap`].
+    pub(crate) fn new_x32_atomic_compare_and_swap(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32AtomicCompareAndSwap,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicCompareAndSwap`].
+    pub(crate) fn new_x64_atomic_compare_and_swap(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X64AtomicCompareAndSwap,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X128AtomicCompareAndSwap`].
+    pub(crate) fn new_x128_atomic_compare_and_swap(
+        rd: XRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        aq: bool,
+        rl: bool,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X128AtomicCompareAndSwap,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                aq,
+                rl,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Shift1Add`].
+    pub(crate) fn new_shift1_add(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::Shift1Add,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Shift2Add`].
+    pub(crate) fn new_shift2_add(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::Shift2Add,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Shift3Add`].
+    pub(crate) fn new_shift3_add(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::Shift3Add,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::AddUnsignedWord`].
+    pub(crate) fn new_add_unsigned_word(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::AddUnsignedWord,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Shift1AddUnsignedWord`].
+    pub(crate) fn new_shift1_add_unsigned_word(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::Shift1AddUnsignedWord,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Shift2AddUnsignedWord`].
+    pub(crate) fn new_shift2_add_unsigned_word(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::Shift2AddUnsignedWord,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Shift3AddUnsignedWord`].
+    pub(crate) fn new_shift3_add_unsigned_word(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::Shift3AddUnsignedWord,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::ShiftLeftImmediateUnsignedWord`].
+    pub(crate) fn new_shift_left_immediate_unsigned_word(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        imm: i64,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::ShiftLeftImmediateUnsignedWord,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                imm,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::AndNot`].
+    pub(crate) fn new_and_not(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::AndNot,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::OrNot`].
+    pub(crate) fn new_or_not(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::OrNot,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::XorNot`].
+    pub(crate) fn new_xor_not(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::XorNot,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::CountLeadingZeros`].
+    pub(crate) fn new_count_leading_zeros(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::CountLeadingZeros,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::CountTrailingZeros`].
+    pub(crate) fn new_count_trailing_zeros(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::CountTrailingZeros,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::CountSetBits`].
+    pub(crate) fn new_count_set_bits(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::CountSetBits,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32CountLeadingZeros`].
+    pub(crate) fn new_x32_count_leading_zeros(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32CountLeadingZeros,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32CountTrailingZeros`].
+    pub(crate) fn new_x32_count_trailing_zeros(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32CountTrailingZeros,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32CountSetBits`].
+    pub(crate) fn new_x32_count_set_bits(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32CountSetBits,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::MaxSigned`].
+    pub(crate) fn new_max_signed(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::MaxSigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::MaxUnsigned`].
+    pub(crate) fn new_max_unsigned(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::MaxUnsigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::MinSigned`].
+    pub(crate) fn new_min_signed(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::MinSigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::MinUnsigned`].
+    pub(crate) fn new_min_unsigned(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::MinUnsigned,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::SignExtendByte`].
+    pub(crate) fn new_sign_extend_byte(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::SignExtendByte,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::SignExtendHalf`].
+    pub(crate) fn new_sign_extend_half(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::SignExtendHalf,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::ZeroExtendHalf`].
+    pub(crate) fn new_zero_extend_half(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::ZeroExtendHalf,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::RotateLeft`].
+    pub(crate) fn new_rotate_left(
+        rd: NonZeroXRegister,
+        rs1: NonZeroXRegister,
+        rs2: NonZeroXRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::RotateLeft,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::RotateRight`].
+    pub(crate) fn new_rotate_right(
+        rd: NonZeroXRegister,
+        rs1: NonZeroXRegister,
+        rs2: NonZeroXRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::RotateRight,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::RotateRightImmediate`].
+    pub(crate) fn new_rotate_right_immediate(
+        rd: NonZeroXRegister,
+        rs1: NonZeroXRegister,
+        imm: i64,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::RotateRightImmediate,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                imm,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32RotateLeft`].
+    pub(crate) fn new_x32_rotate_left(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32RotateLeft,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32RotateRight`].
+    pub(crate) fn new_x32_rotate_right(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32RotateRight,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                rs2: rs2.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32RotateRightImmediate`].
+    pub(crate) fn new_x32_rotate_right_immediate(
+        rd: NonZeroXRegister,
+        rs1: NonZeroXRegister,
+        imm: i64,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::X32RotateRightImmediate,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                imm,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::OrCombineBytes`].
+    pub(crate) fn new_or_combine_bytes(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::OrCombineBytes,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::ByteReverse`].
+    pub(crate) fn new_byte_reverse(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::ByteReverse,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::BitClear`].
+    pub(crate) fn new_bit_clear(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        rs2: XRegister,
         width: InstrWidth,
     ) -> Self {
         Self {
-            opcode: OpCode::X32AtomicLoad,
+            opcode: OpCode::BitClear,
             args: Args {
                 rd: rd.into(),
                 rs1: rs1.into(),
-                aq,
-                rl,
+                rs2: rs2.into(),
                 width,
                 ..Args::DEFAULT
             },
         }
     }
 
-    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicStore`].
-    pub(crate) fn new_x32_atomic_store(
-        rd: XRegister,
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::BitClearImmediate`].
+    pub(crate) fn new_bit_clear_immediate(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        imm: i64,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::BitClearImmediate,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                imm,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::BitExtract`].
+    pub(crate) fn new_bit_extract(
+        rd: NonZeroXRegister,
         rs1: XRegister,
         rs2: XRegister,
-        aq: bool,
-        rl: bool,
         width: InstrWidth,
     ) -> Self {
         Self {
-            opcode: OpCode::X32AtomicStore,
+            opcode: OpCode::BitExtract,
             args: Args {
                 rd: rd.into(),
                 rs1: rs1.into(),
                 rs2: rs2.into(),
-                aq,
-                rl,
                 width,
                 ..Args::DEFAULT
             },
         }
     }
 
-    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicLoad`].
-    pub(crate) fn new_x64_atomic_load(
-        rd: XRegister,
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::BitExtractImmediate`].
+    pub(crate) fn new_bit_extract_immediate(
+        rd: NonZeroXRegister,
         rs1: XRegister,
-        aq: bool,
-        rl: bool,
+        imm: i64,
         width: InstrWidth,
     ) -> Self {
         Self {
-            opcode: OpCode::X64AtomicLoad,
+            opcode: OpCode::BitExtractImmediate,
             args: Args {
                 rd: rd.into(),
                 rs1: rs1.into(),
-                aq,
-                rl,
+                imm,
                 width,
                 ..Args::DEFAULT
             },
         }
     }
 
-    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicStore`].
-    pub(crate) fn new_x64_atomic_store(
-        rd: XRegister,
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::BitInvert`].
+    pub(crate) fn new_bit_invert(
+        rd: NonZeroXRegister,
         rs1: XRegister,
         rs2: XRegister,
-        aq: bool,
-        rl: bool,
         width: InstrWidth,
     ) -> Self {
         Self {
-            opcode: OpCode::X64AtomicStore,
+            opcode: OpCode::BitInvert,
             args: Args {
                 rd: rd.into(),
                 rs1: rs1.into(),
                 rs2: rs2.into(),
-                aq,
-                rl,
                 width,
                 ..Args::DEFAULT
             },
         }
     }
 
-    pub(crate) fn new_x64_rem_signed(
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::BitInvertImmediate`].
+    pub(crate) fn new_bit_invert_immediate(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        imm: i64,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::BitInvertImmediate,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                imm,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::BitSet`].
+    pub(crate) fn new_bit_set(
         rd: NonZeroXRegister,
         rs1: XRegister,
         rs2: XRegister,
         width: InstrWidth,
     ) -> Self {
         Self {
-            opcode: OpCode::X64RemSigned,
+            opcode: OpCode::BitSet,
             args: Args {
                 rd: rd.into(),
                 rs1: rs1.into(),
@@ -1530,15 +2743,34 @@ impl Instruction {
         }
     }
 
-    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64RemUnsigned`].
-    pub(crate) fn new_x64_rem_unsigned(
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::BitSetImmediate`].
+    pub(crate) fn new_bit_set_immediate(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        imm: i64,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::BitSetImmediate,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                imm,
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Pack`].
+    pub(crate) fn new_pack(
         rd: NonZeroXRegister,
         rs1: XRegister,
         rs2: XRegister,
         width: InstrWidth,
     ) -> Self {
         Self {
-            opcode: OpCode::X64RemUnsigned,
+            opcode: OpCode::Pack,
             args: Args {
                 rd: rd.into(),
                 rs1: rs1.into(),
@@ -1549,15 +2781,15 @@ impl Instruction {
         }
     }
 
-    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32RemSigned`].
-    pub(crate) fn new_x32_rem_signed(
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::PackByte`].
+    pub(crate) fn new_pack_byte(
         rd: NonZeroXRegister,
         rs1: XRegister,
         rs2: XRegister,
         width: InstrWidth,
     ) -> Self {
         Self {
-            opcode: OpCode::X32RemSigned,
+            opcode: OpCode::PackByte,
             args: Args {
                 rd: rd.into(),
                 rs1: rs1.into(),
@@ -1568,15 +2800,15 @@ impl Instruction {
         }
     }
 
-    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32RemUnsigned`].
-    pub(crate) fn new_x32_rem_unsigned(
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32Pack`].
+    pub(crate) fn new_x32_pack(
         rd: NonZeroXRegister,
         rs1: XRegister,
         rs2: XRegister,
         width: InstrWidth,
     ) -> Self {
         Self {
-            opcode: OpCode::X32RemUnsigned,
+            opcode: OpCode::X32Pack,
             args: Args {
                 rd: rd.into(),
                 rs1: rs1.into(),
@@ -1587,46 +2819,159 @@ impl Instruction {
         }
     }
 
-    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X32AtomicSwap`].
-    pub(crate) fn new_x32_atomic_swap(
-        rd: XRegister,
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::ReverseBitsInBytes`].
+    pub(crate) fn new_reverse_bits_in_bytes(
+        rd: NonZeroXRegister,
+        rs1: XRegister,
+        width: InstrWidth,
+    ) -> Self {
+        Self {
+            opcode: OpCode::ReverseBitsInBytes,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Sha256Sum0`].
+    pub(crate) fn new_sha256_sum0(rd: NonZeroXRegister, rs1: XRegister, width: InstrWidth) -> Self {
+        Self {
+            opcode: OpCode::Sha256Sum0,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Sha256Sum1`].
+    pub(crate) fn new_sha256_sum1(rd: NonZeroXRegister, rs1: XRegister, width: InstrWidth) -> Self {
+        Self {
+            opcode: OpCode::Sha256Sum1,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Sha256Sig0`].
+    pub(crate) fn new_sha256_sig0(rd: NonZeroXRegister, rs1: XRegister, width: InstrWidth) -> Self {
+        Self {
+            opcode: OpCode::Sha256Sig0,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Sha256Sig1`].
+    pub(crate) fn new_sha256_sig1(rd: NonZeroXRegister, rs1: XRegister, width: InstrWidth) -> Self {
+        Self {
+            opcode: OpCode::Sha256Sig1,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Sha512Sum0`].
+    pub(crate) fn new_sha512_sum0(rd: NonZeroXRegister, rs1: XRegister, width: InstrWidth) -> Self {
+        Self {
+            opcode: OpCode::Sha512Sum0,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Sha512Sum1`].
+    pub(crate) fn new_sha512_sum1(rd: NonZeroXRegister, rs1: XRegister, width: InstrWidth) -> Self {
+        Self {
+            opcode: OpCode::Sha512Sum1,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Sha512Sig0`].
+    pub(crate) fn new_sha512_sig0(rd: NonZeroXRegister, rs1: XRegister, width: InstrWidth) -> Self {
+        Self {
+            opcode: OpCode::Sha512Sig0,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::Sha512Sig1`].
+    pub(crate) fn new_sha512_sig1(rd: NonZeroXRegister, rs1: XRegister, width: InstrWidth) -> Self {
+        Self {
+            opcode: OpCode::Sha512Sig1,
+            args: Args {
+                rd: rd.into(),
+                rs1: rs1.into(),
+                width,
+                ..Args::DEFAULT
+            },
+        }
+    }
+
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::ConditionalZeroEqualZero`].
+    pub(crate) fn new_conditional_zero_equal_zero(
+        rd: NonZeroXRegister,
         rs1: XRegister,
         rs2: XRegister,
-        aq: bool,
-        rl: bool,
         width: InstrWidth,
     ) -> Self {
         Self {
-            opcode: OpCode::X32AtomicSwap,
+            opcode: OpCode::ConditionalZeroEqualZero,
             args: Args {
                 rd: rd.into(),
                 rs1: rs1.into(),
                 rs2: rs2.into(),
-                aq,
-                rl,
                 width,
                 ..Args::DEFAULT
             },
         }
     }
 
-    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::X64AtomicSwap`].
-    pub(crate) fn new_x64_atomic_swap(
-        rd: XRegister,
+    /// Create a new [`Instruction`] with the appropriate [`super::ArgsShape`] for [`OpCode::ConditionalZeroNotEqualZero`].
+    pub(crate) fn new_conditional_zero_not_equal_zero(
+        rd: NonZeroXRegister,
         rs1: XRegister,
         rs2: XRegister,
-        aq: bool,
-        rl: bool,
         width: InstrWidth,
     ) -> Self {
         Self {
-            opcode: OpCode::X64AtomicSwap,
+            opcode: OpCode::ConditionalZeroNotEqualZero,
             args: Args {
                 rd: rd.into(),
                 rs1: rs1.into(),
                 rs2: rs2.into(),
-                aq,
-                rl,
                 width,
                 ..Args::DEFAULT
             },
@@ -2480,4 +3825,70 @@ impl Instruction {
             }
         }
     }
+
+    /// Convert [`InstrCacheable::Rol`] according to whether registers are non-zero.
+    ///
+    /// [`InstrCacheable::Rol`]: crate::parser::instruction::InstrCacheable::Rol
+    pub(super) fn from_ic_rol(args: &NonZeroRdRTypeArgs) -> Instruction {
+        use XRegisterParsed as X;
+        match (split_x0(args.rs1), split_x0(args.rs2)) {
+            // Rotating 0 by any amount is 0.
+            (X::X0, _) => Instruction::new_li(args.rd, 0, InstrWidth::Uncompressed),
+            // Rotating by 0 and storing in rd is equivalent to moving the value to rd.
+            (X::NonZero(rs1), X::X0) => Instruction::new_mv(args.rd, rs1, InstrWidth::Uncompressed),
+            (X::NonZero(rs1), X::NonZero(rs2)) => {
+                Instruction::new_rotate_left(args.rd, rs1, rs2, InstrWidth::Uncompressed)
+            }
+        }
+    }
+
+    /// Convert [`InstrCacheable::Ror`] according to whether registers are non-zero.
+    ///
+    /// [`InstrCacheable::Ror`]: crate::parser::instruction::InstrCacheable::Ror
+    pub(super) fn from_ic_ror(args: &NonZeroRdRTypeArgs) -> Instruction {
+        use XRegisterParsed as X;
+        match (split_x0(args.rs1), split_x0(args.rs2)) {
+            // Rotating 0 by any amount is 0.
+            (X::X0, _) => Instruction::new_li(args.rd, 0, InstrWidth::Uncompressed),
+            // Rotating by 0 and storing in rd is equivalent to moving the value to rd.
+            (X::NonZero(rs1), X::X0) => Instruction::new_mv(args.rd, rs1, InstrWidth::Uncompressed),
+            (X::NonZero(rs1), X::NonZero(rs2)) => {
+                Instruction::new_rotate_right(args.rd, rs1, rs2, InstrWidth::Uncompressed)
+            }
+        }
+    }
+
+    /// Convert [`InstrCacheable::Rori`] according to whether register is non-zero.
+    ///
+    /// [`InstrCacheable::Rori`]: crate::parser::instruction::InstrCacheable::Rori
+    pub(super) fn from_ic_rori(args: &NonZeroRdITypeArgs) -> Instruction {
+        use XRegisterParsed as X;
+        match split_x0(args.rs1) {
+            // Rotating 0 by any amount is 0.
+            X::X0 => Instruction::new_li(args.rd, 0, InstrWidth::Uncompressed),
+            X::NonZero(rs1) => Instruction::new_rotate_right_immediate(
+                args.rd,
+                rs1,
+                args.imm,
+                InstrWidth::Uncompressed,
+            ),
+        }
+    }
+
+    /// Convert [`InstrCacheable::Roriw`] according to whether register is non-zero.
+    ///
+    /// [`InstrCacheable::Roriw`]: crate::parser::instruction::InstrCacheable::Roriw
+    pub(super) fn from_ic_roriw(args: &NonZeroRdITypeArgs) -> Instruction {
+        use XRegisterParsed as X;
+        match split_x0(args.rs1) {
+            // Rotating 0 by any amount is 0.
+            X::X0 => Instruction::new_li(args.rd, 0, InstrWidth::Uncompressed),
+            X::NonZero(rs1) => Instruction::new_x32_rotate_right_immediate(
+                args.rd,
+                rs1,
+                args.imm,
+                InstrWidth::Uncompressed,
+            ),
+        }
+    }
 }
@@ -17,6 +17,7 @@ use super::NonZeroXRegister;
 use super::OpCode;
 use super::XRegister;
 use crate::default::ConstDefault;
+use crate::machine_state::vector::VRegister;
 use crate::parser::instruction::InstrWidth;
 
 #[derive(Error, Debug)]
@@ -28,6 +29,8 @@ pub enum TaggedError {
     UnwrapF(TaggedRegister),
     #[error("Expected NonZeroXRegister got {0:?}")]
     UnwrapNZX(TaggedRegister),
+    #[error("Expected VRegister got {0:?}")]
+    UnwrapV(TaggedRegister),
 }
 
 impl TryFrom<TaggedInstruction> for Instruction {
@@ -119,6 +122,42 @@ impl TryFrom<TaggedInstruction> for Instruction {
                 rl: value.args.rl,
                 width: value.args.width,
             },
+            ArgsShape::VSrcVDest => Args {
+                rd: value.args.rd.unwrap_v()?.into(),
+                rs1: value.args.rs1.unwrap_v()?.into(),
+                rs2: value.args.rs2.unwrap_v()?.into(),
+                imm: value.args.imm,
+                csr: value.args.csr,
+                rs3f: value.args.rs3f,
+                rm: value.args.rm,
+                aq: value.args.aq,
+                rl: value.args.rl,
+                width: value.args.width,
+            },
+            ArgsShape::XSrcVDest => Args {
+                rd: value.args.rd.unwrap_v()?.into(),
+                rs1: value.args.rs1.unwrap_x()?.into(),
+                rs2: value.args.rs2.unwrap_v()?.into(),
+                imm: value.args.imm,
+                csr: value.args.csr,
+                rs3f: value.args.rs3f,
+                rm: value.args.rm,
+                aq: value.args.aq,
+                rl: value.args.rl,
+                width: value.args.width,
+            },
+            ArgsShape::VSrcXDest => Args {
+                rd: value.args.rd.unwrap_x()?.into(),
+                rs1: value.args.rs1.unwrap_v()?.into(),
+                rs2: value.args.rs2.unwrap_v()?.into(),
+                imm: value.args.imm,
+                csr: value.args.csr,
+                rs3f: value.args.rs3f,
+                rm: value.args.rm,
+                aq: value.args.aq,
+                rl: value.args.rl,
+                width: value.args.width,
+            },
         };
         Ok(Instruction {
             opcode: value.opcode,
@@ -225,6 +264,42 @@ impl From<Instruction> for TaggedInstruction {
                 rl: value.args.rl,
                 width: value.args.width,
             },
+            ArgsShape::VSrcVDest => TaggedArgs {
+                rd: unsafe { value.args.rd.v.into() },
+                rs1: unsafe { value.args.rs1.v.into() },
+                rs2: unsafe { value.args.rs2.v.into() },
+                imm: value.args.imm,
+                csr: value.args.csr,
+                rs3f: value.args.rs3f,
+                rm: value.args.rm,
+                aq: value.args.aq,
+                rl: value.args.rl,
+                width: value.args.width,
+            },
+            ArgsShape::XSrcVDest => TaggedArgs {
+                rd: unsafe { value.args.rd.v.into() },
+                rs1: unsafe { value.args.rs1.x.into() },
+                rs2: unsafe { value.args.rs2.v.into() },
+                imm: value.args.imm,
+                csr: value.args.csr,
+                rs3f: value.args.rs3f,
+                rm: value.args.rm,
+                aq: value.args.aq,
+                rl: value.args.rl,
+                width: value.args.width,
+            },
+            ArgsShape::VSrcXDest => TaggedArgs {
+                rd: unsafe { value.args.rd.x.into() },
+                rs1: unsafe { value.args.rs1.v.into() },
+                rs2: unsafe { value.args.rs2.v.into() },
+                imm: value.args.imm,
+                csr: value.args.csr,
+                rs3f: value.args.rs3f,
+                rm: value.args.rm,
+                aq: value.args.aq,
+                rl: value.args.rl,
+                width: value.args.width,
+            },
         };
         TaggedInstruction {
             opcode: value.opcode,
@@ -233,12 +308,13 @@ impl From<Instruction> for TaggedInstruction {
     }
 }
 
-/// Integer or floating-point register.
+/// Integer, floating-point or vector register.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TaggedRegister {
     X(XRegister),
     F(FRegister),
     NonZeroX(NonZeroXRegister),
+    V(VRegister),
 }
 
 impl From<XRegister> for TaggedRegister {
@@ -259,6 +335,12 @@ impl From<NonZeroXRegister> for TaggedRegister {
     }
 }
 
+impl From<VRegister> for TaggedRegister {
+    fn from(value: VRegister) -> Self {
+        TaggedRegister::V(value)
+    }
+}
+
 impl TaggedRegister {
     fn unwrap_x(self) -> Result<XRegister, TaggedError> {
         match self {
@@ -280,6 +362,13 @@ impl TaggedRegister {
             _ => Err(TaggedError::UnwrapNZX(self)),
         }
     }
+
+    fn unwrap_v(self) -> Result<VRegister, TaggedError> {
+        match self {
+            Self::V(v) => Ok(v),
+            _ => Err(TaggedError::UnwrapV(self)),
+        }
+    }
 }
 
 /// Intermediate object for instantiating [Instruction] objects and
@@ -331,6 +420,12 @@ pub enum ArgsShape {
     NZXSrcNZXDest,
     // rd => NZX | rs1, rs2 => X
     XSrcNZXDest,
+    // rd, rs1, rs2 => V
+    VSrcVDest,
+    // rd, rs2 => V | rs1 => X
+    XSrcVDest,
+    // rd => X | rs1, rs2 => V
+    VSrcXDest,
 }
 
 /// This function maps each opcode to the corresponding ArgsShape that the opcode uses
@@ -339,26 +434,72 @@ pub enum ArgsShape {
 pub fn opcode_to_argsshape(opcode: &OpCode) -> ArgsShape {
     use OpCode::*;
     match opcode {
-        X8LoadSigned | X16LoadSigned | X32LoadSigned | X8LoadUnsigned | X16LoadUnsigned
-        | X32LoadUnsigned | X64LoadSigned | X8Store | X16Store | X32Store | X64Store
-        | X32AtomicLoad | X32AtomicStore | X32AtomicSwap | Amoaddw | Amoxorw | Amoandw | Amoorw
-        | Amominw | Amomaxw | Amominuw | Amomaxuw | X64AtomicLoad | X64AtomicStore
-        | X64AtomicSwap | X64AtomicAdd | Amoxord | Amoandd | Amoord | X64AtomicMinSigned
-        | Amomaxd | Amominud | Amomaxud | X32Mul | Csrrw | Csrrs | Csrrc | Csrrwi | Csrrsi
-        | Csrrci => ArgsShape::XSrcXDest,
+        X8LoadSigned
+        | X16LoadSigned
+        | X32LoadSigned
+        | X8LoadUnsigned
+        | X16LoadUnsigned
+        | X32LoadUnsigned
+        | X64LoadSigned
+        | X8Store
+        | X16Store
+        | X32Store
+        | X64Store
+        | X32AtomicLoad
+        | X32AtomicStore
+        | X32AtomicSwap
+        | X32AtomicAdd
+        | X32AtomicXor
+        | X32AtomicAnd
+        | X32AtomicOr
+        | X32AtomicMinSigned
+        | X32AtomicMaxSigned
+        | X32AtomicMinUnsigned
+        | X32AtomicMaxUnsigned
+        | X64AtomicLoad
+        | X64AtomicStore
+        | X64AtomicSwap
+        | X64AtomicAdd
+        | X64AtomicXor
+        | X64AtomicAnd
+        | X64AtomicOr
+        | X64AtomicMinSigned
+        | X64AtomicMaxSigned
+        | X64AtomicMinUnsigned
+        | X64AtomicMaxUnsigned
+        | X32AtomicCompareAndSwap
+        | X64AtomicCompareAndSwap
+        | X128AtomicCompareAndSwap
+        | X32Mul
+        | Csrrw
+        | Csrrs
+        | Csrrc
+        | Csrrwi
+        | Csrrsi
+        | Csrrci
+        | Vsetvli
+        | Vsetivli
+        | Vsetvl
+        | CboZero => ArgsShape::XSrcXDest,
 
         Fadds | Fsubs | Fmuls | Fdivs | Fsqrts | Fmins | Fmaxs | Fsgnjs | Fsgnjns | Fsgnjxs
         | Fmadds | Fmsubs | Fnmsubs | Fnmadds | Faddd | Fsubd | Fmuld | Fdivd | Fsqrtd | Fmind
         | Fmaxd | Fsgnjd | Fsgnjnd | Fsgnjxd | Fcvtsd | Fcvtds | Fmaddd | Fmsubd | Fnmsubd
-        | Fnmaddd => ArgsShape::FSrcFDest,
+        | Fnmaddd | Faddh | Fsubh | Fmulh | Fdivh | Fsqrth | Fminh | Fmaxh | Fmaddh | Fmsubh
+        | Fnmsubh | Fnmaddh | Fcvtsh | Fcvths | Fcvtdh | Fcvthd | Fsgnjh | Fsgnjnh | Fsgnjxh => {
+            ArgsShape::FSrcFDest
+        }
 
         Flw | Fld | FmvWX | Fcvtsw | Fcvtswu | Fcvtsl | Fcvtslu | FmvDX | Fcvtdw | Fcvtdwu
-        | Fcvtdl | Fcvtdlu | CFld | CFldsp => ArgsShape::XSrcFDest,
+        | Fcvtdl | Fcvtdlu | CFld | CFldsp | Flh | Fcvthw | Fcvthwu | Fcvthl | Fcvthlu | FmvHX => {
+            ArgsShape::XSrcFDest
+        }
 
         Feqs | Fles | Flts | Feqd | Fled | Fltd | FclassS | FmvXW | Fcvtws | Fcvtwus | Fcvtls
-        | Fcvtlus | FclassD | FmvXD | Fcvtwd | Fcvtwud | Fcvtld | Fcvtlud => ArgsShape::FSrcXDest,
+        | Fcvtlus | FclassD | FmvXD | Fcvtwd | Fcvtwud | Fcvtld | Fcvtlud | FclassH | Feqh
+        | Fleh | Flth | Fcvtwh | Fcvtwuh | Fcvtlh | Fcvtluh | FmvXH => ArgsShape::FSrcXDest,
 
-        Fsw | Fsd | CFsd | CFsdsp => ArgsShape::XSrcFSrc,
+        Fsw | Fsd | CFsd | CFsdsp | Fsh => ArgsShape::XSrcFSrc,
 
         Addi
         | Andi
@@ -407,6 +548,10 @@ pub fn opcode_to_argsshape(opcode: &OpCode) -> ArgsShape {
         | JalrImm
         | Nop
         | ECall
+        | RotateLeft
+        | RotateRight
+        | RotateRightImmediate
+        | X32RotateRightImmediate
         | Unknown => ArgsShape::NZXSrcNZXDest,
 
         AddWordImmediate
@@ -427,7 +572,67 @@ pub fn opcode_to_argsshape(opcode: &OpCode) -> ArgsShape {
         | X64DivSigned
         | X64DivUnsigned
         | X32DivSigned
-        | X32DivUnsigned => ArgsShape::XSrcNZXDest,
+        | X32DivUnsigned
+        | Shift1Add
+        | Shift2Add
+        | Shift3Add
+        | AddUnsignedWord
+        | Shift1AddUnsignedWord
+        | Shift2AddUnsignedWord
+        | Shift3AddUnsignedWord
+        | ShiftLeftImmediateUnsignedWord
+        | AndNot
+        | OrNot
+        | XorNot
+        | CountLeadingZeros
+        | CountTrailingZeros
+        | CountSetBits
+        | X32CountLeadingZeros
+        | X32CountTrailingZeros
+        | X32CountSetBits
+        | MaxSigned
+        | MaxUnsigned
+        | MinSigned
+        | MinUnsigned
+        | SignExtendByte
+        | SignExtendHalf
+        | ZeroExtendHalf
+        | X32RotateLeft
+        | X32RotateRight
+        | OrCombineBytes
+        | ByteReverse
+        | BitClear
+        | BitClearImmediate
+        | BitExtract
+        | BitExtractImmediate
+        | BitInvert
+        | BitInvertImmediate
+        | BitSet
+        | BitSetImmediate
+        | Pack
+        | PackByte
+        | X32Pack
+        | ReverseBitsInBytes
+        | Sha256Sum0
+        | Sha256Sum1
+        | Sha256Sig0
+        | Sha256Sig1
+        | Sha512Sum0
+        | Sha512Sum1
+        | Sha512Sig0
+        | Sha512Sig1
+        | ConditionalZeroEqualZero
+        | ConditionalZeroNotEqualZero => ArgsShape::XSrcNZXDest,
+
+        VmvXS => ArgsShape::VSrcXDest,
+
+        Vle8 | Vle16 | Vle32 | Vle64 | Vse8 | Vse16 | Vse32 | Vse64 | VaddVX | VsubVX | VrsubVX
+        | VandVX | VorVX | VxorVX | VsllVX | VsrlVX | VsraVX | VminuVX | VminVX | VmaxuVX
+        | VmaxVX | VmulVX | VmvVX | VmvSX => ArgsShape::XSrcVDest,
+
+        VaddVV | VaddVI | VsubVV | VrsubVI | VandVV | VandVI | VorVV | VorVI | VxorVV | VxorVI
+        | VsllVV | VsllVI | VsrlVV | VsrlVI | VsraVV | VsraVI | VminuVV | VminVV | VmaxuVV
+        | VmaxVV | VmulVV | VmvVV | VmvVI => ArgsShape::VSrcVDest,
     }
 }
 
@@ -7,8 +7,12 @@ mod config;
 mod protection;
 mod state;
 
+use std::fmt;
 use std::num::NonZeroU64;
+use std::str::FromStr;
 
+use serde::Deserialize;
+use serde::Serialize;
 use tezos_smart_rollup_constants::riscv::SbiError;
 
 use super::registers::XValue;
@@ -52,6 +56,16 @@ pub const PAGE_SIZE: NonZeroU64 = {
     }
 };
 
+/// Size of a cache block, which is the unit of memory zeroed by `cbo.zero`
+///
+/// Guests discover it through the `riscv,cboz-block-size` property of the device tree.
+/// Zeroing a page takes 16 `cbo.zero` steps, while keeping the memory touched by a single
+/// step small enough for its proof. It must divide [`PAGE_SIZE`], so that a cache block
+/// never straddles two pages.
+pub const CACHE_BLOCK_SIZE: u64 = 256;
+
+const _: () = assert!(PAGE_SIZE.get() % CACHE_BLOCK_SIZE == 0);
+
 /// Memory address
 pub type Address = XValue;
 
@@ -231,7 +245,7 @@ pub trait Memory<M: ManagerBase>: NewState<M> + Sized {
     /// Zero-out all memory.
     fn reset(&mut self)
     where
-        M: ManagerWrite;
+        M: ManagerReadWrite;
 
     /// Protect the pages that belong to the given address range.
     fn protect_pages(
@@ -287,11 +301,40 @@ pub trait Memory<M: ManagerBase>: NewState<M> + Sized {
     }
 }
 
+/// Layout of a memory instance backed by the [`Owned`] manager.
+///
+/// JIT-compiled code uses this to access memory directly, rather than calling out to
+/// [`Memory::read`] and [`Memory::write`].
+///
+/// [`Owned`]: crate::state_backend::owned_backend::Owned
+#[derive(Debug, Clone, Copy)]
+pub struct OwnedMemoryLayout {
+    /// Offset of the pointer to the memory contents
+    pub data: usize,
+
+    /// Offset of the pointer to the read permissions, which are one byte per page
+    pub readable_pages: usize,
+
+    /// Offset of the pointer to the write permissions, which are one byte per page
+    pub writable_pages: usize,
+
+    /// Offset of the pointer to the touched pages of the memory contents, which are one byte per
+    /// page. Pages written to must be marked as touched, see [`DynRegion`].
+    ///
+    /// [`DynRegion`]: crate::state_backend::owned_backend::DynRegion
+    pub touched_pages: usize,
+}
+
 /// Memory configuration
 pub trait MemoryConfig: 'static {
     /// Number of bytes in the memory
     const TOTAL_BYTES: usize;
 
+    /// Layout of the memory instance, when backed by the [`Owned`] manager
+    ///
+    /// [`Owned`]: crate::state_backend::owned_backend::Owned
+    const OWNED_LAYOUT: OwnedMemoryLayout;
+
     /// Layout for memory instance's state
     type Layout: CommitmentLayout + ProofLayout;
 
@@ -317,3 +360,131 @@ pub use config::M4G;
 pub use config::M4K;
 pub use config::M8K;
 pub use config::M64M;
+
+/// Memory configurations which can be selected at runtime
+///
+/// Use [`dispatch_memory_size!`] to obtain the [`MemoryConfig`] for a size.
+///
+/// [`dispatch_memory_size!`]: crate::dispatch_memory_size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MemorySize {
+    /// 4 KiB, see [`M4K`]
+    M4K,
+
+    /// 8 KiB, see [`M8K`]
+    M8K,
+
+    /// 1 MiB, see [`M1M`]
+    M1M,
+
+    /// 64 MiB, see [`M64M`]
+    M64M,
+
+    /// 1 GiB, see [`M1G`]
+    M1G,
+
+    /// 4 GiB, see [`M4G`]
+    M4G,
+}
+
+impl MemorySize {
+    /// All memory sizes, from smallest to largest
+    pub const ALL: [Self; 6] = [
+        Self::M4K,
+        Self::M8K,
+        Self::M1M,
+        Self::M64M,
+        Self::M1G,
+        Self::M4G,
+    ];
+
+    /// Number of bytes in the memory
+    pub const fn total_bytes(self) -> usize {
+        crate::dispatch_memory_size!(self, MC => <MC as MemoryConfig>::TOTAL_BYTES)
+    }
+
+    /// Name of the memory size, as displayed and parsed
+    const fn name(self) -> &'static str {
+        match self {
+            Self::M4K => "4KiB",
+            Self::M8K => "8KiB",
+            Self::M1M => "1MiB",
+            Self::M64M => "64MiB",
+            Self::M1G => "1GiB",
+            Self::M4G => "4GiB",
+        }
+    }
+}
+
+impl fmt::Display for MemorySize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A string did not name any of the supported [`MemorySize`]s
+#[derive(Clone, PartialEq, Eq, Debug, thiserror::Error)]
+#[error("Unknown memory size {0:?}, expected one of 4KiB, 8KiB, 1MiB, 64MiB, 1GiB or 4GiB")]
+pub struct UnknownMemorySize(String);
+
+impl FromStr for MemorySize {
+    type Err = UnknownMemorySize;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|size| size.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| UnknownMemorySize(s.to_owned()))
+    }
+}
+
+/// Evaluate an expression for the [`MemoryConfig`] of a [`MemorySize`] known only at runtime.
+///
+/// The expression is instantiated once per memory size, with the given identifier naming that
+/// size's memory configuration.
+///
+/// ```
+/// use octez_riscv::dispatch_memory_size;
+/// use octez_riscv::machine_state::memory::MemoryConfig;
+/// use octez_riscv::machine_state::memory::MemorySize;
+///
+/// let size: MemorySize = "64MiB".parse().unwrap();
+/// let bytes = dispatch_memory_size!(size, MC => <MC as MemoryConfig>::TOTAL_BYTES);
+///
+/// assert_eq!(bytes, 64 * 1024 * 1024);
+/// assert_eq!(size.to_string(), "64MiB");
+/// ```
+///
+/// [`MemoryConfig`]: crate::machine_state::memory::MemoryConfig
+/// [`MemorySize`]: crate::machine_state::memory::MemorySize
+#[macro_export]
+macro_rules! dispatch_memory_size {
+    ($size:expr, $mc:ident => $body:expr) => {
+        match $size {
+            $crate::machine_state::memory::MemorySize::M4K => {
+                type $mc = $crate::machine_state::memory::M4K;
+                $body
+            }
+            $crate::machine_state::memory::MemorySize::M8K => {
+                type $mc = $crate::machine_state::memory::M8K;
+                $body
+            }
+            $crate::machine_state::memory::MemorySize::M1M => {
+                type $mc = $crate::machine_state::memory::M1M;
+                $body
+            }
+            $crate::machine_state::memory::MemorySize::M64M => {
+                type $mc = $crate::machine_state::memory::M64M;
+                $body
+            }
+            $crate::machine_state::memory::MemorySize::M1G => {
+                type $mc = $crate::machine_state::memory::M1G;
+                $body
+            }
+            $crate::machine_state::memory::MemorySize::M4G => {
+                type $mc = $crate::machine_state::memory::M4G;
+                $body
+            }
+        }
+    };
+}
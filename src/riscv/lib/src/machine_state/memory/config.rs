@@ -2,6 +2,8 @@
 //
 // SPDX-License-Identifier: MIT
 
+use std::mem;
+
 use super::buddy::BuddyLayout;
 use super::buddy::BuddyLayoutProxy;
 use super::protection::PagePermissions;
@@ -9,12 +11,16 @@ use super::protection::PagePermissionsLayout;
 use super::state::MemoryImpl;
 use crate::state::NewState;
 use crate::state_backend::AllocatedOf;
+use crate::state_backend::Cell;
 use crate::state_backend::DynArray;
 use crate::state_backend::DynCells;
 use crate::state_backend::FnManager;
 use crate::state_backend::ManagerAlloc;
 use crate::state_backend::ManagerBase;
 use crate::state_backend::Ref;
+use crate::state_backend::owned_backend::DynRegion;
+use crate::state_backend::owned_backend::Owned;
+use crate::state_backend::owned_backend::TOUCHED_PAGE_SIZE;
 
 /// State layout for the memory component
 pub struct MemoryConfig<const PAGES: usize, const TOTAL_BYTES: usize>;
@@ -46,6 +52,23 @@ where
 {
     const TOTAL_BYTES: usize = TOTAL_BYTES;
 
+    const OWNED_LAYOUT: super::OwnedMemoryLayout = {
+        // Page permissions are accessed directly as one byte per page
+        assert!(mem::size_of::<Cell<bool, Owned>>() == 1);
+
+        // Touched pages of the memory contents are marked per memory page
+        assert!(TOUCHED_PAGE_SIZE as u64 == super::PAGE_SIZE.get());
+
+        let data = mem::offset_of!(Self::State<Owned>, data);
+
+        super::OwnedMemoryLayout {
+            data: data + DynRegion::<TOTAL_BYTES>::DATA_OFFSET,
+            readable_pages: mem::offset_of!(Self::State<Owned>, readable_pages),
+            writable_pages: mem::offset_of!(Self::State<Owned>, writable_pages),
+            touched_pages: data + DynRegion::<TOTAL_BYTES>::TOUCHED_OFFSET,
+        }
+    };
+
     type Layout = (
         DynArray<TOTAL_BYTES>,
         PagePermissionsLayout<PAGES>,
@@ -208,23 +208,35 @@ where
 
     fn reset(&mut self)
     where
-        M: ManagerWrite,
+        M: ManagerReadWrite,
     {
         const SIZE_OF_U64: usize = mem::size_of::<u64>();
 
-        let mut address = 0;
-        let mut outstanding = TOTAL_BYTES;
+        let page_size = PAGE_SIZE.get() as usize;
 
-        // Write 64-bit chunks
-        while outstanding >= SIZE_OF_U64 {
-            self.data.write(address, 0u64);
-            address += SIZE_OF_U64;
-            outstanding -= SIZE_OF_U64;
-        }
+        for page in (0..TOTAL_BYTES).step_by(page_size) {
+            let length = page_size.min(TOTAL_BYTES - page);
+
+            // Pages that have never been written to are already zero. Skipping them keeps
+            // sparse memory from being populated.
+            if self.data.is_untouched(page, length) {
+                continue;
+            }
+
+            let mut address = page;
+            let mut outstanding = length;
 
-        // Write remaining bytes
-        for i in 0..outstanding {
-            self.data.write(address.saturating_add(i), 0u8);
+            // Write 64-bit chunks
+            while outstanding >= SIZE_OF_U64 {
+                self.data.write(address, 0u64);
+                address += SIZE_OF_U64;
+                outstanding -= SIZE_OF_U64;
+            }
+
+            // Write remaining bytes
+            for i in 0..outstanding {
+                self.data.write(address.saturating_add(i), 0u8);
+            }
         }
     }
 
@@ -170,6 +170,21 @@ impl XRegister {
     pub fn is_zero(self) -> bool {
         self == x0
     }
+
+    /// The register holding the upper half of the register pair starting at `self`,
+    /// as used by quad-word instructions such as `AMOCAS.Q`.
+    ///
+    /// The pair starting at `x0` is made of `x0` twice, so that both halves read
+    /// as zero and writes to either are discarded.
+    #[inline]
+    pub fn pair_high(self) -> XRegister {
+        if self.is_zero() {
+            return x0;
+        }
+
+        let next = (self as u8).wrapping_add(2) & 0b1_1111;
+        parse_xregister(u5::new(next))
+    }
 }
 
 impl From<NonZeroXRegister> for XRegister {
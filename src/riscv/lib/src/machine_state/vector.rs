@@ -0,0 +1,389 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Vector register state for the Zve64x extension
+//!
+//! Only the integer subset of the vector extension is supported, with a fixed
+//! vector register length of [`VLEN`] bits and a maximum element width of [`ELEN`] bits.
+//!
+//! Chapter 31 - Unprivileged spec
+
+use std::fmt;
+
+use arbitrary_int::u5;
+
+use crate::machine_state::backend;
+use crate::state::NewState;
+
+/// Length of a vector register in bits
+pub const VLEN: usize = 128;
+
+/// Length of a vector register in bytes
+pub const VLENB: usize = VLEN / 8;
+
+/// Maximum size of a vector element in bits
+pub const ELEN: usize = 64;
+
+/// The `vill` bit of `vtype`, set when the vector unit holds an illegal configuration
+pub const VTYPE_VILL: u64 = 1 << 63;
+
+/// Bits of `vtype` which are not reserved: `vlmul`, `vsew`, `vta` & `vma`
+const VTYPE_FIELDS_MASK: u64 = 0xFF;
+
+/// Number of 64-bit words backing a single vector register
+const WORDS_PER_VREGISTER: usize = VLENB / size_of::<u64>();
+
+/// Number of 64-bit words backing the whole vector register file
+const VREGISTERS_WORDS: usize = 32 * WORDS_PER_VREGISTER;
+
+/// Vector register index
+#[expect(non_camel_case_types, reason = "Consistent with RISC-V spec")]
+#[repr(u8)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    strum::EnumIter,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum VRegister {
+    v0 = 0,
+    v1,
+    v2,
+    v3,
+    v4,
+    v5,
+    v6,
+    v7,
+    v8,
+    v9,
+    v10,
+    v11,
+    v12,
+    v13,
+    v14,
+    v15,
+    v16,
+    v17,
+    v18,
+    v19,
+    v20,
+    v21,
+    v22,
+    v23,
+    v24,
+    v25,
+    v26,
+    v27,
+    v28,
+    v29,
+    v30,
+    v31,
+}
+
+#[inline(always)]
+pub const fn parse_vregister(r: u5) -> VRegister {
+    let r = r.value();
+
+    // SAFETY: the possible values of u5 are known to correspond to
+    // the possible values of the underlying representation of VRegister.
+    unsafe { std::mem::transmute(r) }
+}
+
+impl fmt::Display for VRegister {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{}", *self as u8)
+    }
+}
+
+/// Width of a vector element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElementWidth {
+    E8 = 0,
+    E16,
+    E32,
+    E64,
+}
+
+impl ElementWidth {
+    /// Base-2 logarithm of the element width in bytes.
+    #[inline(always)]
+    pub const fn log2_bytes(self) -> usize {
+        self as usize
+    }
+
+    /// Element width in bytes.
+    #[inline(always)]
+    pub const fn bytes(self) -> usize {
+        1 << self.log2_bytes()
+    }
+
+    /// Element width in bits.
+    #[inline(always)]
+    pub const fn bits(self) -> usize {
+        self.bytes() * 8
+    }
+
+    /// Mask selecting the bits of a 64-bit value that fit into an element.
+    #[inline(always)]
+    pub const fn mask(self) -> u64 {
+        u64::MAX >> (64 - self.bits())
+    }
+
+    /// Sign-extend the lowest [`Self::bits`] bits of `val` to 64 bits.
+    #[inline(always)]
+    pub const fn sign_extend(self, val: u64) -> u64 {
+        let shift = 64 - self.bits();
+        (((val << shift) as i64) >> shift) as u64
+    }
+
+    /// Decode the 3-bit `vsew` field of `vtype`.
+    const fn from_vsew(vsew: u64) -> Option<Self> {
+        match vsew {
+            0b000 => Some(Self::E8),
+            0b001 => Some(Self::E16),
+            0b010 => Some(Self::E32),
+            0b011 => Some(Self::E64),
+            _ => None,
+        }
+    }
+}
+
+/// Legal vector configuration, as held by the `vtype` CSR
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VType {
+    /// Selected element width, SEW
+    pub sew: ElementWidth,
+
+    /// Base-2 logarithm of the register group multiplier, LMUL, ranging from -3 to 3
+    pub lmul_log2: isize,
+
+    /// Raw value of the `vtype` CSR
+    pub raw: u64,
+}
+
+impl VType {
+    /// Decode a `vtype` value, returning `None` if the configuration is not supported.
+    pub const fn decode(raw: u64) -> Option<Self> {
+        if raw & !VTYPE_FIELDS_MASK != 0 {
+            return None;
+        }
+
+        let Some(sew) = ElementWidth::from_vsew((raw >> 3) & 0b111) else {
+            return None;
+        };
+
+        let lmul_log2 = match raw & 0b111 {
+            0b000 => 0,
+            0b001 => 1,
+            0b010 => 2,
+            0b011 => 3,
+            0b101 => -3,
+            0b110 => -2,
+            0b111 => -1,
+            _ => return None,
+        };
+
+        // Fractional LMUL must leave room for at least one element of SEW <= ELEN * LMUL
+        if lmul_log2 < 0 && sew.bits() > ELEN >> -lmul_log2 {
+            return None;
+        }
+
+        Some(Self {
+            sew,
+            lmul_log2,
+            raw,
+        })
+    }
+
+    /// Maximum number of elements that an instruction can operate on: VLEN / SEW * LMUL
+    #[inline(always)]
+    pub const fn vlmax(self) -> usize {
+        Self::group_elements(self.sew, self.lmul_log2)
+    }
+
+    /// Compute the effective group multiplier, EMUL, of an operand with element width
+    /// `eew`, returning its base-2 logarithm if it is supported.
+    #[inline(always)]
+    pub const fn emul_log2(self, eew: ElementWidth) -> Option<isize> {
+        let emul_log2 = self.lmul_log2 + eew.log2_bytes() as isize - self.sew.log2_bytes() as isize;
+        if -3 <= emul_log2 && emul_log2 <= 3 {
+            Some(emul_log2)
+        } else {
+            None
+        }
+    }
+
+    /// Number of elements of width `eew` in a register group of multiplier `2^lmul_log2`.
+    #[inline(always)]
+    const fn group_elements(eew: ElementWidth, lmul_log2: isize) -> usize {
+        let elements = VLEN / eew.bits();
+        if lmul_log2 < 0 {
+            elements >> -lmul_log2
+        } else {
+            elements << lmul_log2
+        }
+    }
+}
+
+/// Check that `reg` is the first register of a group of multiplier `2^lmul_log2`.
+///
+/// Fractional groups occupy a single register, so any register is valid for them.
+#[inline(always)]
+pub const fn is_group_aligned(reg: VRegister, lmul_log2: isize) -> bool {
+    lmul_log2 <= 0 || (reg as usize) % (1 << lmul_log2) == 0
+}
+
+/// Layout for [VRegisters]
+pub type VRegistersLayout = backend::Array<u64, VREGISTERS_WORDS>;
+
+/// Vector registers
+///
+/// The register file is stored as little-endian 64-bit words, so that element `i` of width
+/// `SEW` of a register group starting at `vN` occupies bytes `N * VLENB + i * SEW / 8` onwards.
+pub struct VRegisters<M: backend::ManagerBase> {
+    registers: backend::Cells<u64, VREGISTERS_WORDS, M>,
+}
+
+impl<M: backend::ManagerBase> VRegisters<M> {
+    /// Bind the vector register space to the allocated space.
+    pub fn bind(space: backend::AllocatedOf<VRegistersLayout, M>) -> Self {
+        VRegisters { registers: space }
+    }
+
+    /// Given a manager morphism `f : &M -> N`, return the layout's allocated structure containing
+    /// the constituents of `N` that were produced from the constituents of `&M`.
+    pub fn struct_ref<'a, F: backend::FnManager<backend::Ref<'a, M>>>(
+        &'a self,
+    ) -> backend::AllocatedOf<VRegistersLayout, F::Output> {
+        self.registers.struct_ref::<F>()
+    }
+
+    /// Reset the vector registers.
+    pub fn reset(&mut self)
+    where
+        M: backend::ManagerWrite,
+    {
+        for i in 0..VREGISTERS_WORDS {
+            self.registers.write(i, 0);
+        }
+    }
+
+    /// Locate the word holding element `index` of width `eew` of the register group starting
+    /// at `reg`, and the bit offset of the element within that word.
+    #[inline(always)]
+    fn locate(reg: VRegister, eew: ElementWidth, index: usize) -> (usize, usize) {
+        let byte_offset = reg as usize * VLENB + (index << eew.log2_bytes());
+        debug_assert!(byte_offset < VREGISTERS_WORDS * size_of::<u64>());
+
+        let word = byte_offset / size_of::<u64>();
+        let shift = (byte_offset % size_of::<u64>()) * 8;
+        (word, shift)
+    }
+
+    /// Read element `index` of width `eew` from the register group starting at `reg`,
+    /// zero-extended to 64 bits.
+    #[inline(always)]
+    pub fn read(&self, reg: VRegister, eew: ElementWidth, index: usize) -> u64
+    where
+        M: backend::ManagerRead,
+    {
+        let (word, shift) = Self::locate(reg, eew, index);
+        (self.registers.read(word) >> shift) & eew.mask()
+    }
+
+    /// Write the lowest bits of `val` to element `index` of width `eew` of the register group
+    /// starting at `reg`.
+    #[inline(always)]
+    pub fn write(&mut self, reg: VRegister, eew: ElementWidth, index: usize, val: u64)
+    where
+        M: backend::ManagerReadWrite,
+    {
+        let (word, shift) = Self::locate(reg, eew, index);
+        let mask = eew.mask() << shift;
+        let old = self.registers.read(word);
+        self.registers
+            .write(word, (old & !mask) | ((val << shift) & mask));
+    }
+}
+
+impl<M: backend::ManagerBase> NewState<M> for VRegisters<M> {
+    fn new(manager: &mut M) -> Self
+    where
+        M: backend::ManagerAlloc,
+    {
+        Self {
+            registers: backend::Cells::new(manager),
+        }
+    }
+}
+
+impl<M: backend::ManagerClone> Clone for VRegisters<M> {
+    fn clone(&self) -> Self {
+        Self {
+            registers: self.registers.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VRegister::*;
+    use super::*;
+    use crate::backend_test;
+
+    #[test]
+    fn test_vtype_decode() {
+        // e8, m1
+        let vtype = VType::decode(0b000_000).unwrap();
+        assert_eq!(vtype.sew, ElementWidth::E8);
+        assert_eq!(vtype.vlmax(), 16);
+
+        // e64, m8, ta, ma
+        let vtype = VType::decode(0b11_011_011).unwrap();
+        assert_eq!(vtype.sew, ElementWidth::E64);
+        assert_eq!(vtype.vlmax(), 16);
+
+        // e16, mf4
+        let vtype = VType::decode(0b001_110).unwrap();
+        assert_eq!(vtype.vlmax(), 2);
+
+        // e64, mf2 requires ELEN >= 128
+        assert_eq!(VType::decode(0b011_111), None);
+
+        // Reserved vlmul, vsew & vill encodings
+        assert_eq!(VType::decode(0b000_100), None);
+        assert_eq!(VType::decode(0b100_000), None);
+        assert_eq!(VType::decode(VTYPE_VILL), None);
+    }
+
+    backend_test!(test_element_access, F, {
+        let mut registers = VRegisters::new(&mut F::manager());
+
+        registers.write(v1, ElementWidth::E64, 1, 0x0123_4567_89ab_cdef);
+
+        // Elements of different widths alias the same bytes, in little-endian order
+        assert_eq!(registers.read(v1, ElementWidth::E8, 8), 0xef);
+        assert_eq!(registers.read(v1, ElementWidth::E16, 5), 0x89ab);
+        assert_eq!(registers.read(v1, ElementWidth::E32, 3), 0x0123_4567);
+
+        // Group indices run into the following registers
+        assert_eq!(registers.read(v0, ElementWidth::E32, 7), 0x0123_4567);
+
+        // Writing an element leaves its neighbours untouched
+        registers.write(v1, ElementWidth::E8, 9, 0x1ff);
+        assert_eq!(
+            registers.read(v1, ElementWidth::E64, 1),
+            0x0123_4567_89ab_ffef
+        );
+        assert_eq!(registers.read(v1, ElementWidth::E64, 0), 0);
+        assert_eq!(registers.read(v2, ElementWidth::E64, 0), 0);
+    });
+}
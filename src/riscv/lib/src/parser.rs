@@ -22,6 +22,8 @@ use crate::machine_state::registers::XRegister;
 use crate::machine_state::registers::parse_fregister;
 use crate::machine_state::registers::parse_xregister;
 use crate::machine_state::registers::x0;
+use crate::machine_state::vector::VRegister;
+use crate::machine_state::vector::parse_vregister;
 
 /// Given an instruction encoded as a little-endian `u32`, extract `n` bits
 /// starting at `pos`.
@@ -132,6 +134,40 @@ const fn width(instr: u32) -> u32 {
     bits(instr, 12, 3)
 }
 
+#[inline(always)]
+const fn vd(instr: u32) -> VRegister {
+    parse_vregister(u5::extract_u32(instr, 7))
+}
+
+#[inline(always)]
+const fn vs1(instr: u32) -> VRegister {
+    parse_vregister(rs1_bits_u5(instr))
+}
+
+#[inline(always)]
+const fn vs2(instr: u32) -> VRegister {
+    parse_vregister(rs2_bits_u5(instr))
+}
+
+/// Vector arithmetic function encoding
+#[inline(always)]
+const fn funct6(instr: u32) -> u32 {
+    bits(instr, 26, 6)
+}
+
+/// Vector mask bit, set for unmasked instructions
+#[inline(always)]
+const fn vm(instr: u32) -> bool {
+    bit(instr, 25)
+}
+
+/// Vector load / store addressing mode, made of the `nf`, `mew`, `mop`, `vm` and
+/// `lumop` / `sumop` fields
+#[inline(always)]
+const fn vmem_mode(instr: u32) -> u32 {
+    bits(instr, 20, 12)
+}
+
 const fn csr(instr: u32) -> Option<CSRegister> {
     CSRegister::try_parse(bits(instr, 20, 12))
 }
@@ -182,6 +218,11 @@ const fn j_imm(instr: u32) -> i64 {
     ((instr_31 >> 11) | instr_19_12 | (instr_20 >> 9) | (instr_30_21 >> 20)) as i64
 }
 
+/// Sign-extended 5-bit immediate of vector instructions
+const fn v_imm(instr: u32) -> i64 {
+    (((instr as i32) << 12) >> 27) as i64
+}
+
 macro_rules! r_instr {
     ($enum_variant:ident, $instr:expr) => {
         $enum_variant(instruction::RTypeArgs {
@@ -200,6 +241,15 @@ macro_rules! r_instr {
     };
 }
 
+macro_rules! r1_instr {
+    ($enum_variant:ident, $instr:expr, $rd:expr) => {
+        $enum_variant(instruction::NonZeroRdR1TypeArgs {
+            rd: $rd,
+            rs1: rs1($instr),
+        })
+    };
+}
+
 macro_rules! i_instr {
     ($enum_variant:ident, $instr:expr) => {
         $enum_variant(instruction::ITypeArgs {
@@ -216,6 +266,14 @@ macro_rules! i_instr {
             imm: i_imm($instr),
         })
     };
+
+    ($enum_variant:ident, $instr:expr, $rd:expr, $imm:expr) => {
+        $enum_variant(instruction::NonZeroRdITypeArgs {
+            rd: $rd,
+            rs1: rs1($instr),
+            imm: $imm,
+        })
+    };
 }
 
 macro_rules! s_instr {
@@ -369,6 +427,54 @@ macro_rules! f_r_rm_3_instr {
     };
 }
 
+macro_rules! v_vv_instr {
+    ($enum_variant:ident, $instr:expr) => {
+        $enum_variant(instruction::VVArgs {
+            vd: vd($instr),
+            vs1: vs1($instr),
+            vs2: vs2($instr),
+        })
+    };
+}
+
+macro_rules! v_vx_instr {
+    ($enum_variant:ident, $instr:expr) => {
+        $enum_variant(instruction::VXArgs {
+            vd: vd($instr),
+            rs1: rs1($instr),
+            vs2: vs2($instr),
+        })
+    };
+}
+
+macro_rules! v_vi_instr {
+    ($enum_variant:ident, $instr:expr, $imm:expr) => {
+        $enum_variant(instruction::VIArgs {
+            vd: vd($instr),
+            imm: $imm,
+            vs2: vs2($instr),
+        })
+    };
+}
+
+macro_rules! vl_instr {
+    ($enum_variant:ident, $instr:expr) => {
+        $enum_variant(instruction::VLoadArgs {
+            vd: vd($instr),
+            rs1: rs1($instr),
+        })
+    };
+}
+
+macro_rules! vs_instr {
+    ($enum_variant:ident, $instr:expr) => {
+        $enum_variant(instruction::VStoreArgs {
+            vs3: vd($instr),
+            rs1: rs1($instr),
+        })
+    };
+}
+
 macro_rules! fence_instr {
     ($enum_variant:ident, $instr:expr) => {
         $enum_variant(instruction::FenceArgs {
@@ -447,6 +553,7 @@ const OP_FNMSUB: u32 = 0b100_1011;
 const OP_FNMADD: u32 = 0b100_1111;
 const OP_FP_STORE: u32 = 0b010_0111;
 const OP_AMO: u32 = 0b010_1111;
+const OP_V: u32 = 0b101_0111;
 
 const F3_0: u32 = 0b000;
 const F3_1: u32 = 0b001;
@@ -475,14 +582,49 @@ const F5_30: u32 = 0b1_1110;
 
 const F7_0: u32 = 0b0;
 const F7_1: u32 = 0b1;
+const F7_4: u32 = 0b000_0100;
+const F7_5: u32 = 0b000_0101;
+const F7_7: u32 = 0b000_0111;
 const F7_8: u32 = 0b000_1000;
+const F7_10: u32 = 0b001_0000;
+const F7_14: u32 = 0b001_0100;
 const F7_20: u32 = 0b10_0000;
+const F7_24: u32 = 0b010_0100;
+const F7_30: u32 = 0b011_0000;
+const F7_34: u32 = 0b011_0100;
+const F7_35: u32 = 0b011_0101;
+const F7_40: u32 = 0b100_0000;
+
+const F6_0: u32 = 0b00_0000;
+const F6_2: u32 = 0b00_0010;
+const F6_3: u32 = 0b00_0011;
+const F6_4: u32 = 0b00_0100;
+const F6_5: u32 = 0b00_0101;
+const F6_6: u32 = 0b00_0110;
+const F6_7: u32 = 0b00_0111;
+const F6_9: u32 = 0b00_1001;
+const F6_A: u32 = 0b00_1010;
+const F6_B: u32 = 0b00_1011;
+const F6_10: u32 = 0b01_0000;
+const F6_17: u32 = 0b01_0111;
+const F6_25: u32 = 0b10_0101;
+const F6_28: u32 = 0b10_1000;
+const F6_29: u32 = 0b10_1001;
 
 const FMT_S: u32 = 0b0;
 const FMT_D: u32 = 0b01;
+const FMT_H: u32 = 0b10;
 
+const WIDTH_H: u32 = 0b001;
 const WIDTH_W: u32 = 0b010;
 const WIDTH_D: u32 = 0b011;
+const WIDTH_V8: u32 = 0b000;
+const WIDTH_V16: u32 = 0b101;
+const WIDTH_V32: u32 = 0b110;
+const WIDTH_V64: u32 = 0b111;
+
+/// Unit-stride, unmasked vector load / store of a single field
+const VMEM_UNIT_STRIDE: u32 = 0b0000_0010_0000;
 
 const RM_0: u32 = 0b000;
 const RM_1: u32 = 0b001;
@@ -496,7 +638,13 @@ const RM_MAX: u32 = RM_1;
 const RS1_0: u32 = 0b0;
 const RS2_0: u32 = 0b0;
 const RS2_1: u32 = 0b1;
+const RS2_2: u32 = 0b10;
+const RS2_3: u32 = 0b11;
+const RS2_4: u32 = 0b100;
 const RS2_5: u32 = 0b101;
+const RS2_6: u32 = 0b110;
+const RS2_7: u32 = 0b111;
+const RS2_24: u32 = 0b1_1000;
 
 const RS2_0_U5: u5 = u5::new(0b0);
 const RS2_1_U5: u5 = u5::new(0b1);
@@ -506,6 +654,8 @@ const RS2_3_U5: u5 = u5::new(0b11);
 const FM_0: u32 = 0b0;
 const FM_8: u32 = 0b1000;
 
+const CBO_ZERO: u32 = 0b100;
+
 pub(crate) const SHIFT_BITMASK: i64 = 0b11_1111;
 
 /// Parse an uncompressed instruction from a u32.
@@ -532,32 +682,70 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 (F7_0, X0) => Hint { instr },
                 (F7_0, NonZero(rd)) => r_instr!(Xor, instr, rd),
                 (F7_1, _) => r_instr!(Div, instr),
+                (F7_4, X0) => Hint { instr },
+                (F7_4, NonZero(rd)) => r_instr!(Pack, instr, rd),
+                (F7_5, X0) => Hint { instr },
+                (F7_5, NonZero(rd)) => r_instr!(Min, instr, rd),
+                (F7_10, X0) => Hint { instr },
+                (F7_10, NonZero(rd)) => r_instr!(Sh2add, instr, rd),
+                (F7_20, X0) => Hint { instr },
+                (F7_20, NonZero(rd)) => r_instr!(Xnor, instr, rd),
                 _ => Unknown { instr },
             },
             F3_6 => match (funct7(instr), split_x0(rd(instr))) {
                 (F7_0, X0) => Hint { instr },
                 (F7_0, NonZero(rd)) => r_instr!(Or, instr, rd),
                 (F7_1, _) => r_instr!(Rem, instr),
+                (F7_5, X0) => Hint { instr },
+                (F7_5, NonZero(rd)) => r_instr!(Max, instr, rd),
+                (F7_10, X0) => Hint { instr },
+                (F7_10, NonZero(rd)) => r_instr!(Sh3add, instr, rd),
+                (F7_20, X0) => Hint { instr },
+                (F7_20, NonZero(rd)) => r_instr!(Orn, instr, rd),
                 _ => Unknown { instr },
             },
             F3_7 => match (funct7(instr), split_x0(rd(instr))) {
                 (F7_0, X0) => Hint { instr },
                 (F7_0, NonZero(rd)) => r_instr!(And, instr, rd),
                 (F7_1, _) => r_instr!(Remu, instr),
+                (F7_4, X0) => Hint { instr },
+                (F7_4, NonZero(rd)) => r_instr!(Packh, instr, rd),
+                (F7_5, X0) => Hint { instr },
+                (F7_5, NonZero(rd)) => r_instr!(Maxu, instr, rd),
+                (F7_20, X0) => Hint { instr },
+                (F7_20, NonZero(rd)) => r_instr!(Andn, instr, rd),
+                (F7_7, X0) => Hint { instr },
+                (F7_7, NonZero(rd)) => r_instr!(CzeroNez, instr, rd),
                 _ => Unknown { instr },
             },
             F3_1 => match (funct7(instr), split_x0(rd(instr))) {
                 (F7_0, X0) => Hint { instr },
                 (F7_0, NonZero(rd)) => r_instr!(Sll, instr, rd),
                 (F7_1, _) => r_instr!(Mulh, instr),
+                (F7_14, X0) => Hint { instr },
+                (F7_14, NonZero(rd)) => r_instr!(Bset, instr, rd),
+                (F7_24, X0) => Hint { instr },
+                (F7_24, NonZero(rd)) => r_instr!(Bclr, instr, rd),
+                (F7_30, X0) => Hint { instr },
+                (F7_30, NonZero(rd)) => r_instr!(Rol, instr, rd),
+                (F7_34, X0) => Hint { instr },
+                (F7_34, NonZero(rd)) => r_instr!(Binv, instr, rd),
                 _ => Unknown { instr },
             },
             F3_5 => match (funct7(instr), split_x0(rd(instr))) {
                 (F7_0, X0) => Hint { instr },
                 (F7_0, NonZero(rd)) => r_instr!(Srl, instr, rd),
                 (F7_1, _) => r_instr!(Divu, instr),
+                (F7_5, X0) => Hint { instr },
+                (F7_5, NonZero(rd)) => r_instr!(Minu, instr, rd),
                 (F7_20, X0) => Hint { instr },
                 (F7_20, NonZero(rd)) => r_instr!(Sra, instr, rd),
+                (F7_24, X0) => Hint { instr },
+                (F7_24, NonZero(rd)) => r_instr!(Bext, instr, rd),
+                (F7_30, X0) => Hint { instr },
+                (F7_30, NonZero(rd)) => r_instr!(Ror, instr, rd),
+                (F7_7, X0) => Hint { instr },
+                (F7_7, NonZero(rd)) => r_instr!(CzeroEqz, instr, rd),
                 _ => Unknown { instr },
             },
 
@@ -565,6 +753,8 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 (F7_0, X0) => Hint { instr },
                 (F7_0, NonZero(rd)) => r_instr!(Slt, instr, rd),
                 (F7_1, _) => r_instr!(Mulhsu, instr),
+                (F7_10, X0) => Hint { instr },
+                (F7_10, NonZero(rd)) => r_instr!(Sh1add, instr, rd),
                 _ => Unknown { instr },
             },
 
@@ -582,16 +772,34 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 (F7_0, X0) => Hint { instr },
                 (F7_0, NonZero(rd)) => r_instr!(Addw, instr, rd),
                 (F7_1, _) => r_instr!(Mulw, instr),
+                (F7_4, X0) => Hint { instr },
+                (F7_4, NonZero(rd)) => r_instr!(AddUw, instr, rd),
                 (F7_20, X0) => Hint { instr },
                 (F7_20, NonZero(rd)) => r_instr!(Subw, instr, rd),
                 _ => Unknown { instr },
             },
-            F3_1 => match split_x0(rd(instr)) {
-                X0 => Hint { instr },
-                NonZero(rd) => r_instr!(Sllw, instr, rd),
+            F3_1 => match (funct7(instr), split_x0(rd(instr))) {
+                (F7_0, X0) => Hint { instr },
+                (F7_0, NonZero(rd)) => r_instr!(Sllw, instr, rd),
+                (F7_30, X0) => Hint { instr },
+                (F7_30, NonZero(rd)) => r_instr!(Rolw, instr, rd),
+                _ => Unknown { instr },
             },
-            F3_4 => match funct7(instr) {
-                F7_1 => r_instr!(Divw, instr),
+            F3_2 => match (funct7(instr), split_x0(rd(instr))) {
+                (F7_10, X0) => Hint { instr },
+                (F7_10, NonZero(rd)) => r_instr!(Sh1addUw, instr, rd),
+                _ => Unknown { instr },
+            },
+            F3_4 => match (funct7(instr), split_x0(rd(instr))) {
+                (F7_1, _) => r_instr!(Divw, instr),
+                (F7_4, X0) => Hint { instr },
+                (F7_4, NonZero(rd)) => match rs2_bits(instr) {
+                    // `ZEXT.H` is encoded as `PACKW` with `rs2 = x0`
+                    RS2_0 => r1_instr!(ZextH, instr, rd),
+                    _ => r_instr!(Packw, instr, rd),
+                },
+                (F7_10, X0) => Hint { instr },
+                (F7_10, NonZero(rd)) => r_instr!(Sh2addUw, instr, rd),
                 _ => Unknown { instr },
             },
             F3_5 => match (funct7(instr), split_x0(rd(instr))) {
@@ -600,11 +808,15 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 (F7_1, NonZero(_)) => r_instr!(Divuw, instr),
                 (F7_20, X0) => Hint { instr },
                 (F7_20, NonZero(rd)) => r_instr!(Sraw, instr, rd),
+                (F7_30, X0) => Hint { instr },
+                (F7_30, NonZero(rd)) => r_instr!(Rorw, instr, rd),
                 _ => Unknown { instr },
             },
 
-            F3_6 => match funct7(instr) {
-                F7_1 => r_instr!(Remw, instr),
+            F3_6 => match (funct7(instr), split_x0(rd(instr))) {
+                (F7_1, _) => r_instr!(Remw, instr),
+                (F7_10, X0) => Hint { instr },
+                (F7_10, NonZero(rd)) => r_instr!(Sh3addUw, instr, rd),
                 _ => Unknown { instr },
             },
             F3_7 => match funct7(instr) {
@@ -636,6 +848,35 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 // imm[0:5] -> shift amount
                 (F7_0, X0) => Hint { instr },
                 (F7_0, NonZero(rd)) => i_instr!(Slli, instr, rd),
+                // imm[0:5] -> bit index
+                (F7_14, X0) => Hint { instr },
+                (F7_14, NonZero(rd)) => i_instr!(Bseti, instr, rd, shift_imm(instr)),
+                (F7_24, X0) => Hint { instr },
+                (F7_24, NonZero(rd)) => i_instr!(Bclri, instr, rd, shift_imm(instr)),
+                (F7_34, X0) => Hint { instr },
+                (F7_34, NonZero(rd)) => i_instr!(Binvi, instr, rd, shift_imm(instr)),
+                // imm[0:4] -> type of unary operation
+                (F7_30, X0) => Hint { instr },
+                (F7_30, NonZero(rd)) => match (funct7(instr), rs2_bits(instr)) {
+                    (F7_30, RS2_0) => r1_instr!(Clz, instr, rd),
+                    (F7_30, RS2_1) => r1_instr!(Ctz, instr, rd),
+                    (F7_30, RS2_2) => r1_instr!(Cpop, instr, rd),
+                    (F7_30, RS2_4) => r1_instr!(SextB, instr, rd),
+                    (F7_30, RS2_5) => r1_instr!(SextH, instr, rd),
+                    _ => Unknown { instr },
+                },
+                (F7_8, X0) => Hint { instr },
+                (F7_8, NonZero(rd)) => match (funct7(instr), rs2_bits(instr)) {
+                    (F7_8, RS2_0) => r1_instr!(Sha256sum0, instr, rd),
+                    (F7_8, RS2_1) => r1_instr!(Sha256sum1, instr, rd),
+                    (F7_8, RS2_2) => r1_instr!(Sha256sig0, instr, rd),
+                    (F7_8, RS2_3) => r1_instr!(Sha256sig1, instr, rd),
+                    (F7_8, RS2_4) => r1_instr!(Sha512sum0, instr, rd),
+                    (F7_8, RS2_5) => r1_instr!(Sha512sum1, instr, rd),
+                    (F7_8, RS2_6) => r1_instr!(Sha512sig0, instr, rd),
+                    (F7_8, RS2_7) => r1_instr!(Sha512sig1, instr, rd),
+                    _ => Unknown { instr },
+                },
                 _ => Unknown { instr },
             },
             (F3_5, rd) => match (imm_11_6(instr), rd) {
@@ -648,6 +889,23 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                     rs1: rs1(instr),
                     imm: shift_imm(instr),
                 }),
+                (F7_30, X0) => Hint { instr },
+                (F7_30, NonZero(rd)) => i_instr!(Rori, instr, rd, shift_imm(instr)),
+                // imm[0:5] -> bit index
+                (F7_24, X0) => Hint { instr },
+                (F7_24, NonZero(rd)) => i_instr!(Bexti, instr, rd, shift_imm(instr)),
+                // The whole immediate determines the operation
+                (F7_14, X0) => Hint { instr },
+                (F7_14, NonZero(rd)) => match (funct7(instr), rs2_bits(instr)) {
+                    (F7_14, RS2_7) => r1_instr!(OrcB, instr, rd),
+                    _ => Unknown { instr },
+                },
+                (F7_34, X0) => Hint { instr },
+                (F7_34, NonZero(rd)) => match (funct7(instr), rs2_bits(instr)) {
+                    (F7_35, RS2_24) => r1_instr!(Rev8, instr, rd),
+                    (F7_34, RS2_7) => r1_instr!(Brev8, instr, rd),
+                    _ => Unknown { instr },
+                },
                 _ => Unknown { instr },
             },
             (F3_2, X0) => Hint { instr },
@@ -673,6 +931,17 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 // imm[0:4] -> shift amount
                 (F7_0, X0) => Hint { instr },
                 (F7_0, NonZero(rd)) => i_instr!(Slliw, instr, rd),
+                // imm[0:5] -> shift amount
+                (F7_4, X0) => Hint { instr },
+                (F7_4, NonZero(rd)) => i_instr!(SlliUw, instr, rd, shift_imm(instr)),
+                // imm[0:4] -> type of unary operation
+                (F7_30, X0) => Hint { instr },
+                (F7_30, NonZero(rd)) => match (funct7(instr), rs2_bits(instr)) {
+                    (F7_30, RS2_0) => r1_instr!(Clzw, instr, rd),
+                    (F7_30, RS2_1) => r1_instr!(Ctzw, instr, rd),
+                    (F7_30, RS2_2) => r1_instr!(Cpopw, instr, rd),
+                    _ => Unknown { instr },
+                },
                 _ => Unknown { instr },
             },
             (F3_5, rd) => match (imm_11_6(instr), rd) {
@@ -681,6 +950,11 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 (F7_0, NonZero(rd)) => i_instr!(Srliw, instr, rd),
                 (F7_20, X0) => Hint { instr },
                 (F7_20, NonZero(rd)) => i_instr!(Sraiw, instr, rd),
+                (F7_30, X0) => Hint { instr },
+                (F7_30, NonZero(rd)) => match funct7(instr) {
+                    F7_30 => i_instr!(Roriw, instr, rd, rs2_bits(instr) as i64),
+                    _ => Unknown { instr },
+                },
                 _ => Unknown { instr },
             },
             _ => Unknown { instr },
@@ -696,6 +970,10 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 _ => Unknown { instr },
             },
             F3_1 => return Instr::Uncacheable(InstrUncacheable::FenceI),
+            F3_2 => match (bits(instr, 20, 12), rd(instr)) {
+                (CBO_ZERO, x0) => CboZero(instruction::CboArgs { rs1: rs1(instr) }),
+                _ => Unknown { instr },
+            },
             _ => Unknown { instr },
         },
         OP_SYS => match funct3(instr) {
@@ -735,6 +1013,7 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 F5_20 => amo_instr!(Amomaxw, instr),
                 F5_24 => amo_instr!(Amominuw, instr),
                 F5_28 => amo_instr!(Amomaxuw, instr),
+                F5_5 => amo_instr!(Amocasw, instr),
                 _ => Unknown { instr },
             },
             F3_3 => match funct5(instr) {
@@ -752,6 +1031,12 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 F5_20 => amo_instr!(Amomaxd, instr),
                 F5_24 => amo_instr!(Amominud, instr),
                 F5_28 => amo_instr!(Amomaxud, instr),
+                F5_5 => amo_instr!(Amocasd, instr),
+                _ => Unknown { instr },
+            },
+            F3_4 => match funct5(instr) {
+                // Odd register numbers for the `rd` and `rs2` pairs are reserved.
+                F5_5 if !bit(instr, 7) && !bit(instr, 20) => amo_instr!(Amocasq, instr),
                 _ => Unknown { instr },
             },
             _ => Unknown { instr },
@@ -787,7 +1072,7 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
             NonZero(rd) => u_instr!(Auipc, instr, rd),
         },
 
-        // F/D-type instructions
+        // F/D/H-type instructions
         OP_FP => match fmt(instr) {
             FMT_S => match (funct5(instr), funct3(instr), rs2_bits_u5(instr)) {
                 (F5_0, rounding, rs2_bits) => {
@@ -808,6 +1093,7 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 (F5_5, RM_MIN, rs2_bits) => f_r_instr!(Fmins, instr, rs2_bits),
                 (F5_5, RM_MAX, rs2_bits) => f_r_instr!(Fmaxs, instr, rs2_bits),
                 (F5_8, rounding, RS2_1_U5) => f_r_rm_1_instr!(Fcvtsd, instr, rounding),
+                (F5_8, rounding, RS2_2_U5) => f_r_rm_1_instr!(Fcvtsh, instr, rounding),
                 (F5_11, rounding, RS2_0_U5) => f_r_rm_1_instr!(Fsqrts, instr, rounding),
                 (F5_20, RM_EQ, rs2_bits) => f_cmp_instr!(Feqs, instr, rs2_bits),
                 (F5_20, RM_LE, rs2_bits) => f_cmp_instr!(Fles, instr, rs2_bits),
@@ -853,6 +1139,7 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 (F5_5, RM_MIN, rs2_bits) => f_r_instr!(Fmind, instr, rs2_bits),
                 (F5_5, RM_MAX, rs2_bits) => f_r_instr!(Fmaxd, instr, rs2_bits),
                 (F5_8, rounding, RS2_0_U5) => f_r_rm_1_instr!(Fcvtds, instr, rounding),
+                (F5_8, rounding, RS2_2_U5) => f_r_rm_1_instr!(Fcvtdh, instr, rounding),
                 (F5_11, rounding, RS2_0_U5) => f_r_rm_1_instr!(Fsqrtd, instr, rounding),
                 (F5_20, RM_EQ, rs2_bits) => f_cmp_instr!(Feqd, instr, rs2_bits),
                 (F5_20, RM_LE, rs2_bits) => f_cmp_instr!(Fled, instr, rs2_bits),
@@ -879,39 +1166,183 @@ pub const fn parse_uncompressed_instruction(instr: u32) -> Instr {
                 }),
                 _ => Unknown { instr },
             },
+            FMT_H => match (funct5(instr), funct3(instr), rs2_bits_u5(instr)) {
+                (F5_0, rounding, rs2_bits) => {
+                    f_r_rm_2_instr!(Faddh, instr, rs2_bits, rounding)
+                }
+                (F5_1, rounding, rs2_bits) => {
+                    f_r_rm_2_instr!(Fsubh, instr, rs2_bits, rounding)
+                }
+                (F5_2, rounding, rs2_bits) => {
+                    f_r_rm_2_instr!(Fmulh, instr, rs2_bits, rounding)
+                }
+                (F5_3, rounding, rs2_bits) => {
+                    f_r_rm_2_instr!(Fdivh, instr, rs2_bits, rounding)
+                }
+                (F5_4, RM_0, rs2_bits) => f_r_instr!(Fsgnjh, instr, rs2_bits),
+                (F5_4, RM_1, rs2_bits) => f_r_instr!(Fsgnjnh, instr, rs2_bits),
+                (F5_4, RM_2, rs2_bits) => f_r_instr!(Fsgnjxh, instr, rs2_bits),
+                (F5_5, RM_MIN, rs2_bits) => f_r_instr!(Fminh, instr, rs2_bits),
+                (F5_5, RM_MAX, rs2_bits) => f_r_instr!(Fmaxh, instr, rs2_bits),
+                (F5_8, rounding, RS2_0_U5) => f_r_rm_1_instr!(Fcvths, instr, rounding),
+                (F5_8, rounding, RS2_1_U5) => f_r_rm_1_instr!(Fcvthd, instr, rounding),
+                (F5_11, rounding, RS2_0_U5) => f_r_rm_1_instr!(Fsqrth, instr, rounding),
+                (F5_20, RM_EQ, rs2_bits) => f_cmp_instr!(Feqh, instr, rs2_bits),
+                (F5_20, RM_LE, rs2_bits) => f_cmp_instr!(Fleh, instr, rs2_bits),
+                (F5_20, RM_LT, rs2_bits) => f_cmp_instr!(Flth, instr, rs2_bits),
+                (F5_24, rounding, RS2_0_U5) => f_r_int_fmt_instr!(Fcvtwh, instr, rounding),
+                (F5_24, rounding, RS2_1_U5) => f_r_int_fmt_instr!(Fcvtwuh, instr, rounding),
+                (F5_24, rounding, RS2_2_U5) => f_r_int_fmt_instr!(Fcvtlh, instr, rounding),
+                (F5_24, rounding, RS2_3_U5) => f_r_int_fmt_instr!(Fcvtluh, instr, rounding),
+                (F5_26, rounding, RS2_0_U5) => f_r_fmt_int_instr!(Fcvthw, instr, rounding),
+                (F5_26, rounding, RS2_1_U5) => f_r_fmt_int_instr!(Fcvthwu, instr, rounding),
+                (F5_26, rounding, RS2_2_U5) => f_r_fmt_int_instr!(Fcvthl, instr, rounding),
+                (F5_26, rounding, RS2_3_U5) => f_r_fmt_int_instr!(Fcvthlu, instr, rounding),
+                (F5_28, RM_0, RS2_0_U5) => FmvXH(FRegToXRegArgs {
+                    rd: rd(instr),
+                    rs1: rs1_f(instr),
+                }),
+                (F5_28, RM_1, RS2_0_U5) => FclassH(FRegToXRegArgs {
+                    rd: rd(instr),
+                    rs1: rs1_f(instr),
+                }),
+                (F5_30, RM_0, RS2_0_U5) => FmvHX(XRegToFRegArgs {
+                    rd: rd_f(instr),
+                    rs1: rs1(instr),
+                }),
+                _ => Unknown { instr },
+            },
             _ => Unknown { instr },
         },
-        // F/D fused multiply add instructions
+        // F/D/H fused multiply add instructions
         OP_FMADD => match fmt(instr) {
             FMT_S => f_r_rm_3_instr!(Fmadds, instr),
             FMT_D => f_r_rm_3_instr!(Fmaddd, instr),
+            FMT_H => f_r_rm_3_instr!(Fmaddh, instr),
             _ => Unknown { instr },
         },
         OP_FMSUB => match fmt(instr) {
             FMT_S => f_r_rm_3_instr!(Fmsubs, instr),
             FMT_D => f_r_rm_3_instr!(Fmsubd, instr),
+            FMT_H => f_r_rm_3_instr!(Fmsubh, instr),
             _ => Unknown { instr },
         },
         OP_FNMSUB => match fmt(instr) {
             FMT_S => f_r_rm_3_instr!(Fnmsubs, instr),
             FMT_D => f_r_rm_3_instr!(Fnmsubd, instr),
+            FMT_H => f_r_rm_3_instr!(Fnmsubh, instr),
             _ => Unknown { instr },
         },
         OP_FNMADD => match fmt(instr) {
             FMT_S => f_r_rm_3_instr!(Fnmadds, instr),
             FMT_D => f_r_rm_3_instr!(Fnmaddd, instr),
+            FMT_H => f_r_rm_3_instr!(Fnmaddh, instr),
             _ => Unknown { instr },
         },
 
-        // F/D-type load
+        // F/D/H-type load
         OP_FP_LOAD => match width(instr) {
+            WIDTH_H => fl_instr!(Flh, instr),
             WIDTH_W => fl_instr!(Flw, instr),
             WIDTH_D => fl_instr!(Fld, instr),
+            WIDTH_V8 if vmem_mode(instr) == VMEM_UNIT_STRIDE => vl_instr!(Vle8, instr),
+            WIDTH_V16 if vmem_mode(instr) == VMEM_UNIT_STRIDE => vl_instr!(Vle16, instr),
+            WIDTH_V32 if vmem_mode(instr) == VMEM_UNIT_STRIDE => vl_instr!(Vle32, instr),
+            WIDTH_V64 if vmem_mode(instr) == VMEM_UNIT_STRIDE => vl_instr!(Vle64, instr),
             _ => Unknown { instr },
         },
         OP_FP_STORE => match width(instr) {
+            WIDTH_H => fs_instr!(Fsh, instr),
             WIDTH_W => fs_instr!(Fsw, instr),
             WIDTH_D => fs_instr!(Fsd, instr),
+            WIDTH_V8 if vmem_mode(instr) == VMEM_UNIT_STRIDE => vs_instr!(Vse8, instr),
+            WIDTH_V16 if vmem_mode(instr) == VMEM_UNIT_STRIDE => vs_instr!(Vse16, instr),
+            WIDTH_V32 if vmem_mode(instr) == VMEM_UNIT_STRIDE => vs_instr!(Vse32, instr),
+            WIDTH_V64 if vmem_mode(instr) == VMEM_UNIT_STRIDE => vs_instr!(Vse64, instr),
+            _ => Unknown { instr },
+        },
+
+        // Zve64x vector configuration & integer arithmetic
+        OP_V => match (funct3(instr), funct6(instr)) {
+            // OPCFG
+            (F3_7, _) => match bits(instr, 30, 2) {
+                0b00 | 0b01 => Vsetvli(instruction::VsetvliArgs {
+                    rd: rd(instr),
+                    rs1: rs1(instr),
+                    vtypei: bits(instr, 20, 11) as i64,
+                }),
+                0b11 => Vsetivli(instruction::VsetivliArgs {
+                    rd: rd(instr),
+                    uimm: rs1_bits(instr) as i64,
+                    vtypei: bits(instr, 20, 10) as i64,
+                }),
+                _ => match funct7(instr) {
+                    F7_40 => r_instr!(Vsetvl, instr),
+                    _ => Unknown { instr },
+                },
+            },
+            // Masked vector instructions are not supported
+            _ if !vm(instr) => Unknown { instr },
+            // OPIVV
+            (F3_0, F6_0) => v_vv_instr!(VaddVV, instr),
+            (F3_0, F6_2) => v_vv_instr!(VsubVV, instr),
+            (F3_0, F6_4) => v_vv_instr!(VminuVV, instr),
+            (F3_0, F6_5) => v_vv_instr!(VminVV, instr),
+            (F3_0, F6_6) => v_vv_instr!(VmaxuVV, instr),
+            (F3_0, F6_7) => v_vv_instr!(VmaxVV, instr),
+            (F3_0, F6_9) => v_vv_instr!(VandVV, instr),
+            (F3_0, F6_A) => v_vv_instr!(VorVV, instr),
+            (F3_0, F6_B) => v_vv_instr!(VxorVV, instr),
+            (F3_0, F6_17) if rs2_bits(instr) == RS2_0 => VmvVV(instruction::VRegToVRegArgs {
+                vd: vd(instr),
+                vs1: vs1(instr),
+            }),
+            (F3_0, F6_25) => v_vv_instr!(VsllVV, instr),
+            (F3_0, F6_28) => v_vv_instr!(VsrlVV, instr),
+            (F3_0, F6_29) => v_vv_instr!(VsraVV, instr),
+            // OPIVX
+            (F3_4, F6_0) => v_vx_instr!(VaddVX, instr),
+            (F3_4, F6_2) => v_vx_instr!(VsubVX, instr),
+            (F3_4, F6_3) => v_vx_instr!(VrsubVX, instr),
+            (F3_4, F6_4) => v_vx_instr!(VminuVX, instr),
+            (F3_4, F6_5) => v_vx_instr!(VminVX, instr),
+            (F3_4, F6_6) => v_vx_instr!(VmaxuVX, instr),
+            (F3_4, F6_7) => v_vx_instr!(VmaxVX, instr),
+            (F3_4, F6_9) => v_vx_instr!(VandVX, instr),
+            (F3_4, F6_A) => v_vx_instr!(VorVX, instr),
+            (F3_4, F6_B) => v_vx_instr!(VxorVX, instr),
+            (F3_4, F6_17) if rs2_bits(instr) == RS2_0 => VmvVX(instruction::XRegToVRegArgs {
+                vd: vd(instr),
+                rs1: rs1(instr),
+            }),
+            (F3_4, F6_25) => v_vx_instr!(VsllVX, instr),
+            (F3_4, F6_28) => v_vx_instr!(VsrlVX, instr),
+            (F3_4, F6_29) => v_vx_instr!(VsraVX, instr),
+            // OPIVI
+            (F3_3, F6_0) => v_vi_instr!(VaddVI, instr, v_imm(instr)),
+            (F3_3, F6_3) => v_vi_instr!(VrsubVI, instr, v_imm(instr)),
+            (F3_3, F6_9) => v_vi_instr!(VandVI, instr, v_imm(instr)),
+            (F3_3, F6_A) => v_vi_instr!(VorVI, instr, v_imm(instr)),
+            (F3_3, F6_B) => v_vi_instr!(VxorVI, instr, v_imm(instr)),
+            (F3_3, F6_17) if rs2_bits(instr) == RS2_0 => VmvVI(instruction::VImmArgs {
+                vd: vd(instr),
+                imm: v_imm(instr),
+            }),
+            (F3_3, F6_25) => v_vi_instr!(VsllVI, instr, rs1_bits(instr) as i64),
+            (F3_3, F6_28) => v_vi_instr!(VsrlVI, instr, rs1_bits(instr) as i64),
+            (F3_3, F6_29) => v_vi_instr!(VsraVI, instr, rs1_bits(instr) as i64),
+            // OPMVV
+            (F3_2, F6_10) if rs1_bits(instr) == RS1_0 => VmvXS(instruction::VRegToXRegArgs {
+                rd: rd(instr),
+                vs2: vs2(instr),
+            }),
+            (F3_2, F6_25) => v_vv_instr!(VmulVV, instr),
+            // OPMVX
+            (F3_6, F6_10) if rs2_bits(instr) == RS2_0 => VmvSX(instruction::XRegToVRegArgs {
+                vd: vd(instr),
+                rs1: rs1(instr),
+            }),
+            (F3_6, F6_25) => v_vx_instr!(VmulVX, instr),
             _ => Unknown { instr },
         },
         // Jump instructions
@@ -1415,16 +1846,39 @@ mod tests {
     use std::collections::HashMap;
 
     use super::XRegisterParsed::*;
+    use super::instruction::AmoArgs;
+    use super::instruction::CboArgs;
     use super::instruction::CsrArgs;
+    use super::instruction::FLoadArgs;
+    use super::instruction::FR1ArgWithRounding;
+    use super::instruction::FR2ArgsWithRounding;
+    use super::instruction::FR3ArgsWithRounding;
+    use super::instruction::FRegToXRegArgs;
+    use super::instruction::FStoreArgs;
     use super::instruction::Instr;
     use super::instruction::InstrCacheable::*;
+    use super::instruction::InstrRoundingMode;
     use super::instruction::NonZeroRdITypeArgs;
+    use super::instruction::NonZeroRdR1TypeArgs;
+    use super::instruction::NonZeroRdRTypeArgs;
+    use super::instruction::RTypeArgs;
     use super::instruction::SBTypeArgs;
     use super::instruction::UJTypeArgs;
+    use super::instruction::VIArgs;
+    use super::instruction::VLoadArgs;
+    use super::instruction::VRegToXRegArgs;
+    use super::instruction::VStoreArgs;
+    use super::instruction::VVArgs;
+    use super::instruction::VXArgs;
+    use super::instruction::VsetivliArgs;
+    use super::instruction::VsetvliArgs;
     use super::parse_block;
+    use crate::interpreter::float::RoundingMode;
     use crate::machine_state::csregisters::CSRegister;
+    use crate::machine_state::registers::FRegister::*;
     use crate::machine_state::registers::NonZeroXRegister;
     use crate::machine_state::registers::XRegister::*;
+    use crate::machine_state::vector::VRegister::*;
     use crate::parser::NonZeroRdUJTypeArgs;
     use crate::parser::SplitITypeArgs;
     use crate::parser::instruction::CIBNZTypeArgs;
@@ -1661,6 +2115,509 @@ mod tests {
         assert_eq!(instructions, expected);
     }
 
+    #[test]
+    fn test_bitmanip() {
+        let bytes: [u8; 160] = [
+            0x33, 0xA5, 0xC5, 0x20, // SH1ADD X10, X11, X12
+            0x33, 0xC5, 0xC5, 0x20, // SH2ADD X10, X11, X12
+            0x33, 0xE5, 0xC5, 0x20, // SH3ADD X10, X11, X12
+            0x3B, 0x85, 0xC5, 0x08, // ADD.UW X10, X11, X12
+            0x3B, 0xA5, 0xC5, 0x20, // SH1ADD.UW X10, X11, X12
+            0x3B, 0xC5, 0xC5, 0x20, // SH2ADD.UW X10, X11, X12
+            0x3B, 0xE5, 0xC5, 0x20, // SH3ADD.UW X10, X11, X12
+            0x33, 0xF5, 0xC5, 0x40, // ANDN X10, X11, X12
+            0x33, 0xE5, 0xC5, 0x40, // ORN X10, X11, X12
+            0x33, 0xC5, 0xC5, 0x40, // XNOR X10, X11, X12
+            0x33, 0xE5, 0xC5, 0x0A, // MAX X10, X11, X12
+            0x33, 0xF5, 0xC5, 0x0A, // MAXU X10, X11, X12
+            0x33, 0xC5, 0xC5, 0x0A, // MIN X10, X11, X12
+            0x33, 0xD5, 0xC5, 0x0A, // MINU X10, X11, X12
+            0x33, 0x95, 0xC5, 0x60, // ROL X10, X11, X12
+            0x33, 0xD5, 0xC5, 0x60, // ROR X10, X11, X12
+            0x3B, 0x95, 0xC5, 0x60, // ROLW X10, X11, X12
+            0x3B, 0xD5, 0xC5, 0x60, // RORW X10, X11, X12
+            0x33, 0x95, 0xC5, 0x48, // BCLR X10, X11, X12
+            0x33, 0xD5, 0xC5, 0x48, // BEXT X10, X11, X12
+            0x33, 0x95, 0xC5, 0x68, // BINV X10, X11, X12
+            0x33, 0x95, 0xC5, 0x28, // BSET X10, X11, X12
+            0x13, 0x95, 0x05, 0x60, // CLZ X10, X11
+            0x13, 0x95, 0x15, 0x60, // CTZ X10, X11
+            0x13, 0x95, 0x25, 0x60, // CPOP X10, X11
+            0x1B, 0x95, 0x05, 0x60, // CLZW X10, X11
+            0x1B, 0x95, 0x15, 0x60, // CTZW X10, X11
+            0x1B, 0x95, 0x25, 0x60, // CPOPW X10, X11
+            0x13, 0x95, 0x45, 0x60, // SEXT.B X10, X11
+            0x13, 0x95, 0x55, 0x60, // SEXT.H X10, X11
+            0x3B, 0xC5, 0x05, 0x08, // ZEXT.H X10, X11
+            0x13, 0xD5, 0x75, 0x28, // ORC.B X10, X11
+            0x13, 0xD5, 0x85, 0x6B, // REV8 X10, X11
+            0x1B, 0x95, 0x55, 0x0A, // SLLI.UW X10, X11, 37
+            0x13, 0xD5, 0x55, 0x62, // RORI X10, X11, 37
+            0x1B, 0xD5, 0x15, 0x61, // RORIW X10, X11, 17
+            0x13, 0x95, 0x55, 0x4A, // BCLRI X10, X11, 37
+            0x13, 0xD5, 0x55, 0x4A, // BEXTI X10, X11, 37
+            0x13, 0x95, 0x55, 0x6A, // BINVI X10, X11, 37
+            0x13, 0x95, 0x55, 0x2A, // BSETI X10, X11, 37
+        ];
+        let expected = [
+            Instr::Cacheable(Sh1add(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Sh2add(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Sh3add(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(AddUw(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Sh1addUw(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Sh2addUw(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Sh3addUw(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Andn(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Orn(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Xnor(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Max(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Maxu(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Min(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Minu(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Rol(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Ror(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Rolw(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Rorw(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Bclr(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Bext(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Binv(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Bset(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Clz(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Ctz(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Cpop(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Clzw(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Ctzw(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Cpopw(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(SextB(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(SextH(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(ZextH(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(OrcB(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Rev8(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(SlliUw(NonZeroRdITypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                imm: 37,
+            })),
+            Instr::Cacheable(Rori(NonZeroRdITypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                imm: 37,
+            })),
+            Instr::Cacheable(Roriw(NonZeroRdITypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                imm: 17,
+            })),
+            Instr::Cacheable(Bclri(NonZeroRdITypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                imm: 37,
+            })),
+            Instr::Cacheable(Bexti(NonZeroRdITypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                imm: 37,
+            })),
+            Instr::Cacheable(Binvi(NonZeroRdITypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                imm: 37,
+            })),
+            Instr::Cacheable(Bseti(NonZeroRdITypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                imm: 37,
+            })),
+        ];
+        let instructions = parse_block(&bytes);
+        assert_eq!(instructions, expected);
+    }
+
+    #[test]
+    fn test_zicond() {
+        let bytes: [u8; 12] = [
+            0x33, 0xD5, 0xC5, 0x0E, // CZERO.EQZ X10, X11, X12
+            0x33, 0xF5, 0xC5, 0x0E, // CZERO.NEZ X10, X11, X12
+            0x33, 0xD0, 0xC5, 0x0E, // CZERO.EQZ X0, X11, X12
+        ];
+        let expected = [
+            Instr::Cacheable(CzeroEqz(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(CzeroNez(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Hint { instr: 0x0EC5D033 }),
+        ];
+        let instructions = parse_block(&bytes);
+        assert_eq!(instructions, expected);
+    }
+
+    #[test]
+    fn test_zacas() {
+        let bytes: [u8; 20] = [
+            0x2F, 0x25, 0xB6, 0x28, // AMOCAS.W X10, X11, (X12)
+            0x2F, 0x35, 0xB6, 0x2E, // AMOCAS.D.AQRL X10, X11, (X12)
+            0x2F, 0xC5, 0xC5, 0x28, // AMOCAS.Q X10, X12, (X11)
+            0xAF, 0xC5, 0xC6, 0x28, // AMOCAS.Q X11, X12, (X13) - odd rd is reserved
+            0x2F, 0xC5, 0xB6, 0x28, // AMOCAS.Q X10, X11, (X13) - odd rs2 is reserved
+        ];
+        let expected = [
+            Instr::Cacheable(Amocasw(AmoArgs {
+                rd: x10,
+                rs1: x12,
+                rs2: x11,
+                aq: false,
+                rl: false,
+            })),
+            Instr::Cacheable(Amocasd(AmoArgs {
+                rd: x10,
+                rs1: x12,
+                rs2: x11,
+                aq: true,
+                rl: true,
+            })),
+            Instr::Cacheable(Amocasq(AmoArgs {
+                rd: x10,
+                rs1: x11,
+                rs2: x12,
+                aq: false,
+                rl: false,
+            })),
+            Instr::Cacheable(Unknown { instr: 0x28C6C5AF }),
+            Instr::Cacheable(Unknown { instr: 0x28B6C52F }),
+        ];
+        let instructions = parse_block(&bytes);
+        assert_eq!(instructions, expected);
+    }
+
+    #[test]
+    fn test_zbkb_zknh() {
+        let bytes: [u8; 52] = [
+            0x33, 0xC5, 0xC5, 0x08, // PACK X10, X11, X12
+            0x33, 0xF5, 0xC5, 0x08, // PACKH X10, X11, X12
+            0x3B, 0xC5, 0xC5, 0x08, // PACKW X10, X11, X12
+            0x13, 0xD5, 0x75, 0x68, // BREV8 X10, X11
+            0x13, 0x95, 0x05, 0x10, // SHA256SUM0 X10, X11
+            0x13, 0x95, 0x15, 0x10, // SHA256SUM1 X10, X11
+            0x13, 0x95, 0x25, 0x10, // SHA256SIG0 X10, X11
+            0x13, 0x95, 0x35, 0x10, // SHA256SIG1 X10, X11
+            0x13, 0x95, 0x45, 0x10, // SHA512SUM0 X10, X11
+            0x13, 0x95, 0x55, 0x10, // SHA512SUM1 X10, X11
+            0x13, 0x95, 0x65, 0x10, // SHA512SIG0 X10, X11
+            0x13, 0x95, 0x75, 0x10, // SHA512SIG1 X10, X11
+            0x13, 0x90, 0x05, 0x10, // SHA256SUM0 X0, X11
+        ];
+        let expected = [
+            Instr::Cacheable(Pack(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Packh(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Packw(NonZeroRdRTypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(Brev8(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Sha256sum0(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Sha256sum1(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Sha256sig0(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Sha256sig1(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Sha512sum0(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Sha512sum1(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Sha512sig0(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Sha512sig1(NonZeroRdR1TypeArgs {
+                rd: NonZeroXRegister::x10,
+                rs1: x11,
+            })),
+            Instr::Cacheable(Hint { instr: 0x10059013 }),
+        ];
+        let instructions = parse_block(&bytes);
+        assert_eq!(instructions, expected);
+    }
+
+    #[test]
+    fn test_zfh() {
+        let bytes: [u8; 32] = [
+            0x07, 0x95, 0x25, 0x00, // FLH F10, 2(X11)
+            0x27, 0x91, 0xA5, 0x00, // FSH F10, 2(X11)
+            0x53, 0xF5, 0xC5, 0x04, // FADD.H F10, F11, F12
+            0x43, 0xF5, 0xC5, 0x6C, // FMADD.H F10, F11, F12, F13
+            0x53, 0x85, 0x25, 0x40, // FCVT.S.H F10, F11, RNE
+            0x53, 0xF5, 0x15, 0x44, // FCVT.H.D F10, F11
+            0x53, 0x85, 0x05, 0xE4, // FMV.X.H X10, F11
+            0x53, 0xF5, 0x35, 0x44, // FCVT.H.Q F10, F11 - Q is not supported
+        ];
+        let expected = [
+            Instr::Cacheable(Flh(FLoadArgs {
+                rs1: x11,
+                rd: f10,
+                imm: 2,
+            })),
+            Instr::Cacheable(Fsh(FStoreArgs {
+                rs1: x11,
+                rs2: f10,
+                imm: 2,
+            })),
+            Instr::Cacheable(Faddh(FR2ArgsWithRounding {
+                rs1: f11,
+                rs2: f12,
+                rm: InstrRoundingMode::Dynamic,
+                rd: f10,
+            })),
+            Instr::Cacheable(Fmaddh(FR3ArgsWithRounding {
+                rs1: f11,
+                rs2: f12,
+                rs3: f13,
+                rm: InstrRoundingMode::Dynamic,
+                rd: f10,
+            })),
+            Instr::Cacheable(Fcvtsh(FR1ArgWithRounding {
+                rs1: f11,
+                rm: InstrRoundingMode::Static(RoundingMode::RNE),
+                rd: f10,
+            })),
+            Instr::Cacheable(Fcvthd(FR1ArgWithRounding {
+                rs1: f11,
+                rm: InstrRoundingMode::Dynamic,
+                rd: f10,
+            })),
+            Instr::Cacheable(FmvXH(FRegToXRegArgs { rd: x10, rs1: f11 })),
+            Instr::Cacheable(Unknown { instr: 0x4435F553 }),
+        ];
+        let instructions = parse_block(&bytes);
+        assert_eq!(instructions, expected);
+    }
+
+    #[test]
+    fn test_zicboz() {
+        let bytes: [u8; 8] = [
+            0x0F, 0x20, 0x45, 0x00, // CBO.ZERO (X10)
+            0x8F, 0x20, 0x45, 0x00, // CBO.ZERO with rd != X0 is reserved
+        ];
+        let expected = [
+            Instr::Cacheable(CboZero(CboArgs { rs1: x10 })),
+            Instr::Cacheable(Unknown { instr: 0x0045208F }),
+        ];
+        let instructions = parse_block(&bytes);
+        assert_eq!(instructions, expected);
+    }
+
+    #[test]
+    fn test_zve64x() {
+        let bytes: [u8; 44] = [
+            0x57, 0xF5, 0x15, 0x0D, // VSETVLI X10, X11, E32, M2, TA, MA
+            0x57, 0x75, 0x34, 0xC0, // VSETIVLI X10, 8, E8, M8
+            0x57, 0xF5, 0xC5, 0x80, // VSETVL X10, X11, X12
+            0xD7, 0x80, 0x21, 0x02, // VADD.VV V1, V2, V3
+            0xD7, 0xB0, 0x2E, 0x02, // VADD.VI V1, V2, -3
+            0xD7, 0xB0, 0x2F, 0x96, // VSLL.VI V1, V2, 31
+            0xD7, 0xE0, 0x25, 0x96, // VMUL.VX V1, V2, X11
+            0x57, 0x25, 0x20, 0x42, // VMV.X.S X10, V2
+            0x07, 0xE2, 0x05, 0x02, // VLE32.V V4, (X11)
+            0x27, 0xF2, 0x05, 0x02, // VSE64.V V4, (X11)
+            0xD7, 0x80, 0x21, 0x00, // VADD.VV V1, V2, V3, V0.T - masking is not supported
+        ];
+        let expected = [
+            Instr::Cacheable(Vsetvli(VsetvliArgs {
+                rd: x10,
+                rs1: x11,
+                vtypei: 0xD1,
+            })),
+            Instr::Cacheable(Vsetivli(VsetivliArgs {
+                rd: x10,
+                uimm: 8,
+                vtypei: 0x003,
+            })),
+            Instr::Cacheable(Vsetvl(RTypeArgs {
+                rd: x10,
+                rs1: x11,
+                rs2: x12,
+            })),
+            Instr::Cacheable(VaddVV(VVArgs {
+                vd: v1,
+                vs1: v3,
+                vs2: v2,
+            })),
+            Instr::Cacheable(VaddVI(VIArgs {
+                vd: v1,
+                imm: -3,
+                vs2: v2,
+            })),
+            Instr::Cacheable(VsllVI(VIArgs {
+                vd: v1,
+                imm: 31,
+                vs2: v2,
+            })),
+            Instr::Cacheable(VmulVX(VXArgs {
+                vd: v1,
+                rs1: x11,
+                vs2: v2,
+            })),
+            Instr::Cacheable(VmvXS(VRegToXRegArgs { rd: x10, vs2: v2 })),
+            Instr::Cacheable(Vle32(VLoadArgs { vd: v4, rs1: x11 })),
+            Instr::Cacheable(Vse64(VStoreArgs { vs3: v4, rs1: x11 })),
+            Instr::Cacheable(Unknown { instr: 0x002180D7 }),
+        ];
+        let instructions = parse_block(&bytes);
+        assert_eq!(instructions, expected);
+    }
+
     // Check a HINT encoding for each compressed opcode.
     #[test]
     fn test_compressed_hints() {
@@ -16,6 +16,8 @@ use crate::machine_state::csregisters::CSRegister;
 use crate::machine_state::registers::FRegister;
 use crate::machine_state::registers::NonZeroXRegister;
 use crate::machine_state::registers::XRegister;
+use crate::machine_state::vector::VRegister;
+use crate::machine_state::vector::VType;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
 pub struct RTypeArgs {
@@ -32,6 +34,14 @@ pub struct NonZeroRdRTypeArgs {
     pub rs2: XRegister,
 }
 
+/// Intermediate representation of Args for R-type instructions with a single source register
+/// and guaranteed `rd` != `x0`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct NonZeroRdR1TypeArgs {
+    pub rd: NonZeroXRegister,
+    pub rs1: XRegister,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
 pub struct ITypeArgs {
     pub rd: XRegister,
@@ -174,6 +184,20 @@ impl InstrRoundingMode {
             }
         }
     }
+
+    /// Encode the rounding mode as the `rm` field of an instruction.
+    ///
+    /// This is the inverse of [`InstrRoundingMode::from_rm`].
+    pub const fn to_rm(self) -> u32 {
+        match self {
+            Self::Dynamic => 0b111,
+            Self::Static(RoundingMode::RNE) => 0b000,
+            Self::Static(RoundingMode::RTZ) => 0b001,
+            Self::Static(RoundingMode::RDN) => 0b010,
+            Self::Static(RoundingMode::RUP) => 0b011,
+            Self::Static(RoundingMode::RMM) => 0b100,
+        }
+    }
 }
 
 /// Floating-point R-type instruction, containing
@@ -220,6 +244,91 @@ pub struct FLoadArgs {
     pub imm: i64,
 }
 
+/// Intermediate representation of Args for vector-vector instructions.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct VVArgs {
+    pub vd: VRegister,
+    pub vs1: VRegister,
+    pub vs2: VRegister,
+}
+
+/// Intermediate representation of Args for vector-scalar instructions.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct VXArgs {
+    pub vd: VRegister,
+    pub rs1: XRegister,
+    pub vs2: VRegister,
+}
+
+/// Intermediate representation of Args for vector-immediate instructions.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct VIArgs {
+    pub vd: VRegister,
+    pub imm: i64,
+    pub vs2: VRegister,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct VRegToVRegArgs {
+    pub vd: VRegister,
+    pub vs1: VRegister,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct XRegToVRegArgs {
+    pub vd: VRegister,
+    pub rs1: XRegister,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct VImmArgs {
+    pub vd: VRegister,
+    pub imm: i64,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct VRegToXRegArgs {
+    pub rd: XRegister,
+    pub vs2: VRegister,
+}
+
+/// Intermediate representation of Args for unit-stride vector loads.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct VLoadArgs {
+    pub vd: VRegister,
+    pub rs1: XRegister,
+}
+
+/// Intermediate representation of Args for unit-stride vector stores.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct VStoreArgs {
+    pub vs3: VRegister,
+    pub rs1: XRegister,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct VsetvliArgs {
+    pub rd: XRegister,
+    pub rs1: XRegister,
+    pub vtypei: i64,
+}
+
+/// Intermediate representation of Args for `vsetivli`, whose application vector length
+/// is given by the unsigned immediate `uimm`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct VsetivliArgs {
+    pub rd: XRegister,
+    pub uimm: i64,
+    pub vtypei: i64,
+}
+
+/// Intermediate representation of Args for cache-block operations, which act on the
+/// cache block containing the address in `rs1`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
+pub struct CboArgs {
+    pub rs1: XRegister,
+}
+
 // R-type instructions with 2 additional bits which specify memory ordering
 // constraints as viewed by other RISC-V harts
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, serde::Serialize, serde::Deserialize)]
@@ -500,6 +609,22 @@ pub enum InstrCacheable {
     Amominud(AmoArgs),
     Amomaxud(AmoArgs),
 
+    // Zacas atomic compare-and-swap instructions
+    /// `AMOCAS.W` - Loads in `rd` the sign-extended word at the address in `rs1`.
+    /// If it is equal to the low word of the original `val(rd)`, the low word of
+    /// `val(rs2)` is stored back to the address in `rs1`.
+    ///
+    /// The `aq` and `rl` bits specify additional memory constraints in
+    /// multi-hart environments so they are currently ignored.
+    Amocasw(AmoArgs),
+    /// `AMOCAS.D` - Loads in `rd` the double-word at the address in `rs1`.
+    /// If it is equal to the original `val(rd)`, `val(rs2)` is stored back to
+    /// the address in `rs1`.
+    Amocasd(AmoArgs),
+    /// `AMOCAS.Q` - Same as `AMOCAS.D`, but operating on a quad-word in memory
+    /// and on the even-odd register pairs starting at `rd` and `rs2`.
+    Amocasq(AmoArgs),
+
     // RV64M division instructions
     /// `REM` - Compute the remainder of val(rs1) divided by val(rs2). Store result in `rd`.
     /// In case val(rs2) is zero, result is val(rs1). In case of overflow, when
@@ -555,6 +680,143 @@ pub enum InstrCacheable {
     /// and store the sign-extended result in `rd`.
     Mulw(RTypeArgs),
 
+    // Zba address generation instructions
+    /// `SH1ADD` - Perform `(val(rs1) << 1) + val(rs2)` and store the result in `rd`.
+    Sh1add(NonZeroRdRTypeArgs),
+    /// `SH2ADD` - Perform `(val(rs1) << 2) + val(rs2)` and store the result in `rd`.
+    Sh2add(NonZeroRdRTypeArgs),
+    /// `SH3ADD` - Perform `(val(rs1) << 3) + val(rs2)` and store the result in `rd`.
+    Sh3add(NonZeroRdRTypeArgs),
+    /// `ADD.UW` - Zero-extend the lowest 32 bits of `val(rs1)`, add `val(rs2)`
+    /// and store the result in `rd`.
+    AddUw(NonZeroRdRTypeArgs),
+    /// `SH1ADD.UW` - Zero-extend the lowest 32 bits of `val(rs1)`, shift it left by 1,
+    /// add `val(rs2)` and store the result in `rd`.
+    Sh1addUw(NonZeroRdRTypeArgs),
+    /// `SH2ADD.UW` - Zero-extend the lowest 32 bits of `val(rs1)`, shift it left by 2,
+    /// add `val(rs2)` and store the result in `rd`.
+    Sh2addUw(NonZeroRdRTypeArgs),
+    /// `SH3ADD.UW` - Zero-extend the lowest 32 bits of `val(rs1)`, shift it left by 3,
+    /// add `val(rs2)` and store the result in `rd`.
+    Sh3addUw(NonZeroRdRTypeArgs),
+    /// `SLLI.UW` - Zero-extend the lowest 32 bits of `val(rs1)`, shift it left by
+    /// `imm[5:0]` and store the result in `rd`.
+    SlliUw(NonZeroRdITypeArgs),
+
+    // Zbb basic bit-manipulation instructions
+    /// `ANDN` - Saves in `rd` the bitwise AND between `val(rs1)` and the inverse of `val(rs2)`.
+    Andn(NonZeroRdRTypeArgs),
+    /// `ORN` - Saves in `rd` the bitwise OR between `val(rs1)` and the inverse of `val(rs2)`.
+    Orn(NonZeroRdRTypeArgs),
+    /// `XNOR` - Saves in `rd` the inverse of the bitwise XOR between `val(rs1)` and `val(rs2)`.
+    Xnor(NonZeroRdRTypeArgs),
+    /// `CLZ` - Count the number of leading zero bits in `val(rs1)`, storing the result in `rd`.
+    Clz(NonZeroRdR1TypeArgs),
+    /// `CLZW` - Count the number of leading zero bits in the lowest 32 bits of `val(rs1)`.
+    Clzw(NonZeroRdR1TypeArgs),
+    /// `CTZ` - Count the number of trailing zero bits in `val(rs1)`, storing the result in `rd`.
+    Ctz(NonZeroRdR1TypeArgs),
+    /// `CTZW` - Count the number of trailing zero bits in the lowest 32 bits of `val(rs1)`.
+    Ctzw(NonZeroRdR1TypeArgs),
+    /// `CPOP` - Count the number of set bits in `val(rs1)`, storing the result in `rd`.
+    Cpop(NonZeroRdR1TypeArgs),
+    /// `CPOPW` - Count the number of set bits in the lowest 32 bits of `val(rs1)`.
+    Cpopw(NonZeroRdR1TypeArgs),
+    /// `MAX` - Store the larger of `val(rs1)` and `val(rs2)` in `rd`, as _signed integers_.
+    Max(NonZeroRdRTypeArgs),
+    /// `MAXU` - Store the larger of `val(rs1)` and `val(rs2)` in `rd`, as _unsigned integers_.
+    Maxu(NonZeroRdRTypeArgs),
+    /// `MIN` - Store the smaller of `val(rs1)` and `val(rs2)` in `rd`, as _signed integers_.
+    Min(NonZeroRdRTypeArgs),
+    /// `MINU` - Store the smaller of `val(rs1)` and `val(rs2)` in `rd`, as _unsigned integers_.
+    Minu(NonZeroRdRTypeArgs),
+    /// `SEXT.B` - Sign-extend the lowest byte of `val(rs1)`, storing the result in `rd`.
+    SextB(NonZeroRdR1TypeArgs),
+    /// `SEXT.H` - Sign-extend the lowest 16 bits of `val(rs1)`, storing the result in `rd`.
+    SextH(NonZeroRdR1TypeArgs),
+    /// `ZEXT.H` - Zero-extend the lowest 16 bits of `val(rs1)`, storing the result in `rd`.
+    ZextH(NonZeroRdR1TypeArgs),
+    /// `ROL` - Rotate left the bits in `rs1` by `val(rs2)[5:0]`, saving the result in `rd`.
+    Rol(NonZeroRdRTypeArgs),
+    /// `ROLW` - Rotate left only the lowest 32 bits in `rs1` by `val(rs2)[4:0]`,
+    /// saving the sign-extended result in `rd`.
+    Rolw(NonZeroRdRTypeArgs),
+    /// `ROR` - Rotate right the bits in `rs1` by `val(rs2)[5:0]`, saving the result in `rd`.
+    Ror(NonZeroRdRTypeArgs),
+    /// `RORI` - Rotate right the bits in `rs1` by `imm[5:0]`, saving the result in `rd`.
+    Rori(NonZeroRdITypeArgs),
+    /// `RORIW` - Rotate right only the lowest 32 bits in `rs1` by `imm[4:0]`,
+    /// saving the sign-extended result in `rd`.
+    Roriw(NonZeroRdITypeArgs),
+    /// `RORW` - Rotate right only the lowest 32 bits in `rs1` by `val(rs2)[4:0]`,
+    /// saving the sign-extended result in `rd`.
+    Rorw(NonZeroRdRTypeArgs),
+    /// `ORC.B` - Set each byte of `rd` to all ones if the corresponding byte of `val(rs1)`
+    /// is non-zero, or to all zeros otherwise.
+    OrcB(NonZeroRdR1TypeArgs),
+    /// `REV8` - Reverse the order of the bytes in `val(rs1)`, storing the result in `rd`.
+    Rev8(NonZeroRdR1TypeArgs),
+
+    // Zbs single-bit instructions
+    /// `BCLR` - Clear the bit of `val(rs1)` at index `val(rs2)[5:0]`, storing the result in `rd`.
+    Bclr(NonZeroRdRTypeArgs),
+    /// `BCLRI` - Clear the bit of `val(rs1)` at index `imm[5:0]`, storing the result in `rd`.
+    Bclri(NonZeroRdITypeArgs),
+    /// `BEXT` - Store in `rd` the bit of `val(rs1)` at index `val(rs2)[5:0]`.
+    Bext(NonZeroRdRTypeArgs),
+    /// `BEXTI` - Store in `rd` the bit of `val(rs1)` at index `imm[5:0]`.
+    Bexti(NonZeroRdITypeArgs),
+    /// `BINV` - Invert the bit of `val(rs1)` at index `val(rs2)[5:0]`, storing the result in `rd`.
+    Binv(NonZeroRdRTypeArgs),
+    /// `BINVI` - Invert the bit of `val(rs1)` at index `imm[5:0]`, storing the result in `rd`.
+    Binvi(NonZeroRdITypeArgs),
+    /// `BSET` - Set the bit of `val(rs1)` at index `val(rs2)[5:0]`, storing the result in `rd`.
+    Bset(NonZeroRdRTypeArgs),
+    /// `BSETI` - Set the bit of `val(rs1)` at index `imm[5:0]`, storing the result in `rd`.
+    Bseti(NonZeroRdITypeArgs),
+
+    // Zbkb bit-manipulation instructions for cryptography
+    /// `PACK` - Store in `rd` the lowest 32 bits of `val(rs1)` in its lower half and
+    /// the lowest 32 bits of `val(rs2)` in its upper half.
+    Pack(NonZeroRdRTypeArgs),
+    /// `PACKH` - Store in `rd` the lowest byte of `val(rs1)` in bits `[7:0]` and
+    /// the lowest byte of `val(rs2)` in bits `[15:8]`, zeroing the rest.
+    Packh(NonZeroRdRTypeArgs),
+    /// `PACKW` - Pack the lowest 16 bits of `val(rs1)` and `val(rs2)` into a word,
+    /// storing the sign-extended result in `rd`.
+    Packw(NonZeroRdRTypeArgs),
+    /// `BREV8` - Reverse the order of the bits within each byte of `val(rs1)`,
+    /// storing the result in `rd`.
+    Brev8(NonZeroRdR1TypeArgs),
+
+    // Zknh SHA-2 hash function instructions
+    /// `SHA256SUM0` - Apply the SHA-256 `Σ0` function to the lowest 32 bits of `val(rs1)`,
+    /// storing the sign-extended result in `rd`.
+    Sha256sum0(NonZeroRdR1TypeArgs),
+    /// `SHA256SUM1` - Apply the SHA-256 `Σ1` function to the lowest 32 bits of `val(rs1)`,
+    /// storing the sign-extended result in `rd`.
+    Sha256sum1(NonZeroRdR1TypeArgs),
+    /// `SHA256SIG0` - Apply the SHA-256 `σ0` function to the lowest 32 bits of `val(rs1)`,
+    /// storing the sign-extended result in `rd`.
+    Sha256sig0(NonZeroRdR1TypeArgs),
+    /// `SHA256SIG1` - Apply the SHA-256 `σ1` function to the lowest 32 bits of `val(rs1)`,
+    /// storing the sign-extended result in `rd`.
+    Sha256sig1(NonZeroRdR1TypeArgs),
+    /// `SHA512SUM0` - Apply the SHA-512 `Σ0` function to `val(rs1)`, storing the result in `rd`.
+    Sha512sum0(NonZeroRdR1TypeArgs),
+    /// `SHA512SUM1` - Apply the SHA-512 `Σ1` function to `val(rs1)`, storing the result in `rd`.
+    Sha512sum1(NonZeroRdR1TypeArgs),
+    /// `SHA512SIG0` - Apply the SHA-512 `σ0` function to `val(rs1)`, storing the result in `rd`.
+    Sha512sig0(NonZeroRdR1TypeArgs),
+    /// `SHA512SIG1` - Apply the SHA-512 `σ1` function to `val(rs1)`, storing the result in `rd`.
+    Sha512sig1(NonZeroRdR1TypeArgs),
+
+    // Zicond conditional-zero instructions
+    /// `CZERO.EQZ` - Store `0` in `rd` if `val(rs2) == 0`, otherwise store `val(rs1)`.
+    CzeroEqz(NonZeroRdRTypeArgs),
+    /// `CZERO.NEZ` - Store `0` in `rd` if `val(rs2) != 0`, otherwise store `val(rs1)`.
+    CzeroNez(NonZeroRdRTypeArgs),
+
     // RV64F instructions
     FclassS(FRegToXRegArgs),
     Feqs(FCmpArgs),
@@ -621,6 +883,98 @@ pub enum InstrCacheable {
     FmvXD(FRegToXRegArgs),
     FmvDX(XRegToFRegArgs),
 
+    // Zfh half-precision instructions
+    FclassH(FRegToXRegArgs),
+    Feqh(FCmpArgs),
+    Fleh(FCmpArgs),
+    Flth(FCmpArgs),
+    Faddh(FR2ArgsWithRounding),
+    Fsubh(FR2ArgsWithRounding),
+    Fmulh(FR2ArgsWithRounding),
+    Fdivh(FR2ArgsWithRounding),
+    Fsqrth(FR1ArgWithRounding),
+    Fminh(FRArgs),
+    Fmaxh(FRArgs),
+    Fmaddh(FR3ArgsWithRounding),
+    Fmsubh(FR3ArgsWithRounding),
+    Fnmsubh(FR3ArgsWithRounding),
+    Fnmaddh(FR3ArgsWithRounding),
+    Flh(FLoadArgs),
+    Fsh(FStoreArgs),
+    Fcvthw(XRegToFRegArgsWithRounding),
+    Fcvthwu(XRegToFRegArgsWithRounding),
+    Fcvthl(XRegToFRegArgsWithRounding),
+    Fcvthlu(XRegToFRegArgsWithRounding),
+    Fcvtwh(FRegToXRegArgsWithRounding),
+    Fcvtwuh(FRegToXRegArgsWithRounding),
+    Fcvtlh(FRegToXRegArgsWithRounding),
+    Fcvtluh(FRegToXRegArgsWithRounding),
+    Fcvtsh(FR1ArgWithRounding),
+    Fcvths(FR1ArgWithRounding),
+    Fcvtdh(FR1ArgWithRounding),
+    Fcvthd(FR1ArgWithRounding),
+    Fsgnjh(FRArgs),
+    Fsgnjnh(FRArgs),
+    Fsgnjxh(FRArgs),
+    FmvXH(FRegToXRegArgs),
+    FmvHX(XRegToFRegArgs),
+
+    // Zve64x vector instructions
+    Vsetvli(VsetvliArgs),
+    Vsetivli(VsetivliArgs),
+    Vsetvl(RTypeArgs),
+    Vle8(VLoadArgs),
+    Vle16(VLoadArgs),
+    Vle32(VLoadArgs),
+    Vle64(VLoadArgs),
+    Vse8(VStoreArgs),
+    Vse16(VStoreArgs),
+    Vse32(VStoreArgs),
+    Vse64(VStoreArgs),
+    VaddVV(VVArgs),
+    VaddVX(VXArgs),
+    VaddVI(VIArgs),
+    VsubVV(VVArgs),
+    VsubVX(VXArgs),
+    VrsubVX(VXArgs),
+    VrsubVI(VIArgs),
+    VandVV(VVArgs),
+    VandVX(VXArgs),
+    VandVI(VIArgs),
+    VorVV(VVArgs),
+    VorVX(VXArgs),
+    VorVI(VIArgs),
+    VxorVV(VVArgs),
+    VxorVX(VXArgs),
+    VxorVI(VIArgs),
+    VsllVV(VVArgs),
+    VsllVX(VXArgs),
+    VsllVI(VIArgs),
+    VsrlVV(VVArgs),
+    VsrlVX(VXArgs),
+    VsrlVI(VIArgs),
+    VsraVV(VVArgs),
+    VsraVX(VXArgs),
+    VsraVI(VIArgs),
+    VminuVV(VVArgs),
+    VminuVX(VXArgs),
+    VminVV(VVArgs),
+    VminVX(VXArgs),
+    VmaxuVV(VVArgs),
+    VmaxuVX(VXArgs),
+    VmaxVV(VVArgs),
+    VmaxVX(VXArgs),
+    VmulVV(VVArgs),
+    VmulVX(VXArgs),
+    VmvVV(VRegToVRegArgs),
+    VmvVX(XRegToVRegArgs),
+    VmvVI(VImmArgs),
+    VmvXS(VRegToXRegArgs),
+    VmvSX(XRegToVRegArgs),
+
+    // Zicboz cache-block zeroing
+    CboZero(CboArgs),
+
     // Zicsr instructions
     Csrrw(CsrArgs),
     Csrrs(CsrArgs),
@@ -918,6 +1272,9 @@ impl InstrCacheable {
             | Amomaxd(_)
             | Amominud(_)
             | Amomaxud(_)
+            | Amocasw(_)
+            | Amocasd(_)
+            | Amocasq(_)
             | Rem(_)
             | Remu(_)
             | Remw(_)
@@ -931,6 +1288,60 @@ impl InstrCacheable {
             | Mulhsu(_)
             | Mulhu(_)
             | Mulw(_)
+            | Sh1add(_)
+            | Sh2add(_)
+            | Sh3add(_)
+            | AddUw(_)
+            | Sh1addUw(_)
+            | Sh2addUw(_)
+            | Sh3addUw(_)
+            | SlliUw(_)
+            | Andn(_)
+            | Orn(_)
+            | Xnor(_)
+            | Clz(_)
+            | Clzw(_)
+            | Ctz(_)
+            | Ctzw(_)
+            | Cpop(_)
+            | Cpopw(_)
+            | Max(_)
+            | Maxu(_)
+            | Min(_)
+            | Minu(_)
+            | SextB(_)
+            | SextH(_)
+            | ZextH(_)
+            | Rol(_)
+            | Rolw(_)
+            | Ror(_)
+            | Rori(_)
+            | Roriw(_)
+            | Rorw(_)
+            | OrcB(_)
+            | Rev8(_)
+            | Bclr(_)
+            | Bclri(_)
+            | Bext(_)
+            | Bexti(_)
+            | Binv(_)
+            | Binvi(_)
+            | Bset(_)
+            | Bseti(_)
+            | Pack(_)
+            | Packh(_)
+            | Packw(_)
+            | Brev8(_)
+            | Sha256sum0(_)
+            | Sha256sum1(_)
+            | Sha256sig0(_)
+            | Sha256sig1(_)
+            | Sha512sum0(_)
+            | Sha512sum1(_)
+            | Sha512sig0(_)
+            | Sha512sig1(_)
+            | CzeroEqz(_)
+            | CzeroNez(_)
             | FmvXW(_)
             | FmvWX(_)
             | Fcvtsw(_)
@@ -993,6 +1404,92 @@ impl InstrCacheable {
             | Fnmaddd(_)
             | Fld(_)
             | Fsd(_)
+            | FclassH(_)
+            | Feqh(_)
+            | Fleh(_)
+            | Flth(_)
+            | Faddh(_)
+            | Fsubh(_)
+            | Fmulh(_)
+            | Fdivh(_)
+            | Fsqrth(_)
+            | Fminh(_)
+            | Fmaxh(_)
+            | Fmaddh(_)
+            | Fmsubh(_)
+            | Fnmsubh(_)
+            | Fnmaddh(_)
+            | Flh(_)
+            | Fsh(_)
+            | Fcvthw(_)
+            | Fcvthwu(_)
+            | Fcvthl(_)
+            | Fcvthlu(_)
+            | Fcvtwh(_)
+            | Fcvtwuh(_)
+            | Fcvtlh(_)
+            | Fcvtluh(_)
+            | Fcvtsh(_)
+            | Fcvths(_)
+            | Fcvtdh(_)
+            | Fcvthd(_)
+            | Fsgnjh(_)
+            | Fsgnjnh(_)
+            | Fsgnjxh(_)
+            | FmvXH(_)
+            | FmvHX(_)
+            | Vsetvli(_)
+            | Vsetivli(_)
+            | Vsetvl(_)
+            | Vle8(_)
+            | Vle16(_)
+            | Vle32(_)
+            | Vle64(_)
+            | Vse8(_)
+            | Vse16(_)
+            | Vse32(_)
+            | Vse64(_)
+            | VaddVV(_)
+            | VaddVX(_)
+            | VaddVI(_)
+            | VsubVV(_)
+            | VsubVX(_)
+            | VrsubVX(_)
+            | VrsubVI(_)
+            | VandVV(_)
+            | VandVX(_)
+            | VandVI(_)
+            | VorVV(_)
+            | VorVX(_)
+            | VorVI(_)
+            | VxorVV(_)
+            | VxorVX(_)
+            | VxorVI(_)
+            | VsllVV(_)
+            | VsllVX(_)
+            | VsllVI(_)
+            | VsrlVV(_)
+            | VsrlVX(_)
+            | VsrlVI(_)
+            | VsraVV(_)
+            | VsraVX(_)
+            | VsraVI(_)
+            | VminuVV(_)
+            | VminuVX(_)
+            | VminVV(_)
+            | VminVX(_)
+            | VmaxuVV(_)
+            | VmaxuVX(_)
+            | VmaxVV(_)
+            | VmaxVX(_)
+            | VmulVV(_)
+            | VmulVX(_)
+            | VmvVV(_)
+            | VmvVX(_)
+            | VmvVI(_)
+            | VmvXS(_)
+            | VmvSX(_)
+            | CboZero(_)
             | Csrrw(_)
             | Csrrs(_)
             | Csrrc(_)
@@ -1220,6 +1717,65 @@ macro_rules! csri_instr {
     };
 }
 
+macro_rules! vv_instr {
+    ($f:expr, $op:expr, $args:expr) => {
+        write!($f, "{} {},{},{}", $op, $args.vd, $args.vs2, $args.vs1)
+    };
+}
+
+macro_rules! vx_instr {
+    ($f:expr, $op:expr, $args:expr) => {
+        write!($f, "{} {},{},{}", $op, $args.vd, $args.vs2, $args.rs1)
+    };
+}
+
+macro_rules! vi_instr {
+    ($f:expr, $op:expr, $args:expr) => {
+        write!($f, "{} {},{},{}", $op, $args.vd, $args.vs2, $args.imm)
+    };
+}
+
+macro_rules! v_mv_instr {
+    ($f:expr, $op:expr, $dst:expr, $src:expr) => {
+        write!($f, "{} {},{}", $op, $dst, $src)
+    };
+}
+
+macro_rules! v_mem_instr {
+    ($f:expr, $op:expr, $vreg:expr, $args:expr) => {
+        write!($f, "{} {},({})", $op, $vreg, $args.rs1)
+    };
+}
+
+/// Assembly syntax of the `vtypei` immediate of `vset{i}vli`, e.g. `e32,m2,ta,mu`
+struct VTypeI(i64);
+
+impl fmt::Display for VTypeI {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(vtype) = VType::decode(self.0 as u64) else {
+            return write!(f, "0x{:x}", self.0);
+        };
+
+        write!(f, "e{},", vtype.sew.bits())?;
+        if vtype.lmul_log2 < 0 {
+            write!(f, "mf{},", 1 << -vtype.lmul_log2)?;
+        } else {
+            write!(f, "m{},", 1 << vtype.lmul_log2)?;
+        }
+        let tail = if vtype.raw & (1 << 6) != 0 {
+            "ta"
+        } else {
+            "tu"
+        };
+        let mask = if vtype.raw & (1 << 7) != 0 {
+            "ma"
+        } else {
+            "mu"
+        };
+        write!(f, "{tail},{mask}")
+    }
+}
+
 impl fmt::Display for FenceSet {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut out = String::new();
@@ -1352,6 +1908,11 @@ impl fmt::Display for InstrCacheable {
             Amominud(args) => amo_instr!(f, "amominu.d", args),
             Amomaxud(args) => amo_instr!(f, "amomaxu.d", args),
 
+            // Zacas atomic compare-and-swap instructions
+            Amocasw(args) => amo_instr!(f, "amocas.w", args),
+            Amocasd(args) => amo_instr!(f, "amocas.d", args),
+            Amocasq(args) => amo_instr!(f, "amocas.q", args),
+
             // RV64M multiplication and division instructions
             Rem(args) => r_instr!(f, "rem", args),
             Remu(args) => r_instr!(f, "remu", args),
@@ -1367,6 +1928,72 @@ impl fmt::Display for InstrCacheable {
             Mulhu(args) => r_instr!(f, "mulhu", args),
             Mulw(args) => r_instr!(f, "mulw", args),
 
+            // Zba address generation instructions
+            Sh1add(args) => r_instr!(f, "sh1add", args),
+            Sh2add(args) => r_instr!(f, "sh2add", args),
+            Sh3add(args) => r_instr!(f, "sh3add", args),
+            AddUw(args) => r_instr!(f, "add.uw", args),
+            Sh1addUw(args) => r_instr!(f, "sh1add.uw", args),
+            Sh2addUw(args) => r_instr!(f, "sh2add.uw", args),
+            Sh3addUw(args) => r_instr!(f, "sh3add.uw", args),
+            SlliUw(args) => i_instr_hex!(f, "slli.uw", args),
+
+            // Zbb basic bit-manipulation instructions
+            Andn(args) => r_instr!(f, "andn", args),
+            Orn(args) => r_instr!(f, "orn", args),
+            Xnor(args) => r_instr!(f, "xnor", args),
+            Clz(args) => r2_instr!(f, "clz", args),
+            Clzw(args) => r2_instr!(f, "clzw", args),
+            Ctz(args) => r2_instr!(f, "ctz", args),
+            Ctzw(args) => r2_instr!(f, "ctzw", args),
+            Cpop(args) => r2_instr!(f, "cpop", args),
+            Cpopw(args) => r2_instr!(f, "cpopw", args),
+            Max(args) => r_instr!(f, "max", args),
+            Maxu(args) => r_instr!(f, "maxu", args),
+            Min(args) => r_instr!(f, "min", args),
+            Minu(args) => r_instr!(f, "minu", args),
+            SextB(args) => r2_instr!(f, "sext.b", args),
+            SextH(args) => r2_instr!(f, "sext.h", args),
+            ZextH(args) => r2_instr!(f, "zext.h", args),
+            Rol(args) => r_instr!(f, "rol", args),
+            Rolw(args) => r_instr!(f, "rolw", args),
+            Ror(args) => r_instr!(f, "ror", args),
+            Rori(args) => i_instr_hex!(f, "rori", args),
+            Roriw(args) => i_instr_hex!(f, "roriw", args),
+            Rorw(args) => r_instr!(f, "rorw", args),
+            OrcB(args) => r2_instr!(f, "orc.b", args),
+            Rev8(args) => r2_instr!(f, "rev8", args),
+
+            // Zbs single-bit instructions
+            Bclr(args) => r_instr!(f, "bclr", args),
+            Bclri(args) => i_instr_hex!(f, "bclri", args),
+            Bext(args) => r_instr!(f, "bext", args),
+            Bexti(args) => i_instr_hex!(f, "bexti", args),
+            Binv(args) => r_instr!(f, "binv", args),
+            Binvi(args) => i_instr_hex!(f, "binvi", args),
+            Bset(args) => r_instr!(f, "bset", args),
+            Bseti(args) => i_instr_hex!(f, "bseti", args),
+
+            // Zbkb bit-manipulation instructions for cryptography
+            Pack(args) => r_instr!(f, "pack", args),
+            Packh(args) => r_instr!(f, "packh", args),
+            Packw(args) => r_instr!(f, "packw", args),
+            Brev8(args) => r2_instr!(f, "brev8", args),
+
+            // Zknh SHA-2 hash function instructions
+            Sha256sum0(args) => r2_instr!(f, "sha256sum0", args),
+            Sha256sum1(args) => r2_instr!(f, "sha256sum1", args),
+            Sha256sig0(args) => r2_instr!(f, "sha256sig0", args),
+            Sha256sig1(args) => r2_instr!(f, "sha256sig1", args),
+            Sha512sum0(args) => r2_instr!(f, "sha512sum0", args),
+            Sha512sum1(args) => r2_instr!(f, "sha512sum1", args),
+            Sha512sig0(args) => r2_instr!(f, "sha512sig0", args),
+            Sha512sig1(args) => r2_instr!(f, "sha512sig1", args),
+
+            // Zicond conditional-zero instructions
+            CzeroEqz(args) => r_instr!(f, "czero.eqz", args),
+            CzeroNez(args) => r_instr!(f, "czero.nez", args),
+
             // RV64F instructions
             FclassS(args) => f_s1_instr!(f, "fclass.s", args),
             Feqs(args) => r_instr!(f, "feq.s", args),
@@ -1433,6 +2060,110 @@ impl fmt::Display for InstrCacheable {
             FmvXD(args) => f_s1_instr!(f, "fmv.x.d", args),
             FmvDX(args) => f_s1_instr!(f, "fmv.d.x", args),
 
+            // Zfh half-precision instructions
+            FclassH(args) => f_s1_instr!(f, "fclass.h", args),
+            Feqh(args) => r_instr!(f, "feq.h", args),
+            Fleh(args) => r_instr!(f, "fle.h", args),
+            Flth(args) => r_instr!(f, "flt.h", args),
+            Faddh(args) => r_instr!(f, "fadd.h", args),
+            Fsubh(args) => r_instr!(f, "fsub.h", args),
+            Fmulh(args) => r_instr!(f, "fmul.h", args),
+            Fdivh(args) => r_instr!(f, "fdiv.h", args),
+            Fsqrth(args) => r2_instr!(f, "fsqrt.h", args),
+            Fminh(args) => r_instr!(f, "fmin.h", args),
+            Fmaxh(args) => r_instr!(f, "fmax.h", args),
+            Fmaddh(args) => r4_instr!(f, "fmadd.h", args),
+            Fmsubh(args) => r4_instr!(f, "fmsub.h", args),
+            Fnmsubh(args) => r4_instr!(f, "fnmsub.h", args),
+            Fnmaddh(args) => r4_instr!(f, "fnmadd.h", args),
+            Flh(args) => i_instr_load!(f, "flh", args),
+            Fsh(args) => s_instr!(f, "fsh", args),
+            Fcvthw(args) => f_s1_instr!(f, "fcvt.h.w", args),
+            Fcvthwu(args) => f_s1_instr!(f, "fcvt.h.wu", args),
+            Fcvthl(args) => f_s1_instr!(f, "fcvt.h.l", args),
+            Fcvthlu(args) => f_s1_instr!(f, "fcvt.h.lu", args),
+            Fcvtwh(args) => f_r1_rm_instr!(f, "fcvt.w.h", args),
+            Fcvtwuh(args) => f_r1_rm_instr!(f, "fcvt.wu.h", args),
+            Fcvtlh(args) => f_r1_rm_instr!(f, "fcvt.l.h", args),
+            Fcvtluh(args) => f_r1_rm_instr!(f, "fcvt.lu.h", args),
+            Fcvtsh(args) => r2_instr!(f, "fcvt.s.h", args),
+            Fcvths(args) => r2_instr!(f, "fcvt.h.s", args),
+            Fcvtdh(args) => r2_instr!(f, "fcvt.d.h", args),
+            Fcvthd(args) => r2_instr!(f, "fcvt.h.d", args),
+            Fsgnjh(args) => r_instr!(f, "fsgnj.h", args),
+            Fsgnjnh(args) => r_instr!(f, "fsgnjn.h", args),
+            Fsgnjxh(args) => r_instr!(f, "fsgnjx.h", args),
+            FmvXH(args) => f_s1_instr!(f, "fmv.x.h", args),
+            FmvHX(args) => f_s1_instr!(f, "fmv.h.x", args),
+
+            // Zve64x vector instructions
+            Vsetvli(args) => write!(
+                f,
+                "vsetvli {},{},{}",
+                args.rd,
+                args.rs1,
+                VTypeI(args.vtypei)
+            ),
+            Vsetivli(args) => write!(
+                f,
+                "vsetivli {},{},{}",
+                args.rd,
+                args.uimm,
+                VTypeI(args.vtypei)
+            ),
+            Vsetvl(args) => r_instr!(f, "vsetvl", args),
+            Vle8(args) => v_mem_instr!(f, "vle8.v", args.vd, args),
+            Vle16(args) => v_mem_instr!(f, "vle16.v", args.vd, args),
+            Vle32(args) => v_mem_instr!(f, "vle32.v", args.vd, args),
+            Vle64(args) => v_mem_instr!(f, "vle64.v", args.vd, args),
+            Vse8(args) => v_mem_instr!(f, "vse8.v", args.vs3, args),
+            Vse16(args) => v_mem_instr!(f, "vse16.v", args.vs3, args),
+            Vse32(args) => v_mem_instr!(f, "vse32.v", args.vs3, args),
+            Vse64(args) => v_mem_instr!(f, "vse64.v", args.vs3, args),
+            VaddVV(args) => vv_instr!(f, "vadd.vv", args),
+            VaddVX(args) => vx_instr!(f, "vadd.vx", args),
+            VaddVI(args) => vi_instr!(f, "vadd.vi", args),
+            VsubVV(args) => vv_instr!(f, "vsub.vv", args),
+            VsubVX(args) => vx_instr!(f, "vsub.vx", args),
+            VrsubVX(args) => vx_instr!(f, "vrsub.vx", args),
+            VrsubVI(args) => vi_instr!(f, "vrsub.vi", args),
+            VandVV(args) => vv_instr!(f, "vand.vv", args),
+            VandVX(args) => vx_instr!(f, "vand.vx", args),
+            VandVI(args) => vi_instr!(f, "vand.vi", args),
+            VorVV(args) => vv_instr!(f, "vor.vv", args),
+            VorVX(args) => vx_instr!(f, "vor.vx", args),
+            VorVI(args) => vi_instr!(f, "vor.vi", args),
+            VxorVV(args) => vv_instr!(f, "vxor.vv", args),
+            VxorVX(args) => vx_instr!(f, "vxor.vx", args),
+            VxorVI(args) => vi_instr!(f, "vxor.vi", args),
+            VsllVV(args) => vv_instr!(f, "vsll.vv", args),
+            VsllVX(args) => vx_instr!(f, "vsll.vx", args),
+            VsllVI(args) => vi_instr!(f, "vsll.vi", args),
+            VsrlVV(args) => vv_instr!(f, "vsrl.vv", args),
+            VsrlVX(args) => vx_instr!(f, "vsrl.vx", args),
+            VsrlVI(args) => vi_instr!(f, "vsrl.vi", args),
+            VsraVV(args) => vv_instr!(f, "vsra.vv", args),
+            VsraVX(args) => vx_instr!(f, "vsra.vx", args),
+            VsraVI(args) => vi_instr!(f, "vsra.vi", args),
+            VminuVV(args) => vv_instr!(f, "vminu.vv", args),
+            VminuVX(args) => vx_instr!(f, "vminu.vx", args),
+            VminVV(args) => vv_instr!(f, "vmin.vv", args),
+            VminVX(args) => vx_instr!(f, "vmin.vx", args),
+            VmaxuVV(args) => vv_instr!(f, "vmaxu.vv", args),
+            VmaxuVX(args) => vx_instr!(f, "vmaxu.vx", args),
+            VmaxVV(args) => vv_instr!(f, "vmax.vv", args),
+            VmaxVX(args) => vx_instr!(f, "vmax.vx", args),
+            VmulVV(args) => vv_instr!(f, "vmul.vv", args),
+            VmulVX(args) => vx_instr!(f, "vmul.vx", args),
+            VmvVV(args) => v_mv_instr!(f, "vmv.v.v", args.vd, args.vs1),
+            VmvVX(args) => v_mv_instr!(f, "vmv.v.x", args.vd, args.rs1),
+            VmvVI(args) => v_mv_instr!(f, "vmv.v.i", args.vd, args.imm),
+            VmvXS(args) => v_mv_instr!(f, "vmv.x.s", args.rd, args.vs2),
+            VmvSX(args) => v_mv_instr!(f, "vmv.s.x", args.vd, args.rs1),
+
+            // Zicboz cache-block zeroing
+            CboZero(args) => write!(f, "cbo.zero ({})", args.rs1),
+
             // Zicsr instructions
             Csrrw(args) => csr_instr!(f, "csrrw", args),
             Csrrs(args) => csr_instr!(f, "csrrs", args),
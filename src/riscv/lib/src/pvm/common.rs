@@ -514,6 +514,7 @@ where
 #[cfg(test)]
 mod tests {
     use std::mem;
+    use std::num::NonZeroUsize;
 
     use proptest::proptest;
     use rand::Fill;
@@ -530,14 +531,19 @@ mod tests {
     use crate::machine_state::memory;
     use crate::machine_state::memory::M1M;
     use crate::machine_state::memory::Memory;
+    use crate::machine_state::memory::MemoryConfig;
+    use crate::machine_state::memory::Permissions;
     use crate::machine_state::registers::a0;
     use crate::machine_state::registers::a1;
     use crate::machine_state::registers::a2;
     use crate::machine_state::registers::a3;
+    use crate::machine_state::registers::a4;
+    use crate::machine_state::registers::a5;
     use crate::machine_state::registers::a6;
     use crate::machine_state::registers::a7;
     use crate::pvm::common::tests::memory::Address;
     use crate::pvm::linux;
+    use crate::state_backend::hash::with_hashing_threads;
     use crate::state_backend::owned_backend::Owned;
     use crate::state_backend::test_helpers::TestBackendFactory;
 
@@ -851,4 +857,159 @@ mod tests {
         // Reveal data returned correctly
         assert_eq!(reveal_result_buffer, reveal_data[..OUTPUT_BUFFER_SIZE]);
     });
+
+    #[test]
+    fn test_atomic_compare_and_swap_proofs() {
+        type MC = M1M;
+        type B = block::Interpreted<MC, Owned>;
+        type VerifierPvm = Pvm<MC, TestCacheConfig, block::Interpreted<MC, Verifier>, Verifier>;
+
+        const CODE: [u32; 3] = [
+            0x28B6252F, // AMOCAS.W a0, a1, (a2)
+            0x2EB6352F, // AMOCAS.D.AQRL a0, a1, (a2)
+            0x2906472F, // AMOCAS.Q a4, a6, (a2)
+        ];
+
+        // Setup PVM
+        let mut pvm = Pvm::<MC, TestCacheConfig, B, _>::new(&mut Owned, InterpretedBlockBuilder);
+        pvm.reset();
+        pvm.machine_state
+            .core
+            .main_memory
+            .protect_pages(0, MC::TOTAL_BYTES, Permissions::READ_WRITE_EXEC)
+            .unwrap();
+
+        let code_addr = memory::FIRST_ADDRESS;
+        let data_addr = code_addr + 0x100;
+
+        for (i, instr) in CODE.into_iter().enumerate() {
+            pvm.machine_state
+                .core
+                .main_memory
+                .write_instruction_unchecked(code_addr + 4 * i as u64, instr)
+                .unwrap();
+        }
+        pvm.machine_state.core.hart.pc.write(code_addr);
+
+        pvm.machine_state
+            .core
+            .main_memory
+            .write_all(data_addr, &[
+                0x1122_3344_5566_7788u64,
+                0x99AA_BBCC_DDEE_FF00,
+            ])
+            .unwrap();
+
+        let xregisters = &mut pvm.machine_state.core.hart.xregisters;
+
+        // AMOCAS.W succeeds, AMOCAS.D then fails as the low word has changed
+        xregisters.write(a0, 0x5566_7788);
+        xregisters.write(a1, 0xDEAD_BEEF);
+        xregisters.write(a2, data_addr);
+
+        // AMOCAS.Q succeeds on the quad-word left behind by AMOCAS.W
+        xregisters.write(a4, 0x1122_3344_DEAD_BEEF);
+        xregisters.write(a5, 0x99AA_BBCC_DDEE_FF00);
+        xregisters.write(a6, 0x0123_4567_89AB_CDEF);
+        xregisters.write(a7, 0xFEDC_BA98_7654_3210);
+
+        for _ in CODE {
+            let proof = {
+                let mut proof_pvm = pvm.start_proof();
+                proof_pvm.eval_one(&mut PvmHooks::none());
+                proof_pvm.to_proof().unwrap()
+            };
+
+            pvm.eval_one(&mut PvmHooks::none());
+            assert_eq!(proof.final_state_hash(), pvm.hash().unwrap());
+
+            // The proof is sufficient to replay the step and recompute the final state hash
+            let mut verifier_pvm =
+                VerifierPvm::from_proof(proof.tree(), InterpretedBlockBuilder).unwrap();
+            verifier_pvm.eval_one(&mut PvmHooks::none());
+
+            let refs = verifier_pvm.struct_ref::<FnManagerIdent>();
+            let final_hash = PvmLayout::<MC, TestCacheConfig>::partial_state_hash(
+                refs,
+                ProofTree::Present(proof.tree()),
+            )
+            .unwrap();
+            assert_eq!(final_hash, proof.final_state_hash());
+        }
+
+        // No instruction raised an exception
+        assert_eq!(pvm.machine_state.core.hart.pc.read(), code_addr + 12);
+
+        let mut data = [0u64; 2];
+        pvm.machine_state
+            .core
+            .main_memory
+            .read_all(data_addr, &mut data)
+            .unwrap();
+        assert_eq!(data, [0x0123_4567_89AB_CDEF, 0xFEDC_BA98_7654_3210]);
+        assert_eq!(
+            pvm.machine_state.core.hart.xregisters.read(a0),
+            0x1122_3344_DEAD_BEEF
+        );
+    }
+
+    #[test]
+    fn test_hashing_threads_identical_hashes() {
+        type MC = M1M;
+        type B = block::Interpreted<MC, Owned>;
+
+        /// PVM whose memory holds a dense run of written pages, followed by sparse ones
+        fn make_pvm() -> Pvm<MC, TestCacheConfig, B, Owned> {
+            let mut pvm =
+                Pvm::<MC, TestCacheConfig, B, _>::new(&mut Owned, InterpretedBlockBuilder);
+            pvm.machine_state
+                .core
+                .main_memory
+                .set_all_readable_writeable();
+
+            let page_size = memory::PAGE_SIZE.get();
+            let pages = MC::TOTAL_BYTES as u64 / page_size;
+
+            for page in (0..pages * 5 / 8).chain((pages * 5 / 8..pages).step_by(7)) {
+                let words = (0..page_size / 8)
+                    .map(|word| (page * page_size + word).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+                    .collect::<Vec<u64>>();
+                pvm.machine_state
+                    .core
+                    .main_memory
+                    .write_all(page * page_size, &words)
+                    .unwrap();
+            }
+
+            pvm
+        }
+
+        let hashes = |threads| {
+            with_hashing_threads(threads, || {
+                // A fresh state, so that none of its memory's Merkle hashes are cached yet
+                let pvm = make_pvm();
+                let cached_hash = pvm.hash().unwrap();
+
+                // Proof-generating states do not cache Merkle hashes
+                let proof_pvm = pvm.start_proof();
+                let refs = proof_pvm.struct_ref::<FnManagerIdent>();
+                let uncached_hash = PvmLayout::<MC, TestCacheConfig>::state_hash(refs).unwrap();
+
+                let proof_pvm = pvm.start_proof();
+                let refs = proof_pvm.struct_ref::<FnManagerIdent>();
+                let tree = PvmLayout::<MC, TestCacheConfig>::to_merkle_tree(refs).unwrap();
+
+                (cached_hash, uncached_hash, tree.root_hash())
+            })
+        };
+
+        let sequential = hashes(NonZeroUsize::MIN);
+        assert_eq!(sequential.0, sequential.1);
+        assert_eq!(sequential.0, sequential.2);
+
+        for threads in [2, 4, 8] {
+            let parallel = hashes(NonZeroUsize::new(threads).unwrap());
+            assert_eq!(parallel, sequential, "Hashes differ with {threads} threads");
+        }
+    }
 }
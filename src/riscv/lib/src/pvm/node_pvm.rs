@@ -4,16 +4,30 @@
 // SPDX-License-Identifier: MIT
 
 use std::fmt;
+use std::io::Read;
+use std::num::NonZeroUsize;
 use std::ops::Bound;
 use std::path::Path;
 
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::de;
 use thiserror::Error;
 
 use super::Pvm;
 use super::PvmLayout;
+use crate::dispatch_memory_size;
 use crate::machine_state::block_cache::TestCacheConfig;
 use crate::machine_state::block_cache::block::Interpreted;
 use crate::machine_state::block_cache::block::InterpretedBlockBuilder;
+use crate::machine_state::memory::M1G;
+use crate::machine_state::memory::M1M;
+use crate::machine_state::memory::M4G;
+use crate::machine_state::memory::M4K;
+use crate::machine_state::memory::M8K;
+use crate::machine_state::memory::M64M;
+use crate::machine_state::memory::MemoryConfig;
+use crate::machine_state::memory::MemorySize;
 use crate::program::Program;
 use crate::pvm::common::PvmHooks;
 use crate::pvm::common::PvmInput;
@@ -24,12 +38,14 @@ use crate::state_backend::AllocatedOf;
 use crate::state_backend::FnManagerIdent;
 use crate::state_backend::ProofLayout;
 use crate::state_backend::ProofTree;
+use crate::state_backend::hash;
 use crate::state_backend::owned_backend::Owned;
 use crate::state_backend::proof_backend::proof::Proof;
 use crate::state_backend::verify_backend::Verifier;
 use crate::storage;
 use crate::storage::Hash;
 use crate::storage::Repo;
+use crate::storage::binary;
 
 #[derive(Error, Debug)]
 pub enum PvmError {
@@ -37,60 +53,144 @@ pub enum PvmError {
     SerializationError(String),
 }
 
-type NodePvmMemConfig = crate::machine_state::memory::M64M;
+/// Memory size of the PVM state unless specified otherwise
+pub const DEFAULT_MEMORY_SIZE: MemorySize = MemorySize::M64M;
 
-type NodePvmLayout = PvmLayout<NodePvmMemConfig, TestCacheConfig>;
+/// Memory configuration for [`DEFAULT_MEMORY_SIZE`]
+type DefaultMemoryConfig = M64M;
 
-type NodePvmState<M> = Pvm<NodePvmMemConfig, TestCacheConfig, Interpreted<NodePvmMemConfig, M>, M>;
+const _: () = assert!(
+    DEFAULT_MEMORY_SIZE.total_bytes() == <DefaultMemoryConfig as MemoryConfig>::TOTAL_BYTES
+);
+
+type NodePvmLayout<MC> = PvmLayout<MC, TestCacheConfig>;
+
+type SizedNodePvmState<MC, M> = Pvm<MC, TestCacheConfig, Interpreted<MC, M>, M>;
+
+/// PVM state for any of the supported [`MemorySize`]s
+enum NodePvmState<M: state_backend::ManagerBase> {
+    M4K(Box<SizedNodePvmState<M4K, M>>),
+    M8K(Box<SizedNodePvmState<M8K, M>>),
+    M1M(Box<SizedNodePvmState<M1M, M>>),
+    M64M(Box<SizedNodePvmState<M64M, M>>),
+    M1G(Box<SizedNodePvmState<M1G, M>>),
+    M4G(Box<SizedNodePvmState<M4G, M>>),
+}
+
+/// Generates the conversion of a [`SizedNodePvmState`] into a [`NodePvmState`].
+macro_rules! impl_from_sized_state {
+    ($($size:ident),*) => {
+        $(
+            impl<M: state_backend::ManagerBase> From<SizedNodePvmState<$size, M>>
+                for NodePvmState<M>
+            {
+                fn from(state: SizedNodePvmState<$size, M>) -> Self {
+                    Self::$size(Box::new(state))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_sized_state!(M4K, M8K, M1M, M64M, M1G, M4G);
+
+/// Evaluate an expression with `$pvm` bound to the PVM state inside a [`NodePvmState`], whatever
+/// its memory size.
+macro_rules! with_pvm {
+    ($state:expr, $pvm:ident => $body:expr) => {
+        match $state {
+            NodePvmState::M4K($pvm) => $body,
+            NodePvmState::M8K($pvm) => $body,
+            NodePvmState::M1M($pvm) => $body,
+            NodePvmState::M64M($pvm) => $body,
+            NodePvmState::M1G($pvm) => $body,
+            NodePvmState::M4G($pvm) => $body,
+        }
+    };
+}
+
+impl<M: state_backend::ManagerBase> NodePvmState<M> {
+    /// Memory size of the PVM state
+    fn memory_size(&self) -> MemorySize {
+        match self {
+            Self::M4K(_) => MemorySize::M4K,
+            Self::M8K(_) => MemorySize::M8K,
+            Self::M1M(_) => MemorySize::M1M,
+            Self::M64M(_) => MemorySize::M64M,
+            Self::M1G(_) => MemorySize::M1G,
+            Self::M4G(_) => MemorySize::M4G,
+        }
+    }
+}
 
 pub struct NodePvm<M: state_backend::ManagerBase = Owned> {
-    state: Box<NodePvmState<M>>,
+    state: NodePvmState<M>,
+
+    /// Number of threads hashing the state, see [`NodePvm::set_hashing_threads`]
+    hashing_threads: NonZeroUsize,
 }
 
 impl<M: state_backend::ManagerBase> NodePvm<M> {
-    pub fn bind(space: AllocatedOf<NodePvmLayout, M>) -> Self
-    where
-        M::ManagerRoot: state_backend::ManagerReadWrite,
-    {
-        let state = NodePvmState::<M>::bind(space, InterpretedBlockBuilder);
+    fn from_state(state: NodePvmState<M>) -> Self {
         Self {
-            state: Box::new(state),
+            state,
+            hashing_threads: NonZeroUsize::MIN,
         }
     }
 
-    fn with_backend_mut<T, F>(&mut self, f: F) -> T
+    /// Allocate a new PVM state with the given memory size.
+    pub fn new_with_memory_size(manager: &mut M, memory_size: MemorySize) -> Self
     where
-        F: FnOnce(&mut NodePvmState<M>) -> T,
+        M: state_backend::ManagerAlloc,
     {
-        f(&mut self.state)
+        let state = dispatch_memory_size!(memory_size, MC => {
+            SizedNodePvmState::<MC, M>::new(manager, InterpretedBlockBuilder).into()
+        });
+        Self::from_state(state)
     }
 
-    fn with_backend<T, F>(&self, f: F) -> T
+    /// Bind the allocated space of a PVM state with the [default memory size].
+    ///
+    /// [default memory size]: DEFAULT_MEMORY_SIZE
+    pub fn bind(space: AllocatedOf<NodePvmLayout<DefaultMemoryConfig>, M>) -> Self
     where
-        F: FnOnce(&NodePvmState<M>) -> T,
+        M::ManagerRoot: state_backend::ManagerReadWrite,
     {
-        f(&self.state)
+        let state =
+            SizedNodePvmState::<DefaultMemoryConfig, M>::bind(space, InterpretedBlockBuilder);
+        Self::from_state(state.into())
+    }
+
+    /// Hash the PVM state, and produce proofs of it, using up to `threads` threads. Hashing is
+    /// sequential by default. Hashes are identical regardless of the number of threads.
+    pub fn set_hashing_threads(&mut self, threads: NonZeroUsize) {
+        self.hashing_threads = threads;
+    }
+
+    /// Memory size used by the PVM state
+    pub fn memory_size(&self) -> MemorySize {
+        self.state.memory_size()
     }
 
     pub fn get_status(&self) -> PvmStatus
     where
         M: state_backend::ManagerRead,
     {
-        self.with_backend(|pvm| pvm.status())
+        with_pvm!(&self.state, pvm => pvm.status())
     }
 
     pub fn get_tick(&self) -> u64
     where
         M: state_backend::ManagerRead,
     {
-        self.with_backend(|pvm| pvm.tick.read())
+        with_pvm!(&self.state, pvm => pvm.tick.read())
     }
 
     pub fn get_current_level(&self) -> Option<u32>
     where
         M: state_backend::ManagerRead,
     {
-        self.with_backend(|pvm| {
+        with_pvm!(&self.state, pvm => {
             if pvm.level_is_set.read() {
                 Some(pvm.level.read())
             } else {
@@ -103,7 +203,7 @@ impl<M: state_backend::ManagerBase> NodePvm<M> {
     where
         M: state_backend::ManagerRead,
     {
-        self.with_backend(|pvm| pvm.message_counter.read())
+        with_pvm!(&self.state, pvm => pvm.message_counter.read())
     }
 
     /// Get the reveal request from the PVM state
@@ -111,14 +211,14 @@ impl<M: state_backend::ManagerBase> NodePvm<M> {
     where
         M: state_backend::ManagerRead,
     {
-        self.with_backend(|pvm| pvm.reveal_request())
+        with_pvm!(&self.state, pvm => pvm.reveal_request())
     }
 
     pub fn install_boot_sector(&mut self, kernel: &[u8])
     where
         M: state_backend::ManagerReadWrite,
     {
-        self.with_backend_mut(|pvm| {
+        with_pvm!(&mut self.state, pvm => {
             let program = Program::from_elf(kernel).unwrap();
             pvm.setup_linux_process(&program).unwrap()
         })
@@ -128,21 +228,22 @@ impl<M: state_backend::ManagerBase> NodePvm<M> {
     where
         M: state_backend::ManagerReadWrite,
     {
-        self.with_backend_mut(|pvm| pvm.eval_one(pvm_hooks))
+        with_pvm!(&mut self.state, pvm => pvm.eval_one(pvm_hooks))
     }
 
     pub fn compute_step_many(&mut self, pvm_hooks: &mut PvmHooks, max_steps: usize) -> i64
     where
         M: state_backend::ManagerReadWrite,
     {
-        self.with_backend_mut(|pvm| pvm.eval_max(pvm_hooks, Bound::Included(max_steps))) as i64
+        with_pvm!(&mut self.state, pvm => pvm.eval_max(pvm_hooks, Bound::Included(max_steps)))
+            as i64
     }
 
     pub fn set_input(&mut self, input: PvmInput) -> bool
     where
         M: state_backend::ManagerReadWrite,
     {
-        self.with_backend_mut(|pvm| pvm.provide_input(input))
+        with_pvm!(&mut self.state, pvm => pvm.provide_input(input))
     }
 
     /// Only used by the rollup node in "loser mode" in order to test
@@ -154,21 +255,29 @@ impl<M: state_backend::ManagerBase> NodePvm<M> {
     where
         M: state_backend::ManagerReadWrite,
     {
-        self.with_backend_mut(|pvm| {
-            pvm.insert_failure();
-        })
+        with_pvm!(&mut self.state, pvm => pvm.insert_failure())
     }
 }
 
 impl NodePvm {
-    /// Construct an empty PVM state.
+    /// Construct an empty PVM state with the [default memory size].
+    ///
+    /// [default memory size]: DEFAULT_MEMORY_SIZE
     pub fn empty() -> Self {
         Self::new(&mut Owned)
     }
 
+    /// Construct an empty PVM state with the given memory size.
+    pub fn empty_with_memory_size(memory_size: MemorySize) -> Self {
+        Self::new_with_memory_size(&mut Owned, memory_size)
+    }
+
     /// Compute the root hash of the PVM state.
     pub fn hash(&self) -> Hash {
-        self.with_backend(|pvm| pvm.hash().unwrap())
+        hash::with_hashing_threads(
+            self.hashing_threads,
+            || with_pvm!(&self.state, pvm => pvm.hash().unwrap()),
+        )
     }
 
     /// Produce the Merkle proof corresponding to the next step of the PVM.
@@ -178,36 +287,56 @@ impl NodePvm {
         input: Option<PvmInput>,
         pvm_hooks: &mut PvmHooks,
     ) -> Option<Proof> {
-        let mut proof_state = self.state.start_proof();
+        with_pvm!(&self.state, pvm => {
+            let mut proof_state = pvm.start_proof();
 
-        match input {
-            None => proof_state.eval_one(pvm_hooks),
-            Some(input) => {
-                if !proof_state.provide_input(input) {
-                    return None;
+            match input {
+                None => proof_state.eval_one(pvm_hooks),
+                Some(input) => {
+                    if !proof_state.provide_input(input) {
+                        return None;
+                    }
                 }
             }
-        }
 
-        let proof = proof_state.to_proof().ok()?;
-        Some(proof)
+            let proof = hash::with_hashing_threads(self.hashing_threads, || proof_state.to_proof());
+            proof.ok()
+        })
     }
 }
 
 impl NodePvm<Verifier> {
-    /// Verify the proof with the given input. Upon success, return the input
-    /// request which corresponds to the initial state of the proof.
+    /// Verify the proof, produced by a PVM with the [default memory size], with the given input.
+    /// Upon success, return the input request which corresponds to the initial state of the proof.
+    ///
+    /// [default memory size]: DEFAULT_MEMORY_SIZE
     pub fn verify_proof(
         proof: &Proof,
         input: Option<PvmInput>,
         pvm_hooks: &mut PvmHooks,
+    ) -> Option<()> {
+        Self::verify_proof_with_memory_size(proof, DEFAULT_MEMORY_SIZE, input, pvm_hooks)
+    }
+
+    /// Verify the proof, produced by a PVM of the given memory size, with the given input. Upon
+    /// success, return the input request which corresponds to the initial state of the proof.
+    ///
+    /// Proofs do not record the memory size of the PVM they were produced by. The caller is
+    /// responsible for supplying it: the memory size a rollup runs with is fixed for the lifetime
+    /// of the rollup, and known to whoever refutes or verifies its commitments. Verifying against
+    /// a different memory size fails, as the layout of the state differs.
+    pub fn verify_proof_with_memory_size(
+        proof: &Proof,
+        memory_size: MemorySize,
+        input: Option<PvmInput>,
+        pvm_hooks: &mut PvmHooks,
     ) -> Option<()> {
         let proof_tree = proof.tree();
-        let mut pvm = Pvm::from_proof(proof_tree, InterpretedBlockBuilder).map(|state| Self {
-            state: Box::new(state),
-        })?;
 
-        pvm.with_backend_mut(|pvm| {
+        dispatch_memory_size!(memory_size, MC => {
+            let mut pvm =
+                SizedNodePvmState::<MC, Verifier>::from_proof(proof_tree, InterpretedBlockBuilder)?;
+
             match input {
                 None => pvm.eval_one(pvm_hooks),
                 Some(input) => {
@@ -219,7 +348,8 @@ impl NodePvm<Verifier> {
 
             let refs = pvm.struct_ref::<FnManagerIdent>();
             let final_hash =
-                NodePvmLayout::partial_state_hash(refs, ProofTree::Present(proof_tree)).ok()?;
+                NodePvmLayout::<MC>::partial_state_hash(refs, ProofTree::Present(proof_tree))
+                    .ok()?;
             if final_hash != proof.final_state_hash() {
                 return None;
             }
@@ -232,12 +362,14 @@ impl NodePvm<Verifier> {
 
 impl<M: state_backend::ManagerSerialise> fmt::Debug for NodePvm<M> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let refs = self.state.struct_ref::<state_backend::FnManagerIdent>();
-        let rendered = if f.alternate() {
-            serde_json::to_string_pretty(&refs)
-        } else {
-            serde_json::to_string(&refs)
-        }
+        let rendered = with_pvm!(&self.state, pvm => {
+            let refs = pvm.struct_ref::<state_backend::FnManagerIdent>();
+            if f.alternate() {
+                serde_json::to_string_pretty(&refs)
+            } else {
+                serde_json::to_string(&refs)
+            }
+        })
         .expect("Could not serialize PVM state");
         f.write_str(&rendered)
     }
@@ -246,15 +378,30 @@ impl<M: state_backend::ManagerSerialise> fmt::Debug for NodePvm<M> {
 impl<M: state_backend::ManagerClone> Clone for NodePvm<M> {
     fn clone(&self) -> Self {
         Self {
-            state: self.state.clone(),
+            state: with_pvm!(&self.state, pvm => (**pvm).clone().into()),
+            hashing_threads: self.hashing_threads,
         }
     }
 }
 
 impl PartialEq for NodePvm {
     fn eq(&self, other: &Self) -> bool {
-        self.state.struct_ref::<state_backend::FnManagerIdent>()
-            == other.state.struct_ref::<state_backend::FnManagerIdent>()
+        /// Compare two PVM states, provided they have the same memory size
+        macro_rules! eq_same_size {
+            ($lhs:expr, $rhs:expr; $($size:ident),*) => {
+                match ($lhs, $rhs) {
+                    $(
+                        (NodePvmState::$size(lhs), NodePvmState::$size(rhs)) => {
+                            lhs.struct_ref::<state_backend::FnManagerIdent>()
+                                == rhs.struct_ref::<state_backend::FnManagerIdent>()
+                        }
+                    )*
+                    _ => false,
+                }
+            };
+        }
+
+        eq_same_size!(&self.state, &other.state; M4K, M8K, M1M, M64M, M1G, M4G)
     }
 }
 
@@ -265,9 +412,61 @@ impl<M: state_backend::ManagerBase> NewState<M> for NodePvm<M> {
     where
         M: state_backend::ManagerAlloc,
     {
-        Self {
-            state: Box::new(NodePvmState::<M>::new(manager, InterpretedBlockBuilder)),
+        Self::new_with_memory_size(manager, DEFAULT_MEMORY_SIZE)
+    }
+}
+
+/// Tag at the start of commits of PVM states
+///
+/// Commits consist of the tag, [`COMMIT_VERSION`], the memory size and the PVM state. Commits made
+/// before the memory size could be selected are untagged, and hold a state of the
+/// [`DEFAULT_MEMORY_SIZE`]. These can still be checked out.
+const COMMIT_TAG: [u8; 8] = *b"RVPVMSZ\0";
+
+/// Version of the tagged commit format
+const COMMIT_VERSION: u8 = 1;
+
+/// Owned PVM state as committed by [`PvmStorage`] after the [`COMMIT_TAG`]
+struct TaggedNodePvm(NodePvm);
+
+impl<'de> Deserialize<'de> for TaggedNodePvm {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CommitVisitor;
+
+        impl<'de> de::Visitor<'de> for CommitVisitor {
+            type Value = TaggedNodePvm;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a commit version, a memory size and a PVM state")
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let version: u8 = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+                if version != COMMIT_VERSION {
+                    return Err(de::Error::custom(format!(
+                        "Unsupported commit version {version}"
+                    )));
+                }
+
+                let memory_size: MemorySize = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+                let state = dispatch_memory_size!(memory_size, MC => {
+                    let space: AllocatedOf<NodePvmLayout<MC>, Owned> = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                    SizedNodePvmState::<MC, Owned>::bind(space, InterpretedBlockBuilder).into()
+                });
+
+                Ok(TaggedNodePvm(NodePvm::from_state(state)))
+            }
         }
+
+        deserializer.deserialize_tuple(3, CommitVisitor)
     }
 }
 
@@ -295,18 +494,43 @@ impl PvmStorage {
         self.repo.close()
     }
 
-    /// Create a new commit for `state` and  return the commit id.
+    /// Create a new commit for `state` and  return the commit id. The commit records the memory
+    /// size of the state, see [`COMMIT_TAG`].
     pub fn commit(&mut self, state: &NodePvm) -> Result<Hash, PvmStorageError> {
-        Ok(state.with_backend(|pvm| {
+        let memory_size = state.memory_size();
+        Ok(with_pvm!(&state.state, pvm => {
             let struct_ref = pvm.struct_ref::<state_backend::FnManagerIdent>();
-            self.repo.commit_serialised(&struct_ref)
+            self.repo.commit_serialised(&(COMMIT_TAG, COMMIT_VERSION, memory_size, struct_ref))
         })?)
     }
 
     /// Checkout the PVM state committed under `id`, if the commit exists.
     pub fn checkout(&self, id: &Hash) -> Result<NodePvm, PvmStorageError> {
-        let allocated: AllocatedOf<NodePvmLayout, Owned> = self.repo.checkout_serialised(id)?;
-        Ok(NodePvm::bind(allocated))
+        let mut reader = self.repo.checkout_reader(id)?;
+        let mut tag = [0; COMMIT_TAG.len()];
+
+        if reader.read_exact(&mut tag).is_ok() && tag == COMMIT_TAG {
+            if let Some(pvm) = Self::checkout_tagged(reader) {
+                return Ok(pvm);
+            }
+        }
+
+        // Legacy commits are untagged, and hold a state of the default memory size. They may
+        // still start with the tag, as the serialised state begins with register values.
+        let reader = self.repo.checkout_reader(id)?;
+        let space = binary::deserialise_from(reader).map_err(storage::StorageError::from)?;
+        Ok(NodePvm::bind(space))
+    }
+
+    /// Read the remainder of a commit which started with the [`COMMIT_TAG`].
+    fn checkout_tagged(mut reader: impl Read) -> Option<NodePvm> {
+        let TaggedNodePvm(pvm) = binary::deserialise_from(&mut reader).ok()?;
+
+        // A tagged commit is read entirely. An untagged commit starting with the tag is shorter
+        // than a tagged commit of the default memory size. Serialised states of other memory
+        // sizes differ in length by far more than the tag, which tells the two apart.
+        let mut rest = [0; 1];
+        matches!(reader.read(&mut rest), Ok(0)).then_some(pvm)
     }
 
     /// A snapshot is a new repo to which only `id` has been committed.
@@ -318,3 +542,81 @@ impl PvmStorage {
         Ok(self.repo.export_snapshot(id, path)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::machine_state::registers::ra;
+    use crate::machine_state::registers::sp;
+
+    /// Commit a PVM state the way states were committed before their memory size could be
+    /// selected.
+    fn commit_legacy(repo: &mut Repo, state: &SizedNodePvmState<M64M, Owned>) -> Hash {
+        repo.commit_serialised(&state.struct_ref::<FnManagerIdent>())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_checkout_legacy_commit() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let mut storage = PvmStorage::load(tmp_dir.path()).unwrap();
+
+        let mut legacy = SizedNodePvmState::<M64M, Owned>::new(&mut Owned, InterpretedBlockBuilder);
+        legacy.tick.write(42);
+        let id = commit_legacy(&mut storage.repo, &legacy);
+
+        let pvm = storage.checkout(&id).unwrap();
+        assert_eq!(pvm.memory_size(), DEFAULT_MEMORY_SIZE);
+        assert_eq!(pvm.get_tick(), 42);
+        assert_eq!(pvm.hash(), legacy.hash().unwrap());
+
+        // Committing the state again records its memory size
+        let tagged_id = storage.commit(&pvm).unwrap();
+        assert_ne!(tagged_id, id);
+        let bytes = storage.repo.checkout(&tagged_id).unwrap();
+        assert!(bytes.starts_with(&COMMIT_TAG));
+        assert_eq!(storage.checkout(&tagged_id).unwrap(), pvm);
+    }
+
+    #[test]
+    fn test_checkout_legacy_commit_starting_with_tag() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let mut storage = PvmStorage::load(tmp_dir.path()).unwrap();
+
+        // The serialised state begins with the values of `ra` and `sp`. These read as the tag,
+        // the commit version and the default memory size of a tagged commit.
+        let mut header = [0; 8];
+        let version_and_size = binary::serialise(&(COMMIT_VERSION, DEFAULT_MEMORY_SIZE)).unwrap();
+        header[..version_and_size.len()].copy_from_slice(&version_and_size);
+
+        let mut legacy = SizedNodePvmState::<M64M, Owned>::new(&mut Owned, InterpretedBlockBuilder);
+        let xregisters = &mut legacy.machine_state.core.hart.xregisters;
+        xregisters.write(ra, u64::from_le_bytes(COMMIT_TAG));
+        xregisters.write(sp, u64::from_le_bytes(header));
+        let id = commit_legacy(&mut storage.repo, &legacy);
+        assert!(storage.repo.checkout(&id).unwrap().starts_with(&COMMIT_TAG));
+
+        let pvm = storage.checkout(&id).unwrap();
+        assert_eq!(pvm.memory_size(), DEFAULT_MEMORY_SIZE);
+        assert_eq!(pvm.hash(), legacy.hash().unwrap());
+    }
+
+    #[test]
+    fn test_checkout_tagged_commit() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let mut storage = PvmStorage::load(tmp_dir.path()).unwrap();
+
+        for memory_size in [MemorySize::M1M, DEFAULT_MEMORY_SIZE] {
+            let pvm = NodePvm::empty_with_memory_size(memory_size);
+            let id = storage.commit(&pvm).unwrap();
+
+            let bytes = storage.repo.checkout(&id).unwrap();
+            assert!(bytes.starts_with(&COMMIT_TAG));
+            assert_eq!(bytes[COMMIT_TAG.len()], COMMIT_VERSION);
+
+            let checked_out = storage.checkout(&id).unwrap();
+            assert_eq!(checked_out.memory_size(), memory_size);
+            assert_eq!(checked_out, pvm);
+        }
+    }
+}
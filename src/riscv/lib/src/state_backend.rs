@@ -189,6 +189,29 @@ pub trait ManagerRead: ManagerBase {
         values: &mut [E],
     );
 
+    /// Check whether the `len` bytes at `address` of the region are known to have never been
+    /// written to, in which case they are zero. `address` is in bytes.
+    ///
+    /// Managers that do not track writes return `false`, which makes no claim about the bytes.
+    fn dyn_region_is_untouched<const LEN: usize>(
+        _region: &Self::DynRegion<LEN>,
+        _address: usize,
+        _len: usize,
+    ) -> bool {
+        false
+    }
+
+    /// Compute the root hash of the region as committed to by [`DynArray`], if the manager can do
+    /// so without hashing every leaf - e.g. by caching the hashes of leaves that have not been
+    /// written to since the last call.
+    ///
+    /// Managers that do not cache hashes return `None`, in which case every leaf is hashed.
+    fn dyn_region_merkle_hash<const LEN: usize>(
+        _region: &Self::DynRegion<LEN>,
+    ) -> Option<Result<hash::Hash, hash::HashError>> {
+        None
+    }
+
     /// Read the value contained in the enriched cell.
     fn enriched_cell_read_stored<V>(cell: &Self::EnrichedCell<V>) -> V::E
     where
@@ -365,6 +388,20 @@ impl<M: ManagerRead> ManagerRead for Ref<'_, M> {
         M::dyn_region_read_all(region, address, values)
     }
 
+    fn dyn_region_is_untouched<const LEN: usize>(
+        region: &Self::DynRegion<LEN>,
+        address: usize,
+        len: usize,
+    ) -> bool {
+        M::dyn_region_is_untouched(region, address, len)
+    }
+
+    fn dyn_region_merkle_hash<const LEN: usize>(
+        region: &Self::DynRegion<LEN>,
+    ) -> Option<Result<hash::Hash, hash::HashError>> {
+        M::dyn_region_merkle_hash(region)
+    }
+
     fn enriched_cell_read_stored<V>(cell: &Self::EnrichedCell<V>) -> V::E
     where
         V: EnrichedValue,
@@ -12,10 +12,9 @@ use super::Many;
 use super::hash;
 use super::hash::Hash;
 use super::hash::HashError;
-use super::hash::HashWriter;
 use super::proof_backend::merkle::MERKLE_ARITY;
 use super::proof_backend::merkle::MERKLE_LEAF_SIZE;
-use super::proof_backend::merkle::chunks_to_writer;
+use super::proof_backend::merkle::zero_subtree_hashes;
 use crate::state_backend::hash::build_custom_merkle_hash;
 
 /// [`Layouts`] which may be used for commitments
@@ -52,12 +51,52 @@ where
 
 impl<const LEN: usize> CommitmentLayout for DynArray<LEN> {
     fn state_hash<M: ManagerSerialise>(state: AllocatedOf<Self, M>) -> Result<Hash, HashError> {
-        let mut writer = HashWriter::new(MERKLE_LEAF_SIZE);
-        chunks_to_writer::<LEN, _, _>(&mut writer, |address| {
-            state.read::<[u8; MERKLE_LEAF_SIZE.get()]>(address)
-        })?;
-        let hashes = writer.finalise()?;
-        hash::build_custom_merkle_hash(MERKLE_ARITY, hashes)
+        let leaf_size = MERKLE_LEAF_SIZE.get();
+        assert!(LEN >= leaf_size);
+
+        if let Some(hash) = state.cached_merkle_hash() {
+            return hash;
+        }
+
+        /// Number of leaves read before hashing them
+        const BATCH_LEAVES: usize = 1024;
+
+        let full_leaves = LEN / leaf_size;
+        let mut leaves = vec![None; full_leaves];
+        let mut batch = Vec::with_capacity(BATCH_LEAVES.min(full_leaves));
+
+        // Leaves that have never been written to hold only zeroes. They are neither read nor
+        // hashed, see `build_sparse_merkle_hash`. Other leaves are read in batches, which are
+        // hashed in parallel if enabled, see `hash::with_hashing_threads`.
+        for index in 0..full_leaves {
+            let address = index * leaf_size;
+
+            if !state.is_untouched(address, leaf_size) {
+                batch.push((index, state.read::<[u8; MERKLE_LEAF_SIZE.get()]>(address)));
+            }
+
+            if batch.len() == BATCH_LEAVES || index + 1 == full_leaves {
+                let hashes =
+                    hash::map_chunks(&batch, 1, |leaf| Hash::blake2b_hash_bytes(&leaf[0].1))?;
+
+                for ((index, _), hash) in batch.drain(..).zip(hashes) {
+                    leaves[index] = Some(hash);
+                }
+            }
+        }
+
+        let address = full_leaves * leaf_size;
+
+        // When the last leaf is smaller than `MERKLE_LEAF_SIZE`, read the last
+        // `MERKLE_LEAF_SIZE` bytes and hash only those not previously read.
+        if address != LEN {
+            let data = state.read::<[u8; MERKLE_LEAF_SIZE.get()]>(LEN - leaf_size);
+            leaves.push(Some(Hash::blake2b_hash_bytes(
+                &data[address + leaf_size - LEN..],
+            )?));
+        }
+
+        hash::build_sparse_merkle_hash(MERKLE_ARITY, zero_subtree_hashes(), leaves)
     }
 }
 
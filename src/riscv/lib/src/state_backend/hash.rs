@@ -5,6 +5,7 @@
 
 //! Common type for hashes
 
+use std::cell::Cell;
 use std::num::NonZeroUsize;
 
 use thiserror::Error;
@@ -25,6 +26,9 @@ pub enum HashError {
 
     #[error("The input buffer was expected to be non-empty")]
     NonEmptyBufferExpected,
+
+    #[error("Invalid hex encoding: {0}")]
+    InvalidHex(#[from] hex::FromHexError),
 }
 
 /// Size of digest produced by the underlying hash function
@@ -83,6 +87,17 @@ impl std::fmt::Debug for Hash {
     }
 }
 
+impl std::str::FromStr for Hash {
+    type Err = HashError;
+
+    /// Parse a hash from its hex encoding, as produced by its [`Display`] implementation.
+    ///
+    /// [`Display`]: std::fmt::Display
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Hash::try_from(hex::decode(s)?)
+    }
+}
+
 impl TryFrom<&[u8]> for Hash {
     type Error = HashError;
 
@@ -180,6 +195,98 @@ impl std::io::Write for HashWriter {
     }
 }
 
+thread_local! {
+    /// Number of threads hashing large Merkle trees on behalf of the current thread, see
+    /// [`with_hashing_threads`]
+    static HASHING_THREADS: Cell<usize> = const { Cell::new(1) };
+}
+
+/// Minimum number of chunks mapped by each thread of [`map_chunks`], below which spreading the
+/// work over threads costs more than it saves
+const MIN_CHUNKS_PER_THREAD: usize = 64;
+
+/// Run `f`, hashing large Merkle trees - those committing to [`DynArray`]s, or part of their
+/// proofs - using up to `threads` threads. Hashing is sequential otherwise.
+///
+/// Only hashing done by `f` on the current thread is affected. The resulting hashes are identical
+/// regardless of the number of threads.
+///
+/// [`DynArray`]: crate::state_backend::DynArray
+pub fn with_hashing_threads<R>(threads: NonZeroUsize, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous number of threads, even if `f` panics
+    struct Restore(usize);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            HASHING_THREADS.set(self.0);
+        }
+    }
+
+    let _restore = Restore(HASHING_THREADS.replace(threads.get()));
+    f()
+}
+
+/// Map every chunk of `chunk_size` items with `f`, like `items.chunks(chunk_size).map(f)`. When
+/// there are enough chunks, they are spread over the threads set by [`with_hashing_threads`].
+///
+/// Results are in the order of the chunks. The first error encountered is returned, if any.
+pub(crate) fn map_chunks<T, R, F>(items: &[T], chunk_size: usize, f: F) -> Result<Vec<R>, HashError>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&[T]) -> Result<R, HashError> + Sync,
+{
+    map_chunks_on(HASHING_THREADS.get(), items, chunk_size, f)
+}
+
+/// Like [`map_chunks`], using up to `threads` threads.
+fn map_chunks_on<T, R, F>(
+    threads: usize,
+    items: &[T],
+    chunk_size: usize,
+    f: F,
+) -> Result<Vec<R>, HashError>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&[T]) -> Result<R, HashError> + Sync,
+{
+    let chunks = items.len().div_ceil(chunk_size);
+    let threads = threads.min(chunks / MIN_CHUNKS_PER_THREAD);
+
+    if threads <= 1 {
+        return items.chunks(chunk_size).map(f).collect();
+    }
+
+    // Every thread maps a contiguous part of the chunks, keeping the results in order.
+    let part_size = chunks.div_ceil(threads) * chunk_size;
+    let f = &f;
+
+    std::thread::scope(|scope| {
+        let parts = items
+            .chunks(part_size)
+            .map(|part| {
+                scope.spawn(move || {
+                    part.chunks(chunk_size)
+                        .map(f)
+                        .collect::<Result<Vec<_>, _>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::with_capacity(chunks);
+
+        for part in parts {
+            let part = part
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))?;
+            results.extend(part);
+        }
+
+        Ok(results)
+    })
+}
+
 /// Compute the Merkle hash of a vector of leaf hashes by building a Merkle tree
 /// with the given `arity`. The last node in every level might have
 /// a smaller arity.
@@ -196,17 +303,178 @@ pub(crate) fn build_custom_merkle_hash(
         return Err(HashError::NonEmptyBufferExpected);
     }
 
-    let mut next_level = Vec::with_capacity(nodes.len().div_ceil(arity));
-
     while nodes.len() > 1 {
         // Group the nodes into chunks of size `arity` and hash each chunk.
-        for chunk in nodes.chunks(arity) {
-            next_level.push(Hash::combine(chunk)?)
+        nodes = map_chunks(&nodes, arity, Hash::combine)?;
+    }
+
+    Ok(nodes[0])
+}
+
+/// Maximum arity of sparse Merkle trees, such that the hashes of siblings can be gathered on the
+/// stack before combining them
+const MAX_SPARSE_ARITY: usize = 16;
+
+/// Compute the Merkle hash of a vector of leaf hashes, like [`build_custom_merkle_hash`], where
+/// leaves holding only zeroes may be given as `None`.
+///
+/// `zero_subtrees[height]` must be the hash of a full subtree of the given height holding only
+/// zeroes - the hash at height 0 being that of a leaf. Such subtrees are not hashed again.
+///
+/// # Panics
+/// Panics if `arity < 2` or `arity > MAX_SPARSE_ARITY`, or if `zero_subtrees` does not cover the
+/// height of the tree.
+pub(crate) fn build_sparse_merkle_hash(
+    arity: usize,
+    zero_subtrees: &[Hash],
+    mut nodes: Vec<Option<Hash>>,
+) -> Result<Hash, HashError> {
+    assert!(arity >= 2, "Arity must be at least 2");
+    assert!(
+        arity <= MAX_SPARSE_ARITY,
+        "Arity must be at most {MAX_SPARSE_ARITY}"
+    );
+
+    if nodes.is_empty() {
+        return Err(HashError::NonEmptyBufferExpected);
+    }
+
+    let mut height = 0;
+
+    while nodes.len() > 1 {
+        let zero_subtree = zero_subtrees[height];
+        nodes = map_chunks(&nodes, arity, |chunk| {
+            combine_sparse(arity, zero_subtree, chunk)
+        })?;
+        height += 1;
+    }
+
+    Ok(nodes[0].unwrap_or(zero_subtrees[height]))
+}
+
+/// Combine the hashes of sibling nodes of a sparse Merkle tree, where `None` stands for a full
+/// subtree holding only zeroes, whose hash is `zero_subtree`.
+fn combine_sparse(
+    arity: usize,
+    zero_subtree: Hash,
+    chunk: &[Option<Hash>],
+) -> Result<Option<Hash>, HashError> {
+    // Only full subtrees correspond to the hashes of zero subtrees.
+    if chunk.len() == arity && chunk.iter().all(Option::is_none) {
+        return Ok(None);
+    }
+
+    let mut children = [zero_subtree; MAX_SPARSE_ARITY];
+
+    for (child, node) in children.iter_mut().zip(chunk) {
+        if let Some(hash) = node {
+            *child = *hash;
+        }
+    }
+
+    Ok(Some(Hash::combine(&children[..chunk.len()])?))
+}
+
+/// Merkle tree of a vector of leaf hashes, shaped like that of [`build_sparse_merkle_hash`],
+/// whose interior hashes are kept so that updating a few leaves only re-hashes their ancestors.
+#[derive(Clone, Debug)]
+pub(crate) struct SparseMerkleCache {
+    arity: usize,
+
+    /// Nodes of the tree by height, from the leaves up to the root. `None` stands for a full
+    /// subtree holding only zeroes.
+    levels: Vec<Vec<Option<Hash>>>,
+}
+
+impl SparseMerkleCache {
+    /// Create a tree of `leaves` leaves, all of which hold only zeroes.
+    ///
+    /// # Panics
+    /// Panics if `arity < 2` or `arity > MAX_SPARSE_ARITY`, or if there are no leaves.
+    pub(crate) fn new(arity: usize, leaves: usize) -> Self {
+        assert!(arity >= 2, "Arity must be at least 2");
+        assert!(
+            arity <= MAX_SPARSE_ARITY,
+            "Arity must be at most {MAX_SPARSE_ARITY}"
+        );
+        assert!(leaves > 0, "The tree must have at least one leaf");
+
+        let mut levels = vec![vec![None; leaves]];
+        let mut len = leaves;
+
+        while len > 1 {
+            len = len.div_ceil(arity);
+            levels.push(vec![None; len]);
         }
 
-        std::mem::swap(&mut nodes, &mut next_level);
-        next_level.truncate(0);
+        Self { arity, levels }
     }
 
-    Ok(nodes[0])
+    /// Update the given leaves, then compute the root hash of the tree. Leaves must be given in
+    /// increasing order of index. Only the ancestors of updated leaves are hashed again.
+    ///
+    /// `zero_subtrees` is as for [`build_sparse_merkle_hash`].
+    pub(crate) fn update(
+        &mut self,
+        zero_subtrees: &[Hash],
+        leaves: impl IntoIterator<Item = (usize, Option<Hash>)>,
+    ) -> Result<Hash, HashError> {
+        let mut dirty = Vec::new();
+
+        for (index, leaf) in leaves {
+            self.levels[0][index] = leaf;
+            dirty.push(index);
+        }
+
+        for height in 1..self.levels.len() {
+            for index in dirty.iter_mut() {
+                *index /= self.arity;
+            }
+            dirty.dedup();
+
+            let (below, above) = self.levels.split_at_mut(height);
+            let nodes = &below[height - 1];
+
+            for &index in dirty.iter() {
+                let start = index * self.arity;
+                let chunk = &nodes[start..nodes.len().min(start + self.arity)];
+                above[0][index] = combine_sparse(self.arity, zero_subtrees[height - 1], chunk)?;
+            }
+        }
+
+        let height = self.levels.len() - 1;
+        Ok(self.levels[height][0].unwrap_or(zero_subtrees[height]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_chunks_on_threads() {
+        let leaves = (0..1000u32)
+            .map(|i| Hash::blake2b_hash_bytes(&i.to_le_bytes()).unwrap())
+            .collect::<Vec<_>>();
+
+        let expected = leaves
+            .chunks(4)
+            .map(|chunk| Hash::combine(chunk).unwrap())
+            .collect::<Vec<_>>();
+
+        for threads in [1, 2, 3, 8] {
+            let hashes = map_chunks_on(threads, &leaves, 4, Hash::combine).unwrap();
+            assert_eq!(hashes, expected, "Hashes differ with {threads} threads");
+        }
+
+        // Errors are propagated from any thread.
+        let result = map_chunks_on(3, &leaves, 4, |chunk| {
+            if chunk[0] == leaves[996] {
+                Err(HashError::NonEmptyBufferExpected)
+            } else {
+                Hash::combine(chunk)
+            }
+        });
+        assert!(matches!(result, Err(HashError::NonEmptyBufferExpected)));
+    }
 }
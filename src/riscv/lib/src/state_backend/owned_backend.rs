@@ -3,6 +3,8 @@
 //
 // SPDX-License-Identifier: MIT
 
+mod dyn_region;
+
 use std::array;
 use std::fmt;
 use std::marker::PhantomData;
@@ -23,6 +25,11 @@ use super::ManagerReadWrite;
 use super::ManagerSerialise;
 use super::ManagerWrite;
 use super::StaticCopy;
+use super::hash::Hash;
+use super::hash::HashError;
+pub use dyn_region::DynRegion;
+pub use dyn_region::PAGE_MODIFIED;
+pub use dyn_region::TOUCHED_PAGE_SIZE;
 
 /// Manager that allows state binders to own the state storage
 #[derive(Clone, Copy, Debug)]
@@ -40,7 +47,7 @@ impl Owned {
 impl ManagerBase for Owned {
     type Region<E: 'static, const LEN: usize> = [E; LEN];
 
-    type DynRegion<const LEN: usize> = Box<[u8; LEN]>;
+    type DynRegion<const LEN: usize> = dyn_region::DynRegion<LEN>;
 
     type EnrichedCell<V: EnrichedValue> = (V::E, V::D);
 
@@ -66,13 +73,7 @@ impl ManagerAlloc for Owned {
     }
 
     fn allocate_dyn_region<const LEN: usize>(&mut self) -> Self::DynRegion<LEN> {
-        unsafe {
-            let layout = std::alloc::Layout::new::<[u8; LEN]>()
-                .align_to(4096)
-                .unwrap();
-            let alloc = std::alloc::alloc_zeroed(layout);
-            Box::from_raw(alloc.cast())
-        }
+        DynRegion::new()
     }
 }
 
@@ -106,6 +107,20 @@ impl ManagerRead for Owned {
         }
     }
 
+    fn dyn_region_is_untouched<const LEN: usize>(
+        region: &Self::DynRegion<LEN>,
+        address: usize,
+        len: usize,
+    ) -> bool {
+        region.is_untouched(address, len)
+    }
+
+    fn dyn_region_merkle_hash<const LEN: usize>(
+        region: &Self::DynRegion<LEN>,
+    ) -> Option<Result<Hash, HashError>> {
+        Some(region.merkle_hash())
+    }
+
     fn dyn_region_read_all<E: Elem, const LEN: usize>(
         region: &Self::DynRegion<LEN>,
         address: usize,
@@ -171,14 +186,11 @@ impl ManagerWrite for Owned {
         address: usize,
         mut value: E,
     ) {
-        assert!(address + mem::size_of_val(&value) <= LEN);
-
         value.to_stored_in_place();
 
         unsafe {
             region
-                .as_mut_ptr()
-                .add(address)
+                .write_ptr(address, mem::size_of_val(&value))
                 .cast::<E>()
                 .write_unaligned(value);
         }
@@ -189,10 +201,10 @@ impl ManagerWrite for Owned {
         address: usize,
         values: &[E],
     ) {
-        assert!(address + mem::size_of_val(values) <= LEN);
-
         unsafe {
-            let ptr = region.as_mut_ptr().add(address).cast::<E>();
+            let ptr = region
+                .write_ptr(address, mem::size_of_val(values))
+                .cast::<E>();
 
             for (i, mut value) in values.iter().copied().enumerate() {
                 value.to_stored_in_place();
@@ -317,8 +329,9 @@ impl ManagerDeserialise for Owned {
         deserializer: D,
     ) -> Result<Self::DynRegion<LEN>, D::Error> {
         let vec: Vec<u8> = serde::Deserialize::deserialize(deserializer)?;
-        vec.try_into()
-            .map_err(|_err| serde::de::Error::custom("Dynamic region of mismatching length"))
+        let bytes = <&[u8; LEN]>::try_from(vec.as_slice())
+            .map_err(|_err| serde::de::Error::custom("Dynamic region of mismatching length"))?;
+        Ok(DynRegion::from(bytes))
     }
 }
 
@@ -345,15 +358,21 @@ impl ManagerClone for Owned {
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
+    use crate::state::NewState;
     use crate::state_backend::Cell;
     use crate::state_backend::Cells;
+    use crate::state_backend::CommitmentLayout;
+    use crate::state_backend::DynArray;
     use crate::state_backend::DynCells;
     use crate::state_backend::EnrichedCell;
     use crate::state_backend::FnManagerIdent;
     use crate::state_backend::Ref;
+    use crate::state_backend::hash::build_custom_merkle_hash;
     use crate::state_backend::proof_backend::ProofDynRegion;
     use crate::state_backend::proof_backend::ProofGen;
     use crate::state_backend::proof_backend::ProofRegion;
+    use crate::state_backend::proof_backend::merkle::MERKLE_ARITY;
+    use crate::state_backend::proof_backend::merkle::MERKLE_LEAF_SIZE;
     use crate::state_backend::test_helpers::TestBackendFactory;
 
     /// Test backend factory for the owned state manager
@@ -419,7 +438,7 @@ pub(crate) mod test_helpers {
     #[test]
     fn dyn_cells_serialise() {
         proptest::proptest!(|(address in (0usize..120), value: u64)|{
-            let mut cells: DynCells<128, Owned> = DynCells::bind(Box::new([0u8; 128]));
+            let mut cells: DynCells<128, Owned> = DynCells::bind(DynRegion::new());
             cells.write(address, value);
             let bytes = bincode::serialize(&cells).unwrap();
 
@@ -439,6 +458,115 @@ pub(crate) mod test_helpers {
         });
     }
 
+    /// Ensure hashing [`DynCells`] skips untouched pages without affecting the hash.
+    #[test]
+    fn dyn_cells_sparse_hash() {
+        const LEN: usize = 21 * TOUCHED_PAGE_SIZE + 100;
+
+        let hash = |cells: &DynCells<LEN, Owned>| {
+            <DynArray<LEN> as CommitmentLayout>::state_hash(cells.struct_ref::<FnManagerIdent>())
+                .unwrap()
+        };
+
+        proptest::proptest!(|(address in (0usize..LEN - 8), value: u64)|{
+            let mut sparse: DynCells<LEN, Owned> = DynCells::new(&mut Owned);
+            sparse.write(address, value);
+            assert!(sparse.is_untouched(0, address / TOUCHED_PAGE_SIZE * TOUCHED_PAGE_SIZE));
+
+            // Writing zeroes to the whole region marks every page as touched.
+            let mut dense: DynCells<LEN, Owned> = DynCells::new(&mut Owned);
+            dense.write_all(0, vec![0u8; LEN].as_slice());
+            dense.write(address, value);
+            assert!(!dense.is_untouched(0, 1));
+
+            assert_eq!(hash(&sparse), hash(&dense));
+        });
+    }
+
+    /// Ensure clones of [`DynCells`] which share pages hash and serialise like deep copies.
+    #[test]
+    fn dyn_cells_clone_copy_on_write() {
+        const LEN: usize = 5 * TOUCHED_PAGE_SIZE + 100;
+
+        let hash = |cells: &DynCells<LEN, Owned>| {
+            <DynArray<LEN> as CommitmentLayout>::state_hash(cells.struct_ref::<FnManagerIdent>())
+                .unwrap()
+        };
+
+        let deep_copy = |cells: &DynCells<LEN, Owned>| {
+            let mut bytes = vec![0u8; LEN];
+            cells.read_all(0, bytes.as_mut_slice());
+            let bytes = <&[u8; LEN]>::try_from(bytes.as_slice()).unwrap();
+            DynCells::<LEN, Owned>::bind(DynRegion::from(bytes))
+        };
+
+        proptest::proptest!(|(
+            address in (0usize..LEN - 8),
+            before: u64,
+            after: u64,
+        )|{
+            let mut cells: DynCells<LEN, Owned> = DynCells::new(&mut Owned);
+            cells.write(address, before);
+
+            // The clone writes elsewhere, leaving the original value in place.
+            let mut clone = cells.clone();
+            let elsewhere = (address + 8 * TOUCHED_PAGE_SIZE / 2) % (LEN - 8);
+            proptest::prop_assume!(elsewhere.abs_diff(address) >= 8);
+            clone.write(elsewhere, after);
+            cells.write(address, after);
+
+            for cells in [&cells, &clone] {
+                let copy = deep_copy(cells);
+                assert_eq!(hash(cells), hash(&copy));
+                assert_eq!(
+                    bincode::serialize(cells).unwrap(),
+                    bincode::serialize(&copy).unwrap()
+                );
+            }
+
+            assert_eq!(clone.read::<u64>(address), before);
+        });
+    }
+
+    /// Ensure hashing [`DynCells`] with cached hashes agrees with hashing every leaf.
+    #[test]
+    fn dyn_cells_incremental_hash() {
+        const LEN: usize = 37 * TOUCHED_PAGE_SIZE + 100;
+
+        let hash = |cells: &DynCells<LEN, Owned>| {
+            <DynArray<LEN> as CommitmentLayout>::state_hash(cells.struct_ref::<FnManagerIdent>())
+                .unwrap()
+        };
+
+        let full_hash = |cells: &DynCells<LEN, Owned>| {
+            let mut bytes = vec![0u8; LEN];
+            cells.read_all(0, bytes.as_mut_slice());
+            let leaves = bytes
+                .chunks(MERKLE_LEAF_SIZE.get())
+                .map(|leaf| Hash::blake2b_hash_bytes(leaf).unwrap())
+                .collect();
+            build_custom_merkle_hash(MERKLE_ARITY, leaves).unwrap()
+        };
+
+        let write = (0usize..LEN - 8, proptest::prelude::any::<u64>());
+
+        proptest::proptest!(|(writes in proptest::collection::vec(write, 1..8))|{
+            let mut cells: DynCells<LEN, Owned> = DynCells::new(&mut Owned);
+            assert_eq!(hash(&cells), full_hash(&cells));
+
+            for (address, value) in writes {
+                cells.write(address, value);
+                assert_eq!(hash(&cells), full_hash(&cells));
+
+                // Clones share the cached hashes, which must not go stale on either side.
+                let mut clone = cells.clone();
+                clone.write(LEN - 8 - address, value.wrapping_add(1));
+                assert_eq!(hash(&clone), full_hash(&clone));
+                assert_eq!(hash(&cells), full_hash(&cells));
+            }
+        });
+    }
+
     /// Ensure [`EnrichedCell`] can be serialised and deserialised in a consistent way.
     #[test]
     fn enriched_cell_serialise() {
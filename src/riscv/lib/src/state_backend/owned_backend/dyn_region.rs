@@ -0,0 +1,688 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Sparse backing storage for the dynamic regions of the [`Owned`] backend.
+//!
+//! Dynamic regions hold the main memory of a machine, which may span gigabytes whilst only a few
+//! megabytes are ever used. Regions are therefore reserved as anonymous memory, which the
+//! operating system only populates once touched.
+//!
+//! Writes are tracked per page, so that pages never written to are known to hold zeroes without
+//! reading them. See [`DynRegion::is_untouched`].
+//!
+//! On Linux, regions are cloned copy-on-write. Cloning takes a _snapshot_ of the region: an
+//! in-memory file holding its contents, which both the region and its clone then map privately.
+//! Pages are shared until either side writes to them. Further clones reuse the snapshot as long
+//! as the region has not been written to since. Otherwise, the snapshot is updated in place with
+//! the pages written to since, unless a clone still maps it - in which case a new snapshot is
+//! taken, copying the touched pages once.
+//!
+//! The hashes of the Merkle tree committing to a region are cached, see
+//! [`DynRegion::merkle_hash`]. Hashing the region again only hashes the pages written to since,
+//! and their ancestors in the tree.
+//!
+//! [`Owned`]: super::Owned
+
+use std::alloc::Layout;
+use std::mem;
+use std::ops::RangeInclusive;
+use std::ptr;
+use std::ptr::NonNull;
+use std::slice;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU8;
+use std::sync::atomic::Ordering;
+
+use crate::state_backend::hash;
+use crate::state_backend::hash::Hash;
+use crate::state_backend::hash::HashError;
+use crate::state_backend::hash::SparseMerkleCache;
+use crate::state_backend::proof_backend::merkle::MERKLE_ARITY;
+use crate::state_backend::proof_backend::merkle::MERKLE_LEAF_SIZE;
+use crate::state_backend::proof_backend::merkle::zero_subtree_hashes;
+
+/// Size of the pages of a [`DynRegion`] whose writes are tracked, in bytes
+pub const TOUCHED_PAGE_SIZE: usize = 4096;
+
+/// Marker of a page that has never been written to
+const PAGE_UNTOUCHED: u8 = 0;
+
+/// Marker of a page that has been written to since the region was last snapshotted and hashed
+pub const PAGE_MODIFIED: u8 = 1;
+
+/// Flag of a touched page that has not been written to since the region was last snapshotted.
+/// Its contents may still be shared with the snapshot.
+#[cfg_attr(not(target_os = "linux"), expect(dead_code))]
+const PAGE_SNAPSHOTTED: u8 = 2;
+
+/// Flag of a touched page that has not been written to since the region was last hashed. Its
+/// leaf hash is cached.
+const PAGE_HASHED: u8 = 4;
+
+// Pages of a region are the leaves of the Merkle tree committing to it.
+const _: () = assert!(MERKLE_LEAF_SIZE.get() == TOUCHED_PAGE_SIZE);
+
+/// Dynamic region of `LEN` bytes, backed by anonymous memory that is populated on demand
+///
+/// The layout is relied upon by JIT-compiled code, which accesses the bytes of the region
+/// directly. See [`DynRegion::DATA_OFFSET`] and [`DynRegion::TOUCHED_OFFSET`].
+#[repr(C)]
+pub struct DynRegion<const LEN: usize> {
+    /// Start of the mapping holding the bytes of the region
+    data: NonNull<u8>,
+
+    /// One marker per page of the region, which is set to [`PAGE_MODIFIED`] whenever the page is
+    /// written to, and flagged once the page is snapshotted or hashed. Pages that have never been
+    /// written to hold zeroes.
+    touched: NonNull<u8>,
+
+    /// Snapshot the mapping is based on, if any
+    snapshot: Mutex<Option<Arc<Snapshot>>>,
+
+    /// Hashes of the Merkle tree committing to the region, once computed
+    hashes: Mutex<Option<Arc<SparseMerkleCache>>>,
+}
+
+impl<const LEN: usize> DynRegion<LEN> {
+    /// Number of pages in the region, the last of which may be partial
+    const PAGES: usize = LEN.div_ceil(TOUCHED_PAGE_SIZE);
+
+    /// Length of the mapping, which may not be empty
+    const MAPPING_LEN: usize = if LEN == 0 { 1 } else { LEN };
+
+    /// Offset of the pointer to the bytes of the region
+    pub const DATA_OFFSET: usize = mem::offset_of!(Self, data);
+
+    /// Offset of the pointer to the touched pages, which are one marker byte per page
+    pub const TOUCHED_OFFSET: usize = mem::offset_of!(Self, touched);
+
+    /// Reserve a region whose bytes are all zero.
+    pub fn new() -> Self {
+        // SAFETY: A fresh private, anonymous mapping is requested. It does not alias any memory.
+        let data = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                Self::MAPPING_LEN,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_NORESERVE,
+                -1,
+                0,
+            )
+        };
+
+        Self::from_mapping(data, None)
+    }
+
+    /// Wrap a fresh mapping of the bytes of the region, with no page marked as touched.
+    fn from_mapping(data: *mut libc::c_void, snapshot: Option<Arc<Snapshot>>) -> Self {
+        if data == libc::MAP_FAILED {
+            let layout = Layout::from_size_align(Self::MAPPING_LEN, TOUCHED_PAGE_SIZE)
+                .expect("The mapping length is a valid allocation size");
+            std::alloc::handle_alloc_error(layout);
+        }
+
+        let data = NonNull::new(data.cast::<u8>()).expect("Mappings are never null");
+
+        let touched = Box::into_raw(vec![PAGE_UNTOUCHED; Self::PAGES].into_boxed_slice());
+        let touched = NonNull::new(touched.cast::<u8>()).expect("Boxes are never null");
+
+        Self {
+            data,
+            touched,
+            snapshot: Mutex::new(snapshot),
+            hashes: Mutex::new(None),
+        }
+    }
+
+    /// Obtain the bytes of the region.
+    pub fn as_slice(&self) -> &[u8] {
+        // SAFETY: The mapping spans at least `LEN` bytes, which are initialised to zero on
+        // reservation.
+        unsafe { slice::from_raw_parts(self.data.as_ptr(), LEN) }
+    }
+
+    /// Obtain a pointer to the bytes of the region, for reading.
+    pub fn as_ptr(&self) -> *const u8 {
+        self.data.as_ptr()
+    }
+
+    /// Obtain a pointer to the byte at `address`, for writing `len` bytes. The pages spanned by
+    /// the write are marked as touched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the write is out of bounds.
+    pub fn write_ptr(&mut self, address: usize, len: usize) -> *mut u8 {
+        assert!(address.saturating_add(len) <= LEN);
+
+        if let Some(pages) = Self::pages(address, len) {
+            self.touched_mut()[pages].fill(PAGE_MODIFIED);
+        }
+
+        // SAFETY: The address is within bounds of the mapping.
+        unsafe { self.data.as_ptr().add(address) }
+    }
+
+    /// Whether none of the `len` bytes at `address` have ever been written to, in which case they
+    /// are known to be zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn is_untouched(&self, address: usize, len: usize) -> bool {
+        assert!(address.saturating_add(len) <= LEN);
+
+        match Self::pages(address, len) {
+            Some(pages) => self.touched()[pages]
+                .iter()
+                .all(|touched| touched.load(Ordering::Relaxed) == PAGE_UNTOUCHED),
+            None => true,
+        }
+    }
+
+    /// Compute the root hash of the region, as committed to by [`DynArray`]. Only the pages
+    /// written to since the last call are hashed again, along with their ancestors in the tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region is smaller than a page.
+    ///
+    /// [`DynArray`]: crate::state_backend::DynArray
+    pub fn merkle_hash(&self) -> Result<Hash, HashError> {
+        assert!(LEN >= TOUCHED_PAGE_SIZE);
+
+        let mut hashes = self
+            .hashes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        // Without cached hashes, every touched page is hashed - as is the last page if partial,
+        // as its hash is not that of a full page of zeroes.
+        let cached = hashes.is_some();
+        let partial = LEN % TOUCHED_PAGE_SIZE != 0;
+        let cache = hashes
+            .get_or_insert_with(|| Arc::new(SparseMerkleCache::new(MERKLE_ARITY, Self::PAGES)));
+        let cache = Arc::make_mut(cache);
+
+        let bytes = self.as_slice();
+        let touched = self.touched();
+
+        let stale = (0..Self::PAGES)
+            .filter(|&index| {
+                let marker = touched[index].load(Ordering::Relaxed);
+
+                if marker == PAGE_UNTOUCHED {
+                    !cached && partial && index == Self::PAGES - 1
+                } else {
+                    !cached || marker & PAGE_HASHED == 0
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Stale pages are hashed in parallel if enabled, see `hash::with_hashing_threads`.
+        let root = hash::map_chunks(&stale, 1, |index| {
+            Hash::blake2b_hash_bytes(Self::page(bytes, index[0]))
+        })
+        .and_then(|leaves| {
+            let leaves = stale.iter().copied().zip(leaves.into_iter().map(Some));
+            cache.update(zero_subtree_hashes(), leaves)
+        });
+
+        match root {
+            Ok(_) => {
+                for &index in stale.iter() {
+                    if touched[index].load(Ordering::Relaxed) != PAGE_UNTOUCHED {
+                        touched[index].fetch_or(PAGE_HASHED, Ordering::Relaxed);
+                    }
+                }
+            }
+            // The cache may have been partially updated, hence it is discarded.
+            Err(_) => *hashes = None,
+        }
+
+        root
+    }
+
+    /// Range of the pages spanned by the `len` bytes at `address`, if any.
+    fn pages(address: usize, len: usize) -> Option<RangeInclusive<usize>> {
+        let last = address.checked_add(len)?.checked_sub(1)?;
+        Some(address / TOUCHED_PAGE_SIZE..=last / TOUCHED_PAGE_SIZE)
+    }
+
+    /// Markers of the touched pages, which snapshotting updates through a shared reference.
+    fn touched(&self) -> &[AtomicU8] {
+        // SAFETY: The touched pages were allocated with `PAGES` elements. `AtomicU8` has the same
+        // in-memory representation as `u8`.
+        unsafe { slice::from_raw_parts(self.touched.as_ptr().cast::<AtomicU8>(), Self::PAGES) }
+    }
+
+    fn touched_mut(&mut self) -> &mut [u8] {
+        // SAFETY: The touched pages were allocated with `PAGES` elements.
+        unsafe { slice::from_raw_parts_mut(self.touched.as_ptr(), Self::PAGES) }
+    }
+
+    /// Bytes of the page at `index`, which may be partial if it is the last one.
+    fn page(bytes: &[u8], index: usize) -> &[u8] {
+        let start = index * TOUCHED_PAGE_SIZE;
+        &bytes[start..bytes.len().min(start + TOUCHED_PAGE_SIZE)]
+    }
+
+    /// Copy the page at `index` from `bytes`, unless it holds only zeroes.
+    fn copy_page_from(&mut self, index: usize, bytes: &[u8]) {
+        let page = Self::page(bytes, index);
+
+        if page.iter().all(|&byte| byte == 0) {
+            return;
+        }
+
+        let ptr = self.write_ptr(index * TOUCHED_PAGE_SIZE, page.len());
+
+        // SAFETY: `write_ptr` checked that the page lies within bounds of the mapping, which does
+        // not overlap with `bytes`.
+        unsafe { ptr.copy_from_nonoverlapping(page.as_ptr(), page.len()) }
+    }
+
+    /// Copy the region into a fresh one. Only the pages that have been touched are copied,
+    /// keeping the copy sparse.
+    fn copy(&self) -> Self {
+        let mut region = Self::new();
+        let bytes = self.as_slice();
+
+        for (index, touched) in self.touched().iter().enumerate() {
+            if touched.load(Ordering::Relaxed) != PAGE_UNTOUCHED {
+                region.copy_page_from(index, bytes);
+            }
+        }
+
+        region
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl<const LEN: usize> DynRegion<LEN> {
+    /// Clone the region copy-on-write, sharing its pages through a snapshot.
+    fn clone_shared(&self) -> std::io::Result<Self> {
+        let snapshot = self.snapshot()?;
+
+        // SAFETY: A fresh private mapping of the whole snapshot is requested. It does not alias
+        // any memory.
+        let data = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                Self::MAPPING_LEN,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_NORESERVE,
+                snapshot.fd(),
+                0,
+            )
+        };
+
+        if data == libc::MAP_FAILED {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        let mut region = Self::from_mapping(data, Some(snapshot));
+
+        // The cached hashes are shared as well, along with the pages they are up to date for.
+        let hashes = self
+            .hashes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        for (marker, touched) in region.touched_mut().iter_mut().zip(self.touched()) {
+            let touched = touched.load(Ordering::Relaxed);
+
+            if touched != PAGE_UNTOUCHED {
+                *marker = PAGE_MODIFIED | PAGE_SNAPSHOTTED | (touched & PAGE_HASHED);
+            }
+        }
+
+        region.hashes = Mutex::new(hashes.clone());
+        drop(hashes);
+
+        Ok(region)
+    }
+
+    /// Obtain a snapshot of the current contents of the region. The previous snapshot is reused
+    /// unless pages have been modified since it was taken.
+    fn snapshot(&self) -> std::io::Result<Arc<Snapshot>> {
+        let mut current = self
+            .snapshot
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let touched = self.touched();
+        let is_modified = |index: usize| {
+            let marker = touched[index].load(Ordering::Relaxed);
+            marker != PAGE_UNTOUCHED && marker & PAGE_SNAPSHOTTED == 0
+        };
+
+        let snapshot = match current.as_ref() {
+            Some(snapshot) if !(0..Self::PAGES).any(is_modified) => return Ok(snapshot.clone()),
+
+            // Only the region maps the previous snapshot, which is hence updated in place with the
+            // pages modified since. The other pages of the region are still those of the snapshot.
+            Some(snapshot) if Arc::strong_count(snapshot) == 1 => {
+                self.write_pages(snapshot, is_modified)?;
+                snapshot.clone()
+            }
+
+            // Clones map the previous snapshot, whose contents must be preserved for them.
+            _ => {
+                let snapshot = Arc::new(Snapshot::create(Self::MAPPING_LEN)?);
+                self.write_pages(&snapshot, |index| {
+                    touched[index].load(Ordering::Relaxed) != PAGE_UNTOUCHED
+                })?;
+                snapshot
+            }
+        };
+
+        // Base the region itself on the snapshot, so that its pages are shared with the clone.
+        //
+        // SAFETY: The mapping is replaced in place by one with identical contents. Writes require
+        // an exclusive reference to the region, hence none can be lost whilst remapping.
+        let data = unsafe {
+            libc::mmap(
+                self.data.as_ptr().cast(),
+                Self::MAPPING_LEN,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_NORESERVE | libc::MAP_FIXED,
+                snapshot.fd(),
+                0,
+            )
+        };
+
+        if data == libc::MAP_FAILED {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        for touched in touched {
+            if touched.load(Ordering::Relaxed) != PAGE_UNTOUCHED {
+                touched.fetch_or(PAGE_SNAPSHOTTED, Ordering::Relaxed);
+            }
+        }
+
+        *current = Some(snapshot.clone());
+
+        Ok(snapshot)
+    }
+
+    /// Write the pages of the region selected by `filter` into `snapshot`.
+    fn write_pages(
+        &self,
+        snapshot: &Snapshot,
+        filter: impl Fn(usize) -> bool,
+    ) -> std::io::Result<()> {
+        let bytes = self.as_slice();
+
+        for index in (0..Self::PAGES).filter(|&index| filter(index)) {
+            snapshot.write_at(Self::page(bytes, index), index * TOUCHED_PAGE_SIZE)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const LEN: usize> Default for DynRegion<LEN> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const LEN: usize> Drop for DynRegion<LEN> {
+    fn drop(&mut self) {
+        // SAFETY: Both the mapping and the touched pages were allocated in `from_mapping`, and
+        // are no longer referenced.
+        unsafe {
+            libc::munmap(self.data.as_ptr().cast(), Self::MAPPING_LEN);
+            drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+                self.touched.as_ptr(),
+                Self::PAGES,
+            )));
+        }
+    }
+}
+
+impl<const LEN: usize> Clone for DynRegion<LEN> {
+    /// On Linux, the clone shares its pages with the region until either side writes to them.
+    /// Elsewhere, or if snapshotting fails, only the pages that have been touched are copied.
+    fn clone(&self) -> Self {
+        #[cfg(target_os = "linux")]
+        match self.clone_shared() {
+            Ok(region) => return region,
+            Err(error) => crate::log::warning! {
+                error = format!("{error:?}"),
+                "Unable to clone dynamic region copy-on-write, copying it instead",
+            },
+        }
+
+        self.copy()
+    }
+}
+
+impl<const LEN: usize> From<&[u8; LEN]> for DynRegion<LEN> {
+    /// Only the pages holding non-zero bytes are copied, and marked as touched.
+    fn from(bytes: &[u8; LEN]) -> Self {
+        let mut region = Self::new();
+
+        for index in 0..Self::PAGES {
+            region.copy_page_from(index, bytes);
+        }
+
+        region
+    }
+}
+
+impl<const LEN: usize> std::fmt::Debug for DynRegion<LEN> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynRegion")
+            .field("len", &LEN)
+            .field(
+                "touched_pages",
+                &self
+                    .touched()
+                    .iter()
+                    .filter(|touched| touched.load(Ordering::Relaxed) != PAGE_UNTOUCHED)
+                    .count(),
+            )
+            .finish()
+    }
+}
+
+// SAFETY: The region exclusively owns its mapping and touched pages, like a `Box` would. The
+// snapshot it may share with other regions is only written to whilst no other region maps it.
+unsafe impl<const LEN: usize> Send for DynRegion<LEN> {}
+
+// SAFETY: Shared references only allow reading, like those of a `Box` would. Snapshotting
+// preserves the contents of the region, and updates the touched pages atomically.
+unsafe impl<const LEN: usize> Sync for DynRegion<LEN> {}
+
+/// Contents of a region, held in an in-memory file which regions map copy-on-write
+#[cfg_attr(not(target_os = "linux"), expect(dead_code))]
+struct Snapshot {
+    file: std::fs::File,
+}
+
+#[cfg(target_os = "linux")]
+impl Snapshot {
+    /// Create a snapshot of `len` zero bytes. Holes in the file read as zeroes, without
+    /// occupying any memory.
+    fn create(len: usize) -> std::io::Result<Self> {
+        use std::os::fd::FromRawFd;
+
+        // SAFETY: The name is a valid, nul-terminated string.
+        let fd = unsafe { libc::memfd_create(c"dyn-region-snapshot".as_ptr(), libc::MFD_CLOEXEC) };
+
+        if fd < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        // SAFETY: The file descriptor has just been created, and is not owned by anything else.
+        let file = unsafe { std::fs::File::from_raw_fd(fd) };
+        file.set_len(len as u64)?;
+
+        Ok(Self { file })
+    }
+
+    /// Write `bytes` at `offset` into the snapshot.
+    fn write_at(&self, bytes: &[u8], offset: usize) -> std::io::Result<()> {
+        use std::os::unix::fs::FileExt;
+
+        self.file.write_all_at(bytes, offset as u64)
+    }
+
+    fn fd(&self) -> libc::c_int {
+        use std::os::fd::AsRawFd;
+
+        self.file.as_raw_fd()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEN: usize = 4 * TOUCHED_PAGE_SIZE + 100;
+
+    fn write(region: &mut DynRegion<LEN>, address: usize, value: u8) {
+        // SAFETY: `write_ptr` checks that the write is within bounds.
+        unsafe { region.write_ptr(address, 1).write(value) };
+    }
+
+    #[test]
+    fn test_untouched_pages() {
+        let mut region = DynRegion::<LEN>::new();
+
+        assert!(region.as_slice().iter().all(|&byte| byte == 0));
+        assert!(region.is_untouched(0, LEN));
+
+        // A write spanning two pages touches both of them.
+        let address = 2 * TOUCHED_PAGE_SIZE - 2;
+        let ptr = region.write_ptr(address, 4);
+        // SAFETY: `write_ptr` checked that the write is within bounds.
+        unsafe { ptr.cast::<u32>().write_unaligned(u32::MAX) };
+
+        assert!(region.is_untouched(0, TOUCHED_PAGE_SIZE));
+        assert!(!region.is_untouched(TOUCHED_PAGE_SIZE, TOUCHED_PAGE_SIZE));
+        assert!(!region.is_untouched(2 * TOUCHED_PAGE_SIZE, TOUCHED_PAGE_SIZE));
+        assert!(region.is_untouched(3 * TOUCHED_PAGE_SIZE, LEN - 3 * TOUCHED_PAGE_SIZE));
+        assert!(region.is_untouched(address, 0));
+
+        // The last, partial page is tracked as well.
+        region.write_ptr(LEN - 1, 1);
+        assert!(!region.is_untouched(LEN - 1, 1));
+    }
+
+    #[test]
+    fn test_clone_and_from_bytes() {
+        let mut bytes = Box::new([0u8; LEN]);
+        bytes[TOUCHED_PAGE_SIZE + 7] = 42;
+        bytes[LEN - 1] = 13;
+
+        let region = DynRegion::from(bytes.as_ref());
+        assert_eq!(region.as_slice(), bytes.as_slice());
+
+        // Only pages holding non-zero bytes are touched.
+        assert!(region.is_untouched(0, TOUCHED_PAGE_SIZE));
+        assert!(!region.is_untouched(TOUCHED_PAGE_SIZE, 1));
+        assert!(region.is_untouched(2 * TOUCHED_PAGE_SIZE, 2 * TOUCHED_PAGE_SIZE));
+        assert!(!region.is_untouched(LEN - 1, 1));
+
+        for clone in [region.clone(), region.copy()] {
+            assert_eq!(clone.as_slice(), bytes.as_slice());
+            assert!(clone.is_untouched(0, TOUCHED_PAGE_SIZE));
+            assert!(!clone.is_untouched(TOUCHED_PAGE_SIZE, 1));
+        }
+    }
+
+    #[test]
+    fn test_clone_copy_on_write() {
+        let mut region = DynRegion::<LEN>::new();
+        write(&mut region, 7, 1);
+        write(&mut region, 2 * TOUCHED_PAGE_SIZE, 2);
+
+        let mut first = region.clone();
+        let second = region.clone();
+
+        // Writes to either side are not visible to the other.
+        write(&mut first, 7, 3);
+        write(&mut region, 2 * TOUCHED_PAGE_SIZE, 4);
+        write(&mut region, 3 * TOUCHED_PAGE_SIZE, 5);
+
+        assert_eq!(region.as_slice()[7], 1);
+        assert_eq!(region.as_slice()[2 * TOUCHED_PAGE_SIZE], 4);
+        assert_eq!(region.as_slice()[3 * TOUCHED_PAGE_SIZE], 5);
+
+        assert_eq!(first.as_slice()[7], 3);
+        assert_eq!(first.as_slice()[2 * TOUCHED_PAGE_SIZE], 2);
+        assert!(first.is_untouched(3 * TOUCHED_PAGE_SIZE, TOUCHED_PAGE_SIZE));
+
+        assert_eq!(second.as_slice()[7], 1);
+        assert_eq!(second.as_slice()[2 * TOUCHED_PAGE_SIZE], 2);
+        assert_eq!(second.as_slice()[3 * TOUCHED_PAGE_SIZE], 0);
+
+        // Clones of a modified region reflect its current contents.
+        let third = region.clone();
+        assert_eq!(third.as_slice(), region.as_slice());
+        assert_eq!(third.as_slice(), region.copy().as_slice());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_snapshot_reuse() {
+        let snapshot = |region: &DynRegion<LEN>| region.snapshot.lock().unwrap().clone().unwrap();
+
+        let mut region = DynRegion::<LEN>::new();
+        write(&mut region, 0, 1);
+
+        // Clones of an unmodified region share its snapshot.
+        let first = region.clone();
+        let second = region.clone();
+        assert!(Arc::ptr_eq(&snapshot(&region), &snapshot(&first)));
+        assert!(Arc::ptr_eq(&snapshot(&first), &snapshot(&second)));
+
+        // Once the region is modified, cloning takes a new snapshot.
+        write(&mut region, 0, 2);
+        let third = region.clone();
+        assert!(!Arc::ptr_eq(&snapshot(&first), &snapshot(&third)));
+        assert_eq!(first.as_slice()[0], 1);
+        assert_eq!(third.as_slice()[0], 2);
+    }
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_snapshot_incremental() {
+        let snapshot = |region: &DynRegion<LEN>| region.snapshot.lock().unwrap().clone().unwrap();
+
+        let mut region = DynRegion::<LEN>::new();
+        write(&mut region, 0, 1);
+        write(&mut region, 2 * TOUCHED_PAGE_SIZE, 2);
+        drop(region.clone());
+
+        // Pages not written to since the snapshot was taken are shared with it, rather than
+        // copied into the region.
+        let previous = snapshot(&region);
+        previous.write_at(&[3], 2 * TOUCHED_PAGE_SIZE).unwrap();
+        assert_eq!(region.as_slice()[2 * TOUCHED_PAGE_SIZE], 3);
+
+        let snapshotted = Arc::as_ptr(&previous);
+        drop(previous);
+
+        // No clone maps the snapshot any more, hence it is updated in place. Only the page
+        // modified since is written to it, leaving the other one as is.
+        write(&mut region, 0, 4);
+        let first = region.clone();
+        assert_eq!(Arc::as_ptr(&snapshot(&region)), snapshotted);
+        assert_eq!(first.as_slice()[0], 4);
+        assert_eq!(first.as_slice()[2 * TOUCHED_PAGE_SIZE], 3);
+
+        // Whilst a clone maps the snapshot, modifying the region takes a new one instead.
+        write(&mut region, 0, 5);
+        let second = region.clone();
+        assert_ne!(Arc::as_ptr(&snapshot(&region)), snapshotted);
+        assert_eq!(first.as_slice()[0], 4);
+        assert_eq!(second.as_slice()[0], 5);
+        assert_eq!(second.as_slice()[2 * TOUCHED_PAGE_SIZE], 3);
+    }
+}
@@ -437,6 +437,7 @@ mod tests {
     use crate::state_backend::ProofLayout;
     use crate::state_backend::Ref;
     use crate::state_backend::layout::Array;
+    use crate::state_backend::owned_backend::DynRegion;
     use crate::state_backend::owned_backend::Owned;
 
     const CELLS_SIZE: usize = 32;
@@ -544,7 +545,7 @@ mod tests {
         proptest!(|(byte_before: u8,
                     bytes_after: [u8; ELEM_SIZE],
                     write_address in &address_range)| {
-            let cells = Box::new([byte_before; DYN_REGION_SIZE]);
+            let cells = DynRegion::from(&[byte_before; DYN_REGION_SIZE]);
             let dyn_region: ProofDynRegion<DYN_REGION_SIZE, Owned> = ProofDynRegion::bind(cells);
             let mut dyn_cells: DynCells<DYN_REGION_SIZE, ProofGen<Owned>> =
                 DynCells::bind(dyn_region);
@@ -559,7 +560,7 @@ mod tests {
             let value: u64 = dyn_cells.read(write_address);
             assert_eq!(value, value_after);
 
-            let cells = Box::new([byte_before; DYN_REGION_SIZE]);
+            let cells = DynRegion::from(&[byte_before; DYN_REGION_SIZE]);
             let dyn_region: ProofDynRegion<DYN_REGION_SIZE, Owned> = ProofDynRegion::bind(cells);
             let mut dyn_cells: DynCells<DYN_REGION_SIZE, ProofGen<Owned>> =
                 DynCells::bind(dyn_region);
@@ -580,7 +581,7 @@ mod tests {
             dyn_cells.read_all(write_address, &mut value);
             assert_eq!(value, value_after);
 
-            let cells = Box::new([byte_before; DYN_REGION_SIZE]);
+            let cells = DynRegion::from(&[byte_before; DYN_REGION_SIZE]);
             let dyn_region: ProofDynRegion<DYN_REGION_SIZE, Owned> = ProofDynRegion::bind(cells);
             let mut dyn_cells: DynCells<DYN_REGION_SIZE, ProofGen<Owned>> =
                 DynCells::bind(dyn_region);
@@ -601,7 +602,7 @@ mod tests {
                     bytes_after: [u8; ELEM_SIZE],
                     reads in array::uniform2(&address_range),
                     writes in array::uniform2(&address_range))| {
-            let dyn_array = Box::new([byte_before; DYN_REGION_SIZE]);
+            let dyn_array = DynRegion::from(&[byte_before; DYN_REGION_SIZE]);
             let owned_dyn_cells: DynCells<DYN_REGION_SIZE, Ref<'_, Owned>> =
                 DynCells::bind(&dyn_array);
             let initial_root_hash =
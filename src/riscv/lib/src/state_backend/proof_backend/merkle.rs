@@ -6,10 +6,12 @@
 
 use std::convert::Infallible;
 use std::num::NonZeroUsize;
+use std::sync::OnceLock;
 
 use super::DynAccess;
 use super::proof::MerkleProof;
 use super::proof::MerkleProofLeaf;
+use crate::state_backend::hash;
 use crate::state_backend::hash::Hash;
 use crate::state_backend::hash::HashError;
 use crate::state_backend::proof_backend::tree::ModifyResult;
@@ -27,6 +29,32 @@ pub const MERKLE_LEAF_SIZE: NonZeroUsize = NonZeroUsize::new(4096).unwrap();
 /// [`DynArrays`]: [`crate::state_backend::layout::DynArray`]
 pub const MERKLE_ARITY: usize = 4;
 
+/// Hashes of full subtrees holding only zeroes, in the Merkle trees of [`DynArrays`], by height.
+/// The hash at height 0 is that of a leaf of [`MERKLE_LEAF_SIZE`] zeroes.
+///
+/// [`DynArrays`]: [`crate::state_backend::layout::DynArray`]
+pub(crate) fn zero_subtree_hashes() -> &'static [Hash] {
+    static HASHES: OnceLock<Vec<Hash>> = OnceLock::new();
+
+    HASHES.get_or_init(|| {
+        // A tree over any addressable array has fewer leaves than `2^usize::BITS`, hence it is
+        // never higher than this.
+        let max_height = usize::BITS as usize;
+
+        let mut hash = Hash::blake2b_hash_bytes(&[0; MERKLE_LEAF_SIZE.get()])
+            .expect("Hashing a leaf never fails");
+        let mut hashes = Vec::with_capacity(max_height + 1);
+        hashes.push(hash);
+
+        for _ in 0..max_height {
+            hash = Hash::combine(&[hash; MERKLE_ARITY]).expect("Combining hashes never fails");
+            hashes.push(hash);
+        }
+
+        hashes
+    })
+}
+
 /// A variable-width Merkle tree with access metadata for leaves.
 ///
 /// Values of this type are produced by the proof-generating backend to capture
@@ -246,7 +274,9 @@ pub struct MerkleWriter {
     read_log: DynAccess,
     write_log: DynAccess,
     buffer: Vec<u8>,
-    leaves: Vec<MerkleTree>,
+
+    /// Data of the leaves and whether they were accessed, hashed once finalised
+    leaves: Vec<(Vec<u8>, bool)>,
 }
 
 impl MerkleWriter {
@@ -277,7 +307,7 @@ impl MerkleWriter {
 
     /// Commit the leaf corresponding to the contents of the buffer before
     /// clearing it.
-    fn flush_buffer(&mut self) -> Result<(), HashError> {
+    fn flush_buffer(&mut self) {
         let pos = self.leaves.len() * self.leaf_size;
         let range = pos..pos + self.leaf_size;
 
@@ -287,24 +317,31 @@ impl MerkleWriter {
         let write = self.write_log.includes_range(range);
         let access_info = read || write;
 
-        self.leaves.push(MerkleTree::make_merkle_leaf(
-            self.buffer.clone(),
-            access_info,
-        )?);
+        self.leaves.push((self.buffer.clone(), access_info));
         self.buffer.clear();
-
-        Ok(())
     }
 
     /// Finalise the writer by generating the Merkle tree with the configured
     /// arity from the stored leaves. The last node in every level might have
     /// a smaller arity.
+    ///
+    /// Leaves are hashed in parallel if enabled, see [`hash::with_hashing_threads`].
     pub fn finalise(mut self) -> Result<MerkleTree, HashError> {
         if !self.buffer.is_empty() {
-            self.flush_buffer()?;
+            self.flush_buffer();
         }
 
-        build_custom_merkle_tree(self.arity, self.leaves)
+        let hashes =
+            hash::map_chunks(&self.leaves, 1, |leaf| Hash::blake2b_hash_bytes(&leaf[0].0))?;
+
+        let leaves = self
+            .leaves
+            .into_iter()
+            .zip(hashes)
+            .map(|((data, access_info), hash)| MerkleTree::Leaf(hash, access_info, data))
+            .collect();
+
+        build_custom_merkle_tree(self.arity, leaves)
     }
 }
 
@@ -322,7 +359,7 @@ impl std::io::Write for MerkleWriter {
 
             // If the buffer has been completely filled, flush it.
             if rem_buffer_len == new_buf_len {
-                self.flush_buffer().map_err(std::io::Error::other)?;
+                self.flush_buffer();
             }
         }
         Ok(consumed)
@@ -366,7 +403,8 @@ impl MerkleTree {
 }
 
 /// Build a Merkle tree whose leaves are the elements of `nodes` and in which
-/// each node has the given `arity`.
+/// each node has the given `arity`. Nodes are hashed in parallel if enabled, see
+/// [`hash::with_hashing_threads`].
 pub(crate) fn build_custom_merkle_tree(
     arity: usize,
     mut nodes: Vec<MerkleTree>,
@@ -375,15 +413,17 @@ pub(crate) fn build_custom_merkle_tree(
         return Err(HashError::NonEmptyBufferExpected);
     }
 
-    let mut next_level = Vec::with_capacity(nodes.len().div_ceil(arity));
-
     while nodes.len() > 1 {
-        for chunk in nodes.chunks(arity) {
-            next_level.push(MerkleTree::make_merkle_node(chunk.to_vec())?)
-        }
-
-        std::mem::swap(&mut nodes, &mut next_level);
-        next_level.truncate(0);
+        let hashes = hash::map_chunks(&nodes, arity, |chunk| {
+            let children = chunk.iter().map(MerkleTree::root_hash).collect::<Vec<_>>();
+            Hash::combine(&children)
+        })?;
+
+        nodes = nodes
+            .chunks(arity)
+            .zip(hashes)
+            .map(|(chunk, hash)| MerkleTree::Node(hash, chunk.to_vec()))
+            .collect();
     }
 
     Ok(nodes.pop().unwrap_or_else(|| {
@@ -18,6 +18,8 @@ use super::ManagerReadWrite;
 use super::ManagerSerialise;
 use super::ManagerWrite;
 use super::Ref;
+use super::hash::Hash;
+use super::hash::HashError;
 use super::owned_backend::Owned;
 use super::proof_backend::ProofGen;
 use super::proof_backend::merkle::AccessInfoAggregatable;
@@ -521,6 +523,28 @@ impl<const LEN: usize, M: ManagerBase> DynCells<LEN, M> {
         M::dyn_region_read_all(&self.region, address, values)
     }
 
+    /// Check whether the `len` bytes at `address` are known to have never been written to, in
+    /// which case they are zero. `address` is in bytes. See [`ManagerRead::dyn_region_is_untouched`].
+    #[inline]
+    pub fn is_untouched(&self, address: usize, len: usize) -> bool
+    where
+        M: ManagerRead,
+    {
+        M::dyn_region_is_untouched(&self.region, address, len)
+    }
+
+    /// Compute the root hash of the region as committed to by [`DynArray`], if the manager caches
+    /// hashes. See [`ManagerRead::dyn_region_merkle_hash`].
+    ///
+    /// [`DynArray`]: super::DynArray
+    #[inline]
+    pub fn cached_merkle_hash(&self) -> Option<Result<Hash, HashError>>
+    where
+        M: ManagerRead,
+    {
+        M::dyn_region_merkle_hash(&self.region)
+    }
+
     /// Update an element in the region. `address` is in bytes.
     #[inline]
     pub fn write<E: Elem>(&mut self, address: usize, value: E)
@@ -5,6 +5,7 @@
 
 mod reveals;
 
+use std::num::NonZeroUsize;
 use std::ops::Bound;
 use std::path::Path;
 
@@ -38,6 +39,7 @@ use crate::state_backend::ManagerReadWrite;
 use crate::state_backend::ProofLayout;
 use crate::state_backend::ProofTree;
 use crate::state_backend::Ref;
+use crate::state_backend::hash;
 use crate::state_backend::hash::Hash;
 use crate::state_backend::owned_backend::Owned;
 use crate::state_backend::proof_backend::ProofGen;
@@ -72,6 +74,9 @@ pub struct PvmStepper<
     rollup_address: [u8; 20],
     origination_level: u32,
     reveal_request_response_map: RevealRequestResponseMap,
+
+    /// Number of threads hashing the PVM state, see [`PvmStepper::set_hashing_threads`]
+    hashing_threads: NonZeroUsize,
 }
 
 impl<'hooks, MC: MemoryConfig, B: Block<MC, Owned>, BCC: BlockCacheConfig>
@@ -109,12 +114,19 @@ impl<'hooks, MC: MemoryConfig, B: Block<MC, Owned>, BCC: BlockCacheConfig>
             rollup_address,
             origination_level,
             reveal_request_response_map,
+            hashing_threads: NonZeroUsize::MIN,
         })
     }
 
+    /// Hash the PVM state, and produce proofs of it, using up to `threads` threads. Hashing is
+    /// sequential by default. Hashes are identical regardless of the number of threads.
+    pub fn set_hashing_threads(&mut self, threads: NonZeroUsize) {
+        self.hashing_threads = threads;
+    }
+
     /// Obtain the root hash for the PVM state.
     pub fn hash(&self) -> Hash {
-        self.pvm.hash().unwrap()
+        hash::with_hashing_threads(self.hashing_threads, || self.pvm.hash().unwrap())
     }
 }
 
@@ -130,8 +142,9 @@ impl<MC: MemoryConfig, BCC: BlockCacheConfig> PvmStepper<'_, MC, BCC, Owned> {
 
         proof_stepper.try_step().then_some(())?;
 
-        let proof = proof_stepper.pvm.to_proof().ok()?;
-        Some(proof)
+        let proof =
+            hash::with_hashing_threads(self.hashing_threads, || proof_stepper.pvm.to_proof());
+        proof.ok()
     }
 }
 
@@ -269,6 +282,8 @@ impl<'hooks, MC: MemoryConfig, BCC: BlockCacheConfig, M: ManagerReadWrite>
             hooks: PvmHooks::none(),
 
             reveal_request_response_map: self.reveal_request_response_map.clone(),
+
+            hashing_threads: self.hashing_threads,
         }
     }
 
@@ -296,6 +311,8 @@ impl<'hooks, MC: MemoryConfig, BCC: BlockCacheConfig, M: ManagerReadWrite>
             hooks: PvmHooks::none(),
 
             reveal_request_response_map: self.reveal_request_response_map.clone(),
+
+            hashing_threads: self.hashing_threads,
         };
 
         let stepper = stepper.try_step_partial()?;
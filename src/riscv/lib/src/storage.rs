@@ -175,6 +175,11 @@ impl Repo {
         Ok(bytes)
     }
 
+    /// Open a reader over the bytes committed under `id`, if the commit exists.
+    pub fn checkout_reader(&self, id: &Hash) -> Result<impl io::Read + '_, StorageError> {
+        chunked_io::ChunkedReader::new(&self.backend, id)
+    }
+
     /// Checkout something deserialisable from the store.
     pub fn checkout_serialised<S: serde::de::DeserializeOwned>(
         &self,
@@ -2,6 +2,8 @@
 //
 // SPDX-License-Identifier: MIT
 
+use octez_riscv::machine_state::memory::MemorySize;
+use octez_riscv::pvm::node_pvm::DEFAULT_MEMORY_SIZE;
 use octez_riscv::pvm::node_pvm::NodePvm;
 use octez_riscv::pvm::node_pvm::PvmStorage;
 use octez_riscv::storage::Repo;
@@ -151,3 +153,33 @@ fn test_pvm_storage() {
     assert_eq!(id, id2);
     repo.close()
 }
+
+#[test]
+fn test_pvm_storage_memory_size() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let mut repo = PvmStorage::load(tmp_dir.path()).unwrap();
+
+    let default = NodePvm::empty();
+    assert_eq!(default.memory_size(), DEFAULT_MEMORY_SIZE);
+
+    // The memory size is part of the commit, so that states only differing in memory size are
+    // committed separately and checked out with their own size
+    let small = NodePvm::empty_with_memory_size(MemorySize::M1M);
+    assert_eq!(small.memory_size(), MemorySize::M1M);
+    assert_ne!(small, default);
+
+    let small_id = repo.commit(&small).unwrap();
+    let default_id = repo.commit(&default).unwrap();
+    assert_ne!(small_id, default_id);
+
+    let checked_out_small = repo.checkout(&small_id).unwrap();
+    assert_eq!(checked_out_small.memory_size(), MemorySize::M1M);
+    assert_eq!(checked_out_small, small);
+    assert_eq!(checked_out_small.hash(), small.hash());
+
+    let checked_out_default = repo.checkout(&default_id).unwrap();
+    assert_eq!(checked_out_default.memory_size(), DEFAULT_MEMORY_SIZE);
+    assert_eq!(checked_out_default, default);
+
+    repo.close()
+}
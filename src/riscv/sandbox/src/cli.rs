@@ -4,11 +4,16 @@
 // SPDX-License-Identifier: MIT
 
 use std::error::Error;
+use std::num::NonZeroU64;
+use std::num::NonZeroUsize;
+use std::num::ParseIntError;
 use std::path::Path;
 
 use clap::Parser;
 use clap::Subcommand;
 use clap::ValueEnum;
+use octez_riscv::machine_state::memory::MemorySize;
+use octez_riscv::state_backend::hash::Hash;
 
 #[derive(Debug, Clone, Subcommand)]
 pub enum Mode {
@@ -19,6 +24,9 @@ pub enum Mode {
     /// Launch a gdb server, for debugging the given program.
     #[clap(alias = "debug")]
     GdbServer(GdbServerOptions),
+    /// Run a block dumped by the JIT under both the interpreter and the JIT, and compare the
+    /// resulting machine states.
+    ReplayBlock(ReplayBlockOptions),
 }
 
 #[derive(Clone, ValueEnum, Debug)]
@@ -61,6 +69,9 @@ pub struct RunOptions {
     #[arg(long, default_value_t = false)]
     pub print_steps: bool,
 
+    #[command(flatten)]
+    pub jit: JitOptions,
+
     /// Options for controlling the output of recorded metrics.
     #[cfg(feature = "metrics")]
     #[command(flatten)]
@@ -81,6 +92,10 @@ pub struct GdbServerOptions {
     #[arg(long, short)]
     pub port: u16,
 
+    /// Size of the memory of the machine: 4KiB, 8KiB, 1MiB, 64MiB, 1GiB or 4GiB.
+    #[arg(long, default_value_t = MemorySize::M1G)]
+    pub memory_size: MemorySize,
+
     #[command(flatten)]
     pub preimage: PreimageOptions,
 
@@ -88,6 +103,29 @@ pub struct GdbServerOptions {
     pub inbox: InboxOptions,
 }
 
+#[derive(Debug, Clone, Parser)]
+pub struct ReplayBlockOptions {
+    /// Directory the JIT dumped compiled code to, see `--jit-dump-dir`.
+    #[arg(long)]
+    pub dump_dir: Box<Path>,
+
+    /// Hash of the block to replay.
+    #[arg(long)]
+    pub hash: Hash,
+
+    /// Address of the block, in decimal or hexadecimal (prefixed with `0x`).
+    #[arg(long, default_value = "0", value_parser = parse_address)]
+    pub pc: u64,
+}
+
+/// Parser for addresses given in decimal or hexadecimal.
+fn parse_address(address: &str) -> Result<u64, ParseIntError> {
+    match address.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => address.parse(),
+    }
+}
+
 #[derive(Clone, ValueEnum, Debug)]
 pub enum BenchMode {
     Simple,
@@ -247,6 +285,10 @@ pub struct CommonOptions {
     #[arg(long)]
     pub max_steps: Option<usize>,
 
+    /// Size of the memory of the machine: 4KiB, 8KiB, 1MiB, 64MiB, 1GiB or 4GiB.
+    #[arg(long, default_value_t = MemorySize::M1G)]
+    pub memory_size: MemorySize,
+
     #[command(flatten)]
     pub inbox: InboxOptions,
 
@@ -257,6 +299,53 @@ pub struct CommonOptions {
     pub preimage: PreimageOptions,
 }
 
+#[derive(Debug, Clone, Parser)]
+pub struct JitOptions {
+    /// Directory in which JIT-compiled code is persisted across runs.
+    #[arg(long)]
+    pub jit_cache_dir: Option<Box<Path>>,
+
+    /// Directory to dump the instructions, Cranelift IR and host machine code of every block
+    /// compiled by the JIT.
+    #[arg(long)]
+    pub jit_dump_dir: Option<Box<Path>>,
+
+    /// Maximum size of the code compiled by the JIT, in bytes. Once exceeded, the code of the
+    /// least recently compiled blocks is evicted, and those blocks are interpreted until compiled
+    /// again.
+    #[arg(long)]
+    pub jit_code_budget: Option<NonZeroUsize>,
+
+    /// Number of background threads compiling blocks with the JIT.
+    #[arg(long)]
+    pub jit_threads: Option<NonZeroUsize>,
+
+    /// Number of calls to a block, after which it is compiled.
+    #[arg(long)]
+    pub jit_threshold: Option<usize>,
+
+    /// Number of calls to uncompiled blocks, after which the call counts of all blocks are halved.
+    #[arg(long)]
+    pub jit_decay_interval: Option<NonZeroU64>,
+
+    /// Maximum number of blocks waiting to be compiled.
+    #[arg(long)]
+    pub jit_max_queued: Option<NonZeroUsize>,
+
+    /// Hash of a block that must never be compiled. May be given multiple times.
+    #[arg(long)]
+    pub jit_never_compile: Vec<Hash>,
+
+    /// Lower the compilation threshold as the kernel keeps running, down to the given minimum.
+    #[arg(long)]
+    pub jit_adaptive_min_threshold: Option<usize>,
+
+    /// Number of runs of a compiled block, after which the path taken from it is compiled into a
+    /// superblock. No superblocks are formed if not given.
+    #[arg(long)]
+    pub jit_superblock_threshold: Option<usize>,
+}
+
 #[derive(Debug, Clone, Parser)]
 pub struct InboxOptions {
     /// Keep going after the inbox has been drained.
@@ -5,8 +5,10 @@
 
 pub mod bench;
 mod gdb;
+mod replay_block;
 pub mod run;
 
 pub use bench::bench;
 pub use gdb::gdb_server;
+pub use replay_block::replay_block;
 pub use run::run;
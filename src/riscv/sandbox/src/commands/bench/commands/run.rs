@@ -10,6 +10,7 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use enum_tag::EnumTag;
+use octez_riscv::dispatch_memory_size;
 use octez_riscv::machine_state::memory::Address;
 use octez_riscv::machine_state::memory::Memory;
 use octez_riscv::parser::instruction::Instr;
@@ -141,16 +142,18 @@ fn bench_iteration(path: &Path, opts: &BenchRunOptions) -> Result<BenchData, Box
     let program = std::fs::read(path)?;
     let initrd = opts.initrd.as_ref().map(fs::read).transpose()?;
 
-    let mut stepper = make_pvm_stepper::<BlockImpl>(
-        program.as_slice(),
-        initrd.as_deref(),
-        &opts.common,
-        Default::default(),
-    )?;
-
-    Ok(match opts.mode {
-        BenchMode::Simple => bench_simple(&mut stepper, opts),
-        BenchMode::Fine => bench_fine(&mut stepper, opts),
+    dispatch_memory_size!(opts.common.memory_size, MC => {
+        let mut stepper = make_pvm_stepper::<MC, BlockImpl<MC>>(
+            program.as_slice(),
+            initrd.as_deref(),
+            &opts.common,
+            Default::default(),
+        )?;
+
+        Ok(match opts.mode {
+            BenchMode::Simple => bench_simple(&mut stepper, opts),
+            BenchMode::Fine => bench_fine(&mut stepper, opts),
+        })
     })
 }
 
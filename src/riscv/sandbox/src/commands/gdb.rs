@@ -37,9 +37,9 @@ use gdbstub::target::ext::breakpoints::SwBreakpoint;
 use gdbstub::target::ext::breakpoints::SwBreakpointOps;
 use gdbstub::target::ext::exec_file::ExecFile;
 use gdbstub_arch::riscv::reg::RiscvCoreRegs;
+use octez_riscv::dispatch_memory_size;
 use octez_riscv::machine_state::block_cache::block::InterpretedBlockBuilder;
 use octez_riscv::machine_state::memory::BadMemoryAccess;
-use octez_riscv::machine_state::memory::M1G;
 use octez_riscv::machine_state::memory::Memory;
 use octez_riscv::pvm::PvmHooks;
 use octez_riscv::state_backend::FnManagerIdent;
@@ -71,30 +71,32 @@ pub fn gdb_server(opts: GdbServerOptions) -> Result<(), Box<dyn Error>> {
 
     let rollup_address = SmartRollupAddress::from_b58check(opts.inbox.address.as_str())?;
 
-    let mut stepper = PvmStepper::<M1G>::new(
-        program.as_slice(),
-        initrd.as_deref(),
-        inbox,
-        PvmHooks::default(),
-        rollup_address.into_hash().as_ref().try_into()?,
-        opts.inbox.origination_level,
-        opts.preimage.preimages_dir,
-        InterpretedBlockBuilder,
-    )?;
-
-    // loop
-    let mut target = RiscvGdb {
-        fname,
-        stepper: &mut stepper,
-        breakpoints: HashSet::new(),
-        single_step: false,
-    };
-
-    let connection = wait_for_gdb_connection(opts.port)?;
-    let connection: Box<dyn ConnectionExt<Error = std::io::Error>> = Box::new(connection);
-    let debugger = GdbStub::new(connection);
-
-    let disconnect = debugger.run_blocking::<RiscvEventLoop<_>>(&mut target)?;
+    let disconnect = dispatch_memory_size!(opts.memory_size, MC => {
+        let mut stepper = PvmStepper::<MC>::new(
+            program.as_slice(),
+            initrd.as_deref(),
+            inbox,
+            PvmHooks::default(),
+            rollup_address.into_hash().as_ref().try_into()?,
+            opts.inbox.origination_level,
+            opts.preimage.preimages_dir,
+            InterpretedBlockBuilder,
+        )?;
+
+        // loop
+        let mut target = RiscvGdb {
+            fname,
+            stepper: &mut stepper,
+            breakpoints: HashSet::new(),
+            single_step: false,
+        };
+
+        let connection = wait_for_gdb_connection(opts.port)?;
+        let connection: Box<dyn ConnectionExt<Error = std::io::Error>> = Box::new(connection);
+        let debugger = GdbStub::new(connection);
+
+        debugger.run_blocking::<RiscvEventLoop<_>>(&mut target)?
+    });
 
     eprintln!("{:?}", disconnect);
     Ok(())
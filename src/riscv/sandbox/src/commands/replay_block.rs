@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2025 TriliTech <contact@trili.tech>
+//
+// SPDX-License-Identifier: MIT
+
+//! Replay of blocks dumped by the JIT, to track down differences between the interpreter and the
+//! JIT.
+
+use std::error::Error;
+
+use octez_riscv::jit::dump::CodeDump;
+use octez_riscv::machine_state::memory::M1M;
+
+use crate::cli::ReplayBlockOptions;
+
+pub fn replay_block(opts: ReplayBlockOptions) -> Result<(), Box<dyn Error>> {
+    let dump = CodeDump::open(&opts.dump_dir)?;
+    let instr = dump.instructions(&opts.hash)?;
+
+    let replay = octez_riscv::jit::dump::replay_block::<M1M>(&instr, opts.pc)?;
+
+    println!("Interpreted: {:?}", replay.interpreted);
+    println!("JIT:         {:?}", replay.jitted);
+
+    for difference in replay.differences.iter() {
+        println!(
+            "{}: interpreted = {}, jitted = {}",
+            difference.path, difference.interpreted, difference.jitted
+        );
+    }
+
+    if !replay.is_identical() {
+        return Err("The interpreter and the JIT disagree".into());
+    }
+
+    println!("The interpreter and the JIT agree");
+    Ok(())
+}
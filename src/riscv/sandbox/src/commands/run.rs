@@ -8,10 +8,17 @@ use std::fs;
 use std::io::Write;
 use std::ops::Bound;
 
+use octez_riscv::dispatch_memory_size;
+#[cfg(not(feature = "disable-jit"))]
+use octez_riscv::jit::JIT;
+#[cfg(not(feature = "disable-jit"))]
+use octez_riscv::jit::JitError;
 use octez_riscv::machine_state::block_cache::DefaultCacheConfig;
 use octez_riscv::machine_state::block_cache::block;
 use octez_riscv::machine_state::block_cache::block::Block;
-use octez_riscv::machine_state::memory::M1G;
+use octez_riscv::machine_state::block_cache::block::TieringMode;
+use octez_riscv::machine_state::block_cache::block::TieringPolicy;
+use octez_riscv::machine_state::memory::MemoryConfig;
 use octez_riscv::pvm::PvmHooks;
 use octez_riscv::state_backend::owned_backend::Owned;
 use octez_riscv::stepper::StepResult;
@@ -23,20 +30,21 @@ use tezos_smart_rollup::utils::inbox::InboxBuilder;
 use tezos_smart_rollup_encoding::smart_rollup::SmartRollupAddress;
 
 use crate::cli::CommonOptions;
+use crate::cli::JitOptions;
 use crate::cli::RunOptions;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "disable-jit")] {
         /// Inner execution strategy for blocks.
-        type BlockImplInner = block::Interpreted<M1G, Owned>;
+        type BlockImplInner<MC> = block::Interpreted<MC, Owned>;
     } else if #[cfg(feature = "inline-jit")] {
         /// Inner execution strategy for blocks.
-        type BlockImplInner = block::Jitted<octez_riscv::machine_state::block_cache::block::InlineCompiler<M1G, Owned>, M1G, Owned>;
+        type BlockImplInner<MC> = block::Jitted<octez_riscv::machine_state::block_cache::block::InlineCompiler<MC, Owned>, MC, Owned>;
     } else {
         /// Inner execution strategy for blocks.
-        type BlockImplInner = block::Jitted<
-            octez_riscv::machine_state::block_cache::block::OutlineCompiler<M1G, Owned>,
-            M1G,
+        type BlockImplInner<MC> = block::Jitted<
+            octez_riscv::machine_state::block_cache::block::OutlineCompiler<MC, Owned>,
+            MC,
             Owned,
         >;
     }
@@ -44,24 +52,27 @@ cfg_if::cfg_if! {
 
 /// Executor of blocks
 #[cfg(not(feature = "metrics"))]
-pub type BlockImpl = BlockImplInner;
+pub type BlockImpl<MC> = BlockImplInner<MC>;
 
 /// Executor of blocks
 #[cfg(feature = "metrics")]
-pub type BlockImpl = octez_riscv::machine_state::block_cache::metrics::BlockMetrics<BlockImplInner>;
+pub type BlockImpl<MC> =
+    octez_riscv::machine_state::block_cache::metrics::BlockMetrics<BlockImplInner<MC>>;
 
 pub fn run(opts: RunOptions) -> Result<(), Box<dyn Error>> {
     let program = fs::read(&opts.input)?;
     let initrd = opts.initrd.as_ref().map(fs::read).transpose()?;
 
-    let stepper = make_pvm_stepper::<BlockImpl>(
-        program.as_slice(),
-        initrd.as_deref(),
-        &opts.common,
-        Default::default(),
-    )?;
+    let steps = dispatch_memory_size!(opts.common.memory_size, MC => {
+        let stepper = make_pvm_stepper::<MC, BlockImpl<MC>>(
+            program.as_slice(),
+            initrd.as_deref(),
+            &opts.common,
+            make_block_builder::<MC>(&opts.jit)?,
+        )?;
 
-    let steps = run_stepper(stepper, opts.common.max_steps)?;
+        run_stepper(stepper, opts.common.max_steps)?
+    });
 
     if opts.print_steps {
         println!("Run consumed {steps} steps.");
@@ -76,13 +87,110 @@ pub fn run(opts: RunOptions) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-pub(crate) fn make_pvm_stepper<B: Block<M1G, Owned>>(
+/// Policy deciding when blocks are JIT-compiled, if any was given in the options.
+fn tiering_policy(opts: &JitOptions) -> Option<TieringPolicy> {
+    let JitOptions {
+        jit_threshold,
+        jit_decay_interval,
+        jit_max_queued,
+        jit_never_compile,
+        jit_adaptive_min_threshold,
+        jit_superblock_threshold,
+        ..
+    } = opts;
+
+    if jit_threshold.is_none()
+        && jit_decay_interval.is_none()
+        && jit_max_queued.is_none()
+        && jit_never_compile.is_empty()
+        && jit_adaptive_min_threshold.is_none()
+        && jit_superblock_threshold.is_none()
+    {
+        return None;
+    }
+
+    let mode = match jit_adaptive_min_threshold {
+        Some(min_threshold) => TieringMode::Adaptive {
+            min_threshold: *min_threshold,
+        },
+        None => TieringMode::Fixed,
+    };
+
+    Some(TieringPolicy {
+        threshold: jit_threshold.unwrap_or(block::DEFAULT_THRESHOLD),
+        decay_interval: *jit_decay_interval,
+        max_queued: *jit_max_queued,
+        never_compile: jit_never_compile.iter().copied().collect(),
+        mode,
+        superblock_threshold: *jit_superblock_threshold,
+    })
+}
+
+/// Create the block builder, configuring the JIT from the given options.
+fn make_block_builder<MC: MemoryConfig + Send>(
+    opts: &JitOptions,
+) -> Result<<BlockImpl<MC> as Block<MC, Owned>>::BlockBuilder, Box<dyn Error>> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "disable-jit")] {
+            if opts.jit_cache_dir.is_some()
+                || opts.jit_dump_dir.is_some()
+                || opts.jit_code_budget.is_some()
+                || opts.jit_threads.is_some()
+                || tiering_policy(opts).is_some()
+            {
+                return Err("JIT options given, but the JIT is disabled".into());
+            }
+
+            Ok(Default::default())
+        } else if #[cfg(feature = "inline-jit")] {
+            if opts.jit_threads.is_some() {
+                return Err("Compiler threads given, but the JIT compiles inline".into());
+            }
+
+            let mut compiler = block::InlineCompiler::with_jit(make_jit(opts)?);
+
+            if let Some(policy) = tiering_policy(opts) {
+                compiler.set_tiering(policy);
+            }
+
+            Ok(compiler)
+        } else {
+            let mut compiler =
+                block::OutlineCompiler::with_jit_factory(opts.jit_threads, || make_jit(opts))?;
+
+            if let Some(policy) = tiering_policy(opts) {
+                compiler.set_tiering(policy);
+            }
+
+            Ok(compiler)
+        }
+    }
+}
+
+/// Create a JIT, configured from the given options.
+#[cfg(not(feature = "disable-jit"))]
+fn make_jit<MC: MemoryConfig>(opts: &JitOptions) -> Result<JIT<MC, Owned>, JitError> {
+    let mut jit = match opts.jit_cache_dir.as_deref() {
+        None => JIT::new()?,
+        Some(dir) => JIT::with_code_cache(dir)?,
+    };
+
+    if let Some(dir) = opts.jit_dump_dir.as_deref() {
+        jit.enable_dump(dir)?;
+    }
+
+    jit.set_code_budget(opts.jit_code_budget);
+
+    Ok(jit)
+}
+
+pub(crate) fn make_pvm_stepper<MC: MemoryConfig, B: Block<MC, Owned>>(
     program: &[u8],
     initrd: Option<&[u8]>,
     common: &CommonOptions,
     block_builder: B::BlockBuilder,
 ) -> Result<
-    PvmStepper<'static, M1G, DefaultCacheConfig, Owned, B>,
+    PvmStepper<'static, MC, DefaultCacheConfig, Owned, B>,
     std::boxed::Box<dyn std::error::Error>,
 > {
     let mut inbox = InboxBuilder::new();
@@ -102,7 +210,7 @@ pub(crate) fn make_pvm_stepper<B: Block<M1G, Owned>>(
         let _written = console.write(&[c]).unwrap();
     });
 
-    let stepper = PvmStepper::<'_, M1G, DefaultCacheConfig, Owned, B>::new(
+    let stepper = PvmStepper::<'_, MC, DefaultCacheConfig, Owned, B>::new(
         program,
         initrd,
         inbox.build(),
@@ -13,6 +13,7 @@ use octez_riscv::stepper::StepperStatus;
 
 use self::commands::bench;
 use self::commands::gdb_server;
+use self::commands::replay_block;
 use self::commands::run;
 
 fn format_status(result: &StepperStatus) -> String {
@@ -58,5 +59,6 @@ fn main() -> Result<(), Box<dyn Error>> {
         cli::Mode::Run(opts) => run(opts),
         cli::Mode::Bench(opts) => bench(opts),
         cli::Mode::GdbServer(opts) => gdb_server(opts),
+        cli::Mode::ReplayBlock(opts) => replay_block(opts),
     }
 }